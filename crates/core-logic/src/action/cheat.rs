@@ -7,10 +7,11 @@ use crate::{
     component::{ActionEndNotification, AfterActionPerformNotification, VerifyResult},
     input_parser::{InputParseError, InputParser},
     resource::{AttributeNameCatalog, SkillNameCatalog},
+    spawn_prototype,
     vital_change::{ValueChangeOperation, VitalChangeMessageParams, VitalChangeVisualizationType},
-    ActionTag, BasicTokens, BeforeActionNotification, CommandTarget, Description, MessageCategory,
-    MessageDelay, MessageFormat, NoTokens, Notification, Stat, Stats, VerifyActionNotification,
-    VitalChange, VitalType, World, Xp, XpAwardNotification,
+    ActionTag, BasicTokens, BeforeActionNotification, CommandTarget, Description, Location,
+    MessageCategory, MessageDelay, MessageFormat, NoTokens, Notification, Stat, Stats,
+    VerifyActionNotification, VitalChange, VitalType, World, Xp, XpAwardNotification,
 };
 
 use super::{Action, ActionInterruptResult, ActionNotificationSender, ActionResult};
@@ -97,7 +98,15 @@ impl Action for CheatAction {
                 "set_energy",
                 world,
             ),
+            "set_body_temperature" => set_vital(
+                performing_entity,
+                &self.args,
+                VitalType::BodyTemperature,
+                "set_body_temperature",
+                world,
+            ),
             "set_stat" => set_stat(performing_entity, &self.args, world),
+            "spawn" => spawn(performing_entity, &self.args, world),
             x => ActionResult::error(performing_entity, format!("Unknown cheat command: {x}")),
         }
     }
@@ -349,3 +358,36 @@ fn set_stat(entity: Entity, args: &[String], world: &mut World) -> ActionResult
         Err(e) => ActionResult::error(entity, format!("Error: {e}")),
     }
 }
+
+fn spawn(entity: Entity, args: &[String], world: &mut World) -> ActionResult {
+    if args.len() != 1 {
+        return ActionResult::error(entity, "spawn requires 1 prototype ID".to_string());
+    }
+    let prototype = args[0].trim();
+
+    let Some(location) = world.get::<Location>(entity).map(|location| location.id) else {
+        return ActionResult::error(entity, "You aren't anywhere.".to_string());
+    };
+
+    match spawn_prototype(prototype, location, world) {
+        Ok(spawned_entity) => {
+            let message = MessageFormat::new("Spawned ${spawned.name}.")
+                .expect("message format should be valid")
+                .interpolate(
+                    entity,
+                    &BasicTokens::new().with_entity("spawned".into(), spawned_entity),
+                    world,
+                )
+                .expect("message should interpolate correctly");
+
+            ActionResult::message(
+                entity,
+                message,
+                MessageCategory::System,
+                MessageDelay::None,
+                false,
+            )
+        }
+        Err(e) => ActionResult::error(entity, format!("Error: {e}")),
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
     command_format::{entity_part, one_of_literal_part, optional_literal_part},
     component::{
         ActionEndNotification, AfterActionPerformNotification, Connection, Container, Description,
-        Room, VerifyResult,
+        LightLevel, Location, Room, VerifyResult,
     },
     game_map::Coordinates,
     input_parser::{input_formats_if_has_component, CommandTarget, InputParseError, InputParser},
@@ -162,6 +162,20 @@ impl Action for LookAction {
         }
 
         if let Some(desc) = target.get::<Description>() {
+            // things lying around in a dark room can't be made out, but things being carried can still be felt
+            let in_dark_room = target
+                .get::<Location>()
+                .filter(|location| world.get::<Room>(location.id).is_some())
+                .is_some_and(|location| {
+                    LightLevel::of_room(location.id, world) == LightLevel::Dark
+                });
+            if in_dark_room && self.target != performing_entity {
+                return ActionResult::error(
+                    performing_entity,
+                    "It's too dark to see that clearly.".to_string(),
+                );
+            }
+
             let message = if self.detailed {
                 GameMessage::DetailedEntity(DetailedEntityDescription::for_entity(
                     performing_entity,
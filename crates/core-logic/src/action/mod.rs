@@ -42,8 +42,8 @@ pub use put::PutAction;
 pub use put::PutParser;
 
 mod throw;
-#[expect(unused)]
 pub use throw::ThrowAction;
+pub use throw::ThrowMessageTokens;
 pub use throw::ThrowParser;
 
 mod pour;
@@ -125,6 +125,13 @@ pub use spend_advacement_point::SpendAttributePointParser;
 pub use spend_advacement_point::SpendSkillPointAction;
 pub use spend_advacement_point::SpendSkillPointParser;
 
+mod preferences;
+#[expect(unused)]
+pub use preferences::PreferencesAction;
+pub use preferences::PreferencesParser;
+pub use preferences::ScreenReaderParser;
+pub use preferences::SetPreferenceParser;
+
 mod cheat;
 pub use cheat::CheatParser;
 
@@ -16,6 +16,7 @@ use crate::{
     input_parser::{CommandTarget, InputParseError, InputParser},
     move_entity,
     notification::Notification,
+    resource::{Weather, WeatherKind},
     ActionTag, BasicTokens, BeforeActionNotification, Direction, DynamicMessage,
     DynamicMessageLocation, InternalMessageCategory, MessageCategory, MessageDelay, MessageFormat,
     SurroundingsMessageCategory, VerifyActionNotification, STANDARD_CHECK_XP,
@@ -59,6 +60,7 @@ impl InputParser for MoveParser {
             Ok(parsed) => {
                 return Ok(Box::new(MoveAction {
                     direction: parsed.get(DIRECTION_PART_ID),
+                    slowed_ticks: 0,
                     notification_sender: ActionNotificationSender::new(),
                 }));
             }
@@ -72,6 +74,7 @@ impl InputParser for MoveParser {
         let parsed = MOVE_WITH_VERB_FORMAT.parse(input, source_entity, world)?;
         Ok(Box::new(MoveAction {
             direction: parsed.get(DIRECTION_PART_ID),
+            slowed_ticks: 0,
             notification_sender: ActionNotificationSender::new(),
         }))
     }
@@ -89,6 +92,8 @@ impl InputParser for MoveParser {
 #[derive(Debug)]
 pub struct MoveAction {
     pub direction: Direction,
+    /// How many ticks the moving entity has been slowed down by the weather for so far.
+    pub slowed_ticks: u8,
     pub notification_sender: ActionNotificationSender<Self>,
 }
 
@@ -112,6 +117,24 @@ impl Action for MoveAction {
             let new_room_id = connection.destination;
             should_tick = true;
 
+            // bad weather makes it take longer to get around outside
+            if let Some((penalty_ticks, message)) = Weather::around(performing_entity, world)
+                .and_then(WeatherKind::get_movement_penalty)
+            {
+                if self.slowed_ticks < penalty_ticks {
+                    if self.slowed_ticks == 0 {
+                        result_builder = result_builder.with_message(
+                            performing_entity,
+                            message.to_string(),
+                            MessageCategory::Internal(InternalMessageCategory::Action),
+                            MessageDelay::Short,
+                        );
+                    }
+                    self.slowed_ticks += 1;
+                    return result_builder.build_incomplete(true);
+                }
+            }
+
             let can_move;
             (result_builder, can_move) = try_escape_combat(
                 performing_entity,
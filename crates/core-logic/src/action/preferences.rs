@@ -0,0 +1,195 @@
+use std::{collections::HashSet, sync::LazyLock};
+
+use bevy_ecs::prelude::*;
+use nonempty::nonempty;
+
+use crate::{
+    command_format::{
+        any_text_part, literal_part, one_of_literal_part, CommandFormat, CommandPartId,
+    },
+    component::{ActionEndNotification, AfterActionPerformNotification, Player, VerifyResult},
+    input_parser::{InputParseError, InputParser},
+    ActionTag, BeforeActionNotification, GameMessage, VerifyActionNotification, World,
+};
+
+use super::{Action, ActionInterruptResult, ActionNotificationSender, ActionResult};
+
+static SETTING_PART_ID: CommandPartId<String> = CommandPartId::new("setting");
+static VALUE_PART_ID: CommandPartId<String> = CommandPartId::new("value");
+
+static PREFERENCES_FORMAT: LazyLock<CommandFormat> =
+    LazyLock::new(|| CommandFormat::new(one_of_literal_part(nonempty!["set", "config"])));
+
+static SET_PREFERENCE_FORMAT: LazyLock<CommandFormat> = LazyLock::new(|| {
+    CommandFormat::new(one_of_literal_part(nonempty!["set", "config"]))
+        .then(literal_part(" "))
+        .then(
+            any_text_part(SETTING_PART_ID)
+                .with_if_unparsed("what")
+                .with_placeholder_for_format_string("setting"),
+        )
+        .then(literal_part(" "))
+        .then(
+            any_text_part(VALUE_PART_ID)
+                .with_if_unparsed("to what")
+                .with_placeholder_for_format_string("value"),
+        )
+});
+
+static SCREEN_READER_FORMAT: LazyLock<CommandFormat> = LazyLock::new(|| {
+    CommandFormat::new(literal_part("screenreader"))
+        .then(literal_part(" "))
+        .then(
+            any_text_part(VALUE_PART_ID)
+                .with_if_unparsed("on or off")
+                .with_placeholder_for_format_string("on/off"),
+        )
+});
+
+pub struct PreferencesParser;
+pub struct SetPreferenceParser;
+pub struct ScreenReaderParser;
+
+impl InputParser for PreferencesParser {
+    fn parse(
+        &self,
+        input: &str,
+        source_entity: Entity,
+        world: &World,
+    ) -> Result<Box<dyn Action>, InputParseError> {
+        PREFERENCES_FORMAT.parse(input, source_entity, world)?;
+        Ok(Box::new(PreferencesAction {
+            change: None,
+            notification_sender: ActionNotificationSender::new(),
+        }))
+    }
+
+    fn get_input_formats(&self) -> Vec<String> {
+        vec![PREFERENCES_FORMAT.get_format_description().to_string()]
+    }
+
+    fn get_input_formats_for(&self, _: Entity, _: Entity, _: &World) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+impl InputParser for SetPreferenceParser {
+    fn parse(
+        &self,
+        input: &str,
+        source_entity: Entity,
+        world: &World,
+    ) -> Result<Box<dyn Action>, InputParseError> {
+        let parsed = SET_PREFERENCE_FORMAT.parse(input, source_entity, world)?;
+        Ok(Box::new(PreferencesAction {
+            change: Some((parsed.get(SETTING_PART_ID), parsed.get(VALUE_PART_ID))),
+            notification_sender: ActionNotificationSender::new(),
+        }))
+    }
+
+    fn get_input_formats(&self) -> Vec<String> {
+        vec![SET_PREFERENCE_FORMAT.get_format_description().to_string()]
+    }
+
+    fn get_input_formats_for(&self, _: Entity, _: Entity, _: &World) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+impl InputParser for ScreenReaderParser {
+    fn parse(
+        &self,
+        input: &str,
+        source_entity: Entity,
+        world: &World,
+    ) -> Result<Box<dyn Action>, InputParseError> {
+        let parsed = SCREEN_READER_FORMAT.parse(input, source_entity, world)?;
+        Ok(Box::new(PreferencesAction {
+            change: Some(("screenreader".to_string(), parsed.get(VALUE_PART_ID))),
+            notification_sender: ActionNotificationSender::new(),
+        }))
+    }
+
+    fn get_input_formats(&self) -> Vec<String> {
+        vec![SCREEN_READER_FORMAT.get_format_description().to_string()]
+    }
+
+    fn get_input_formats_for(&self, _: Entity, _: Entity, _: &World) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Shows an entity their preferences, optionally changing one of them first.
+#[derive(Debug)]
+pub struct PreferencesAction {
+    /// The name of the setting to change, and the value to change it to.
+    pub change: Option<(String, String)>,
+    pub notification_sender: ActionNotificationSender<Self>,
+}
+
+impl Action for PreferencesAction {
+    fn perform(&mut self, performing_entity: Entity, world: &mut World) -> ActionResult {
+        let Some(mut player) = world.get_mut::<Player>(performing_entity) else {
+            return ActionResult::error(
+                performing_entity,
+                "Only players have preferences.".to_string(),
+            );
+        };
+
+        if let Some((setting, value)) = &self.change {
+            if let Err(e) = player.preferences.set(setting, value) {
+                return ActionResult::error(performing_entity, e.to_string());
+            }
+        }
+
+        let message = GameMessage::Preferences(player.preferences.clone());
+
+        ActionResult::builder()
+            .with_game_message(performing_entity, message)
+            .build_complete_no_tick(true)
+    }
+
+    fn interrupt(&self, _: Entity, _: &mut World) -> ActionInterruptResult {
+        ActionInterruptResult::none()
+    }
+
+    fn may_require_tick(&self) -> bool {
+        false
+    }
+
+    fn get_tags(&self) -> HashSet<ActionTag> {
+        [].into()
+    }
+
+    fn send_before_notification(
+        &self,
+        notification_type: BeforeActionNotification,
+        world: &mut World,
+    ) {
+        self.notification_sender
+            .send_before_notification(notification_type, self, world);
+    }
+
+    fn send_verify_notification(
+        &self,
+        notification_type: VerifyActionNotification,
+        world: &mut World,
+    ) -> Vec<VerifyResult> {
+        self.notification_sender
+            .send_verify_notification(notification_type, self, world)
+    }
+
+    fn send_after_perform_notification(
+        &self,
+        notification_type: AfterActionPerformNotification,
+        world: &mut World,
+    ) {
+        self.notification_sender
+            .send_after_perform_notification(notification_type, self, world);
+    }
+
+    fn send_end_notification(&self, notification_type: ActionEndNotification, world: &mut World) {
+        self.notification_sender
+            .send_end_notification(notification_type, self, world);
+    }
+}
@@ -706,7 +706,7 @@ mod tests {
             TestPlayer,
         },
         world_setup::spawn_room,
-        Color, Game, GameOptions, MapIcon, SpawnRoom, Time,
+        Color, Game, GameOptions, MapIcon, NewCharacter, SpawnRoom, Time,
     };
 
     use super::*;
@@ -1725,7 +1725,8 @@ mod tests {
         let mut game = Game::new(GameOptions {
             skip_worldgen: true,
             ..GameOptions::default()
-        });
+        })
+        .unwrap();
 
         let mut world = game.world.write().unwrap();
         let room_coords = Coordinates {
@@ -1747,7 +1748,7 @@ mod tests {
         drop(world);
 
         let (p1_entity, p1_command_sender, p1_message_receiver) =
-            game.add_player("player 1".to_string());
+            game.add_player(NewCharacter::new("player 1".to_string()));
         let player_1 = TestPlayer {
             entity: p1_entity,
             command_sender: p1_command_sender,
@@ -1756,7 +1757,7 @@ mod tests {
 
         let player_2 = if let NumPlayers::Two = num_players {
             let (p2_entity, p2_command_sender, p2_message_receiver) =
-                game.add_player("player 2".to_string());
+                game.add_player(NewCharacter::new("player 2".to_string()));
             Some(TestPlayer {
                 entity: p2_entity,
                 command_sender: p2_command_sender,
@@ -19,14 +19,18 @@ use crate::{
     input_parser::{input_formats_if_has_component, InputParseError, InputParser},
     is_living_entity, move_entity,
     notification::Notification,
+    resource::{
+        DODGE_EXTREME_FAIL, DODGE_EXTREME_SUCCESS, DODGE_FAIL, DODGE_SUCCESS, THROW_EXTREME_FAIL,
+        THROW_EXTREME_SUCCESS, THROW_FAIL, THROW_SUCCESS,
+    },
     vital_change::{
         ValueChangeOperation, VitalChange, VitalChangeMessageParams, VitalChangeVisualizationType,
         VitalType,
     },
     ActionTag, BeforeActionNotification, Description, DynamicMessage, DynamicMessageLocation,
-    GameMessage, InternalMessageCategory, MessageCategory, MessageDelay, MessageFormat,
-    MessageTokens, SurroundingsMessageCategory, TokenName, TokenValue, VerifyActionNotification,
-    Volume, Xp, STANDARD_CHECK_XP,
+    FixedMessageTokens, GameMessage, InternalMessageCategory, MessageCategory, MessageDelay,
+    MessageTokens, SurroundingsMessageCategory, TokenKind, TokenName, TokenValue,
+    VerifyActionNotification, Volume, Xp, STANDARD_CHECK_XP,
 };
 
 use super::{Action, ActionInterruptResult, ActionNotificationSender, ActionResult, EquipAction};
@@ -256,41 +260,23 @@ impl Action for ThrowAction {
             target,
         };
 
-        let hit;
-        let dynamic_message = match throw_result {
-            CheckResult::ExtremeFailure => {
-                hit = false;
-                if target_is_living {
-                    build_dodge_extreme_success_message(tokens)
-                } else {
-                    build_throw_extreme_fail_message(tokens)
-                }
-            }
-            CheckResult::Failure => {
-                hit = false;
-                if target_is_living {
-                    build_dodge_success_message(tokens)
-                } else {
-                    build_throw_fail_message(tokens)
-                }
-            }
-            CheckResult::Success => {
-                hit = true;
-                if target_is_living {
-                    build_dodge_fail_message(tokens)
-                } else {
-                    build_throw_success_message(tokens)
-                }
-            }
-            CheckResult::ExtremeSuccess => {
-                hit = true;
-                if target_is_living {
-                    build_dodge_extreme_fail_message(tokens)
-                } else {
-                    build_throw_extreme_success_message(tokens)
-                }
-            }
+        let (hit, template) = match (throw_result, target_is_living) {
+            (CheckResult::ExtremeFailure, true) => (false, DODGE_EXTREME_SUCCESS),
+            (CheckResult::ExtremeFailure, false) => (false, THROW_EXTREME_FAIL),
+            (CheckResult::Failure, true) => (false, DODGE_SUCCESS),
+            (CheckResult::Failure, false) => (false, THROW_FAIL),
+            (CheckResult::Success, true) => (true, DODGE_FAIL),
+            (CheckResult::Success, false) => (true, THROW_SUCCESS),
+            (CheckResult::ExtremeSuccess, true) => (true, DODGE_EXTREME_FAIL),
+            (CheckResult::ExtremeSuccess, false) => (true, THROW_EXTREME_SUCCESS),
         };
+        let dynamic_message = DynamicMessage::new_from_template(
+            MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
+            MessageDelay::Short,
+            &template,
+            tokens,
+            world,
+        );
 
         if hit && target_is_living {
             let mut damage = get_hit_damage(item, world);
@@ -417,13 +403,13 @@ fn get_inanimate_target_difficulty(target: Entity, world: &World) -> CheckDiffic
 
 /// Tokens for messages about throws.
 #[derive(Debug)]
-struct ThrowMessageTokens {
+pub struct ThrowMessageTokens {
     /// The entity doing the throwing
-    thrower: Entity,
+    pub thrower: Entity,
     /// The entity getting thrown
-    item: Entity,
+    pub item: Entity,
     /// The entity getting thrown at
-    target: Entity,
+    pub target: Entity,
 }
 
 impl MessageTokens for ThrowMessageTokens {
@@ -437,102 +423,15 @@ impl MessageTokens for ThrowMessageTokens {
     }
 }
 
-/// Builds a message for when the throw was an extreme failure.
-fn build_throw_extreme_fail_message(
-    tokens: ThrowMessageTokens,
-) -> DynamicMessage<ThrowMessageTokens> {
-    DynamicMessage::new(
-        MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
-        MessageDelay::Short,
-        MessageFormat::<ThrowMessageTokens>::new("${thrower.Name} ${thrower.you:hurl/hurls} ${item.name} wildly, and it comes nowhere close to ${target.name}.")
-            .expect("message format should be valid"),
-        tokens,
-    )
-}
-
-/// Builds a message for when the throw was a failure.
-fn build_throw_fail_message(tokens: ThrowMessageTokens) -> DynamicMessage<ThrowMessageTokens> {
-    DynamicMessage::new(
-        MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
-        MessageDelay::Short,
-        MessageFormat::<ThrowMessageTokens>::new(
-            "${thrower.Name} ${thrower.you:throw/throws} ${item.name}, and ${item.they} ${item.whiz/whizzes} just past ${target.name}.",
-        )
-        .expect("message format should be valid"),
-        tokens,
-    )
-}
-
-/// Builds a message for when the dodge was an extreme failure.
-fn build_dodge_extreme_fail_message(
-    tokens: ThrowMessageTokens,
-) -> DynamicMessage<ThrowMessageTokens> {
-    DynamicMessage::new(
-        MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
-        MessageDelay::Short,
-        MessageFormat::<ThrowMessageTokens>::new("${thrower.Name} ${thrower.you:throw/throws} ${item.name}, and it seems like ${target.name} ${target.you:don't/doesn't} even try to move out of the way before ${item.they} ${item.hit/hits} ${target.them} directly in the face.")
-            .expect("message format should be valid"),
-            tokens,
-    )
-}
-
-/// Builds a message for when the dodge was a failure.
-fn build_dodge_fail_message(tokens: ThrowMessageTokens) -> DynamicMessage<ThrowMessageTokens> {
-    DynamicMessage::new(
-        MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
-        MessageDelay::Short,
-        MessageFormat::<ThrowMessageTokens>::new("${thrower.Name} ${thrower.you:throw/throws} ${item.name}, and ${target.name} ${target.you:aren't/isn't} able to get out of the way before ${item.they} ${item.hit/hits} ${target.them} in the chest.")
-            .expect("message format should be valid"),
-            tokens,
-    )
-}
-
-/// Builds a message for when the dodge was a success.
-fn build_dodge_success_message(tokens: ThrowMessageTokens) -> DynamicMessage<ThrowMessageTokens> {
-    DynamicMessage::new(
-        MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
-        MessageDelay::Short,
-        MessageFormat::<ThrowMessageTokens>::new("${thrower.Name} ${thrower.you:throw/throws} ${item.name}, but ${target.name} ${target.you:move/moves} out of the way just before ${item.they} ${item.hit/hits} ${target.them}.")
-            .expect("message format should be valid"),
-            tokens,
-    )
-}
-
-/// Builds a message for when the dodge was an extreme success.
-fn build_dodge_extreme_success_message(
-    tokens: ThrowMessageTokens,
-) -> DynamicMessage<ThrowMessageTokens> {
-    DynamicMessage::new(
-        MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
-        MessageDelay::Short,
-        MessageFormat::<ThrowMessageTokens>::new("${thrower.Name} ${thrower.you:throw/throws} ${item.name}, but ${target.name} calmly ${target.you:shift/shifts} just enough to avoid being hit.")
-            .expect("message format should be valid"),
-            tokens,
-    )
-}
-
-/// Builds a message for when the throw was a success and the target didn't attempt to dodge.
-fn build_throw_success_message(tokens: ThrowMessageTokens) -> DynamicMessage<ThrowMessageTokens> {
-    DynamicMessage::new(
-        MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
-        MessageDelay::Short,
-        MessageFormat::<ThrowMessageTokens>::new("${thrower.Name} ${thrower.you:throw/throws} ${item.name}, and ${item.they} ${item.hit/hits} ${target.name}.")
-            .expect("message format should be valid"),
-            tokens,
-    )
-}
-
-/// Builds a message for when the throw was an extreme success and the target didn't attempt to dodge.
-fn build_throw_extreme_success_message(
-    tokens: ThrowMessageTokens,
-) -> DynamicMessage<ThrowMessageTokens> {
-    DynamicMessage::new(
-        MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
-        MessageDelay::Short,
-        MessageFormat::<ThrowMessageTokens>::new("${thrower.Name} deftly ${thrower.you:throw/throws} ${item.name}, and ${item.they} ${item.impact/impacts} ${target.name} perfectly.")
-            .expect("message format should be valid"),
-            tokens,
-    )
+impl FixedMessageTokens for ThrowMessageTokens {
+    fn get_token_kinds() -> HashMap<TokenName, TokenKind> {
+        [
+            ("thrower".into(), TokenKind::Entity),
+            ("item".into(), TokenKind::Entity),
+            ("target".into(), TokenKind::Entity),
+        ]
+        .into()
+    }
 }
 
 /// Attempts to equip the item to throw automatically before an attempt is made to throw it.
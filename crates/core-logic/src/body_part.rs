@@ -1,19 +1,36 @@
 use std::collections::HashMap;
 
-use bevy_ecs::prelude::*;
-
+use bevy_ecs::{
+    entity::{EntityMapper, MapEntities},
+    prelude::*,
+};
 use rand_distr::{Distribution, WeightedError, WeightedIndex};
+use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
 /// The body parts an entity has.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct BodyParts {
     /// Map of body part types to entities of that type.
+    #[serde(with = "crate::map_as_pairs")]
     type_to_entities: HashMap<BodyPartType, Vec<Entity>>,
     /// Weights to use when randomly choosing a body part.
     weights: BodyPartWeights,
 }
 
+impl MapEntities for BodyParts {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for entities in self.type_to_entities.values_mut() {
+            for entity in entities.iter_mut() {
+                *entity = entity_mapper.map_entity(*entity);
+            }
+        }
+        for entity in self.weights.body_parts.iter_mut() {
+            *entity = entity_mapper.map_entity(*entity);
+        }
+    }
+}
+
 impl BodyParts {
     /// Gets all the body parts.
     pub fn get_all(&self) -> Vec<Entity> {
@@ -72,9 +89,11 @@ impl BodyParts {
     }
 }
 
-#[derive(Component)]
+#[derive(Component, Clone, Serialize, Deserialize)]
+#[serde(try_from = "Vec<(Entity, f32)>", into = "Vec<(Entity, f32)>")]
 pub struct BodyPartWeights {
     body_parts: Vec<Entity>,
+    weights: Vec<f32>,
     dist: WeightedIndex<f32>,
 }
 
@@ -82,22 +101,37 @@ impl BodyPartWeights {
     /// Initializes the weights. Returns an error if the weights are invalid per `WeightedIndex::new`.
     /// Note: it is assumed that all the keys in the provided map are body parts.
     fn new(part_to_weight: HashMap<Entity, f32>) -> Result<BodyPartWeights, WeightedError> {
-        let (body_parts, weights) = part_to_weight.iter().unzip();
+        BodyPartWeights::try_from(part_to_weight.into_iter().collect::<Vec<(Entity, f32)>>())
+    }
+
+    /// Gets a random body part entity.
+    pub fn get_random(&self) -> Entity {
+        self.body_parts[self.dist.sample(&mut rand::thread_rng())]
+    }
+}
+
+impl TryFrom<Vec<(Entity, f32)>> for BodyPartWeights {
+    type Error = WeightedError;
+
+    fn try_from(part_to_weight: Vec<(Entity, f32)>) -> Result<Self, Self::Error> {
+        let (body_parts, weights): (Vec<Entity>, Vec<f32>) = part_to_weight.into_iter().unzip();
 
         Ok(BodyPartWeights {
+            dist: WeightedIndex::new(&weights)?,
             body_parts,
-            dist: WeightedIndex::new::<Vec<f32>>(weights)?,
+            weights,
         })
     }
+}
 
-    /// Gets a random body part entity.
-    pub fn get_random(&self) -> Entity {
-        self.body_parts[self.dist.sample(&mut rand::thread_rng())]
+impl From<BodyPartWeights> for Vec<(Entity, f32)> {
+    fn from(value: BodyPartWeights) -> Self {
+        value.body_parts.into_iter().zip(value.weights).collect()
     }
 }
 
 /// A single body part of an entity.
-#[derive(Debug, Clone, Component)]
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
 pub struct BodyPart {
     /// The type of body part this is.
     pub part_type: BodyPartType,
@@ -105,8 +139,16 @@ pub struct BodyPart {
     pub attached_to: Option<Entity>,
 }
 
+impl MapEntities for BodyPart {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.attached_to = self
+            .attached_to
+            .map(|attached_to| entity_mapper.map_entity(attached_to));
+    }
+}
+
 /// Defines the different types of body part.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum BodyPartType {
     Head,
     Torso,
@@ -122,7 +164,7 @@ pub enum BodyPartType {
 }
 
 /// Amount to multiply damage by for attacks that hit this body part.
-#[derive(Component, Debug)]
+#[derive(Component, Debug, Serialize, Deserialize)]
 pub struct BodyPartDamageMultiplier(pub f32);
 
 impl BodyPart {
@@ -0,0 +1,332 @@
+use std::{collections::HashMap, fmt::Display};
+
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::{
+    component::{Attribute, Skill, Stats},
+    resource::{get_attribute_name, get_skill_name, AttributeNameCatalog, SkillNameCatalog},
+    Pronouns,
+};
+
+/// The value attributes start at before any points are spent on them.
+const BASE_ATTRIBUTE_VALUE: u16 = 10;
+
+/// The value skills start at before any points are spent on them.
+const BASE_SKILL_VALUE: u16 = 5;
+
+/// The number of points new characters can spend on attributes.
+const ATTRIBUTE_POINTS: u16 = 4;
+
+/// The number of points new characters can spend on skills.
+const SKILL_POINTS: u16 = 10;
+
+/// The maximum number of points that can be spent on a single attribute or skill.
+const MAX_POINTS_PER_STAT: u16 = 5;
+
+/// The description to give characters if their player doesn't provide one.
+const DEFAULT_CHARACTER_DESCRIPTION: &str = "A human-shaped person-type thing.";
+
+/// The choices a player made when creating their character.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewCharacter {
+    /// The name of the character.
+    pub name: String,
+    /// The pronouns to use to refer to the character.
+    pub pronouns: Pronouns,
+    /// The description of the character.
+    pub description: String,
+    /// The points spent on the character's stats.
+    pub stat_allocation: StatAllocation,
+}
+
+impl NewCharacter {
+    /// Creates a character with the provided name and default everything else.
+    pub fn new(name: String) -> NewCharacter {
+        NewCharacter {
+            name,
+            pronouns: Pronouns::they(),
+            description: DEFAULT_CHARACTER_DESCRIPTION.to_string(),
+            stat_allocation: StatAllocation::default(),
+        }
+    }
+}
+
+/// The things players can choose from when creating a character.
+#[derive(Debug, Clone)]
+pub struct CharacterCreationOptions {
+    /// The names of the attributes that points can be spent on.
+    pub attribute_names: Vec<String>,
+    /// The names of the skills that points can be spent on.
+    pub skill_names: Vec<String>,
+    /// The value attributes start at before any points are spent on them.
+    pub base_attribute_value: u16,
+    /// The value skills start at before any points are spent on them.
+    pub base_skill_value: u16,
+    /// The number of points that can be spent on attributes.
+    pub attribute_points: u16,
+    /// The number of points that can be spent on skills.
+    pub skill_points: u16,
+    /// The maximum number of points that can be spent on a single attribute or skill.
+    pub max_points_per_stat: u16,
+}
+
+impl CharacterCreationOptions {
+    /// Builds the character creation options for the provided world.
+    pub fn from_world(world: &World) -> CharacterCreationOptions {
+        CharacterCreationOptions {
+            attribute_names: Attribute::iter()
+                .filter(|attribute| !matches!(attribute, Attribute::Custom(_)))
+                .map(|attribute| get_attribute_name(&attribute, world).full)
+                .collect(),
+            skill_names: Skill::iter()
+                .filter(|skill| !matches!(skill, Skill::Custom(_)))
+                .map(|skill| get_skill_name(&skill, world))
+                .collect(),
+            base_attribute_value: BASE_ATTRIBUTE_VALUE,
+            base_skill_value: BASE_SKILL_VALUE,
+            attribute_points: ATTRIBUTE_POINTS,
+            skill_points: SKILL_POINTS,
+            max_points_per_stat: MAX_POINTS_PER_STAT,
+        }
+    }
+
+    /// Finds the name of the attribute with the provided name, ignoring case.
+    fn find_attribute_name(&self, name: &str) -> Option<&String> {
+        self.attribute_names
+            .iter()
+            .find(|attribute_name| attribute_name.eq_ignore_ascii_case(name))
+    }
+
+    /// Finds the name of the skill with the provided name, ignoring case.
+    fn find_skill_name(&self, name: &str) -> Option<&String> {
+        self.skill_names
+            .iter()
+            .find(|skill_name| skill_name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// The points spent on a new character's attributes and skills.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatAllocation {
+    /// Points spent on attributes, keyed by attribute name.
+    attributes: HashMap<String, u16>,
+    /// Points spent on skills, keyed by skill name.
+    skills: HashMap<String, u16>,
+}
+
+/// An error spending points on a stat.
+#[derive(Debug, PartialEq, Eq)]
+pub enum StatAllocationError {
+    /// There is no attribute or skill with the provided name.
+    UnknownStat(String),
+    /// There aren't enough points left to spend that many.
+    NotEnoughPoints { available: u16 },
+    /// Spending that many points would put more than the maximum on the stat.
+    TooManyPoints { max: u16 },
+}
+
+impl Display for StatAllocationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatAllocationError::UnknownStat(name) => {
+                write!(f, "There's no attribute or skill called {name}.")
+            }
+            StatAllocationError::NotEnoughPoints { available } => {
+                write!(f, "You only have {available} points left to spend on that.")
+            }
+            StatAllocationError::TooManyPoints { max } => {
+                write!(
+                    f,
+                    "You can't spend more than {max} points on a single stat."
+                )
+            }
+        }
+    }
+}
+
+impl StatAllocation {
+    /// Spends the provided number of points on the attribute or skill with the provided name.
+    pub fn allocate(
+        &mut self,
+        stat_name: &str,
+        points: u16,
+        options: &CharacterCreationOptions,
+    ) -> Result<(), StatAllocationError> {
+        let (allocated, name, total_points) =
+            if let Some(name) = options.find_attribute_name(stat_name) {
+                (&mut self.attributes, name, options.attribute_points)
+            } else if let Some(name) = options.find_skill_name(stat_name) {
+                (&mut self.skills, name, options.skill_points)
+            } else {
+                return Err(StatAllocationError::UnknownStat(stat_name.to_string()));
+            };
+
+        let available = total_points.saturating_sub(allocated.values().sum());
+        if points > available {
+            return Err(StatAllocationError::NotEnoughPoints { available });
+        }
+
+        let spent = allocated.entry(name.clone()).or_insert(0);
+        if *spent + points > options.max_points_per_stat {
+            return Err(StatAllocationError::TooManyPoints {
+                max: options.max_points_per_stat,
+            });
+        }
+        *spent += points;
+
+        Ok(())
+    }
+
+    /// Un-spends all the spent points.
+    pub fn clear(&mut self) {
+        self.attributes.clear();
+        self.skills.clear();
+    }
+
+    /// Gets the number of points left to spend on attributes.
+    pub fn get_remaining_attribute_points(&self, options: &CharacterCreationOptions) -> u16 {
+        options
+            .attribute_points
+            .saturating_sub(self.attributes.values().sum())
+    }
+
+    /// Gets the number of points left to spend on skills.
+    pub fn get_remaining_skill_points(&self, options: &CharacterCreationOptions) -> u16 {
+        options
+            .skill_points
+            .saturating_sub(self.skills.values().sum())
+    }
+
+    /// Gets the value the attribute with the provided name will have once the character is created.
+    pub fn get_attribute_value(
+        &self,
+        attribute_name: &str,
+        options: &CharacterCreationOptions,
+    ) -> u16 {
+        options.base_attribute_value + self.attributes.get(attribute_name).copied().unwrap_or(0)
+    }
+
+    /// Gets the value the skill with the provided name will have once the character is created.
+    pub fn get_skill_value(&self, skill_name: &str, options: &CharacterCreationOptions) -> u16 {
+        options.base_skill_value + self.skills.get(skill_name).copied().unwrap_or(0)
+    }
+
+    /// Builds the stats for a character with the points spent on them.
+    pub(crate) fn build_stats(&self, world: &World) -> Stats {
+        let mut stats = Stats::new(BASE_ATTRIBUTE_VALUE, BASE_SKILL_VALUE);
+
+        for (attribute_name, points) in &self.attributes {
+            if let Some(attribute) = AttributeNameCatalog::get_attribute(attribute_name, world) {
+                stats.set_attribute(&attribute, BASE_ATTRIBUTE_VALUE + points);
+            }
+        }
+
+        for (skill_name, points) in &self.skills {
+            if let Some(skill) = SkillNameCatalog::get_skill(skill_name, world) {
+                stats.set_skill(&skill, BASE_SKILL_VALUE + points);
+            }
+        }
+
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::resource::insert_resources;
+
+    use super::*;
+
+    fn build_options() -> CharacterCreationOptions {
+        let mut world = World::new();
+        insert_resources(&mut world);
+        CharacterCreationOptions::from_world(&world)
+    }
+
+    #[test]
+    fn allocate_within_budget() {
+        let options = build_options();
+        let mut allocation = StatAllocation::default();
+
+        allocation.allocate("strength", 2, &options).unwrap();
+        allocation.allocate("Cook", 3, &options).unwrap();
+
+        assert_eq!(12, allocation.get_attribute_value("Strength", &options));
+        assert_eq!(8, allocation.get_skill_value("Cook", &options));
+        assert_eq!(
+            ATTRIBUTE_POINTS - 2,
+            allocation.get_remaining_attribute_points(&options)
+        );
+        assert_eq!(
+            SKILL_POINTS - 3,
+            allocation.get_remaining_skill_points(&options)
+        );
+    }
+
+    #[test]
+    fn allocate_over_budget() {
+        let options = build_options();
+        let mut allocation = StatAllocation::default();
+
+        allocation.allocate("strength", 3, &options).unwrap();
+        let result = allocation.allocate("agility", 2, &options);
+
+        assert_eq!(
+            Err(StatAllocationError::NotEnoughPoints {
+                available: ATTRIBUTE_POINTS - 3
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn allocate_over_max_per_stat() {
+        let options = build_options();
+        let mut allocation = StatAllocation::default();
+
+        allocation.allocate("cook", 4, &options).unwrap();
+        let result = allocation.allocate("cook", 2, &options);
+
+        assert_eq!(
+            Err(StatAllocationError::TooManyPoints {
+                max: MAX_POINTS_PER_STAT
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn allocate_unknown_stat() {
+        let options = build_options();
+        let mut allocation = StatAllocation::default();
+
+        let result = allocation.allocate("juggling", 1, &options);
+
+        assert_eq!(
+            Err(StatAllocationError::UnknownStat("juggling".to_string())),
+            result
+        );
+    }
+
+    #[test]
+    fn build_stats() {
+        let mut world = World::new();
+        insert_resources(&mut world);
+        let options = CharacterCreationOptions::from_world(&world);
+        let mut allocation = StatAllocation::default();
+        allocation.allocate("strength", 2, &options).unwrap();
+        allocation.allocate("cook", 3, &options).unwrap();
+
+        let stats = allocation.build_stats(&world);
+
+        assert_eq!(12, stats.attributes.get_raw(&Attribute::Strength));
+        assert_eq!(
+            BASE_ATTRIBUTE_VALUE,
+            stats.attributes.get_raw(&Attribute::Agility)
+        );
+        assert_eq!(8, stats.skills.get_raw(&Skill::Cook));
+        assert_eq!(BASE_SKILL_VALUE, stats.skills.get_raw(&Skill::Firearms));
+    }
+}
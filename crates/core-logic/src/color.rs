@@ -1,4 +1,6 @@
-#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum Color {
     Black,
     DarkGray,
@@ -5,6 +5,7 @@ use bevy_ecs::prelude::*;
 use itertools::Itertools;
 use nonempty::nonempty;
 use rand::seq::SliceRandom;
+use strum::IntoEnumIterator;
 
 use crate::body_part::BodyPartType;
 use crate::command_format::{
@@ -18,16 +19,17 @@ use crate::{
         CommandPartValidateResult, PartValidatorContext,
     },
     find_owning_entity, in_same_room, is_living_entity,
-    resource::WeaponTypeStatCatalog,
+    resource::{WeaponTypeStatCatalog, WEAPON_HIT, WEAPON_MISS},
     vital_change::{ValueChangeOperation, VitalChangeMessageParams, VitalChangeVisualizationType},
     Action, ActionNotificationSender, ActionQueue, ActionResult, ActionResultBuilder, ActionTag,
     AttackType, BasicTokens, BeforeActionNotification, BodyPart, CheckModifiers, CheckResult,
     CombatRange, CombatState, Container, Description, DynamicMessage, DynamicMessageLocation,
     EquipAction, EquippedItems, ExitCombatNotification, GameMessage, InnateWeapon,
-    IntegerExtensions, InternalMessageCategory, MessageCategory, MessageDelay, MessageFormat,
-    Notification, Skill, Stats, SurroundingsMessageCategory, VerifyActionNotification,
-    VerifyResult, VitalChange, VitalType, Vitals, VsCheckParams, VsParticipant, Weapon,
-    WeaponHitMessageTokens, WeaponMissMessageTokens, WeaponUnusableError, STANDARD_CHECK_XP,
+    IntegerExtensions, InternalMessageCategory, LightLevel, Locale, MessageCategory, MessageDelay,
+    MessageFormat, Notification, Skill, Stats, SurroundingsMessageCategory,
+    VerifyActionNotification, VerifyResult, VitalChange, VitalType, Vitals, VsCheckParams,
+    VsParticipant, Weapon, WeaponHitMessageTokens, WeaponMessages, WeaponMissMessageTokens,
+    WeaponUnusableError, STANDARD_CHECK_XP,
 };
 
 /// The fraction of a target's health that counts as a high amount of damage.
@@ -36,6 +38,12 @@ const HIGH_DAMAGE_THRESHOLD: f32 = 0.4;
 /// The fraction of a target's health that counts as a low amount of damage.
 const LOW_DAMAGE_THRESHOLD: f32 = 0.1;
 
+/// The to-hit penalty for attacking in dim light.
+const DIM_LIGHT_TO_HIT_PENALTY: f32 = 2.0;
+
+/// The to-hit penalty for attacking in the dark.
+const DARKNESS_TO_HIT_PENALTY: f32 = 6.0;
+
 /// Describes an attack, parsed into entities.
 pub struct ParsedAttack {
     /// The target of the attack.
@@ -599,7 +607,7 @@ pub enum HitError {
     TargetHasNoBodyParts,
 }
 
-/// Performs a check to see if `attacker` hits `target` with `weapon`.
+/// Performs a check to see if `attacker` hits `target` with `weapon`. It's harder to hit things when it's dark.
 /// Returns `Some` if it was a hit, `Ok(None)` if it was a miss, and `Err` if the weapon is unusable or the target has no body parts.
 pub fn check_for_hit(
     attacker: Entity,
@@ -614,6 +622,12 @@ pub fn check_for_hit(
         .expect("weapon should be a weapon");
     let primary_weapon_stat = WeaponTypeStatCatalog::get_stats(&weapon.weapon_type, world).primary;
 
+    let darkness_penalty = match LightLevel::around(attacker, world) {
+        LightLevel::Dark => DARKNESS_TO_HIT_PENALTY,
+        LightLevel::Dim => DIM_LIGHT_TO_HIT_PENALTY,
+        LightLevel::Bright => 0.0,
+    };
+
     let (to_hit_result, _) = if attacker == target {
         (CheckResult::ExtremeSuccess, CheckResult::ExtremeSuccess)
     } else {
@@ -621,7 +635,7 @@ pub fn check_for_hit(
             VsParticipant {
                 entity: attacker,
                 stat: primary_weapon_stat,
-                modifiers: CheckModifiers::modify_value(to_hit_modification),
+                modifiers: CheckModifiers::modify_value(to_hit_modification - darkness_penalty),
             },
             VsParticipant {
                 entity: target,
@@ -690,19 +704,30 @@ pub fn handle_damage<A: AttackType>(
         .expect("target should have vitals");
     let damage_fraction = hit_params.damage as f32 / target_health.get_max();
 
-    let hit_messages_to_choose_from = if hit_params.target == hit_params.performing_entity {
-        weapon_messages.map(|m| &m.self_hit)
-    } else if damage_fraction >= HIGH_DAMAGE_THRESHOLD {
-        weapon_messages.map(|m| &m.major_hit)
-    } else if damage_fraction > LOW_DAMAGE_THRESHOLD {
-        weapon_messages.map(|m: &crate::WeaponMessages| &m.regular_hit)
-    } else {
-        weapon_messages.map(|m| &m.minor_hit)
+    let choose_hit_message = |messages: Option<&WeaponMessages>, locale: Locale| {
+        let hit_messages_to_choose_from = if hit_params.target == hit_params.performing_entity {
+            messages.map(|m| &m.self_hit)
+        } else if damage_fraction >= HIGH_DAMAGE_THRESHOLD {
+            messages.map(|m| &m.major_hit)
+        } else if damage_fraction > LOW_DAMAGE_THRESHOLD {
+            messages.map(|m| &m.regular_hit)
+        } else {
+            messages.map(|m| &m.minor_hit)
+        };
+
+        hit_messages_to_choose_from
+            .and_then(|m| m.choose(&mut rand::thread_rng()).cloned())
+            .unwrap_or_else(|| WEAPON_HIT.choose_in(locale, world))
     };
 
-    let hit_message = hit_messages_to_choose_from
-        .and_then(|m| m.choose(&mut rand::thread_rng()).cloned())
-        .unwrap_or_else(|| MessageFormat::new("${attacker.Name} ${attacker.you:hit/hits} ${target.name's} ${body_part.plain_name} with ${weapon.name}.").expect("message format should be valid"));
+    let hit_message = choose_hit_message(weapon_messages, Locale::default());
+    let localized_hit_messages = Locale::iter()
+        .filter(|locale| *locale != Locale::default())
+        .map(|locale| {
+            let localized_messages = weapon_messages.and_then(|m| m.localized.get(&locale));
+            (locale, choose_hit_message(localized_messages, locale))
+        })
+        .collect();
 
     let hit_message_tokens = WeaponHitMessageTokens {
         attacker: hit_params.performing_entity,
@@ -719,12 +744,15 @@ pub fn handle_damage<A: AttackType>(
             amount: hit_params.damage as f32,
             message_params: vec![
                 (
-                    VitalChangeMessageParams::Dynamic(DynamicMessage::new(
-                        MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
-                        MessageDelay::Short,
-                        hit_message,
-                        hit_message_tokens,
-                    )),
+                    VitalChangeMessageParams::Dynamic(
+                        DynamicMessage::new(
+                            MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
+                            MessageDelay::Short,
+                            hit_message,
+                            hit_message_tokens,
+                        )
+                        .with_localized_formats(localized_hit_messages),
+                    ),
                     VitalChangeVisualizationType::Abbreviated,
                 ),
                 (
@@ -756,10 +784,23 @@ pub fn handle_miss<A: AttackType>(
     result_builder: ActionResultBuilder,
     world: &mut World,
 ) -> ActionResultBuilder {
-    let miss_message = A::get_messages(weapon_entity, world)
-        .map(|m| &m.miss)
-        .and_then(|m| m.choose(&mut rand::thread_rng())).cloned()
-        .unwrap_or_else(|| MessageFormat::new("${attacker.Name} ${attacker.you:fail/fails} to hit ${target.name} with ${weapon.name}.").expect("message format should be valid"));
+    let weapon_messages = A::get_messages(weapon_entity, world);
+    let choose_miss_message = |messages: Option<&WeaponMessages>, locale: Locale| {
+        messages
+            .map(|m| &m.miss)
+            .and_then(|m| m.choose(&mut rand::thread_rng()))
+            .cloned()
+            .unwrap_or_else(|| WEAPON_MISS.choose_in(locale, world))
+    };
+
+    let miss_message = choose_miss_message(weapon_messages, Locale::default());
+    let localized_miss_messages = Locale::iter()
+        .filter(|locale| *locale != Locale::default())
+        .map(|locale| {
+            let localized_messages = weapon_messages.and_then(|m| m.localized.get(&locale));
+            (locale, choose_miss_message(localized_messages, locale))
+        })
+        .collect();
 
     let miss_message_tokens = WeaponMissMessageTokens {
         attacker: performing_entity,
@@ -775,7 +816,8 @@ pub fn handle_miss<A: AttackType>(
             MessageDelay::Short,
             miss_message,
             miss_message_tokens,
-        ),
+        )
+        .with_localized_formats(localized_miss_messages),
         world,
     )
 }
@@ -1,6 +1,7 @@
 use itertools::Itertools;
 use std::collections::HashSet;
 use std::hash::Hash;
+use std::sync::{LazyLock, Mutex, PoisonError};
 use std::{any::type_name, collections::HashMap, marker::PhantomData};
 
 use bevy_ecs::prelude::*;
@@ -747,6 +748,27 @@ impl<T> CommandPartId<T> {
     pub const fn new(value: &'static str) -> CommandPartId<T> {
         CommandPartId(value, PhantomData)
     }
+
+    /// Creates a part ID from a value that's only known at runtime, like the name of a part in a world definition file.
+    /// Each distinct value is only allocated once, and is kept around for the rest of the program.
+    pub fn interned(value: &str) -> CommandPartId<T> {
+        static INTERNED_VALUES: LazyLock<Mutex<HashSet<&'static str>>> =
+            LazyLock::new(|| Mutex::new(HashSet::new()));
+
+        let mut interned_values = INTERNED_VALUES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let interned = match interned_values.get(value) {
+            Some(interned) => interned,
+            None => {
+                let interned: &'static str = Box::leak(value.to_string().into_boxed_str());
+                interned_values.insert(interned);
+                interned
+            }
+        };
+
+        CommandPartId::new(interned)
+    }
 }
 
 impl CommandFormat {
@@ -232,7 +232,7 @@ fn generate_literal_permutations(next_literal_parts: &[LiteralPart]) -> Vec<Stri
                     *permutation += to_append;
                 } else {
                     // this is the not-last of more than one string to append, so a new permutation needs to be created
-                    new_permutations.insert(permutation.clone() + to_append);
+                    new_permutations.insert(permutation.clone() + *to_append);
                 }
             }
         }
@@ -0,0 +1,273 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game_map::Coordinates,
+    notification::Notification,
+    resource::Weather,
+    vital_change::{
+        ValueChangeOperation, VitalChange, VitalChangeMessageParams, VitalChangeVisualizationType,
+        VitalType,
+    },
+    InternalMessageCategory, LightLevel, MessageCategory, NoTokens, TickNotification, Time,
+};
+
+use super::{
+    find_containing_room, Overheated, Vitals, Wearable, Wetness, WornItems, NORMAL_BODY_TEMPERATURE,
+};
+
+/// The temperature of rooms inside buildings that don't set their own, in degrees Celsius.
+const INDOOR_TEMPERATURE: f32 = 18.0;
+/// The temperature outside during the day, in degrees Celsius, before the weather is taken into account.
+const DAYTIME_TEMPERATURE: f32 = 22.0;
+/// The temperature outside at dawn and dusk, in degrees Celsius, before the weather is taken into account.
+const TWILIGHT_TEMPERATURE: f32 = 15.0;
+/// The temperature outside at night, in degrees Celsius, before the weather is taken into account.
+const NIGHTTIME_TEMPERATURE: f32 = 8.0;
+
+/// The range of felt temperatures, in degrees Celsius, in which body temperature returns to normal.
+const COMFORTABLE_TEMPERATURES: std::ops::RangeInclusive<f32> = 16.0..=30.0;
+/// How many degrees warmer each point of insulation from worn items makes things feel.
+const WARMTH_PER_INSULATION: f32 = 2.0;
+/// How many degrees colder being soaked through makes things feel.
+const CHILL_WHEN_SOAKED: f32 = 10.0;
+/// How far from normal body temperature settles for each degree outside the comfortable range it feels.
+/// Body temperature only settles at the lowest or highest possible value when it feels extremely cold or hot.
+const BODY_TEMPERATURE_CHANGE_PER_DEGREE: f32 = 1.5;
+/// The fraction of the way body temperature moves each tick towards the temperature it's settling at.
+const BODY_TEMPERATURE_ADJUSTMENT_RATE: f32 = 0.02;
+
+/// How much health is lost per tick at the lowest or highest possible body temperature.
+const TEMPERATURE_DAMAGE_PER_TICK: f32 = 2.0;
+/// How much extra hydration is lost per tick while overheated.
+const OVERHEATED_HYDRATION_LOSS_PER_TICK: f32 = 0.02;
+
+/// The temperature of a room, in degrees Celsius. Rooms without this are a standard temperature indoors, and depend on the time of day and weather outdoors.
+#[derive(Debug, Clone, Copy, Component, Serialize, Deserialize)]
+pub struct AmbientTemperature(pub f32);
+
+impl AmbientTemperature {
+    /// Determines the temperature of the provided room, in degrees Celsius.
+    pub fn of_room(room: Entity, world: &World) -> f32 {
+        if let Some(temperature) = world.get::<AmbientTemperature>(room) {
+            return temperature.0;
+        }
+
+        let Some(weather) = world
+            .get::<Coordinates>(room)
+            .and_then(|coords| Weather::at(coords, world))
+        else {
+            return INDOOR_TEMPERATURE;
+        };
+
+        let daylight = world
+            .get_resource::<Time>()
+            .map(Time::get_daylight)
+            .unwrap_or(LightLevel::Bright);
+        let base_temperature = match daylight {
+            LightLevel::Bright => DAYTIME_TEMPERATURE,
+            LightLevel::Dim => TWILIGHT_TEMPERATURE,
+            LightLevel::Dark => NIGHTTIME_TEMPERATURE,
+        };
+
+        base_temperature + weather.get_temperature_change()
+    }
+
+    /// Determines the temperature around the provided entity, in degrees Celsius.
+    pub fn around(entity: Entity, world: &World) -> f32 {
+        find_containing_room(entity, world)
+            .map(|room| AmbientTemperature::of_room(room, world))
+            .unwrap_or(INDOOR_TEMPERATURE)
+    }
+}
+
+/// Determines the total insulation of everything the provided entity is wearing.
+fn get_total_insulation(entity: Entity, world: &World) -> u32 {
+    world
+        .get::<WornItems>(entity)
+        .map(|worn_items| {
+            worn_items
+                .get_all_items()
+                .into_iter()
+                .filter_map(|item| world.get::<Wearable>(item))
+                .map(|wearable| wearable.insulation)
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+/// Determines how warm it feels to the provided entity, in degrees Celsius, taking into account what it's wearing and how wet it is.
+pub fn get_felt_temperature(entity: Entity, world: &World) -> f32 {
+    AmbientTemperature::around(entity, world)
+        + get_total_insulation(entity, world) as f32 * WARMTH_PER_INSULATION
+        - Wetness::get(entity, world) * CHILL_WHEN_SOAKED
+}
+
+/// Determines the body temperature that something that feels the provided temperature, in degrees Celsius, will settle at.
+fn get_settling_body_temperature(felt_temperature: f32) -> f32 {
+    if felt_temperature < *COMFORTABLE_TEMPERATURES.start() {
+        NORMAL_BODY_TEMPERATURE
+            - (COMFORTABLE_TEMPERATURES.start() - felt_temperature)
+                * BODY_TEMPERATURE_CHANGE_PER_DEGREE
+    } else if felt_temperature > *COMFORTABLE_TEMPERATURES.end() {
+        NORMAL_BODY_TEMPERATURE
+            + (felt_temperature - COMFORTABLE_TEMPERATURES.end())
+                * BODY_TEMPERATURE_CHANGE_PER_DEGREE
+    } else {
+        NORMAL_BODY_TEMPERATURE
+    }
+}
+
+/// Changes the body temperature of living things based on how warm it feels to them.
+pub fn change_body_temperature_on_tick(_: &Notification<TickNotification, ()>, world: &mut World) {
+    let mut changes = Vec::new();
+    let mut query = world.query::<(Entity, &Vitals, Option<&Overheated>)>();
+    for (entity, vitals, overheated) in query.iter(world) {
+        let felt_temperature = get_felt_temperature(entity, world);
+        let body_temperature = vitals.body_temperature.get();
+        let difference = get_settling_body_temperature(felt_temperature) - body_temperature;
+        let operation = if difference < 0.0 {
+            ValueChangeOperation::Subtract
+        } else {
+            ValueChangeOperation::Add
+        };
+        let amount = difference.abs() * BODY_TEMPERATURE_ADJUSTMENT_RATE;
+
+        if amount > 0.0 {
+            changes.push(VitalChange::<NoTokens> {
+                entity,
+                vital_type: VitalType::BodyTemperature,
+                operation,
+                amount,
+                message_params: vec![],
+            });
+        }
+
+        let extreme_message = if body_temperature <= vitals.body_temperature.get_min() {
+            Some("You're freezing to death!")
+        } else if body_temperature >= vitals.body_temperature.get_max() {
+            Some("You're dying of heatstroke!")
+        } else {
+            None
+        };
+        if let Some(message) = extreme_message {
+            changes.push(VitalChange {
+                entity,
+                vital_type: VitalType::Health,
+                operation: ValueChangeOperation::Subtract,
+                amount: TEMPERATURE_DAMAGE_PER_TICK,
+                message_params: vec![(
+                    VitalChangeMessageParams::Direct {
+                        entity,
+                        message: message.to_string(),
+                        category: MessageCategory::Internal(InternalMessageCategory::Misc),
+                    },
+                    VitalChangeVisualizationType::Full,
+                )],
+            });
+        }
+
+        if overheated.is_some() {
+            changes.push(VitalChange {
+                entity,
+                vital_type: VitalType::Hydration,
+                operation: ValueChangeOperation::Subtract,
+                amount: OVERHEATED_HYDRATION_LOSS_PER_TICK,
+                message_params: vec![],
+            });
+        }
+    }
+
+    changes.into_iter().for_each(|change| change.apply(world));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        add_human_body_parts,
+        body_part::BodyPartType,
+        component::{register_component_handlers, Container, StatusEffects},
+        game_map::GameMap,
+        move_entity,
+        resource::{insert_resources, WeatherKind},
+        test_utils::spawn_test_room,
+    };
+
+    use super::*;
+
+    fn set_up_world(weather: WeatherKind) -> (World, Entity) {
+        let mut world = World::new();
+        world.insert_resource(GameMap::new());
+        world.insert_resource(Time::new());
+        insert_resources(&mut world);
+        world.insert_resource(Weather {
+            kind: weather,
+            ticks_until_change: 100,
+        });
+        register_component_handlers(&mut world);
+        let room = spawn_test_room(None, &mut world);
+        let person = world
+            .spawn((Vitals::new(), WornItems::new(5), Container::new(None, None)))
+            .id();
+        move_entity(person, room, &mut world);
+        add_human_body_parts(person, &mut world);
+
+        (world, person)
+    }
+
+    #[test]
+    fn snow_makes_things_cold() {
+        let (mut world, person) = set_up_world(WeatherKind::Snow);
+        for _ in 0..300 {
+            Notification::send_no_contents(TickNotification, &mut world);
+        }
+
+        assert!(
+            world.get::<Vitals>(person).unwrap().body_temperature.get() < NORMAL_BODY_TEMPERATURE
+        );
+        assert!(StatusEffects::get_all(person, &world)
+            .iter()
+            .any(|status_effect| status_effect.name == "Cold"));
+    }
+
+    #[test]
+    fn clear_night_outside_is_not_deadly() {
+        let (mut world, person) = set_up_world(WeatherKind::Clear);
+        world.resource_mut::<Time>().hour = 21;
+        let starting_health = world.get::<Vitals>(person).unwrap().health.get();
+        for _ in 0..1000 {
+            Notification::send_no_contents(TickNotification, &mut world);
+        }
+
+        let vitals = world.get::<Vitals>(person).unwrap();
+        assert!(vitals.body_temperature.get() < NORMAL_BODY_TEMPERATURE);
+        assert!(vitals.body_temperature.get() > vitals.body_temperature.get_min());
+        assert_eq!(starting_health, vitals.health.get());
+    }
+
+    #[test]
+    fn insulation_and_wetness_change_felt_temperature() {
+        let (mut world, person) = set_up_world(WeatherKind::Clear);
+        let base_temperature = get_felt_temperature(person, &world);
+
+        let coat = world
+            .spawn(Wearable {
+                thickness: 2,
+                body_parts: [BodyPartType::Torso].into(),
+                insulation: 3,
+            })
+            .id();
+        move_entity(coat, person, &mut world);
+        WornItems::wear(person, coat, &mut world).unwrap();
+        assert_eq!(
+            base_temperature + 3.0 * WARMTH_PER_INSULATION,
+            get_felt_temperature(person, &world)
+        );
+
+        Wetness::change(person, 1.0, &mut world);
+        assert_eq!(
+            base_temperature + 3.0 * WARMTH_PER_INSULATION - CHILL_WHEN_SOAKED,
+            get_felt_temperature(person, &world)
+        );
+    }
+}
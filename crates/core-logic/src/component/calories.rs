@@ -1,4 +1,5 @@
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     action::EatAction,
@@ -16,7 +17,7 @@ use super::AfterActionPerformNotification;
 const SATIETY_GAIN_PER_CALORIE: f32 = 0.01;
 
 /// Describes how many calories an entity contains.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct Calories(pub u16);
 
 /// Increases satiety when an entity is eaten based on its calories.
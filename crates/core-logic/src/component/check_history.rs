@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use super::Stat;
 
@@ -8,8 +9,8 @@ const INCREASE_PER_CHECK: f32 = 1.5;
 const DECREASE_PER_OTHER_CHECK: f32 = 1.0;
 
 /// Keeps track of how often an entity's stats have been used in checks recently.
-#[derive(Component, PartialEq, Debug)]
-pub struct CheckHistory(HashMap<Stat, f32>);
+#[derive(Component, PartialEq, Debug, Serialize, Deserialize)]
+pub struct CheckHistory(#[serde(with = "crate::map_as_pairs")] HashMap<Stat, f32>);
 
 impl CheckHistory {
     /// Creates an empty check history
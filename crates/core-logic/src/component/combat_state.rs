@@ -1,6 +1,10 @@
 use std::{collections::HashMap, fmt::Display};
 
-use bevy_ecs::prelude::*;
+use bevy_ecs::{
+    entity::{EntityMapper, MapEntities},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     get_or_insert_mut, notification::Notification, DeathNotification, DespawnNotification,
@@ -8,15 +12,25 @@ use crate::{
 };
 
 /// Describes who an entity is in combat with.
-#[derive(Component, Default)]
+#[derive(Component, Default, Serialize, Deserialize)]
 pub struct CombatState {
     /// The entities this entity is currently in combat with, and the ranges to them.
     entities_in_combat_with: HashMap<Entity, CombatRange>,
 }
 
+impl MapEntities for CombatState {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.entities_in_combat_with = self
+            .entities_in_combat_with
+            .drain()
+            .map(|(entity, range)| (entity_mapper.map_entity(entity), range))
+            .collect();
+    }
+}
+
 /// Represents how far away two combatants are from each other.
 #[repr(u8)]
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum CombatRange {
     Shortest,
     Short,
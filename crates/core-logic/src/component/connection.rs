@@ -1,4 +1,8 @@
-use bevy_ecs::prelude::*;
+use bevy_ecs::{
+    entity::{EntityMapper, MapEntities},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     game_map::Coordinates, AttributeDescription, Direction, GameMessage, InternalMessageCategory,
@@ -6,11 +10,12 @@ use crate::{
 };
 
 use super::{
-    AttributeDescriber, AttributeDetailLevel, Container, DescribeAttributes, OpenState, Room,
+    AttributeDescriber, AttributeDescriberKind, AttributeDetailLevel, Container,
+    DescribeAttributes, OpenState, Room,
 };
 
 /// Describes a connection an entity makes to another room.
-#[derive(PartialEq, Eq, Debug, Component)]
+#[derive(PartialEq, Eq, Debug, Component, Serialize, Deserialize)]
 pub struct Connection {
     /// The direction the connection is in.
     pub direction: Direction,
@@ -20,6 +25,15 @@ pub struct Connection {
     pub other_side: Option<Entity>,
 }
 
+impl MapEntities for Connection {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.destination = entity_mapper.map_entity(self.destination);
+        self.other_side = self
+            .other_side
+            .map(|other_side| entity_mapper.map_entity(other_side));
+    }
+}
+
 /// Describes where the entity connects to.
 #[derive(Debug)]
 struct ConnectionAttributeDescriber;
@@ -76,6 +90,10 @@ impl AttributeDescriber for ConnectionAttributeDescriber {
 
         descriptions
     }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::Connection
+    }
 }
 
 impl DescribeAttributes for Connection {
@@ -1,6 +1,10 @@
 use std::collections::HashSet;
 
-use bevy_ecs::prelude::*;
+use bevy_ecs::{
+    entity::{EntityMapper, MapEntities},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     action::PutAction,
@@ -13,12 +17,13 @@ use crate::{
 };
 
 use super::{
-    AttributeDescriber, AttributeDetailLevel, Connection, DescribeAttributes, Description,
-    OpenState, VerifyActionNotification, Volume, Weight,
+    AttributeDescriber, AttributeDescriberKind, AttributeDetailLevel, Connection,
+    DescribeAttributes, Description, LightLevel, Location, OpenState, VerifyActionNotification,
+    Volume, Weight,
 };
 
 /// Entities contained within an entity.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct Container {
     /// The contained entities.
     entities: HashSet<Entity>,
@@ -28,6 +33,16 @@ pub struct Container {
     pub max_weight: Option<Weight>,
 }
 
+impl MapEntities for Container {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.entities = self
+            .entities
+            .drain()
+            .map(|entity| entity_mapper.map_entity(entity))
+            .collect();
+    }
+}
+
 impl Container {
     /// Creates an empty container that can hold an infinite amount of objects.
     pub fn new_infinite() -> Container {
@@ -65,10 +80,22 @@ impl Container {
 
     /// Gets all the entities in this container, from the perspective of the provided entity.
     pub fn get_entities(&self, pov_entity: Entity, world: &World) -> HashSet<Entity> {
+        // everything in the container is in the same place, so the light level only needs to be determined once
+        let light_level = self
+            .entities
+            .iter()
+            .next()
+            .and_then(|entity| world.get::<Location>(*entity))
+            .and_then(|location| LightLevel::of_location(location.id, world));
+
         self.entities
             .iter()
             .copied()
-            .filter(|entity| Invisible::is_visible_to(*entity, pov_entity, world))
+            .filter(|entity| {
+                Invisible::is_visible_to(*entity, pov_entity, world)
+                    && !light_level
+                        .is_some_and(|light_level| light_level.hides(*entity, pov_entity, world))
+            })
             .collect()
     }
 
@@ -259,6 +286,10 @@ impl AttributeDescriber for ContainerAttributeDescriber {
 
         Vec::new()
     }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::Container
+    }
 }
 
 impl DescribeAttributes for Container {
@@ -1,10 +1,89 @@
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::input_parser::InputParser;
 
+use super::{FistActions, KeyedLock, OpenState, PortableLight, Respawner};
+
 #[derive(Component)]
 pub struct CustomInputParser {
     pub parsers: Vec<Box<dyn InputParser>>,
+    /// The kinds of components that contributed the parsers, used to re-create them when loading a saved world.
+    kinds: Vec<CustomInputParserKind>,
+}
+
+/// The different kinds of components that provide custom input parsers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomInputParserKind {
+    OpenState,
+    KeyedLock,
+    FistActions,
+    Respawner,
+    PortableLight,
+}
+
+impl CustomInputParserKind {
+    /// Creates the parsers for this kind of component.
+    fn get_parsers(self) -> Vec<Box<dyn InputParser>> {
+        match self {
+            CustomInputParserKind::OpenState => OpenState::get_parsers(),
+            CustomInputParserKind::KeyedLock => KeyedLock::get_parsers(),
+            CustomInputParserKind::FistActions => FistActions::get_parsers(),
+            CustomInputParserKind::Respawner => Respawner::get_parsers(),
+            CustomInputParserKind::PortableLight => PortableLight::get_parsers(),
+        }
+    }
+}
+
+impl CustomInputParser {
+    /// Creates a `CustomInputParser` with the parsers for the provided kinds of components.
+    fn from_kinds(kinds: Vec<CustomInputParserKind>) -> CustomInputParser {
+        CustomInputParser {
+            parsers: kinds.iter().flat_map(|kind| kind.get_parsers()).collect(),
+            kinds,
+        }
+    }
+
+    /// Adds parsers to the provided entity that aren't re-created from their kind when loading a saved world.
+    /// Whatever adds them is responsible for adding them again once a saved world is loaded.
+    pub fn add_unsaved_parsers(
+        entity: Entity,
+        parsers: Vec<Box<dyn InputParser>>,
+        world: &mut World,
+    ) {
+        if let Some(mut input_parser) = world.get_mut::<CustomInputParser>(entity) {
+            input_parser.parsers.extend(parsers);
+        } else {
+            world.entity_mut(entity).insert(CustomInputParser {
+                parsers,
+                kinds: Vec::new(),
+            });
+        }
+    }
+
+    /// Removes any parsers added to the provided entity with `add_unsaved_parsers`, leaving only the ones re-created from their kind.
+    pub fn remove_unsaved_parsers(entity: Entity, world: &mut World) {
+        if let Some(mut input_parser) = world.get_mut::<CustomInputParser>(entity) {
+            input_parser.parsers = input_parser
+                .kinds
+                .iter()
+                .flat_map(|kind| kind.get_parsers())
+                .collect();
+        }
+    }
+}
+
+impl Serialize for CustomInputParser {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.kinds.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomInputParser {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<CustomInputParserKind>::deserialize(deserializer).map(CustomInputParser::from_kinds)
+    }
 }
 
 /// Trait for components that parse input.
@@ -13,6 +92,7 @@ pub trait ParseCustomInput {
     fn register_custom_input_parser(entity: Entity, world: &mut World) {
         if let Some(mut input_parser) = world.get_mut::<CustomInputParser>(entity) {
             input_parser.parsers.extend(Self::get_parsers());
+            input_parser.kinds.push(Self::get_kind());
         } else {
             world
                 .entity_mut(entity)
@@ -22,11 +102,12 @@ pub trait ParseCustomInput {
 
     /// Creates a `CustomInputParser` with the parser for this component.
     fn new_custom_input_parser() -> CustomInputParser {
-        CustomInputParser {
-            parsers: Self::get_parsers(),
-        }
+        CustomInputParser::from_kinds(vec![Self::get_kind()])
     }
 
     /// Returns the `InputParser` for this component.
     fn get_parsers() -> Vec<Box<dyn InputParser>>;
+
+    /// Returns the kind of this component, used to re-create its parsers when loading a saved world.
+    fn get_kind() -> CustomInputParserKind;
 }
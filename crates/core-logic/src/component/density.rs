@@ -6,11 +6,12 @@ use std::{
 
 use bevy_ecs::prelude::*;
 use float_cmp::approx_eq;
+use serde::{Deserialize, Serialize};
 
 use super::{Volume, Weight};
 
 /// The density of an entity, in kilograms per liter.
-#[derive(Debug, Clone, Copy, Component, PartialOrd)]
+#[derive(Debug, Clone, Copy, Component, PartialOrd, Serialize, Deserialize)]
 pub struct Density(pub f32);
 
 impl Add for Density {
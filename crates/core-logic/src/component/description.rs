@@ -2,14 +2,21 @@ use std::collections::HashSet;
 
 use bevy_ecs::prelude::*;
 use ordered_float::OrderedFloat;
+use serde::{Deserialize, Serialize};
 use voca_rs::Voca;
 
-use crate::{component::Room, find_owning_entity, is_living_entity, GameMessage};
+use crate::{
+    component::Room, find_owning_entity, is_living_entity, GameMessage, GrammaticalGender, Locale,
+};
 
-use super::Location;
+use super::{
+    Connection, Container, Edible, EquippedItems, FluidContainer, GeigerCounter, Item, KeyedLock,
+    LocalizedDescriptions, Location, OpenState, PortableLight, SleepState, Volume, Weapon,
+    Wearable, Weight, Wetness, WornItems,
+};
 
 /// The description of an entity.
-#[derive(Component, Debug)]
+#[derive(Component, Debug, Serialize, Deserialize)]
 pub struct Description {
     /// The name of the entity.
     pub name: String,
@@ -26,10 +33,35 @@ pub struct Description {
     /// The description of the entity.
     pub description: String,
     /// Describers for dynamic attributes of the entity.
+    #[serde(with = "attribute_describer_kinds")]
     pub attribute_describers: Vec<Box<dyn AttributeDescriber>>,
 }
 
-#[derive(Debug, Clone)]
+/// (De)serializes attribute describers as their kinds, since the describers themselves are trait objects.
+mod attribute_describer_kinds {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::{AttributeDescriber, AttributeDescriberKind};
+
+    pub fn serialize<S: Serializer>(
+        describers: &[Box<dyn AttributeDescriber>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(describers.iter().map(|describer| describer.get_kind()))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Box<dyn AttributeDescriber>>, D::Error> {
+        let kinds = Vec::<AttributeDescriberKind>::deserialize(deserializer)?;
+        Ok(kinds
+            .into_iter()
+            .map(AttributeDescriberKind::to_describer)
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Pronouns {
     /// The personal subject form (e.g. he, she, they)
     pub personal_subject: String,
@@ -90,100 +122,82 @@ impl Pronouns {
         Pronouns::new("it", "it", "its", "its", "itself", false)
     }
 
+    /// Gets the pronouns to use when referring to the provided entity, in the locale of the POV entity.
+    ///
+    /// If a POV entity is provided, and it's the same as the entity, this will return the forms of "you".
+    /// If the entity has no description and is alive, this will return the forms of "they".
+    /// If the entity has no description and is not alive, this will return the forms of "it".
+    pub fn get(entity: Entity, pov_entity: Option<Entity>, world: &World) -> Pronouns {
+        let locale = Locale::of(pov_entity, world);
+        if pov_entity == Some(entity) {
+            return locale.second_person_pronouns();
+        }
+
+        let Some(desc) = world.get::<Description>(entity) else {
+            return locale.default_pronouns(is_living_entity(entity, world));
+        };
+
+        let gender = LocalizedDescriptions::get(entity, locale, world)
+            .map(|localized| localized.gender)
+            .unwrap_or_else(|| desc.pronouns.get_gender());
+        locale
+            .gendered_pronouns(gender, desc.pronouns.plural)
+            .unwrap_or_else(|| desc.pronouns.clone())
+    }
+
     /// Gets the personal subject pronoun to use when referring to the provided entity (e.g. he, she, they).
     ///
-    /// If a POV entity is provided, and it's the same as the entity, this will return "you".
-    /// If the entity has no description and is alive, this will return "they".
-    /// If the entity has no description and is not alive, this will return "it".
+    /// See `Pronouns::get` for how the pronouns are chosen.
     pub fn get_personal_subject(
         entity: Entity,
         pov_entity: Option<Entity>,
         world: &World,
     ) -> String {
-        if pov_entity == Some(entity) {
-            "you".to_string()
-        } else if let Some(desc) = world.get::<Description>(entity) {
-            desc.pronouns.personal_subject.clone()
-        } else if is_living_entity(entity, world) {
-            "they".to_string()
-        } else {
-            "it".to_string()
-        }
+        Pronouns::get(entity, pov_entity, world).personal_subject
     }
 
     /// Gets the personal object pronoun to use when referring to the provided entity (e.g. him, her, them).
     ///
-    /// If a POV entity is provided, and it's the same as the entity, this will return "you".
-    /// If the entity has no description and is alive, this will return "them".
-    /// If the entity has no description and is not alive, this will return "it".
+    /// See `Pronouns::get` for how the pronouns are chosen.
     pub fn get_personal_object(
         entity: Entity,
         pov_entity: Option<Entity>,
         world: &World,
     ) -> String {
-        if pov_entity == Some(entity) {
-            "you".to_string()
-        } else if let Some(desc) = world.get::<Description>(entity) {
-            desc.pronouns.personal_object.clone()
-        } else if is_living_entity(entity, world) {
-            "them".to_string()
-        } else {
-            "it".to_string()
-        }
+        Pronouns::get(entity, pov_entity, world).personal_object
     }
 
     /// Gets the possessive pronoun to use when referring to the provided entity (e.g. his, hers, theirs).
     ///
-    /// If a POV entity is provided, and it's the same as the entity, this will return "yours".
-    /// If the entity has no description and is alive, this will return "theirs".
-    /// If the entity has no description and is not alive, this will return "its".
+    /// See `Pronouns::get` for how the pronouns are chosen.
     pub fn get_possessive(entity: Entity, pov_entity: Option<Entity>, world: &World) -> String {
-        if pov_entity == Some(entity) {
-            "yours".to_string()
-        } else if let Some(desc) = world.get::<Description>(entity) {
-            desc.pronouns.possessive.clone()
-        } else if is_living_entity(entity, world) {
-            "theirs".to_string()
-        } else {
-            "its".to_string()
-        }
+        Pronouns::get(entity, pov_entity, world).possessive
     }
 
     /// Gets the possessive adjective pronoun to use when referring to the provided entity (e.g. his, her, their).
     ///
-    /// If a POV entity is provided, and it's the same as the entity, this will return "your".
-    /// If the entity has no description and is alive, this will return "their".
-    /// If the entity has no description and is not alive, this will return "its".
+    /// See `Pronouns::get` for how the pronouns are chosen.
     pub fn get_possessive_adjective(
         entity: Entity,
         pov_entity: Option<Entity>,
         world: &World,
     ) -> String {
-        if pov_entity == Some(entity) {
-            "your".to_string()
-        } else if let Some(desc) = world.get::<Description>(entity) {
-            desc.pronouns.possessive_adjective.clone()
-        } else if is_living_entity(entity, world) {
-            "their".to_string()
-        } else {
-            "its".to_string()
-        }
+        Pronouns::get(entity, pov_entity, world).possessive_adjective
     }
 
     /// Gets the reflexive pronoun to use when referring to the provided entity (e.g. himself, herself, themself).
     ///
-    /// If a POV entity is provided, and it's the same as the entity, this will return "yourself".
-    /// If the entity has no description and is alive, this will return "themself".
-    /// If the entity has no description and is not alive, this will return "itself".
+    /// See `Pronouns::get` for how the pronouns are chosen.
     pub fn get_reflexive(entity: Entity, pov_entity: Option<Entity>, world: &World) -> String {
-        if pov_entity == Some(entity) {
-            "yourself".to_string()
-        } else if let Some(desc) = world.get::<Description>(entity) {
-            desc.pronouns.reflexive.clone()
-        } else if is_living_entity(entity, world) {
-            "themself".to_string()
+        Pronouns::get(entity, pov_entity, world).reflexive
+    }
+
+    /// Guesses the grammatical gender of something with these pronouns, for locales that need one when it doesn't have a localized description.
+    fn get_gender(&self) -> GrammaticalGender {
+        if self.personal_subject == "she" {
+            GrammaticalGender::Feminine
         } else {
-            "itself".to_string()
+            GrammaticalGender::Masculine
         }
     }
 
@@ -289,20 +303,37 @@ impl Description {
             .or_else(|| world.get::<Room>(entity).map(|r| r.name.clone()))
     }
 
-    /// Builds a string to use to refer to the provided entity from the point of view of another entity.
+    /// Gets the name of the provided entity in the locale of the POV entity, if it has one.
+    ///
+    /// If the entity has no name in that locale, this will return the same thing as `get_name`.
+    pub fn get_localized_name(
+        entity: Entity,
+        pov_entity: Option<Entity>,
+        world: &World,
+    ) -> Option<String> {
+        let locale = Locale::of(pov_entity, world);
+        LocalizedDescriptions::get(entity, locale, world)
+            .map(|localized| localized.name.clone())
+            .or_else(|| Description::get_name(entity, world))
+    }
+
+    /// Builds a string to use to refer to the provided entity from the point of view of another entity, in that entity's locale.
     ///
     /// For example, if the entity is named "book", this will return "the book".
     ///
     /// If `pov_entity` is the same as `entity`, this will return "you".
     pub fn get_reference_name(entity: Entity, pov_entity: Option<Entity>, world: &World) -> String {
+        let locale = Locale::of(pov_entity, world);
         if Some(entity) == pov_entity {
-            return "you".to_string();
+            return locale.you(false).to_string();
         }
 
         let article = Description::get_definite_article(entity, pov_entity, world)
             .map_or_else(|| "".to_string(), |a| format!("{a} "));
-        Description::get_name(entity, world)
-            .map_or("it".to_string(), |name| format!("{article}{name}"))
+        Description::get_localized_name(entity, pov_entity, world).map_or_else(
+            || locale.default_pronouns(false).personal_subject,
+            |name| format!("{article}{name}"),
+        )
     }
 
     /// Gets the definite article to use when referring to the provided entity.
@@ -311,7 +342,7 @@ impl Description {
     ///
     /// If some other entity owns it, this will return that entity's possessive adjective pronoun (e.g. "his", "her", "their", etc.).
     ///
-    /// Otherwise, this will return `Some("the")` if the entity has no description or has an article defined in its description,
+    /// Otherwise, this will return `Some("the")` (or its equivalent in the POV entity's locale) if the entity has no description or has an article defined in its description,
     /// or `None` if the entity has a description but no article.
     pub fn get_definite_article(
         entity: Entity,
@@ -328,14 +359,25 @@ impl Description {
             ));
         }
 
-        if world
-            .get::<Description>(entity)
-            .map(|d| d.article.is_none())
-            .unwrap_or(false)
-        {
+        let locale = Locale::of(pov_entity, world);
+        let plural = Pronouns::is_plural(entity, world);
+        if let Some(localized) = LocalizedDescriptions::get(entity, locale, world) {
+            if localized.proper_noun {
+                return None;
+            }
+            return Some(
+                locale
+                    .definite_article(localized.gender, plural)
+                    .to_string(),
+            );
+        }
+
+        let desc = world.get::<Description>(entity);
+        if desc.map(|d| d.article.is_none()).unwrap_or(false) {
             return None;
         }
-        Some("the".to_string())
+        let gender = desc.map(|d| d.pronouns.get_gender()).unwrap_or_default();
+        Some(locale.definite_article(gender, plural).to_string())
     }
 
     /// Builds a string to use to refer to the provided entity generically.
@@ -392,6 +434,57 @@ pub trait AttributeDescriber: Send + Sync + std::fmt::Debug {
         detail_level: AttributeDetailLevel,
         world: &World,
     ) -> Vec<AttributeDescription>;
+
+    /// Gets the kind of this describer, used to re-create it when loading a saved world.
+    fn get_kind(&self) -> AttributeDescriberKind;
+}
+
+/// The different kinds of attribute describers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeDescriberKind {
+    Connection,
+    Container,
+    Edible,
+    EquippedItems,
+    FluidContainer,
+    GeigerCounter,
+    Item,
+    KeyedLock,
+    OpenState,
+    PortableLight,
+    SleepState,
+    Volume,
+    Weapon,
+    Wearable,
+    Weight,
+    WornItems,
+    Wetness,
+}
+
+impl AttributeDescriberKind {
+    /// Creates the attribute describer of this kind.
+    pub fn to_describer(self) -> Box<dyn AttributeDescriber> {
+        match self {
+            AttributeDescriberKind::Connection => Connection::get_attribute_describer(),
+            AttributeDescriberKind::Container => Container::get_attribute_describer(),
+            AttributeDescriberKind::Edible => Edible::get_attribute_describer(),
+            AttributeDescriberKind::EquippedItems => EquippedItems::get_attribute_describer(),
+            AttributeDescriberKind::FluidContainer => FluidContainer::get_attribute_describer(),
+            AttributeDescriberKind::GeigerCounter => GeigerCounter::get_attribute_describer(),
+            AttributeDescriberKind::Item => Item::get_attribute_describer(),
+            AttributeDescriberKind::KeyedLock => KeyedLock::get_attribute_describer(),
+            AttributeDescriberKind::OpenState => OpenState::get_attribute_describer(),
+            AttributeDescriberKind::PortableLight => PortableLight::get_attribute_describer(),
+            AttributeDescriberKind::SleepState => SleepState::get_attribute_describer(),
+            AttributeDescriberKind::Volume => Volume::get_attribute_describer(),
+            AttributeDescriberKind::Weapon => Weapon::get_attribute_describer(),
+            AttributeDescriberKind::Wearable => Wearable::get_attribute_describer(),
+            AttributeDescriberKind::Weight => Weight::get_attribute_describer(),
+            AttributeDescriberKind::WornItems => WornItems::get_attribute_describer(),
+            AttributeDescriberKind::Wetness => Wetness::get_attribute_describer(),
+        }
+    }
 }
 
 /// The level of detail to use for attribute descriptions.
@@ -404,7 +497,7 @@ pub enum AttributeDetailLevel {
 }
 
 /// A description of a single attribute of an entity.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum AttributeDescription {
     /// An attribute that should be described as a regular sentence, like the fact that an entity is closed.
     NonSection(NonSectionAttributeDescription),
@@ -458,7 +551,7 @@ impl AttributeDescription {
 }
 
 /// A description of a single attribute of an entity that shouldn't be part of a description section.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct NonSectionAttributeDescription {
     /// The type of attribute.
     pub attribute_type: NonSectionAttributeType,
@@ -466,7 +559,7 @@ pub struct NonSectionAttributeDescription {
     pub description: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum NonSectionAttributeType {
     /// Something the entity is, like "closed" or "broken".
     Is,
@@ -480,7 +573,7 @@ pub enum NonSectionAttributeType {
     Wields,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AttributeSection {
     /// The name of the section
     pub name: AttributeSectionName,
@@ -488,7 +581,7 @@ pub struct AttributeSection {
     pub attributes: Vec<SectionAttributeDescription>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
 pub enum AttributeSectionName {
     Item,
     Edible,
@@ -498,7 +591,7 @@ pub enum AttributeSectionName {
     Other(String),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SectionAttributeDescription {
     /// The name of the attribute, e.g. "Weight"
     pub name: String,
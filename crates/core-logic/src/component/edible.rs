@@ -1,14 +1,15 @@
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::AttributeDescription;
 
 use super::{
-    AttributeDescriber, AttributeDetailLevel, AttributeSection, AttributeSectionName, Calories,
-    DescribeAttributes, SectionAttributeDescription,
+    AttributeDescriber, AttributeDescriberKind, AttributeDetailLevel, AttributeSection,
+    AttributeSectionName, Calories, DescribeAttributes, SectionAttributeDescription,
 };
 
 /// Marks an entity as edible.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct Edible;
 
 /// Notes if an entity is edible.
@@ -39,6 +40,10 @@ impl AttributeDescriber for EdibleAttributeDescriber {
 
         Vec::new()
     }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::Edible
+    }
 }
 
 impl DescribeAttributes for Edible {
@@ -1,6 +1,10 @@
 use std::num::NonZeroU8;
 
-use bevy_ecs::prelude::*;
+use bevy_ecs::{
+    entity::{EntityMapper, MapEntities},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     action::{PutAction, WearAction},
@@ -10,12 +14,12 @@ use crate::{
 };
 
 use super::{
-    get_hands_to_equip, AttributeDescriber, AttributeDetailLevel, BeforeActionNotification,
-    DescribeAttributes,
+    get_hands_to_equip, AttributeDescriber, AttributeDescriberKind, AttributeDetailLevel,
+    BeforeActionNotification, DescribeAttributes,
 };
 
 /// The things an entity has equipped.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct EquippedItems {
     /// The number of hands the entity can equip things in.
     pub hands: u8,
@@ -23,6 +27,14 @@ pub struct EquippedItems {
     items: Vec<Entity>,
 }
 
+impl MapEntities for EquippedItems {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for item in self.items.iter_mut() {
+            *item = entity_mapper.map_entity(*item);
+        }
+    }
+}
+
 /// An error when trying to equip something.
 #[derive(Debug)]
 pub enum EquipError {
@@ -198,6 +210,10 @@ impl AttributeDescriber for EquippedItemsAttributeDescriber {
 
         descriptions
     }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::EquippedItems
+    }
 }
 
 impl DescribeAttributes for EquippedItems {
@@ -2,6 +2,7 @@ use std::{collections::HashSet, sync::LazyLock};
 
 use bevy_ecs::prelude::*;
 use rand::{seq::SliceRandom, thread_rng};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     body_part::BodyPartType,
@@ -14,14 +15,15 @@ use crate::{
     notification::ReturningNotificationHandlers,
     parse_attack_input, Action, ActionEndNotification, ActionInterruptResult,
     ActionNotificationSender, ActionResult, ActionTag, AfterActionPerformNotification, AttackType,
-    BasicTokens, BeforeActionNotification, BodyPart, ChosenWeapon, Description, DynamicMessage,
-    DynamicMessageLocation, IntegerExtensions, InternalMessageCategory, MessageCategory,
-    MessageDelay, MessageFormat, NotificationHandlers, ParseCustomInput,
-    SurroundingsMessageCategory, VerifyActionNotification, VerifyResult, Weapon, WeaponMessages,
+    BasicTokens, BeforeActionNotification, BodyPart, ChosenWeapon, CustomInputParserKind,
+    Description, DynamicMessage, DynamicMessageLocation, IntegerExtensions,
+    InternalMessageCategory, MessageCategory, MessageDelay, MessageFormat, NotificationHandlers,
+    ParseCustomInput, SurroundingsMessageCategory, VerifyActionNotification, VerifyResult, Weapon,
+    WeaponMessages,
 };
 
 /// A component that provides special attack actions for fists.
-#[derive(Component)]
+#[derive(Component, Clone, Serialize, Deserialize)]
 pub struct FistActions {
     /// Messages for the uppercut attack.
     pub uppercut_messages: WeaponMessages,
@@ -33,6 +35,10 @@ impl ParseCustomInput for FistActions {
     fn get_parsers() -> Vec<Box<dyn InputParser>> {
         vec![Box::new(UppercutParser), Box::new(HaymakerParser)]
     }
+
+    fn get_kind() -> CustomInputParserKind {
+        CustomInputParserKind::FistActions
+    }
 }
 
 impl FistActions {
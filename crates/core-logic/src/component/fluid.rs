@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
 use crate::resource::FluidDensityCatalog;
@@ -8,8 +9,9 @@ use crate::resource::FluidDensityCatalog;
 use super::{Volume, Weight};
 
 /// Marks an entity as a fluid.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct Fluid {
+    #[serde(with = "crate::map_as_pairs")]
     pub contents: HashMap<FluidType, Volume>,
 }
 
@@ -104,7 +106,7 @@ pub struct FluidTypeAmount {
     pub fraction: f32,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
 pub enum FluidType {
     Water,
     DirtyWater,
@@ -2,6 +2,7 @@ use std::{cmp::Ordering, collections::HashMap};
 
 use bevy_ecs::prelude::*;
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     action::{PourAction, PourAmount},
@@ -12,13 +13,13 @@ use crate::{
 };
 
 use super::{
-    AttributeDescriber, AttributeDetailLevel, AttributeSection, AttributeSectionName,
-    DescribeAttributes, Fluid, OpenState, SectionAttributeDescription, VerifyActionNotification,
-    Volume,
+    AttributeDescriber, AttributeDescriberKind, AttributeDetailLevel, AttributeSection,
+    AttributeSectionName, DescribeAttributes, Fluid, OpenState, SectionAttributeDescription,
+    VerifyActionNotification, Volume,
 };
 
 /// Fluid contents of an entity.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct FluidContainer {
     /// The contained fluid.
     pub contents: Fluid,
@@ -129,6 +130,10 @@ impl AttributeDescriber for FluidContainerAttributeDescriber {
 
         Vec::new()
     }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::FluidContainer
+    }
 }
 
 impl DescribeAttributes for FluidContainer {
@@ -0,0 +1,9 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Records which prototype an entity was spawned from, so it can be updated when the prototype changes.
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct FromPrototype {
+    /// The ID of the prototype.
+    pub id: String,
+}
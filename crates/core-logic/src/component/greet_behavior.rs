@@ -1,4 +1,5 @@
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     action::{ActionNotificationSender, MoveAction, SayAction},
@@ -8,7 +9,7 @@ use crate::{
 use super::{ActionQueue, AfterActionPerformNotification, Container, Location};
 
 /// Makes an entity greet entities that enter its location.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct GreetBehavior {
     /// What the entity will say as a greeting.
     pub greeting: String,
@@ -1,9 +1,10 @@
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{Container, Weapon};
 
 /// Marks an entity as the weapon an entity uses when it has nothing equipped.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct InnateWeapon;
 
 impl InnateWeapon {
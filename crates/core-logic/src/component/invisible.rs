@@ -1,15 +1,34 @@
 use std::collections::HashSet;
 
-use bevy_ecs::prelude::*;
+use bevy_ecs::{
+    entity::{EntityMapper, MapEntities},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
 
 /// Marks an entity as invisible to certain other entities.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct Invisible {
     /// The scope describing which entities this one is hidden from.
     scope: InvisibilityScope,
 }
 
+impl MapEntities for Invisible {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        match &mut self.scope {
+            InvisibilityScope::All => (),
+            InvisibilityScope::Entities(entities) | InvisibilityScope::AllExcept(entities) => {
+                *entities = entities
+                    .drain()
+                    .map(|entity| entity_mapper.map_entity(entity))
+                    .collect();
+            }
+        }
+    }
+}
+
 /// Describes which entities an invisible entity is hidden from.
+#[derive(Serialize, Deserialize)]
 pub enum InvisibilityScope {
     /// Invisible to all entities.
     All,
@@ -1,16 +1,17 @@
 use std::num::NonZeroU8;
 
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::AttributeDescription;
 
 use super::{
-    AttributeDescriber, AttributeDetailLevel, AttributeSection, AttributeSectionName,
-    DescribeAttributes, SectionAttributeDescription,
+    AttributeDescriber, AttributeDescriberKind, AttributeDetailLevel, AttributeSection,
+    AttributeSectionName, DescribeAttributes, SectionAttributeDescription,
 };
 
 /// Marks an entity as able to be picked up.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct Item {
     /// The number of hands needed to equip the item.
     pub hands_to_equip: NonZeroU8,
@@ -18,7 +19,6 @@ pub struct Item {
 
 impl Item {
     /// Creates an `Item` that requires the provided number of hands to equip.
-    #[expect(unused)]
     pub fn new(hands_to_equip: NonZeroU8) -> Item {
         Item { hands_to_equip }
     }
@@ -67,6 +67,10 @@ impl AttributeDescriber for ItemAttributeDescriber {
 
         Vec::new()
     }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::Item
+    }
 }
 
 impl DescribeAttributes for Item {
@@ -1,6 +1,7 @@
 use std::{collections::HashSet, sync::LazyLock};
 
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     action::{Action, ActionInterruptResult, ActionNotificationSender, ActionResult, OpenAction},
@@ -18,8 +19,9 @@ use crate::{
 
 use super::{
     ActionEndNotification, ActionQueue, AfterActionPerformNotification, AttributeDescriber,
-    AttributeDetailLevel, BeforeActionNotification, Connection, Container, DescribeAttributes,
-    Description, Location, ParseCustomInput, VerifyActionNotification,
+    AttributeDescriberKind, AttributeDetailLevel, BeforeActionNotification, Connection, Container,
+    CustomInputParserKind, DescribeAttributes, Description, Location, ParseCustomInput,
+    VerifyActionNotification,
 };
 
 static TARGET_PART_ID: CommandPartId<Entity> = CommandPartId::new("target");
@@ -288,12 +290,12 @@ impl Action for LockAction {
 }
 
 /// The ID of an entity that can be used to lock or unlock things.
-#[derive(Component, Clone, PartialEq, Eq)]
+#[derive(Component, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct KeyId(pub u32);
 
 /// Describes whether an entity is locked or unlocked using a key.
 /// TODO make locks entities that are installed on things, rather than components
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct KeyedLock {
     /// Whether the entity is locked.
     pub is_locked: bool,
@@ -343,6 +345,10 @@ impl ParseCustomInput for KeyedLock {
     fn get_parsers() -> Vec<Box<dyn InputParser>> {
         vec![Box::new(LockParser)]
     }
+
+    fn get_kind() -> CustomInputParserKind {
+        CustomInputParserKind::KeyedLock
+    }
 }
 
 /// Describes whether the entity is locked or not.
@@ -369,6 +375,10 @@ impl AttributeDescriber for KeyedLockAttributeDescriber {
 
         Vec::new()
     }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::KeyedLock
+    }
 }
 
 impl DescribeAttributes for KeyedLock {
@@ -0,0 +1,195 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{game_map::Coordinates, is_living_entity, Time};
+
+use super::{Connection, Container, EquippedItems, Location, PortableLight, Room};
+
+/// How well lit a place is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum LightLevel {
+    /// Too dark to see anything that isn't right in front of you.
+    Dark,
+    /// Dark enough to make things harder to see, but not to hide them.
+    Dim,
+    /// Light enough to see everything.
+    Bright,
+}
+
+/// Makes an entity give off light, lighting up the room it's in. If the entity is a room, the room itself is lit.
+#[derive(Debug, Clone, Copy, Component, Serialize, Deserialize)]
+pub struct LightSource {
+    /// How much light the entity gives off.
+    pub light_level: LightLevel,
+}
+
+impl LightLevel {
+    /// Determines how well lit the provided room is.
+    ///
+    /// Outdoor rooms are lit by the sun depending on the time of day, and all rooms are lit by any light sources in them,
+    /// including portable lights that are lying in the room or equipped by someone in it.
+    pub fn of_room(room: Entity, world: &World) -> LightLevel {
+        let daylight = match world.get::<Coordinates>(room) {
+            Some(coords) if coords.parent.is_none() => world
+                .get_resource::<Time>()
+                .map(Time::get_daylight)
+                .unwrap_or(LightLevel::Bright),
+            _ => LightLevel::Dark,
+        };
+
+        let contained_entities = world
+            .get::<Container>(room)
+            .map(|container| container.get_entities_including_invisible())
+            .into_iter()
+            .flatten()
+            .copied();
+        let equipped_items = contained_entities
+            .clone()
+            .filter_map(|entity| world.get::<EquippedItems>(entity))
+            .flat_map(|equipped_items| equipped_items.get_items())
+            .copied();
+
+        [room]
+            .into_iter()
+            .chain(contained_entities)
+            .chain(equipped_items)
+            .filter_map(|entity| {
+                world
+                    .get::<LightSource>(entity)
+                    .map(|light_source| light_source.light_level)
+                    .or_else(|| PortableLight::get_light_level(entity, world))
+            })
+            .fold(daylight, LightLevel::max)
+    }
+
+    /// Determines how well lit the room the provided entity is in is, even if it's inside something else in the room.
+    /// Entities that aren't in a room at all are considered to be in the dark.
+    pub fn around(entity: Entity, world: &World) -> LightLevel {
+        find_containing_room(entity, world)
+            .map(|room| LightLevel::of_room(room, world))
+            .unwrap_or(LightLevel::Dark)
+    }
+
+    /// Determines whether `entity` is hidden from `pov_entity` because it's lying in a dark room.
+    ///
+    /// Living things can still be made out in the dark, and exits can still be felt for.
+    pub fn is_hidden_by_darkness(entity: Entity, pov_entity: Entity, world: &World) -> bool {
+        world
+            .get::<Location>(entity)
+            .and_then(|location| LightLevel::of_location(location.id, world))
+            .is_some_and(|light_level| light_level.hides(entity, pov_entity, world))
+    }
+
+    /// Determines how well lit the provided location is, if it's a room. Things inside other things aren't lit or hidden by the room around them.
+    pub fn of_location(location: Entity, world: &World) -> Option<LightLevel> {
+        world
+            .get::<Room>(location)
+            .map(|_| LightLevel::of_room(location, world))
+    }
+
+    /// Determines whether `entity` is hidden from `pov_entity` because it's lying in a room with this light level.
+    ///
+    /// Living things can still be made out in the dark, and exits can still be felt for.
+    pub fn hides(self, entity: Entity, pov_entity: Entity, world: &World) -> bool {
+        self == LightLevel::Dark
+            && entity != pov_entity
+            && !is_living_entity(entity, world)
+            && world.get::<Connection>(entity).is_none()
+    }
+}
+
+/// Finds the room the provided entity is in, even if it's inside something else in the room.
+pub(crate) fn find_containing_room(entity: Entity, world: &World) -> Option<Entity> {
+    let mut location = world.get::<Location>(entity);
+    while let Some(Location { id }) = location {
+        if world.get::<Room>(*id).is_some() {
+            return Some(*id);
+        }
+        location = world.get::<Location>(*id);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{game_map::GameMap, move_entity, test_utils::spawn_test_room};
+
+    use super::*;
+
+    fn set_up_world(hour: u8) -> World {
+        let mut world = World::new();
+        world.insert_resource(GameMap::new());
+        world.insert_resource(Time {
+            day: 1,
+            hour,
+            minute: 0,
+            second: 0,
+        });
+        world
+    }
+
+    #[test]
+    fn outdoor_rooms_follow_the_sun() {
+        let mut world = set_up_world(12);
+        let room = spawn_test_room(None, &mut world);
+        assert_eq!(LightLevel::Bright, LightLevel::of_room(room, &world));
+
+        world.resource_mut::<Time>().hour = 20;
+        assert_eq!(LightLevel::Dim, LightLevel::of_room(room, &world));
+
+        world.resource_mut::<Time>().hour = 2;
+        assert_eq!(LightLevel::Dark, LightLevel::of_room(room, &world));
+    }
+
+    #[test]
+    fn indoor_rooms_are_lit_by_light_sources() {
+        let mut world = set_up_world(12);
+        let room = spawn_test_room(
+            Some(Coordinates {
+                x: 5,
+                y: 5,
+                z: 0,
+                parent: None,
+            }),
+            &mut world,
+        );
+        assert_eq!(LightLevel::Dark, LightLevel::of_room(room, &world));
+
+        let lamp = world
+            .spawn(LightSource {
+                light_level: LightLevel::Dim,
+            })
+            .id();
+        move_entity(lamp, room, &mut world);
+        assert_eq!(LightLevel::Dim, LightLevel::of_room(room, &world));
+
+        world.entity_mut(room).insert(LightSource {
+            light_level: LightLevel::Bright,
+        });
+        assert_eq!(LightLevel::Bright, LightLevel::of_room(room, &world));
+    }
+
+    #[test]
+    fn objects_are_hidden_in_the_dark() {
+        let mut world = set_up_world(2);
+        let room = spawn_test_room(None, &mut world);
+        let looker = world.spawn_empty().id();
+        move_entity(looker, room, &mut world);
+        let object = world.spawn_empty().id();
+        move_entity(object, room, &mut world);
+
+        assert!(LightLevel::is_hidden_by_darkness(object, looker, &world));
+        assert!(!LightLevel::is_hidden_by_darkness(looker, looker, &world));
+        let visible = world
+            .get::<Container>(room)
+            .unwrap()
+            .get_entities(looker, &world);
+        assert_eq!(HashSet::from([looker]), visible);
+
+        world.resource_mut::<Time>().hour = 12;
+        assert!(!LightLevel::is_hidden_by_darkness(object, looker, &world));
+    }
+}
@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{GrammaticalGender, Locale};
+
+/// The names and descriptions of an entity in locales other than the default one, which its `Description` is in.
+#[derive(Component, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LocalizedDescriptions(pub HashMap<Locale, LocalizedDescription>);
+
+/// The name and description of an entity in a particular locale.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LocalizedDescription {
+    /// The name of the entity.
+    pub name: String,
+    /// The name to use when referring to multiple instances of the entity. Defaults to the name.
+    #[serde(default)]
+    pub plural_name: Option<String>,
+    /// The grammatical gender of the name, which determines the articles and pronouns used with it.
+    #[serde(default)]
+    pub gender: GrammaticalGender,
+    /// Whether the name is a proper noun, and so shouldn't have an article.
+    #[serde(default)]
+    pub proper_noun: bool,
+    /// The description of the entity. Defaults to the one in the entity's `Description`.
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl LocalizedDescriptions {
+    /// Gets the name and description of the provided entity in the provided locale, if it has them.
+    pub fn get(entity: Entity, locale: Locale, world: &World) -> Option<&LocalizedDescription> {
+        world
+            .get::<LocalizedDescriptions>(entity)
+            .and_then(|localized| localized.0.get(&locale))
+    }
+}
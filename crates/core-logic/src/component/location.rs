@@ -1,8 +1,18 @@
-use bevy_ecs::prelude::*;
+use bevy_ecs::{
+    entity::{EntityMapper, MapEntities},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
 
 /// The location of an entity.
-#[derive(Component, PartialEq, Eq, Debug)]
+#[derive(Component, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct Location {
     /// The ID of the other entity the entity is located in.
     pub id: Entity,
 }
+
+impl MapEntities for Location {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.id = entity_mapper.map_entity(self.id);
+    }
+}
@@ -2,6 +2,7 @@ use bevy_ecs::prelude::*;
 
 mod description;
 pub use description::AttributeDescriber;
+pub use description::AttributeDescriberKind;
 pub use description::AttributeDescription;
 pub use description::AttributeDetailLevel;
 pub use description::AttributeSection;
@@ -14,6 +15,10 @@ pub use description::PortionMatched;
 pub use description::Pronouns;
 pub use description::SectionAttributeDescription;
 
+mod localized_descriptions;
+pub use localized_descriptions::LocalizedDescription;
+pub use localized_descriptions::LocalizedDescriptions;
+
 mod location;
 pub use location::Location;
 
@@ -32,11 +37,13 @@ pub use keyed_lock::KeyedLock;
 
 mod custom_input_parser;
 pub use custom_input_parser::CustomInputParser;
+pub use custom_input_parser::CustomInputParserKind;
 pub use custom_input_parser::ParseCustomInput;
 
 mod player;
 pub use player::Player;
 pub use player::PlayerId;
+pub use player::PlayerMessageTokens;
 
 mod action_queue;
 pub use action_queue::try_perform_queued_actions;
@@ -64,6 +71,7 @@ pub use density::Density;
 
 mod vitals;
 pub use vitals::Vitals;
+pub use vitals::NORMAL_BODY_TEMPERATURE;
 
 mod respawner;
 pub use respawner::Respawner;
@@ -93,6 +101,16 @@ pub use self_defense_behavior::SelfDefenseBehavior;
 mod greet_behavior;
 pub use greet_behavior::GreetBehavior;
 
+mod script;
+pub use script::Script;
+
+mod script_command;
+pub use script_command::ScriptCommandAction;
+pub use script_command::ScriptCommands;
+
+mod from_prototype;
+pub use from_prototype::FromPrototype;
+
 mod item;
 pub use item::get_hands_to_equip;
 pub use item::Item;
@@ -140,8 +158,6 @@ pub use weapon::WeaponMissMessageTokens;
 pub use weapon::WeaponPerformanceAdjustment;
 pub use weapon::WeaponRanges;
 pub use weapon::WeaponStatBonuses;
-pub use weapon::WeaponStatRequirement;
-pub use weapon::WeaponStatRequirementNotMetBehavior;
 #[expect(unused)]
 pub use weapon::WeaponToHitAdjustment;
 pub use weapon::WeaponType;
@@ -157,6 +173,27 @@ pub use combat_state::CombatState;
 pub use combat_state::EnterCombatNotification;
 pub use combat_state::ExitCombatNotification;
 
+mod light_source;
+pub(crate) use light_source::find_containing_room;
+pub use light_source::LightLevel;
+pub use light_source::LightSource;
+
+mod ambient_temperature;
+pub use ambient_temperature::AmbientTemperature;
+
+mod wetness;
+pub use wetness::Wetness;
+
+mod radiation;
+pub use radiation::AntiRadiation;
+pub use radiation::GeigerCounter;
+pub use radiation::RadiationDose;
+pub use radiation::RadiationSource;
+
+mod portable_light;
+pub use portable_light::PortableLight;
+pub use portable_light::PowerSource;
+
 mod invisible;
 #[expect(unused)]
 pub use invisible::InvisibilityScope;
@@ -180,8 +217,12 @@ pub use status_effect::*;
 /// Registers notification handlers related to components.
 pub fn register_component_handlers(world: &mut World) {
     register_status_effect_handlers(world);
+    script::register_script_handlers(world);
 
     NotificationHandlers::add_handler(open_state::auto_open_connections, world);
+    NotificationHandlers::add_handler(portable_light::drain_portable_lights_on_tick, world);
+    NotificationHandlers::add_handler(ambient_temperature::change_body_temperature_on_tick, world);
+    NotificationHandlers::add_handler(radiation::absorb_radiation_on_tick, world);
     ReturningNotificationHandlers::add_handler(
         open_state::prevent_moving_through_closed_connections,
         world,
@@ -207,6 +248,7 @@ pub fn register_component_handlers(world: &mut World) {
     NotificationHandlers::add_handler(respawner::look_after_respawn, world);
 
     NotificationHandlers::add_handler(calories::increase_satiety_on_eat, world);
+    NotificationHandlers::add_handler(radiation::reduce_radiation_dose_on_eat, world);
 
     ReturningNotificationHandlers::add_handler(
         fluid_container::verify_source_and_target_container_amounts,
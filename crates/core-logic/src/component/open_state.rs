@@ -1,6 +1,7 @@
 use std::{collections::HashSet, sync::LazyLock};
 
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     action::{
@@ -21,8 +22,9 @@ use crate::{
 
 use super::{
     description::DescribeAttributes, ActionEndNotification, ActionQueue,
-    AfterActionPerformNotification, AttributeDescriber, AttributeDescription, AttributeDetailLevel,
-    Connection, Container, Description, Location, ParseCustomInput,
+    AfterActionPerformNotification, AttributeDescriber, AttributeDescriberKind,
+    AttributeDescription, AttributeDetailLevel, Connection, Container, CustomInputParserKind,
+    Description, Location, ParseCustomInput,
 };
 
 static TARGET_PART_ID: CommandPartId<Entity> = CommandPartId::new("target");
@@ -201,7 +203,7 @@ impl Action for SlamAction {
 }
 
 /// Describes whether an entity is open or closed.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct OpenState {
     /// Whether the entity is open.
     pub is_open: bool,
@@ -250,6 +252,10 @@ impl ParseCustomInput for OpenState {
     fn get_parsers() -> Vec<Box<dyn InputParser>> {
         vec![Box::new(SlamParser)]
     }
+
+    fn get_kind() -> CustomInputParserKind {
+        CustomInputParserKind::OpenState
+    }
 }
 
 /// Describes whether the entity is open or not.
@@ -272,6 +278,10 @@ impl AttributeDescriber for OpenStateAttributeDescriber {
 
         Vec::new()
     }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::OpenState
+    }
 }
 
 impl DescribeAttributes for OpenState {
@@ -9,7 +9,8 @@ use log::warn;
 use strum::IntoEnumIterator;
 
 use crate::{
-    GameMessage, InternalMessageCategory, MessageCategory, SurroundingsMessageCategory, Time,
+    FixedMessageTokens, GameMessage, InternalMessageCategory, MessageCategory, MessageTokens,
+    PlayerPreferences, SurroundingsMessageCategory, Time, TokenKind, TokenName, TokenValue,
 };
 
 /// A unique identifier for a player.
@@ -23,40 +24,101 @@ impl PlayerId {
     }
 }
 
+/// Tokens used in messages about players, like when they connect or die.
+#[derive(Debug)]
+pub struct PlayerMessageTokens {
+    /// The player's entity
+    pub player: Entity,
+}
+
+impl MessageTokens for PlayerMessageTokens {
+    fn get_token_map(&self) -> HashMap<TokenName, TokenValue> {
+        [("player".into(), TokenValue::Entity(self.player))].into()
+    }
+}
+
+impl FixedMessageTokens for PlayerMessageTokens {
+    fn get_token_kinds() -> HashMap<TokenName, TokenKind> {
+        [("player".into(), TokenKind::Entity)].into()
+    }
+}
+
 #[derive(Component)]
 pub struct Player {
     /// The unique ID of the player.
     pub id: PlayerId,
-    /// The sender to use to send messages to the player.
-    sender: Sender<(GameMessage, Time)>,
+    /// The name the player identified themself with when connecting, used to reattach them to this entity if they reconnect.
+    pub identity: String,
+    /// The sender to use to send messages to the player. `None` if the player has disconnected.
+    sender: Option<Sender<(GameMessage, Time)>>,
     /// Filter for messages to send to the player.
     pub message_filter: MessageFilter,
+    /// The settings the player has chosen for how the game is presented to them.
+    pub preferences: PlayerPreferences,
     /// The time this player last sent a command.
     pub last_command_time: SystemTime,
 }
 
 impl Player {
-    /// Creates a player with the provided ID and message sender.
-    pub fn new(id: PlayerId, sender: Sender<(GameMessage, Time)>) -> Player {
+    /// Creates a player with the provided ID, identity, and message sender.
+    pub fn new(id: PlayerId, identity: String, sender: Sender<(GameMessage, Time)>) -> Player {
         Player {
             id,
-            sender,
+            identity,
+            sender: Some(sender),
             message_filter: MessageFilter::new(),
+            preferences: PlayerPreferences::default(),
             last_command_time: SystemTime::now(),
         }
     }
 
-    /// Sends a message to this player. Logs and ignores any errors from the message sender.
+    /// Creates a player with the provided ID and identity that isn't connected to anything.
+    pub fn new_linkdead(id: PlayerId, identity: String) -> Player {
+        Player {
+            id,
+            identity,
+            sender: None,
+            message_filter: MessageFilter::new(),
+            preferences: PlayerPreferences::default(),
+            last_command_time: SystemTime::now(),
+        }
+    }
+
+    /// Disconnects this player from their message sender.
+    pub fn detach(&mut self) {
+        self.sender = None;
+    }
+
+    /// Connects this player to a new message sender, giving them the provided ID.
+    pub fn reattach(&mut self, id: PlayerId, sender: Sender<(GameMessage, Time)>) {
+        self.id = id;
+        self.sender = Some(sender);
+        self.last_command_time = SystemTime::now();
+    }
+
+    /// Determines whether this player has disconnected.
+    pub fn is_linkdead(&self) -> bool {
+        self.sender.is_none()
+    }
+
+    /// Sends a message to this player, unless it's filtered out or they've chosen not to get messages like it. Logs and ignores any errors from the message sender.
     pub fn send_message(&self, message: GameMessage, time: Time) {
-        if self.message_filter.accept(&message) {
-            if let Err(e) = self.sender.send((message, time)) {
-                warn!("Error sending message to player {:?}: {}", self.id, e);
+        if let Some(sender) = &self.sender {
+            if self.message_filter.accept(&message) && self.preferences.accept(&message) {
+                let message = self.preferences.apply(message);
+                if let Err(e) = sender.send((message, time)) {
+                    warn!("Error sending message to player {:?}: {}", self.id, e);
+                }
             }
         }
     }
 
-    /// Determines whether this player is AFK.
+    /// Determines whether this player is AFK. Players who have disconnected are always AFK.
     pub fn is_afk(&self, afk_timeout: Option<Duration>) -> bool {
+        if self.is_linkdead() {
+            return true;
+        }
+
         if let Some(afk_timeout) = afk_timeout {
             match SystemTime::now().duration_since(self.last_command_time) {
                 Ok(elapsed) => elapsed >= afk_timeout,
@@ -0,0 +1,488 @@
+use std::{collections::HashSet, sync::LazyLock};
+
+use bevy_ecs::prelude::*;
+use nonempty::nonempty;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    action::{Action, ActionInterruptResult, ActionNotificationSender, ActionResult},
+    command_format::{
+        entity_part_builder, literal_part, one_of_literal_part,
+        validate_parsed_value_has_component, CommandFormat, CommandPartId,
+    },
+    component::VerifyResult,
+    input_parser::{input_formats_if_has_component, InputParseError, InputParser},
+    notification::Notification,
+    ActionTag, BasicTokens, BeforeActionNotification, DynamicMessage, DynamicMessageLocation,
+    InternalMessageCategory, MessageCategory, MessageDelay, MessageFormat,
+    SurroundingsMessageCategory, TickNotification, VerifyActionNotification,
+};
+
+use super::{
+    description::DescribeAttributes, light_source::find_containing_room, ActionEndNotification,
+    AfterActionPerformNotification, AttributeDescriber, AttributeDescriberKind,
+    AttributeDescription, AttributeDetailLevel, CustomInputParserKind, Description, LightLevel,
+    ParseCustomInput,
+};
+
+static TARGET_PART_ID: CommandPartId<Entity> = CommandPartId::new("target");
+static TURN_ON_FORMAT: LazyLock<CommandFormat> = LazyLock::new(|| {
+    CommandFormat::new(
+        one_of_literal_part(nonempty!["turn on", "switch on", "light"])
+            .with_error_string_override("turn on"),
+    )
+    .then(literal_part(" "))
+    .then(
+        entity_part_builder(TARGET_PART_ID)
+            .with_validator(|context, world| {
+                validate_parsed_value_has_component::<PortableLight>(context, "turn on", world)
+            })
+            .build()
+            .with_if_unparsed("what")
+            .with_placeholder_for_format_string("light"),
+    )
+});
+static TURN_OFF_FORMAT: LazyLock<CommandFormat> = LazyLock::new(|| {
+    CommandFormat::new(
+        one_of_literal_part(nonempty!["turn off", "switch off", "put out", "extinguish"])
+            .with_error_string_override("turn off"),
+    )
+    .then(literal_part(" "))
+    .then(
+        entity_part_builder(TARGET_PART_ID)
+            .with_validator(|context, world| {
+                validate_parsed_value_has_component::<PortableLight>(context, "turn off", world)
+            })
+            .build()
+            .with_if_unparsed("what")
+            .with_placeholder_for_format_string("light"),
+    )
+});
+
+struct TurnOnParser;
+
+impl InputParser for TurnOnParser {
+    fn parse(
+        &self,
+        input: &str,
+        source_entity: Entity,
+        world: &World,
+    ) -> Result<Box<dyn Action>, InputParseError> {
+        let (parsed, should_be_on) = match TURN_ON_FORMAT.parse(input, source_entity, world) {
+            Ok(parsed) => (parsed, true),
+            Err(e) => {
+                if e.num_parts_matched() > 0 {
+                    return Err(e.into());
+                }
+                (TURN_OFF_FORMAT.parse(input, source_entity, world)?, false)
+            }
+        };
+
+        Ok(Box::new(TurnOnAction {
+            target: parsed.get(TARGET_PART_ID),
+            should_be_on,
+            notification_sender: ActionNotificationSender::new(),
+        }))
+    }
+
+    fn get_input_formats(&self) -> Vec<String> {
+        vec![
+            TURN_ON_FORMAT.get_format_description().to_string(),
+            TURN_OFF_FORMAT.get_format_description().to_string(),
+        ]
+    }
+
+    fn get_input_formats_for(&self, entity: Entity, _: Entity, world: &World) -> Vec<String> {
+        input_formats_if_has_component::<PortableLight>(
+            entity,
+            world,
+            &[
+                TURN_ON_FORMAT
+                    .get_format_description()
+                    .with_targeted_entity(TARGET_PART_ID, entity, world),
+                TURN_OFF_FORMAT
+                    .get_format_description()
+                    .with_targeted_entity(TARGET_PART_ID, entity, world),
+            ],
+        )
+    }
+}
+
+/// Makes an entity turn a portable light on or off.
+#[derive(Debug)]
+struct TurnOnAction {
+    target: Entity,
+    should_be_on: bool,
+    notification_sender: ActionNotificationSender<Self>,
+}
+
+impl Action for TurnOnAction {
+    fn perform(&mut self, performing_entity: Entity, world: &mut World) -> ActionResult {
+        let target_name =
+            Description::get_reference_name(self.target, Some(performing_entity), world);
+        let Some(light) = world.get::<PortableLight>(self.target) else {
+            return ActionResult::error(
+                performing_entity,
+                format!("You can't turn {target_name} on or off."),
+            );
+        };
+        let power_source = light.power_source;
+
+        if light.is_on == self.should_be_on {
+            let on_or_off = if light.is_on { "on" } else { "off" };
+            return ActionResult::message(
+                performing_entity,
+                format!("{target_name} is already {on_or_off}."),
+                MessageCategory::Internal(InternalMessageCategory::Misc),
+                MessageDelay::Short,
+                false,
+            );
+        }
+
+        if self.should_be_on && light.fuel == 0 {
+            return ActionResult::error(
+                performing_entity,
+                format!(
+                    "{target_name} has no {} left.",
+                    power_source.get_fuel_name()
+                ),
+            );
+        }
+
+        if let Some(mut light) = world.get_mut::<PortableLight>(self.target) {
+            light.is_on = self.should_be_on;
+        }
+
+        let (second_person_verb, third_person_verb) = if self.should_be_on {
+            power_source.get_turn_on_verbs()
+        } else {
+            power_source.get_turn_off_verbs()
+        };
+
+        ActionResult::builder()
+            .with_dynamic_message(
+                Some(performing_entity),
+                DynamicMessageLocation::SourceEntity,
+                DynamicMessage::new(
+                    MessageCategory::Internal(InternalMessageCategory::Action),
+                    MessageDelay::Short,
+                    MessageFormat::new(&format!("You {second_person_verb} ${{target.name}}."))
+                        .expect("message format should be valid"),
+                    BasicTokens::new().with_entity("target".into(), self.target),
+                )
+                .only_send_to(performing_entity),
+                world,
+            )
+            .with_dynamic_message(
+                Some(performing_entity),
+                DynamicMessageLocation::SourceEntity,
+                DynamicMessage::new_third_person(
+                    MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
+                    MessageDelay::Short,
+                    MessageFormat::new(&format!(
+                        "${{performer.Name}} {third_person_verb} ${{target.name}}."
+                    ))
+                    .expect("message format should be valid"),
+                    BasicTokens::new()
+                        .with_entity("performer".into(), performing_entity)
+                        .with_entity("target".into(), self.target),
+                ),
+                world,
+            )
+            .build_complete_should_tick(false)
+    }
+
+    fn interrupt(&self, performing_entity: Entity, _: &mut World) -> ActionInterruptResult {
+        let on_or_off = if self.should_be_on { "on" } else { "off" };
+        ActionInterruptResult::message(
+            performing_entity,
+            format!("You stop turning the light {on_or_off}."),
+            MessageCategory::Internal(InternalMessageCategory::Action),
+            MessageDelay::None,
+        )
+    }
+
+    fn may_require_tick(&self) -> bool {
+        false
+    }
+
+    fn get_tags(&self) -> HashSet<ActionTag> {
+        [].into()
+    }
+
+    fn send_before_notification(
+        &self,
+        notification_type: BeforeActionNotification,
+        world: &mut World,
+    ) {
+        self.notification_sender
+            .send_before_notification(notification_type, self, world);
+    }
+
+    fn send_verify_notification(
+        &self,
+        notification_type: VerifyActionNotification,
+        world: &mut World,
+    ) -> Vec<VerifyResult> {
+        self.notification_sender
+            .send_verify_notification(notification_type, self, world)
+    }
+
+    fn send_after_perform_notification(
+        &self,
+        notification_type: AfterActionPerformNotification,
+        world: &mut World,
+    ) {
+        self.notification_sender
+            .send_after_perform_notification(notification_type, self, world);
+    }
+
+    fn send_end_notification(&self, notification_type: ActionEndNotification, world: &mut World) {
+        self.notification_sender
+            .send_end_notification(notification_type, self, world);
+    }
+}
+
+/// What a portable light runs on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PowerSource {
+    /// Batteries, like a flashlight.
+    Battery,
+    /// Oil, like a lantern.
+    Oil,
+    /// The light itself burns away, like a torch.
+    Burning,
+}
+
+impl PowerSource {
+    /// The name of what runs out as the light is used.
+    fn get_fuel_name(self) -> &'static str {
+        match self {
+            PowerSource::Battery => "battery charge",
+            PowerSource::Oil => "oil",
+            PowerSource::Burning => "fuel",
+        }
+    }
+
+    /// The second-person and third-person verbs for turning on a light with this power source.
+    fn get_turn_on_verbs(self) -> (&'static str, &'static str) {
+        match self {
+            PowerSource::Battery => ("turn on", "turns on"),
+            PowerSource::Oil | PowerSource::Burning => ("light", "lights"),
+        }
+    }
+
+    /// The second-person and third-person verbs for turning off a light with this power source.
+    fn get_turn_off_verbs(self) -> (&'static str, &'static str) {
+        match self {
+            PowerSource::Battery => ("turn off", "turns off"),
+            PowerSource::Oil | PowerSource::Burning => ("put out", "puts out"),
+        }
+    }
+
+    /// What a light with this power source does when it runs out.
+    fn get_run_out_description(self) -> &'static str {
+        match self {
+            PowerSource::Battery => "flickers and dies",
+            PowerSource::Oil => "sputters and goes out",
+            PowerSource::Burning => "burns out",
+        }
+    }
+}
+
+/// A light that can be carried around and turned on and off, and runs out of fuel while it's on.
+///
+/// It only lights up the room it's in if it's lying in the room or is equipped by someone in the room.
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
+pub struct PortableLight {
+    /// How much light it gives off when it's on.
+    pub light_level: LightLevel,
+    /// What it runs on.
+    pub power_source: PowerSource,
+    /// How many ticks it can stay on for when it's full.
+    pub capacity: u32,
+    /// How many ticks it can stay on for before it runs out.
+    pub fuel: u32,
+    /// Whether it's on.
+    pub is_on: bool,
+}
+
+impl PortableLight {
+    /// Creates a light that's off and full of fuel.
+    pub fn new(light_level: LightLevel, power_source: PowerSource, capacity: u32) -> PortableLight {
+        PortableLight {
+            light_level,
+            power_source,
+            capacity,
+            fuel: capacity,
+            is_on: false,
+        }
+    }
+
+    /// Determines how much light the provided entity is giving off as a portable light, if it's one that's on.
+    pub fn get_light_level(entity: Entity, world: &World) -> Option<LightLevel> {
+        world
+            .get::<PortableLight>(entity)
+            .filter(|light| light.is_on && light.fuel > 0)
+            .map(|light| light.light_level)
+    }
+}
+
+impl ParseCustomInput for PortableLight {
+    fn get_parsers() -> Vec<Box<dyn InputParser>> {
+        vec![Box::new(TurnOnParser)]
+    }
+
+    fn get_kind() -> CustomInputParserKind {
+        CustomInputParserKind::PortableLight
+    }
+}
+
+/// Describes whether a portable light is on, and how much fuel it has left.
+#[derive(Debug)]
+struct PortableLightAttributeDescriber;
+
+impl AttributeDescriber for PortableLightAttributeDescriber {
+    fn describe(
+        &self,
+        _: Entity,
+        entity: Entity,
+        detail_level: AttributeDetailLevel,
+        world: &World,
+    ) -> Vec<AttributeDescription> {
+        let Some(light) = world.get::<PortableLight>(entity) else {
+            return Vec::new();
+        };
+
+        let on_or_off = if light.is_on { "on" } else { "off" };
+        let mut descriptions = vec![AttributeDescription::is(on_or_off.to_string())];
+
+        let fuel_name = light.power_source.get_fuel_name();
+        if light.fuel == 0 {
+            descriptions.push(AttributeDescription::has(format!("no {fuel_name} left")));
+        } else if detail_level >= AttributeDetailLevel::Advanced {
+            let percent_left = (light.fuel as f32 / light.capacity.max(1) as f32 * 100.0).ceil();
+            descriptions.push(AttributeDescription::has(format!(
+                "{percent_left}% of its {fuel_name} left"
+            )));
+        }
+
+        descriptions
+    }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::PortableLight
+    }
+}
+
+impl DescribeAttributes for PortableLight {
+    fn get_attribute_describer() -> Box<dyn AttributeDescriber> {
+        Box::new(PortableLightAttributeDescriber)
+    }
+}
+
+/// Uses up the fuel of portable lights that are on, turning them off when they run out.
+pub fn drain_portable_lights_on_tick(_: &Notification<TickNotification, ()>, world: &mut World) {
+    let mut run_out = Vec::new();
+    let mut query = world.query::<(Entity, &mut PortableLight)>();
+    for (entity, mut light) in query.iter_mut(world) {
+        if !light.is_on {
+            continue;
+        }
+
+        light.fuel = light.fuel.saturating_sub(1);
+        if light.fuel == 0 {
+            light.is_on = false;
+            run_out.push((entity, light.power_source));
+        }
+    }
+
+    for (entity, power_source) in run_out {
+        if let Some(room) = find_containing_room(entity, world) {
+            DynamicMessage::new_third_person(
+                MessageCategory::Surroundings(SurroundingsMessageCategory::Flavor),
+                MessageDelay::Short,
+                MessageFormat::new(&format!(
+                    "${{light.Name}} {}.",
+                    power_source.get_run_out_description()
+                ))
+                .expect("message format should be valid"),
+                BasicTokens::new().with_entity("light".into(), entity),
+            )
+            .send(None, DynamicMessageLocation::Location(room), world);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        component::{Container, EquippedItems, Item},
+        game_map::{Coordinates, GameMap},
+        move_entity,
+        notification::NotificationHandlers,
+        test_utils::spawn_test_room,
+        Time,
+    };
+
+    use super::*;
+
+    fn set_up_world() -> (World, Entity, Entity, Entity) {
+        let mut world = World::new();
+        world.insert_resource(GameMap::new());
+        world.insert_resource(Time::new());
+        let room = spawn_test_room(
+            Some(Coordinates {
+                x: 0,
+                y: 0,
+                z: 0,
+                parent: None,
+            }),
+            &mut world,
+        );
+        let holder = world
+            .spawn((Container::new(None, None), EquippedItems::new(2)))
+            .id();
+        move_entity(holder, room, &mut world);
+        let flashlight = world
+            .spawn((
+                Item::new_one_handed(),
+                PortableLight::new(LightLevel::Bright, PowerSource::Battery, 2),
+            ))
+            .id();
+        move_entity(flashlight, holder, &mut world);
+
+        (world, room, holder, flashlight)
+    }
+
+    #[test]
+    fn equipped_light_lights_room() {
+        let (mut world, room, holder, flashlight) = set_up_world();
+        world.get_mut::<PortableLight>(flashlight).unwrap().is_on = true;
+        assert_eq!(LightLevel::Dark, LightLevel::of_room(room, &world));
+
+        EquippedItems::equip(holder, flashlight, &mut world).unwrap();
+        assert_eq!(LightLevel::Bright, LightLevel::of_room(room, &world));
+
+        world.get_mut::<PortableLight>(flashlight).unwrap().is_on = false;
+        assert_eq!(LightLevel::Dark, LightLevel::of_room(room, &world));
+    }
+
+    #[test]
+    fn lights_run_out() {
+        let (mut world, room, holder, flashlight) = set_up_world();
+        NotificationHandlers::add_handler(drain_portable_lights_on_tick, &mut world);
+        EquippedItems::equip(holder, flashlight, &mut world).unwrap();
+        world.get_mut::<PortableLight>(flashlight).unwrap().is_on = true;
+
+        Notification::send_no_contents(TickNotification, &mut world);
+        assert_eq!(1, world.get::<PortableLight>(flashlight).unwrap().fuel);
+        assert_eq!(LightLevel::Bright, LightLevel::of_room(room, &world));
+
+        Notification::send_no_contents(TickNotification, &mut world);
+        let light = world.get::<PortableLight>(flashlight).unwrap();
+        assert_eq!(0, light.fuel);
+        assert!(!light.is_on);
+        assert_eq!(LightLevel::Dark, LightLevel::of_room(room, &world));
+    }
+}
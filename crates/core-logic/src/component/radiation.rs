@@ -0,0 +1,305 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    action::EatAction, notification::Notification, send_message, GameMessage,
+    InternalMessageCategory, MessageCategory, MessageDelay, TickNotification,
+};
+
+use super::{
+    description::DescribeAttributes, find_containing_room, AfterActionPerformNotification,
+    AttributeDescriber, AttributeDescriberKind, AttributeDescription, AttributeDetailLevel,
+    Container, Vitals,
+};
+
+/// How many rads of absorbed radiation an entity's body gets rid of on its own each tick.
+const DOSE_RECOVERY_PER_TICK: f32 = 0.05;
+/// The radiation level, in rads per tick, at or above which a Geiger counter clicks rapidly rather than slowly.
+const HIGH_RADIATION_LEVEL: f32 = 1.0;
+/// The radiation level, in rads per tick, at or above which a Geiger counter crackles frantically.
+const EXTREME_RADIATION_LEVEL: f32 = 5.0;
+
+/// Makes an entity give off radiation, exposing everything in the room it's in. If the entity is a room, everything in the room is exposed.
+#[derive(Debug, Clone, Copy, Component, Serialize, Deserialize)]
+pub struct RadiationSource {
+    /// How much radiation the entity gives off, in rads per tick.
+    pub rads_per_tick: f32,
+}
+
+impl RadiationSource {
+    /// Determines how much radiation there is in the provided room, in rads per tick.
+    ///
+    /// Everything in the room adds to it, including things that are being carried or are inside other things.
+    pub fn level_in_room(room: Entity, world: &World) -> f32 {
+        RadiationSource::level_within(room, world)
+    }
+
+    /// Determines how much radiation the provided entity and everything inside it give off, in rads per tick.
+    fn level_within(entity: Entity, world: &World) -> f32 {
+        let own_level = world
+            .get::<RadiationSource>(entity)
+            .map_or(0.0, |source| source.rads_per_tick);
+        let contained_level = world
+            .get::<Container>(entity)
+            .map(|container| container.get_entities_including_invisible())
+            .into_iter()
+            .flatten()
+            .map(|contained_entity| RadiationSource::level_within(*contained_entity, world))
+            .sum::<f32>();
+
+        own_level + contained_level
+    }
+
+    /// Determines how much radiation there is where the provided entity is, in rads per tick.
+    /// Entities that aren't in a room at all aren't exposed to any radiation.
+    pub fn level_around(entity: Entity, world: &World) -> f32 {
+        find_containing_room(entity, world)
+            .map_or(0.0, |room| RadiationSource::level_in_room(room, world))
+    }
+}
+
+/// The total amount of radiation an entity has absorbed, in rads. Entities without this haven't absorbed any.
+#[derive(Debug, Clone, Copy, Component, Serialize, Deserialize)]
+pub struct RadiationDose(pub f32);
+
+impl RadiationDose {
+    /// Determines how much radiation the provided entity has absorbed.
+    pub fn get(entity: Entity, world: &World) -> f32 {
+        world
+            .get::<RadiationDose>(entity)
+            .map_or(0.0, |dose| dose.0)
+    }
+
+    /// Increases the radiation the provided entity has absorbed by the provided amount, or decreases it if it's negative.
+    pub fn change(entity: Entity, amount: f32, world: &mut World) {
+        let new_dose = (RadiationDose::get(entity, world) + amount).max(0.0);
+        if new_dose > 0.0 {
+            world.entity_mut(entity).insert(RadiationDose(new_dose));
+        } else {
+            world.entity_mut(entity).remove::<RadiationDose>();
+        }
+    }
+
+    /// Decreases the radiation the provided entity has absorbed because of some treatment, and lets them know it helped.
+    pub fn treat(entity: Entity, amount: f32, world: &mut World) {
+        if amount <= 0.0 || RadiationDose::get(entity, world) <= 0.0 {
+            return;
+        }
+
+        RadiationDose::change(entity, -amount, world);
+        send_message(
+            world,
+            entity,
+            GameMessage::Message {
+                content: "You feel some of the radiation sickness ebb away.".to_string(),
+                category: MessageCategory::Internal(InternalMessageCategory::Misc),
+                delay: MessageDelay::Short,
+                decorations: Vec::new(),
+            },
+        );
+    }
+}
+
+/// Makes an entity reduce the radiation absorbed by whoever eats it.
+#[derive(Debug, Clone, Copy, Component, Serialize, Deserialize)]
+pub struct AntiRadiation(pub f32);
+
+/// Makes an entity report how much radiation there is wherever it is.
+#[derive(Debug, Clone, Copy, Component, Serialize, Deserialize)]
+pub struct GeigerCounter;
+
+/// Describes the reading of a Geiger counter.
+#[derive(Debug)]
+struct GeigerCounterAttributeDescriber;
+
+impl AttributeDescriber for GeigerCounterAttributeDescriber {
+    fn describe(
+        &self,
+        _: Entity,
+        entity: Entity,
+        detail_level: AttributeDetailLevel,
+        world: &World,
+    ) -> Vec<AttributeDescription> {
+        if world.get::<GeigerCounter>(entity).is_none() {
+            return Vec::new();
+        }
+
+        let level = RadiationSource::level_around(entity, world);
+        let clicking = if level <= 0.0 {
+            return vec![AttributeDescription::is("silent".to_string())];
+        } else if level >= EXTREME_RADIATION_LEVEL {
+            "crackling frantically"
+        } else if level >= HIGH_RADIATION_LEVEL {
+            "clicking rapidly"
+        } else {
+            "clicking slowly"
+        };
+
+        let mut descriptions = vec![AttributeDescription::is(clicking.to_string())];
+        if detail_level >= AttributeDetailLevel::Advanced {
+            descriptions.push(AttributeDescription::does(format!(
+                "reads {level:.2} rads per tick"
+            )));
+        }
+
+        descriptions
+    }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::GeigerCounter
+    }
+}
+
+impl DescribeAttributes for GeigerCounter {
+    fn get_attribute_describer() -> Box<dyn AttributeDescriber> {
+        Box::new(GeigerCounterAttributeDescriber)
+    }
+}
+
+/// Exposes living things to the radiation around them, and lets their bodies get rid of some of what they've already absorbed.
+pub fn absorb_radiation_on_tick(_: &Notification<TickNotification, ()>, world: &mut World) {
+    let mut query = world.query_filtered::<Entity, With<Vitals>>();
+    let living_entities = query.iter(world).collect::<Vec<_>>();
+    for entity in living_entities {
+        let level = RadiationSource::level_around(entity, world);
+        let change = level - DOSE_RECOVERY_PER_TICK;
+        if change > 0.0 || world.get::<RadiationDose>(entity).is_some() {
+            RadiationDose::change(entity, change, world);
+        }
+    }
+}
+
+/// Reduces absorbed radiation when an entity eats something that treats it.
+pub fn reduce_radiation_dose_on_eat(
+    notification: &Notification<AfterActionPerformNotification, EatAction>,
+    world: &mut World,
+) {
+    if notification.notification_type.action_complete
+        && notification.notification_type.action_successful
+    {
+        if let Some(anti_radiation) = world.get::<AntiRadiation>(notification.contents.target) {
+            RadiationDose::treat(
+                notification.notification_type.performing_entity,
+                anti_radiation.0,
+                world,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        action::{Action, ActionNotificationSender},
+        component::{register_component_handlers, StatusEffects},
+        game_map::GameMap,
+        move_entity,
+        resource::insert_resources,
+        spawn_prototype,
+        test_utils::spawn_test_room,
+        world_definition::WorldDefinition,
+        Time,
+    };
+
+    use super::*;
+
+    fn set_up_world() -> (World, Entity, Entity) {
+        let mut world = World::new();
+        world.insert_resource(GameMap::new());
+        world.insert_resource(Time::new());
+        insert_resources(&mut world);
+        register_component_handlers(&mut world);
+        let room = spawn_test_room(None, &mut world);
+        let person = world
+            .spawn((Vitals::new(), Container::new(None, None)))
+            .id();
+        move_entity(person, room, &mut world);
+
+        (world, room, person)
+    }
+
+    #[test]
+    fn radiation_adds_up_from_rooms_and_items() {
+        let (mut world, room, person) = set_up_world();
+        assert_eq!(0.0, RadiationSource::level_around(person, &world));
+
+        world
+            .entity_mut(room)
+            .insert(RadiationSource { rads_per_tick: 1.0 });
+        let rock = world.spawn(RadiationSource { rads_per_tick: 0.5 }).id();
+        move_entity(rock, person, &mut world);
+        assert_eq!(1.5, RadiationSource::level_around(person, &world));
+
+        let geiger_counter = world.spawn(GeigerCounter).id();
+        move_entity(geiger_counter, room, &mut world);
+        let descriptions = GeigerCounterAttributeDescriber
+            .describe(
+                person,
+                geiger_counter,
+                AttributeDetailLevel::Advanced,
+                &world,
+            )
+            .into_iter()
+            .filter_map(|description| match description {
+                AttributeDescription::NonSection(description) => Some(description.description),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["clicking rapidly", "reads 1.50 rads per tick"],
+            descriptions
+        );
+    }
+
+    #[test]
+    fn dose_builds_up_and_causes_sickness() {
+        let (mut world, room, person) = set_up_world();
+        world.entity_mut(room).insert(RadiationSource {
+            rads_per_tick: 15.0,
+        });
+
+        for _ in 0..10 {
+            Notification::send_no_contents(TickNotification, &mut world);
+        }
+        assert!(RadiationDose::get(person, &world) > 100.0);
+        assert!(StatusEffects::get_all(person, &world)
+            .iter()
+            .any(|status_effect| status_effect.name == "Radiation sickness"));
+
+        world.entity_mut(room).remove::<RadiationSource>();
+        RadiationDose::treat(person, 200.0, &mut world);
+        Notification::send_no_contents(TickNotification, &mut world);
+        assert_eq!(0.0, RadiationDose::get(person, &world));
+        assert!(StatusEffects::get_all(person, &world).is_empty());
+    }
+
+    #[test]
+    fn eating_anti_rad_pill_reduces_dose() {
+        let (mut world, _, person) = set_up_world();
+        let prototype_catalog = WorldDefinition::built_in()
+            .unwrap()
+            .build_prototype_catalog()
+            .unwrap();
+        world.insert_resource(prototype_catalog);
+        let pill = spawn_prototype("anti_rad_pill", person, &mut world).unwrap();
+        RadiationDose::change(person, 200.0, &mut world);
+
+        let mut action = EatAction {
+            target: pill,
+            notification_sender: ActionNotificationSender::new(),
+        };
+        let mut result = action.perform(person, &mut world);
+        action.send_after_perform_notification(
+            AfterActionPerformNotification {
+                performing_entity: person,
+                action_complete: result.is_complete,
+                action_successful: result.was_successful,
+            },
+            &mut world,
+        );
+        result.post_effects.drain(..).for_each(|f| f(&mut world));
+
+        assert_eq!(50.0, RadiationDose::get(person, &world));
+        assert!(world.get_entity(pill).is_none());
+    }
+}
@@ -2,6 +2,7 @@ use std::{collections::HashSet, sync::LazyLock};
 
 use bevy_ecs::prelude::*;
 use nonempty::nonempty;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     action::{Action, ActionInterruptResult, ActionNotificationSender, ActionResult, LookAction},
@@ -17,7 +18,8 @@ use crate::{
 };
 
 use super::{
-    ActionEndNotification, ActionQueue, AfterActionPerformNotification, ParseCustomInput, Vitals,
+    ActionEndNotification, ActionQueue, AfterActionPerformNotification, CustomInputParserKind,
+    ParseCustomInput, Vitals,
 };
 
 static RESPAWN_FORMAT: LazyLock<CommandFormat> =
@@ -133,13 +135,17 @@ impl Action for RespawnAction {
 }
 
 /// A component that provides the respawn action.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct Respawner;
 
 impl ParseCustomInput for Respawner {
     fn get_parsers() -> Vec<Box<dyn InputParser>> {
         vec![Box::new(RespawnParser)]
     }
+
+    fn get_kind() -> CustomInputParserKind {
+        CustomInputParserKind::Respawner
+    }
 }
 
 /// Notification handler that queues up a look action after an entity respawns, so they can see where they ended up.
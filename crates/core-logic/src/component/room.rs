@@ -1,9 +1,10 @@
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::game_map::MapIcon;
 
 /// A room in the world.
-#[derive(PartialEq, Eq, Debug, Component)]
+#[derive(PartialEq, Eq, Debug, Component, Serialize, Deserialize)]
 pub struct Room {
     /// The name of the room.
     pub name: String,
@@ -0,0 +1,387 @@
+use bevy_ecs::prelude::*;
+use rhai::{Dynamic, Map};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    action::{
+        Action, AttackAction, DrinkAction, EatAction, EquipAction, LookAction, MoveAction,
+        OpenAction, PourAction, PutAction, RemoveAction, SayAction, SleepAction, ThrowAction,
+        WearAction,
+    },
+    notification::{Notification, NotificationHandlers},
+    scripting::call_script_function,
+    DeathNotification, EntityMovedNotification, TickNotification,
+};
+
+use super::{AfterActionPerformNotification, Container, Location, ScriptCommandAction};
+
+/// Makes an entity or room behave according to a script.
+///
+/// The script can define any of these functions, which will be called when the relevant thing happens:
+/// * `on_tick(world, me)`: every tick.
+/// * `on_entity_moved(world, me, moving_entity, source, destination)`: when an entity enters or leaves the room `me` is in (or is). `source` is `()` if the entity just appeared.
+/// * `on_death(world, me, dead_entity)`: when an entity dies in the room `me` is in (or is).
+/// * `on_action(world, me, performing_entity, action, details)`: when an entity finishes performing an action in the room `me` is in (or is).
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct Script {
+    /// The ID of the script.
+    pub id: String,
+}
+
+/// An action scripts can be notified about.
+trait ScriptVisibleAction: Action + 'static {
+    /// The name to give scripts for the action.
+    const NAME: &'static str;
+
+    /// Details about the action to give scripts.
+    fn get_details(&self) -> Map {
+        Map::new()
+    }
+}
+
+impl ScriptVisibleAction for LookAction {
+    const NAME: &'static str = "look";
+}
+
+impl ScriptVisibleAction for MoveAction {
+    const NAME: &'static str = "move";
+
+    fn get_details(&self) -> Map {
+        Map::from([("direction".into(), self.direction.to_string().into())])
+    }
+}
+
+impl ScriptVisibleAction for OpenAction {
+    const NAME: &'static str = "open";
+}
+
+impl ScriptVisibleAction for PutAction {
+    const NAME: &'static str = "put";
+}
+
+impl ScriptVisibleAction for ThrowAction {
+    const NAME: &'static str = "throw";
+}
+
+impl ScriptVisibleAction for PourAction {
+    const NAME: &'static str = "pour";
+}
+
+impl ScriptVisibleAction for WearAction {
+    const NAME: &'static str = "wear";
+}
+
+impl ScriptVisibleAction for RemoveAction {
+    const NAME: &'static str = "remove";
+}
+
+impl ScriptVisibleAction for EquipAction {
+    const NAME: &'static str = "equip";
+}
+
+impl ScriptVisibleAction for EatAction {
+    const NAME: &'static str = "eat";
+}
+
+impl ScriptVisibleAction for DrinkAction {
+    const NAME: &'static str = "drink";
+}
+
+impl ScriptVisibleAction for SleepAction {
+    const NAME: &'static str = "sleep";
+}
+
+impl ScriptVisibleAction for SayAction {
+    const NAME: &'static str = "say";
+
+    fn get_details(&self) -> Map {
+        Map::from([("text".into(), self.text.clone().into())])
+    }
+}
+
+impl ScriptVisibleAction for AttackAction {
+    const NAME: &'static str = "attack";
+}
+
+impl ScriptVisibleAction for ScriptCommandAction {
+    const NAME: &'static str = "command";
+
+    fn get_details(&self) -> Map {
+        Map::from([
+            ("command".into(), self.get_command_id().into()),
+            ("args".into(), self.get_args().clone().into()),
+        ])
+    }
+}
+
+/// Registers notification handlers that call scripts.
+pub(super) fn register_script_handlers(world: &mut World) {
+    NotificationHandlers::add_handler(run_scripts_on_tick, world);
+    NotificationHandlers::add_handler(run_scripts_on_entity_moved, world);
+    NotificationHandlers::add_handler(run_scripts_on_death, world);
+
+    NotificationHandlers::add_handler(run_scripts_after_action::<LookAction>, world);
+    NotificationHandlers::add_handler(run_scripts_after_action::<MoveAction>, world);
+    NotificationHandlers::add_handler(run_scripts_after_action::<OpenAction>, world);
+    NotificationHandlers::add_handler(run_scripts_after_action::<PutAction>, world);
+    NotificationHandlers::add_handler(run_scripts_after_action::<ThrowAction>, world);
+    NotificationHandlers::add_handler(run_scripts_after_action::<PourAction>, world);
+    NotificationHandlers::add_handler(run_scripts_after_action::<WearAction>, world);
+    NotificationHandlers::add_handler(run_scripts_after_action::<RemoveAction>, world);
+    NotificationHandlers::add_handler(run_scripts_after_action::<EquipAction>, world);
+    NotificationHandlers::add_handler(run_scripts_after_action::<EatAction>, world);
+    NotificationHandlers::add_handler(run_scripts_after_action::<DrinkAction>, world);
+    NotificationHandlers::add_handler(run_scripts_after_action::<SleepAction>, world);
+    NotificationHandlers::add_handler(run_scripts_after_action::<SayAction>, world);
+    NotificationHandlers::add_handler(run_scripts_after_action::<AttackAction>, world);
+    NotificationHandlers::add_handler(run_scripts_after_action::<ScriptCommandAction>, world);
+}
+
+/// Finds the scripted entities in the provided room, and the room itself if it's scripted.
+fn find_scripted_entities_in(room: Entity, world: &World) -> Vec<(Entity, String)> {
+    let mut scripted = Vec::new();
+    if let Some(script) = world.get::<Script>(room) {
+        scripted.push((room, script.id.clone()));
+    }
+
+    if let Some(container) = world.get::<Container>(room) {
+        let mut entities = container
+            .get_entities_including_invisible()
+            .iter()
+            .copied()
+            .collect::<Vec<Entity>>();
+        entities.sort();
+        for entity in entities {
+            if let Some(script) = world.get::<Script>(entity) {
+                scripted.push((entity, script.id.clone()));
+            }
+        }
+    }
+
+    scripted
+}
+
+/// Calls `on_tick` on all scripted entities.
+fn run_scripts_on_tick(_: &Notification<TickNotification, ()>, world: &mut World) {
+    let mut scripted = world
+        .query::<(Entity, &Script)>()
+        .iter(world)
+        .map(|(entity, script)| (entity, script.id.clone()))
+        .collect::<Vec<(Entity, String)>>();
+    scripted.sort();
+
+    for (entity, script_id) in scripted {
+        call_script_function(&script_id, "on_tick", vec![Dynamic::from(entity)], world);
+    }
+}
+
+/// Calls `on_entity_moved` on scripted entities in the rooms an entity moved between.
+fn run_scripts_on_entity_moved(
+    notification: &Notification<EntityMovedNotification, ()>,
+    world: &mut World,
+) {
+    let moving_entity = notification.notification_type.moving_entity;
+    let source = notification.notification_type.source;
+    let destination = notification.notification_type.destination;
+
+    let mut scripted = find_scripted_entities_in(destination, world);
+    if let Some(source) = source {
+        scripted.extend(find_scripted_entities_in(source, world));
+    }
+
+    let source_arg = source.map_or(Dynamic::UNIT, Dynamic::from);
+    for (entity, script_id) in scripted {
+        call_script_function(
+            &script_id,
+            "on_entity_moved",
+            vec![
+                Dynamic::from(entity),
+                Dynamic::from(moving_entity),
+                source_arg.clone(),
+                Dynamic::from(destination),
+            ],
+            world,
+        );
+    }
+}
+
+/// Calls `on_death` on scripted entities in the room an entity died in, and stops the dead entity's own script from running afterwards.
+fn run_scripts_on_death(notification: &Notification<DeathNotification, ()>, world: &mut World) {
+    let dead_entity = notification.notification_type.entity;
+    let Some(location) = world.get::<Location>(dead_entity) else {
+        return;
+    };
+
+    for (entity, script_id) in find_scripted_entities_in(location.id, world) {
+        call_script_function(
+            &script_id,
+            "on_death",
+            vec![Dynamic::from(entity), Dynamic::from(dead_entity)],
+            world,
+        );
+    }
+
+    world.entity_mut(dead_entity).remove::<Script>();
+}
+
+/// Calls `on_action` on scripted entities in the room an action was completed in.
+fn run_scripts_after_action<A: ScriptVisibleAction>(
+    notification: &Notification<AfterActionPerformNotification, A>,
+    world: &mut World,
+) {
+    if !notification.notification_type.action_complete {
+        return;
+    }
+
+    let performing_entity = notification.notification_type.performing_entity;
+    let Some(location) = world.get::<Location>(performing_entity) else {
+        return;
+    };
+
+    let mut details = notification.contents.get_details();
+    details.insert(
+        "successful".into(),
+        notification.notification_type.action_successful.into(),
+    );
+
+    for (entity, script_id) in find_scripted_entities_in(location.id, world) {
+        call_script_function(
+            &script_id,
+            "on_action",
+            vec![
+                Dynamic::from(entity),
+                Dynamic::from(performing_entity),
+                Dynamic::from(A::NAME),
+                Dynamic::from(details.clone()),
+            ],
+            world,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        component::{ActionQueue, Vitals},
+        kill_entity, move_entity,
+        resource::MessageTemplateCatalog,
+        scripting::ScriptCatalog,
+        test_utils::spawn_entity_in_location,
+    };
+
+    use super::*;
+
+    fn set_up_world(scripts: &[(&str, &str)]) -> (World, Entity) {
+        let mut world = World::new();
+        let mut catalog = ScriptCatalog::new();
+        for (id, source) in scripts {
+            catalog.add(id.to_string(), source).unwrap();
+        }
+        world.insert_resource(catalog);
+        world.insert_resource(MessageTemplateCatalog::new());
+        register_script_handlers(&mut world);
+
+        let room = world.spawn(Container::new_infinite()).id();
+
+        (world, room)
+    }
+
+    #[test]
+    fn on_tick_queues_action() {
+        let (mut world, room) = set_up_world(&[(
+            "chatty",
+            r#"
+fn on_tick(world, me) {
+    if !world.has_queued_actions(me) {
+        world.say(me, "Hello from " + world.name(me));
+    }
+}
+"#,
+        )]);
+        let npc = spawn_entity_in_location("npc", room, &mut world);
+        let unscripted = spawn_entity_in_location("unscripted", room, &mut world);
+        world.entity_mut(npc).insert(Script {
+            id: "chatty".to_string(),
+        });
+
+        Notification::send_no_contents(TickNotification, &mut world);
+
+        assert!(ActionQueue::has_any_queued_actions(&world, npc));
+        assert!(!ActionQueue::has_any_queued_actions(&world, unscripted));
+    }
+
+    #[test]
+    fn room_trigger_on_entity_moved() {
+        let (mut world, room) = set_up_world(&[(
+            "trap",
+            r#"
+fn on_entity_moved(world, me, moving_entity, source, destination) {
+    if destination == me && source != () {
+        world.move(moving_entity, "north");
+    }
+}
+"#,
+        )]);
+        world.entity_mut(room).insert(Script {
+            id: "trap".to_string(),
+        });
+        let other_room = world.spawn(Container::new_infinite()).id();
+        let entity = spawn_entity_in_location("entity", other_room, &mut world);
+        assert!(!ActionQueue::has_any_queued_actions(&world, entity));
+
+        move_entity(entity, room, &mut world);
+
+        assert!(ActionQueue::has_any_queued_actions(&world, entity));
+    }
+
+    #[test]
+    fn on_death_removes_own_script() {
+        let (mut world, room) = set_up_world(&[(
+            "mourner",
+            r#"
+fn on_death(world, me, dead_entity) {
+    if dead_entity != me {
+        world.say(me, "Oh no!");
+    }
+}
+"#,
+        )]);
+        let mourner = spawn_entity_in_location("mourner", room, &mut world);
+        let victim = spawn_entity_in_location("victim", room, &mut world);
+        for entity in [mourner, victim] {
+            world.entity_mut(entity).insert((
+                Script {
+                    id: "mourner".to_string(),
+                },
+                Vitals::new(),
+            ));
+        }
+
+        kill_entity(victim, &mut world);
+
+        assert!(ActionQueue::has_any_queued_actions(&world, mourner));
+        assert!(!ActionQueue::has_any_queued_actions(&world, victim));
+        assert!(world.get::<Script>(victim).is_none());
+    }
+
+    #[test]
+    fn script_errors_do_not_affect_world() {
+        let (mut world, room) = set_up_world(&[(
+            "broken",
+            r#"
+fn on_tick(world, me) {
+    world.move(me, "sideways");
+}
+"#,
+        )]);
+        let npc = spawn_entity_in_location("npc", room, &mut world);
+        world.entity_mut(npc).insert(Script {
+            id: "broken".to_string(),
+        });
+
+        Notification::send_no_contents(TickNotification, &mut world);
+
+        assert!(!ActionQueue::has_any_queued_actions(&world, npc));
+        assert!(world.get::<Script>(npc).is_some());
+    }
+}
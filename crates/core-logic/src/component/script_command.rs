@@ -0,0 +1,416 @@
+use std::{collections::HashSet, sync::Arc};
+
+use bevy_ecs::prelude::*;
+use log::warn;
+use rhai::{Dynamic, Map};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    action::{Action, ActionInterruptResult, ActionNotificationSender, ActionResult},
+    component::VerifyResult,
+    input_parser::{InputParseError, InputParser},
+    scripting::{
+        run_script_command_body, run_script_command_check, ParsedScriptCommand, ScriptCatalog,
+        ScriptCommand,
+    },
+    ActionTag, BeforeActionNotification, InternalMessageCategory, MessageCategory, MessageDelay,
+    VerifyActionNotification,
+};
+
+use super::{ActionEndNotification, AfterActionPerformNotification, CustomInputParser};
+
+/// Gives an item or room commands defined by content authors, which can be entered by anyone able to interact with it.
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct ScriptCommands {
+    /// The IDs of the commands.
+    pub ids: Vec<String>,
+}
+
+impl ScriptCommands {
+    /// Gives the provided entity the commands with the provided IDs.
+    pub fn add_to(entity: Entity, ids: Vec<String>, world: &mut World) {
+        world.entity_mut(entity).insert(ScriptCommands { ids });
+        register_parsers(entity, world);
+    }
+
+    /// Replaces the parsers for every entity's script commands with ones for the commands currently in the catalog.
+    /// The parsers aren't saved along with the entities, so this needs to be called after loading a saved world, as well as after the catalog changes.
+    pub fn register_all_parsers(world: &mut World) {
+        let entities = world
+            .query_filtered::<Entity, With<ScriptCommands>>()
+            .iter(world)
+            .collect::<Vec<Entity>>();
+        for entity in entities {
+            CustomInputParser::remove_unsaved_parsers(entity, world);
+            register_parsers(entity, world);
+        }
+    }
+}
+
+/// Adds parsers for the script commands of the provided entity.
+fn register_parsers(entity: Entity, world: &mut World) {
+    let Some(script_commands) = world.get::<ScriptCommands>(entity) else {
+        return;
+    };
+    let Some(catalog) = world.get_resource::<ScriptCatalog>() else {
+        return;
+    };
+
+    let parsers = script_commands
+        .ids
+        .iter()
+        .filter_map(|id| match catalog.get_command(id) {
+            Some(command) => {
+                Some(Box::new(ScriptCommandParser { entity, command }) as Box<dyn InputParser>)
+            }
+            None => {
+                warn!("No command with ID {id:?} exists");
+                None
+            }
+        })
+        .collect();
+
+    CustomInputParser::add_unsaved_parsers(entity, parsers, world);
+}
+
+/// Parses a script command attached to a specific entity.
+struct ScriptCommandParser {
+    /// The entity the command is attached to.
+    entity: Entity,
+    /// The command.
+    command: Arc<ScriptCommand>,
+}
+
+impl InputParser for ScriptCommandParser {
+    fn parse(
+        &self,
+        input: &str,
+        source_entity: Entity,
+        world: &World,
+    ) -> Result<Box<dyn Action>, InputParseError> {
+        let parsed = self
+            .command
+            .parse(input, self.entity, source_entity, world)?;
+
+        Ok(Box::new(ScriptCommandAction {
+            command: self.command.clone(),
+            parsed,
+            ticks_taken: 0,
+            notification_sender: ActionNotificationSender::new(),
+        }))
+    }
+
+    fn get_input_formats(&self) -> Vec<String> {
+        vec![self.command.get_format_description().to_string()]
+    }
+
+    fn get_input_formats_for(&self, entity: Entity, _: Entity, world: &World) -> Vec<String> {
+        if entity != self.entity {
+            return Vec::new();
+        }
+
+        vec![self
+            .command
+            .get_format_description_for(entity, world)
+            .to_string()]
+    }
+}
+
+/// Performs a script command.
+#[derive(Debug)]
+pub struct ScriptCommandAction {
+    command: Arc<ScriptCommand>,
+    parsed: ParsedScriptCommand,
+    ticks_taken: u32,
+    notification_sender: ActionNotificationSender<Self>,
+}
+
+impl ScriptCommandAction {
+    /// Gets the ID of the command being performed.
+    pub fn get_command_id(&self) -> &str {
+        &self.command.id
+    }
+
+    /// Gets the parsed parts of the command being performed, by name.
+    pub fn get_args(&self) -> &Map {
+        &self.parsed.args
+    }
+
+    /// Builds the arguments to pass to the command's scripts after the world.
+    fn build_script_args(&self, performing_entity: Entity) -> Vec<Dynamic> {
+        vec![
+            Dynamic::from(self.parsed.me),
+            Dynamic::from(performing_entity),
+            Dynamic::from(self.parsed.args.clone()),
+        ]
+    }
+}
+
+impl Action for ScriptCommandAction {
+    fn perform(&mut self, performing_entity: Entity, world: &mut World) -> ActionResult {
+        if world.get_entity(self.parsed.me).is_none() {
+            return ActionResult::error(
+                performing_entity,
+                "You can't do that anymore.".to_string(),
+            );
+        }
+
+        if self.ticks_taken == 0 {
+            if let Some(reason) = run_script_command_check(
+                &self.command,
+                self.build_script_args(performing_entity),
+                world,
+            ) {
+                return ActionResult::error(performing_entity, reason);
+            }
+        }
+
+        if self.ticks_taken + 1 < self.command.tick_cost {
+            self.ticks_taken += 1;
+            return ActionResult::builder().build_incomplete(true);
+        }
+
+        let mut result_builder = ActionResult::builder();
+        if let Some(message) = run_script_command_body(
+            &self.command,
+            self.build_script_args(performing_entity),
+            world,
+        ) {
+            result_builder = result_builder.with_message(
+                performing_entity,
+                message,
+                MessageCategory::Internal(InternalMessageCategory::Action),
+                MessageDelay::Short,
+            );
+        }
+
+        if self.command.tick_cost > 0 {
+            result_builder.build_complete_should_tick(true)
+        } else {
+            result_builder.build_complete_no_tick(true)
+        }
+    }
+
+    fn interrupt(&self, _: Entity, _: &mut World) -> ActionInterruptResult {
+        ActionInterruptResult::none()
+    }
+
+    fn may_require_tick(&self) -> bool {
+        self.command.tick_cost > 0
+    }
+
+    fn get_tags(&self) -> HashSet<ActionTag> {
+        [].into()
+    }
+
+    fn send_before_notification(
+        &self,
+        notification_type: BeforeActionNotification,
+        world: &mut World,
+    ) {
+        self.notification_sender
+            .send_before_notification(notification_type, self, world);
+    }
+
+    fn send_verify_notification(
+        &self,
+        notification_type: VerifyActionNotification,
+        world: &mut World,
+    ) -> Vec<VerifyResult> {
+        self.notification_sender
+            .send_verify_notification(notification_type, self, world)
+    }
+
+    fn send_after_perform_notification(
+        &self,
+        notification_type: AfterActionPerformNotification,
+        world: &mut World,
+    ) {
+        self.notification_sender
+            .send_after_perform_notification(notification_type, self, world);
+    }
+
+    fn send_end_notification(&self, notification_type: ActionEndNotification, world: &mut World) {
+        self.notification_sender
+            .send_end_notification(notification_type, self, world);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        component::Container,
+        input_parser::parse_input,
+        scripting::{
+            ScriptCommandDefinition, ScriptCommandPartKind, ScriptCommandValidator,
+            ScriptCommandValidatorRule,
+        },
+        test_utils::spawn_entity_in_location,
+        GameMessage, StandardInputParsers,
+    };
+
+    use super::*;
+
+    struct TestWorld {
+        world: World,
+        radio: Entity,
+        player: Entity,
+    }
+
+    fn set_up_world(tick_cost: u32, check: Option<&str>) -> TestWorld {
+        let mut world = World::new();
+        world.insert_resource(StandardInputParsers::new());
+
+        let mut catalog = ScriptCatalog::new();
+        catalog
+            .add_command(
+                "tune_radio".to_string(),
+                ScriptCommandDefinition {
+                    format: "tune <radio> to <station>",
+                    parts: vec![
+                        ("radio".to_string(), ScriptCommandPartKind::Target),
+                        ("station".to_string(), ScriptCommandPartKind::Text),
+                    ],
+                    validators: vec![ScriptCommandValidator {
+                        part: "station".to_string(),
+                        rule: ScriptCommandValidatorRule::Matches("^[0-9.]+$".to_string()),
+                        error: "That's not a station.".to_string(),
+                    }],
+                    tick_cost,
+                    check,
+                    body: r#""You tune " + world.name(me) + " to " + args.station + ".""#,
+                },
+            )
+            .unwrap();
+        world.insert_resource(catalog);
+
+        let room = world.spawn(Container::new_infinite()).id();
+        let radio = spawn_entity_in_location("radio", room, &mut world);
+        ScriptCommands::add_to(radio, vec!["tune_radio".to_string()], &mut world);
+        spawn_entity_in_location("chair", room, &mut world);
+        let player = spawn_entity_in_location("player", room, &mut world);
+
+        TestWorld {
+            world,
+            radio,
+            player,
+        }
+    }
+
+    fn get_messages(result: &ActionResult, entity: Entity) -> Vec<String> {
+        result
+            .messages
+            .get(&entity)
+            .into_iter()
+            .flatten()
+            .map(|message| match message {
+                GameMessage::Message { content, .. } => content.clone(),
+                GameMessage::Error(content) => content.clone(),
+                other => panic!("unexpected message: {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_and_perform() {
+        let TestWorld {
+            mut world, player, ..
+        } = set_up_world(1, None);
+
+        let mut action = parse_input("tune entity radio name to 88.5", player, &world).unwrap();
+        let result = action.perform(player, &mut world);
+
+        assert!(result.is_complete);
+        assert!(result.should_tick);
+        assert!(result.was_successful);
+        assert_eq!(
+            vec!["You tune entity radio name to 88.5.".to_string()],
+            get_messages(&result, player)
+        );
+    }
+
+    #[test]
+    fn target_must_have_command() {
+        let TestWorld { world, player, .. } = set_up_world(1, None);
+
+        let error = parse_input("tune entity chair name to 88.5", player, &world).unwrap_err();
+
+        assert!(matches!(
+            error,
+            InputParseError::PostFormatParse(message) if message == "You can't tune the entity chair name."
+        ));
+    }
+
+    #[test]
+    fn validator_rejects_invalid_part() {
+        let TestWorld { world, player, .. } = set_up_world(1, None);
+
+        let error = parse_input("tune entity radio name to jazz", player, &world).unwrap_err();
+
+        assert!(matches!(
+            error,
+            InputParseError::PostFormatParse(message) if message == "That's not a station."
+        ));
+    }
+
+    #[test]
+    fn check_prevents_command() {
+        let TestWorld {
+            mut world, player, ..
+        } = set_up_world(1, Some(r#"if args.station == "66.6" { "Not that one." }"#));
+
+        let mut action = parse_input("tune entity radio name to 66.6", player, &world).unwrap();
+        let result = action.perform(player, &mut world);
+
+        assert!(!result.was_successful);
+        assert!(!result.should_tick);
+        assert_eq!(
+            vec!["Not that one.".to_string()],
+            get_messages(&result, player)
+        );
+
+        let mut action = parse_input("tune entity radio name to 88.5", player, &world).unwrap();
+        assert!(action.perform(player, &mut world).was_successful);
+    }
+
+    #[test]
+    fn tick_cost() {
+        let TestWorld {
+            mut world, player, ..
+        } = set_up_world(2, None);
+
+        let mut action = parse_input("tune entity radio name to 88.5", player, &world).unwrap();
+
+        let first_result = action.perform(player, &mut world);
+        assert!(!first_result.is_complete);
+        assert!(first_result.should_tick);
+        assert!(get_messages(&first_result, player).is_empty());
+
+        let second_result = action.perform(player, &mut world);
+        assert!(second_result.is_complete);
+        assert!(second_result.should_tick);
+        assert_eq!(1, get_messages(&second_result, player).len());
+    }
+
+    #[test]
+    fn input_formats() {
+        let TestWorld {
+            world,
+            radio,
+            player,
+        } = set_up_world(0, None);
+
+        let parsers = &world.get::<CustomInputParser>(radio).unwrap().parsers;
+        assert_eq!(
+            vec!["tune <radio> to <station>".to_string()],
+            parsers[0].get_input_formats()
+        );
+        assert_eq!(
+            vec!["tune entity radio name to <station>".to_string()],
+            parsers[0].get_input_formats_for(radio, player, &world)
+        );
+        assert!(parsers[0]
+            .get_input_formats_for(player, player, &world)
+            .is_empty());
+    }
+}
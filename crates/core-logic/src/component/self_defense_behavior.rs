@@ -1,5 +1,6 @@
 use bevy_ecs::prelude::*;
 use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     action::{
@@ -12,7 +13,7 @@ use crate::{
 use super::{ActionQueue, CombatRange, CombatState, Weapon};
 
 /// Makes an entity attack entities they are in combat with.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct SelfDefenseBehavior;
 
 /// Makes NPCs fight back.
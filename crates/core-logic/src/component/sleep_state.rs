@@ -1,4 +1,5 @@
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     action::{LookAction, SayAction},
@@ -8,12 +9,12 @@ use crate::{
 };
 
 use super::{
-    description::DescribeAttributes, AttributeDescriber, AttributeDescription,
-    AttributeDetailLevel, VerifyActionNotification,
+    description::DescribeAttributes, AttributeDescriber, AttributeDescriberKind,
+    AttributeDescription, AttributeDetailLevel, VerifyActionNotification,
 };
 
 /// Describes whether an entity is asleep or awake.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct SleepState {
     /// Whether the entity is asleep.
     pub is_asleep: bool,
@@ -39,6 +40,10 @@ impl AttributeDescriber for SleepStateAttributeDescriber {
 
         Vec::new()
     }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::SleepState
+    }
 }
 
 impl DescribeAttributes for SleepState {
@@ -5,6 +5,7 @@ use std::{
 
 use bevy_ecs::prelude::*;
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 use strum::{EnumIter, IntoEnumIterator};
 
 use crate::{
@@ -21,15 +22,15 @@ const XP_FOR_FIRST_ATTRIBUTE_POINT: Xp = Xp(500);
 const ADVANCEMENT_POINT_NEXT_LEVEL_MULTIPLIER: f32 = 1.15;
 
 /// The stats an entity started with, before spending any advancement points.
-#[derive(Component)]
-pub struct StartingStats(#[expect(unused)] pub Stats);
+#[derive(Component, Serialize, Deserialize)]
+pub struct StartingStats(pub Stats);
 
 /// A unique key used to identify a set of stat adjustments
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct StatAdjustmentKey(pub &'static str);
 
 /// The stats of an entity.
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Serialize, Deserialize)]
 pub struct Stats {
     /// The innate attributes of the entity, like strength.
     pub attributes: Attributes,
@@ -37,7 +38,9 @@ pub struct Stats {
     pub skills: Skills,
     /// The entity's XP and stuff.
     pub advancement: StatAdvancement,
-    /// The entity's active stat adjustments
+    /// The entity's active stat adjustments.
+    /// These aren't saved, since they're re-applied by whatever added them (e.g. status effects).
+    #[serde(skip)]
     adjustments: HashMap<StatAdjustmentKey, StatAdjustments>,
 }
 
@@ -153,7 +156,7 @@ impl Stats {
 }
 
 /// The innate attributes of an entity, like strength.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Attributes {
     standard: HashMap<Attribute, u16>,
     custom: HashMap<String, u16>,
@@ -200,7 +203,7 @@ impl Attributes {
 }
 
 /// The learned skills of an entity, like cooking.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Skills {
     standard: HashMap<Skill, u16>,
     custom: HashMap<String, u16>,
@@ -251,7 +254,7 @@ impl Skills {
 pub struct StatAdjustments(pub HashMap<Stat, Vec<StatAdjustment>>);
 
 /// An adjustments to a single stat.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub enum StatAdjustment {
     /// Increase the stat's value
     Add(f32),
@@ -314,7 +317,7 @@ impl StatAdjustments {
 }
 
 /// An amount of experience points.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Xp(pub u64);
 
 /// A notification that an entity is getting some XP.
@@ -373,7 +376,7 @@ pub fn increase_xp_and_advancement_points_on_xp_awarded(
 }
 
 /// Information about an entity's available avenues of increasing their stats.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct StatAdvancement {
     /// The total amount of XP the entity has earned
     pub total_xp: Xp,
@@ -384,7 +387,7 @@ pub struct StatAdvancement {
 }
 
 /// The skill or attribute points of an entity.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct AdvancementPoints {
     /// The total number of points the entity has earned
     pub total_earned: u32,
@@ -395,7 +398,7 @@ pub struct AdvancementPoints {
 }
 
 /// A type of advancement point.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum AdvancementPointType {
     /// A skill point
     Skill,
@@ -434,7 +437,7 @@ impl StatAdvancement {
 }
 
 /// A stat (i.e. either an attribute or a skill)
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Stat {
     Attribute(Attribute),
     Skill(Skill),
@@ -486,7 +489,7 @@ impl Stat {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
 pub enum Attribute {
     Strength,
     Agility,
@@ -496,7 +499,7 @@ pub enum Attribute {
     Custom(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
 pub enum Skill {
     Firearms,
     Bows,
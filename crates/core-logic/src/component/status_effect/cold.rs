@@ -0,0 +1,85 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    component::{
+        status_effect::StatusEffect, Attribute, Stat, StatAdjustment, StatAdjustmentKey,
+        StatAdjustments, Stats, StatusEffectDetails, StatusEffectId,
+    },
+    notification::{Notification, NotificationHandlers},
+    vital_change::VitalChangedNotification,
+    ConstrainedValue, VitalType,
+};
+
+use super::FREEZING_THRESHOLD;
+
+/// The fraction of the maximum body temperature at or below which an entity is cold
+pub const COLD_THRESHOLD: f32 = 0.35;
+
+const STATUS_EFFECT_ID: StatusEffectId = StatusEffectId("cold");
+const STAT_ADJUSTMENT_KEY: StatAdjustmentKey = StatAdjustmentKey("cold");
+
+/// A status effect applied when an entity's body temperature is low, but not dangerously low.
+#[derive(Component)]
+pub struct Cold;
+
+impl Cold {
+    /// Determines what stat adjustments to apply for being cold.
+    fn get_stat_adjustments(&self) -> StatAdjustments {
+        StatAdjustments::new().adjust_stat(
+            Stat::Attribute(Attribute::Agility),
+            StatAdjustment::Subtract(1.0),
+        )
+    }
+}
+
+impl StatusEffect for Cold {
+    fn register_notification_handlers(world: &mut World) {
+        NotificationHandlers::add_handler(add_or_remove_cold, world);
+    }
+
+    fn get_id() -> StatusEffectId {
+        STATUS_EFFECT_ID
+    }
+
+    fn get_details(&self) -> StatusEffectDetails {
+        StatusEffectDetails {
+            name: "Cold".to_string(),
+            stat_adjustments: self.get_stat_adjustments(),
+            other_effects: Vec::new(),
+        }
+    }
+
+    fn on_add(&self, entity: Entity, world: &mut World) {
+        if let Some(mut stats) = world.get_mut::<Stats>(entity) {
+            stats.set_adjustment(STAT_ADJUSTMENT_KEY, self.get_stat_adjustments());
+        }
+    }
+
+    fn on_remove(entity: Entity, world: &mut World) {
+        if let Some(mut stats) = world.get_mut::<Stats>(entity) {
+            stats.remove_adjustment(STAT_ADJUSTMENT_KEY);
+        }
+    }
+}
+
+/// Adds or removes the `Cold` component based on how cold an entity is.
+fn add_or_remove_cold(
+    notification: &Notification<VitalChangedNotification, ()>,
+    world: &mut World,
+) {
+    let entity = notification.notification_type.entity;
+
+    if let VitalType::BodyTemperature = notification.notification_type.vital_type {
+        if is_cold(notification.notification_type.new_value) {
+            Cold.add_to(entity, world);
+        } else {
+            Cold::remove_from(entity, world);
+        }
+    }
+}
+
+/// Determines whether the provided body temperature is cold, but not cold enough to be freezing.
+fn is_cold(body_temperature: ConstrainedValue<f32>) -> bool {
+    let fraction = body_temperature.get() / body_temperature.get_max();
+    fraction <= COLD_THRESHOLD && fraction > FREEZING_THRESHOLD
+}
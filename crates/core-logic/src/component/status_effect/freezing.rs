@@ -0,0 +1,87 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    component::{
+        status_effect::StatusEffect, Attribute, Stat, StatAdjustment, StatAdjustmentKey,
+        StatAdjustments, Stats, StatusEffectDetails, StatusEffectId,
+    },
+    notification::{Notification, NotificationHandlers},
+    vital_change::VitalChangedNotification,
+    ConstrainedValue, VitalType,
+};
+
+/// The fraction of the maximum body temperature at or below which an entity is freezing
+pub const FREEZING_THRESHOLD: f32 = 0.15;
+
+const STATUS_EFFECT_ID: StatusEffectId = StatusEffectId("freezing");
+const STAT_ADJUSTMENT_KEY: StatAdjustmentKey = StatAdjustmentKey("freezing");
+
+/// A status effect applied when an entity's body temperature is dangerously low.
+#[derive(Component)]
+pub struct Freezing;
+
+impl Freezing {
+    /// Determines what stat adjustments to apply for freezing.
+    fn get_stat_adjustments(&self) -> StatAdjustments {
+        StatAdjustments::new()
+            .adjust_stat(
+                Stat::Attribute(Attribute::Agility),
+                StatAdjustment::Subtract(3.0),
+            )
+            .adjust_stat(
+                Stat::Attribute(Attribute::Strength),
+                StatAdjustment::Subtract(2.0),
+            )
+    }
+}
+
+impl StatusEffect for Freezing {
+    fn register_notification_handlers(world: &mut World) {
+        NotificationHandlers::add_handler(add_or_remove_freezing, world);
+    }
+
+    fn get_id() -> StatusEffectId {
+        STATUS_EFFECT_ID
+    }
+
+    fn get_details(&self) -> StatusEffectDetails {
+        StatusEffectDetails {
+            name: "Freezing".to_string(),
+            stat_adjustments: self.get_stat_adjustments(),
+            other_effects: Vec::new(),
+        }
+    }
+
+    fn on_add(&self, entity: Entity, world: &mut World) {
+        if let Some(mut stats) = world.get_mut::<Stats>(entity) {
+            stats.set_adjustment(STAT_ADJUSTMENT_KEY, self.get_stat_adjustments());
+        }
+    }
+
+    fn on_remove(entity: Entity, world: &mut World) {
+        if let Some(mut stats) = world.get_mut::<Stats>(entity) {
+            stats.remove_adjustment(STAT_ADJUSTMENT_KEY);
+        }
+    }
+}
+
+/// Adds or removes the `Freezing` component based on how cold an entity is.
+fn add_or_remove_freezing(
+    notification: &Notification<VitalChangedNotification, ()>,
+    world: &mut World,
+) {
+    let entity = notification.notification_type.entity;
+
+    if let VitalType::BodyTemperature = notification.notification_type.vital_type {
+        if is_freezing(notification.notification_type.new_value) {
+            Freezing.add_to(entity, world);
+        } else {
+            Freezing::remove_from(entity, world);
+        }
+    }
+}
+
+/// Determines whether the provided body temperature is cold enough to be freezing.
+fn is_freezing(body_temperature: ConstrainedValue<f32>) -> bool {
+    body_temperature.get() / body_temperature.get_max() <= FREEZING_THRESHOLD
+}
@@ -15,13 +15,33 @@ pub use thirsty::SEVERE_THIRST_THESHOLD;
 use thirsty::*;
 
 mod overencumbered;
+pub use overencumbered::add_or_remove_overencumbered_for_entity;
 use overencumbered::*;
 
+mod cold;
+pub use cold::COLD_THRESHOLD;
+use cold::*;
+
+mod freezing;
+pub use freezing::FREEZING_THRESHOLD;
+use freezing::*;
+
+mod overheated;
+pub use overheated::Overheated;
+pub use overheated::OVERHEATED_THRESHOLD;
+
+mod radiation_sickness;
+use radiation_sickness::*;
+
 /// Registers notification handlers related to status effects.
 pub fn register_status_effect_handlers(world: &mut World) {
     Hungry::register_notification_handlers(world);
     Thirsty::register_notification_handlers(world);
     Overencumbered::register_notification_handlers(world);
+    Cold::register_notification_handlers(world);
+    Freezing::register_notification_handlers(world);
+    Overheated::register_notification_handlers(world);
+    RadiationSickness::register_notification_handlers(world);
 }
 
 #[derive(Clone, Copy, Hash, PartialEq, Eq)]
@@ -70,7 +70,7 @@ fn add_or_remove_overencumbered_on_despawn(
 /// Adds or removes `Overencumbered` from an entity based on whether its inventory is overfull or not.
 ///
 /// Does nothing if `entity` isn't a living entity.
-fn add_or_remove_overencumbered_for_entity(entity: Entity, world: &mut World) {
+pub fn add_or_remove_overencumbered_for_entity(entity: Entity, world: &mut World) {
     if !is_living_entity(entity, world) {
         return;
     }
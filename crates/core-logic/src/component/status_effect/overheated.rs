@@ -0,0 +1,82 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    component::{
+        status_effect::StatusEffect, Attribute, Stat, StatAdjustment, StatAdjustmentKey,
+        StatAdjustments, Stats, StatusEffectDetails, StatusEffectId,
+    },
+    notification::{Notification, NotificationHandlers},
+    vital_change::VitalChangedNotification,
+    ConstrainedValue, VitalType,
+};
+
+/// The fraction of the maximum body temperature at or above which an entity is overheated
+pub const OVERHEATED_THRESHOLD: f32 = 0.75;
+
+const STATUS_EFFECT_ID: StatusEffectId = StatusEffectId("overheated");
+const STAT_ADJUSTMENT_KEY: StatAdjustmentKey = StatAdjustmentKey("overheated");
+
+/// A status effect applied when an entity's body temperature is high.
+#[derive(Component)]
+pub struct Overheated;
+
+impl Overheated {
+    /// Determines what stat adjustments to apply for being overheated.
+    fn get_stat_adjustments(&self) -> StatAdjustments {
+        StatAdjustments::new().adjust_stat(
+            Stat::Attribute(Attribute::Endurance),
+            StatAdjustment::Subtract(2.0),
+        )
+    }
+}
+
+impl StatusEffect for Overheated {
+    fn register_notification_handlers(world: &mut World) {
+        NotificationHandlers::add_handler(add_or_remove_overheated, world);
+    }
+
+    fn get_id() -> StatusEffectId {
+        STATUS_EFFECT_ID
+    }
+
+    fn get_details(&self) -> StatusEffectDetails {
+        StatusEffectDetails {
+            name: "Overheated".to_string(),
+            stat_adjustments: self.get_stat_adjustments(),
+            other_effects: vec!["gets thirsty faster".to_string()],
+        }
+    }
+
+    fn on_add(&self, entity: Entity, world: &mut World) {
+        if let Some(mut stats) = world.get_mut::<Stats>(entity) {
+            stats.set_adjustment(STAT_ADJUSTMENT_KEY, self.get_stat_adjustments());
+        }
+    }
+
+    fn on_remove(entity: Entity, world: &mut World) {
+        if let Some(mut stats) = world.get_mut::<Stats>(entity) {
+            stats.remove_adjustment(STAT_ADJUSTMENT_KEY);
+        }
+    }
+}
+
+/// Adds or removes the `Overheated` component based on how hot an entity is.
+fn add_or_remove_overheated(
+    notification: &Notification<VitalChangedNotification, ()>,
+    world: &mut World,
+) {
+    let entity = notification.notification_type.entity;
+
+    if let VitalType::BodyTemperature = notification.notification_type.vital_type {
+        if is_overheated(notification.notification_type.new_value) {
+            Overheated.add_to(entity, world);
+        } else {
+            Overheated::remove_from(entity, world);
+        }
+    }
+}
+
+/// Determines whether the provided body temperature is hot enough to be overheated.
+fn is_overheated(body_temperature: ConstrainedValue<f32>) -> bool {
+    body_temperature.get() / body_temperature.get_max() >= OVERHEATED_THRESHOLD
+}
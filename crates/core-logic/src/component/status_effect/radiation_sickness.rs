@@ -0,0 +1,225 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    component::{
+        status_effect::StatusEffect, Attribute, RadiationDose, Stat, StatAdjustment,
+        StatAdjustmentKey, StatAdjustments, Stats, StatusEffectDetails, StatusEffectId, Vitals,
+    },
+    notification::{Notification, NotificationHandlers},
+    send_message,
+    vital_change::{ValueChangeOperation, VitalChange},
+    GameMessage, InternalMessageCategory, MessageCategory, MessageDelay, NoTokens,
+    TickNotification, VitalType,
+};
+
+/// The absorbed radiation, in rads, at which an entity first gets radiation sickness
+const MILD_RADIATION_SICKNESS_THRESHOLD: f32 = 100.0;
+
+/// The absorbed radiation, in rads, at which an entity's radiation sickness becomes severe
+const SEVERE_RADIATION_SICKNESS_THRESHOLD: f32 = 300.0;
+
+/// The absorbed radiation, in rads, at which an entity's radiation sickness becomes critical
+const CRITICAL_RADIATION_SICKNESS_THRESHOLD: f32 = 600.0;
+
+const STATUS_EFFECT_ID: StatusEffectId = StatusEffectId("radiation_sickness");
+const STAT_ADJUSTMENT_KEY: StatAdjustmentKey = StatAdjustmentKey("radiation_sickness");
+
+/// A status effect applied when an entity has absorbed a lot of radiation.
+#[derive(Component)]
+pub struct RadiationSickness(RadiationSicknessSeverity);
+
+/// How severe the radiation sickness status effect is.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum RadiationSicknessSeverity {
+    /// Nauseous and tired
+    Mild,
+    /// Weak and losing health
+    Severe,
+    /// Dying
+    Critical,
+}
+
+impl RadiationSickness {
+    /// Determines what stat adjustments to apply for this level of radiation sickness.
+    fn get_stat_adjustments(&self) -> StatAdjustments {
+        match self.0 {
+            RadiationSicknessSeverity::Mild => StatAdjustments::new().adjust_stat(
+                Stat::Attribute(Attribute::Endurance),
+                StatAdjustment::Subtract(1.0),
+            ),
+            RadiationSicknessSeverity::Severe => StatAdjustments::new()
+                .adjust_stat(
+                    Stat::Attribute(Attribute::Endurance),
+                    StatAdjustment::Subtract(2.0),
+                )
+                .adjust_stat(
+                    Stat::Attribute(Attribute::Strength),
+                    StatAdjustment::Subtract(1.0),
+                ),
+            RadiationSicknessSeverity::Critical => StatAdjustments::new()
+                .adjust_stat(
+                    Stat::Attribute(Attribute::Endurance),
+                    StatAdjustment::Subtract(4.0),
+                )
+                .adjust_stat(
+                    Stat::Attribute(Attribute::Strength),
+                    StatAdjustment::Subtract(2.0),
+                )
+                .adjust_stat(
+                    Stat::Attribute(Attribute::Agility),
+                    StatAdjustment::Subtract(1.0),
+                ),
+        }
+    }
+
+    /// Determines how much of each vital is lost per tick for this level of radiation sickness.
+    fn get_vital_drains(&self) -> Vec<(VitalType, f32)> {
+        match self.0 {
+            RadiationSicknessSeverity::Mild => vec![(VitalType::Energy, 0.02)],
+            RadiationSicknessSeverity::Severe => vec![
+                (VitalType::Energy, 0.04),
+                (VitalType::Satiety, 0.02),
+                (VitalType::Health, 0.1),
+            ],
+            RadiationSicknessSeverity::Critical => vec![
+                (VitalType::Energy, 0.08),
+                (VitalType::Satiety, 0.04),
+                (VitalType::Health, 0.5),
+            ],
+        }
+    }
+}
+
+impl StatusEffect for RadiationSickness {
+    fn register_notification_handlers(world: &mut World) {
+        NotificationHandlers::add_handler(update_radiation_sickness, world);
+    }
+
+    fn get_id() -> StatusEffectId {
+        STATUS_EFFECT_ID
+    }
+
+    fn get_details(&self) -> StatusEffectDetails {
+        let (name, other_effects) = match self.0 {
+            RadiationSicknessSeverity::Mild => ("Radiation sickness", vec!["gets tired faster"]),
+            RadiationSicknessSeverity::Severe => (
+                "Severe radiation sickness",
+                vec![
+                    "gets tired faster",
+                    "gets hungry faster",
+                    "slowly loses health",
+                ],
+            ),
+            RadiationSicknessSeverity::Critical => (
+                "Critical radiation sickness",
+                vec![
+                    "gets tired faster",
+                    "gets hungry faster",
+                    "quickly loses health",
+                ],
+            ),
+        };
+        StatusEffectDetails {
+            name: name.to_string(),
+            stat_adjustments: self.get_stat_adjustments(),
+            other_effects: other_effects.into_iter().map(str::to_string).collect(),
+        }
+    }
+
+    fn on_add(&self, entity: Entity, world: &mut World) {
+        if let Some(mut stats) = world.get_mut::<Stats>(entity) {
+            stats.set_adjustment(STAT_ADJUSTMENT_KEY, self.get_stat_adjustments());
+        }
+    }
+
+    fn on_remove(entity: Entity, world: &mut World) {
+        if let Some(mut stats) = world.get_mut::<Stats>(entity) {
+            stats.remove_adjustment(STAT_ADJUSTMENT_KEY);
+        }
+    }
+}
+
+/// Adds, removes, or modifies the `RadiationSickness` component based on how much radiation each entity has absorbed, and drains the vitals of anyone who's sick.
+fn update_radiation_sickness(_: &Notification<TickNotification, ()>, world: &mut World) {
+    let mut query = world.query_filtered::<Entity, With<Vitals>>();
+    let living_entities = query.iter(world).collect::<Vec<_>>();
+    for entity in living_entities {
+        let old_severity = world
+            .get::<RadiationSickness>(entity)
+            .map(|sickness| sickness.0);
+        let new_severity = determine_radiation_sickness_severity(RadiationDose::get(entity, world));
+
+        if new_severity != old_severity {
+            if let Some(severity) = new_severity {
+                RadiationSickness(severity).add_to(entity, world);
+            } else {
+                RadiationSickness::remove_from(entity, world);
+            }
+
+            if let Some(message) = get_severity_change_message(old_severity, new_severity) {
+                send_message(
+                    world,
+                    entity,
+                    GameMessage::Message {
+                        content: message.to_string(),
+                        category: MessageCategory::Internal(InternalMessageCategory::Misc),
+                        delay: MessageDelay::Short,
+                        decorations: Vec::new(),
+                    },
+                );
+            }
+        }
+
+        let vital_drains = world
+            .get::<RadiationSickness>(entity)
+            .map(RadiationSickness::get_vital_drains)
+            .unwrap_or_default();
+        for (vital_type, amount) in vital_drains {
+            VitalChange::<NoTokens> {
+                entity,
+                vital_type,
+                operation: ValueChangeOperation::Subtract,
+                amount,
+                message_params: vec![],
+            }
+            .apply(world);
+        }
+    }
+}
+
+/// Determines what radiation sickness severity corresponds to the given absorbed radiation.
+/// Returns `None` if not enough radiation has been absorbed to cause radiation sickness.
+fn determine_radiation_sickness_severity(dose: f32) -> Option<RadiationSicknessSeverity> {
+    match dose {
+        x if x >= CRITICAL_RADIATION_SICKNESS_THRESHOLD => {
+            Some(RadiationSicknessSeverity::Critical)
+        }
+        x if x >= SEVERE_RADIATION_SICKNESS_THRESHOLD => Some(RadiationSicknessSeverity::Severe),
+        x if x >= MILD_RADIATION_SICKNESS_THRESHOLD => Some(RadiationSicknessSeverity::Mild),
+        _ => None,
+    }
+}
+
+/// Determines the message to send to an entity whose radiation sickness has changed severity.
+fn get_severity_change_message(
+    old_severity: Option<RadiationSicknessSeverity>,
+    new_severity: Option<RadiationSicknessSeverity>,
+) -> Option<&'static str> {
+    if new_severity < old_severity {
+        return match new_severity {
+            None => Some("You feel much better."),
+            Some(_) => Some("You feel a little less sick."),
+        };
+    }
+
+    match new_severity {
+        None => None,
+        Some(RadiationSicknessSeverity::Mild) => Some("You feel nauseous and tired."),
+        Some(RadiationSicknessSeverity::Severe) => {
+            Some("Your skin starts to blister, and you feel terribly weak.")
+        }
+        Some(RadiationSicknessSeverity::Critical) => {
+            Some("You cough up blood. The radiation is killing you.")
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     action::{ActionNotificationSender, SleepAction},
     component::{
-        MILD_HUNGER_THRESHOLD, MILD_THIRST_THRESHOLD, SEVERE_HUNGER_THESHOLD,
-        SEVERE_THIRST_THESHOLD,
+        COLD_THRESHOLD, FREEZING_THRESHOLD, MILD_HUNGER_THRESHOLD, MILD_THIRST_THRESHOLD,
+        OVERHEATED_THRESHOLD, SEVERE_HUNGER_THESHOLD, SEVERE_THIRST_THESHOLD,
     },
     interrupt_entity, kill_entity,
     notification::Notification,
@@ -24,6 +25,9 @@ const HYDRATION_LOSS_PER_TICK: f32 = 0.008; // loss of 100 hydration in ~2 days
 const ENERGY_LOSS_PER_TICK: f32 = 0.015; // loss of 100 energy in ~1 day
 const ENERGY_GAIN_PER_TICK: f32 = 0.03; // gain of 100 energy in ~12 hours
 
+/// The body temperature entities start out at, and return to when they're comfortable.
+pub const NORMAL_BODY_TEMPERATURE: f32 = 50.0;
+
 const STARVATION_DAMAGE_PER_TICK: f32 = 5.0;
 const THIRST_DAMAGE_PER_TICK: f32 = 5.0;
 
@@ -37,6 +41,19 @@ const THIRST_MESSAGES: [ValueChangeMessage; 3] = [
     ValueChangeMessage::decrease(MILD_THIRST_THRESHOLD, "You feel thirsty."),
     ValueChangeMessage::decrease(SEVERE_THIRST_THESHOLD, "You feel extremely thirsty."),
 ];
+const WARMING_MESSAGES: [ValueChangeMessage; 3] = [
+    ValueChangeMessage::increase(
+        FREEZING_THRESHOLD,
+        "You stop freezing, but you're still cold.",
+    ),
+    ValueChangeMessage::increase(COLD_THRESHOLD, "You warm up."),
+    ValueChangeMessage::increase(OVERHEATED_THRESHOLD, "You're getting overheated."),
+];
+const COOLING_MESSAGES: [ValueChangeMessage; 3] = [
+    ValueChangeMessage::decrease(OVERHEATED_THRESHOLD, "You cool down."),
+    ValueChangeMessage::decrease(COLD_THRESHOLD, "You feel cold."),
+    ValueChangeMessage::decrease(FREEZING_THRESHOLD, "You're freezing!"),
+];
 const TIREDNESS_MESSAGES: [ValueChangeMessage; 4] = [
     ValueChangeMessage::decrease(0.66, "You start feeling a little tired."),
     ValueChangeMessage::decrease(0.5, "You feel tired."),
@@ -90,7 +107,7 @@ enum ValueChangeDirection {
 /// The vital stats of an entity.
 ///
 /// These values should not be mutated directly; use `ValueChange` for that.
-#[derive(Debug, Clone, Component)]
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
 pub struct Vitals {
     /// How healthy the entity is.
     pub health: ConstrainedValue<f32>,
@@ -100,6 +117,9 @@ pub struct Vitals {
     pub hydration: ConstrainedValue<f32>,
     /// How non-tired the entity is.
     pub energy: ConstrainedValue<f32>,
+    /// How warm the entity is. Halfway between the minimum and maximum is normal.
+    #[serde(default = "new_body_temperature")]
+    pub body_temperature: ConstrainedValue<f32>,
 }
 
 impl Default for Vitals {
@@ -116,10 +136,16 @@ impl Vitals {
             satiety: ConstrainedValue::new_max(0.0, 100.0),
             hydration: ConstrainedValue::new_max(0.0, 100.0),
             energy: ConstrainedValue::new_max(0.0, 100.0),
+            body_temperature: new_body_temperature(),
         }
     }
 }
 
+/// Creates a normal body temperature.
+fn new_body_temperature() -> ConstrainedValue<f32> {
+    ConstrainedValue::new(NORMAL_BODY_TEMPERATURE, 0.0, NORMAL_BODY_TEMPERATURE * 2.0)
+}
+
 /// Changes vitals over time.
 pub fn change_vitals_on_tick(_: &Notification<TickNotification, ()>, world: &mut World) {
     let mut changes = Vec::new();
@@ -220,6 +246,13 @@ pub fn send_vitals_update_messages(
                 &TIREDNESS_MESSAGES
             }
         }
+        VitalType::BodyTemperature => {
+            if increased {
+                &WARMING_MESSAGES
+            } else {
+                &COOLING_MESSAGES
+            }
+        }
     };
 
     let old_fraction = old_value.get() / old_value.get_max();
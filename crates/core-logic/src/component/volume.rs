@@ -6,15 +6,16 @@ use std::{
 
 use bevy_ecs::prelude::*;
 use float_cmp::approx_eq;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     AttributeDescription, AttributeSection, AttributeSectionName, SectionAttributeDescription,
 };
 
-use super::{AttributeDescriber, AttributeDetailLevel, DescribeAttributes};
+use super::{AttributeDescriber, AttributeDescriberKind, AttributeDetailLevel, DescribeAttributes};
 
 /// The amount of space an entity takes up, in liters.
-#[derive(Debug, Clone, Copy, Component, PartialOrd)]
+#[derive(Debug, Clone, Copy, Component, PartialOrd, Serialize, Deserialize)]
 pub struct Volume(pub f32);
 
 impl Add for Volume {
@@ -102,6 +103,10 @@ impl AttributeDescriber for VolumeAttributeDescriber {
             }],
         })]
     }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::Volume
+    }
 }
 
 impl DescribeAttributes for Volume {
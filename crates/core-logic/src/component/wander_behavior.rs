@@ -1,5 +1,6 @@
 use bevy_ecs::prelude::*;
 use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     action::{ActionNotificationSender, MoveAction},
@@ -10,7 +11,7 @@ use crate::{
 use super::{ActionQueue, Container, Location};
 
 /// Makes an entity wander around.
-#[derive(Component)]
+#[derive(Component, Clone, Serialize, Deserialize)]
 pub struct WanderBehavior {
     /// The chance the entity will move each tick.
     pub move_chance_per_tick: f32,
@@ -34,6 +35,7 @@ pub fn wander_on_tick(_: &Notification<TickNotification, ()>, world: &mut World)
                 {
                     let action = Box::new(MoveAction {
                         direction: connection.direction,
+                        slowed_ticks: 0,
                         notification_sender: ActionNotificationSender::new(),
                     });
                     actions.push((entity, action));
@@ -1,25 +1,26 @@
-use std::ops::RangeInclusive;
+use std::{collections::HashMap, ops::RangeInclusive};
 
 use bevy_ecs::prelude::*;
 use rand::{thread_rng, Rng};
-use strum::EnumIter;
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
 
 use crate::{
     component::EquippedItems,
     format_list,
     range_extensions::RangeExtensions,
-    resource::{get_stat_name, WeaponTypeNameCatalog, WeaponTypeStatCatalog},
-    AttributeSection, AttributeSectionName, ChosenWeapon, MessageFormat, MessageTokens,
-    SectionAttributeDescription, TokenName, TokenValue,
+    resource::{get_stat_name, MessageTemplateId, WeaponTypeNameCatalog, WeaponTypeStatCatalog},
+    AttributeSection, AttributeSectionName, ChosenWeapon, FixedMessageTokens, Locale,
+    MessageFormat, MessageTokens, SectionAttributeDescription, TokenKind, TokenName, TokenValue,
 };
 
 use super::{
-    combat_state::CombatRange, AttributeDescriber, AttributeDescription, AttributeDetailLevel,
-    DescribeAttributes, InnateWeapon, Stat,
+    combat_state::CombatRange, AttributeDescriber, AttributeDescriberKind, AttributeDescription,
+    AttributeDetailLevel, DescribeAttributes, InnateWeapon, Stat,
 };
 
 /// An entity that can deal damage.
-#[derive(Component)]
+#[derive(Component, Clone, Serialize, Deserialize)]
 pub struct Weapon {
     /// The type of weapon this is.
     pub weapon_type: WeaponType,
@@ -38,7 +39,7 @@ pub struct Weapon {
 }
 
 /// Represents a type of weapon.
-#[derive(PartialEq, Eq, Hash, Clone, EnumIter)]
+#[derive(PartialEq, Eq, Hash, Clone, EnumIter, Serialize, Deserialize)]
 pub enum WeaponType {
     /// A shooty weapon
     Firearm,
@@ -70,6 +71,7 @@ pub trait AttackType: std::fmt::Debug {
 }
 
 /// Describes the ranges at which a weapon can be used.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct WeaponRanges {
     /// The ranges at which the weapon can be used at all.
     pub usable: RangeInclusive<CombatRange>,
@@ -82,7 +84,7 @@ pub struct WeaponRanges {
 }
 
 /// A stat requirement to use a weapon.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct WeaponStatRequirement {
     /// The required stat.
     pub stat: Stat,
@@ -93,63 +95,53 @@ pub struct WeaponStatRequirement {
 }
 
 /// What to do if a weapon stat requirement is not met.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub enum WeaponStatRequirementNotMetBehavior {
     /// The weapon cannot be used at all.
     Unusable,
     /// Adjustments are applied once if the stat requirement is not met, regardless of how much the stat requirement is not met by.
-    #[expect(unused)]
     FlatAdjustments(Vec<WeaponPerformanceAdjustment>),
     /// Adjustments are applied once for each point the using entity is below the stat requirement.
-    #[expect(unused)]
     AdjustmentsPerPointBelowMin(Vec<WeaponPerformanceAdjustment>),
 }
 
 /// Describes how to adjust the performance of a weapon when attacking with it.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub enum WeaponPerformanceAdjustment {
     /// Change the damage done by the weapon.
-    #[expect(unused)]
     Damage(WeaponDamageAdjustment),
     /// Change the likelihood of hitting with the weapon.
-    #[expect(unused)]
     ToHit(WeaponToHitAdjustment),
 }
 
 /// Describes how to adjust the damage of a weapon.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub enum WeaponDamageAdjustment {
     /// Roll for damage from a different range.
-    #[expect(unused)]
     NewRange(RangeInclusive<u32>),
     /// Instead of rolling for damage, do a specific amount of damage.
-    #[expect(unused)]
     Set(u32),
     /// Add some amount to the damage done.
-    #[expect(unused)]
     Add(i32),
     /// Multiply the damage done by some amount.
     Multiply(f32),
     /// Instead of rolling for damage, choose the lowest damage in the range.
-    #[expect(unused)]
     Min,
     /// Instead of rolling for damage, choose the highest damage in the range.
-    #[expect(unused)]
     Max,
 }
 
 /// Describes how to adjust the likelihood of hitting with a weapon.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub enum WeaponToHitAdjustment {
     /// Add some amount to the stat value for the to-hit roll.
-    #[expect(unused)]
     Add(i32),
     /// Multiply the stat value for the to-hit roll by some amount.
-    #[expect(unused)]
     Multiply(f32),
 }
 
 /// Describes bonuses to a weapon based on a user's stats.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct WeaponStatBonuses {
     /// The values for which the damage bonus stat provides more damage bonus.
     /// * If the stat is less than the start of this range, no damage bonus will be applied.
@@ -273,6 +265,10 @@ impl AttributeDescriber for WeaponAttributeDescriber {
         }
         Vec::new()
     }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::Weapon
+    }
 }
 
 fn describe_weapon_performance_reduction(adjustments: &[WeaponPerformanceAdjustment]) -> String {
@@ -309,6 +305,7 @@ impl DescribeAttributes for Weapon {
 }
 
 /// Describes the messages to send when a weapon is used.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct WeaponMessages {
     /// Messages for misses
     pub miss: Vec<MessageFormat<WeaponMissMessageTokens>>,
@@ -320,6 +317,9 @@ pub struct WeaponMessages {
     pub major_hit: Vec<MessageFormat<WeaponHitMessageTokens>>,
     /// Messages for when entities hit themselves
     pub self_hit: Vec<MessageFormat<WeaponHitMessageTokens>>,
+    /// Translations of these messages, for entities using locales other than the default one
+    #[serde(default)]
+    pub localized: HashMap<Locale, WeaponMessages>,
 }
 
 /// Tokens used in weapon messages for hits.
@@ -369,6 +369,61 @@ impl MessageTokens for WeaponMissMessageTokens {
     }
 }
 
+impl FixedMessageTokens for WeaponHitMessageTokens {
+    fn get_token_kinds() -> std::collections::HashMap<TokenName, TokenKind> {
+        [
+            ("attacker".into(), TokenKind::Entity),
+            ("target".into(), TokenKind::Entity),
+            ("weapon".into(), TokenKind::Entity),
+            ("body_part".into(), TokenKind::Entity),
+        ]
+        .into()
+    }
+}
+
+impl FixedMessageTokens for WeaponMissMessageTokens {
+    fn get_token_kinds() -> std::collections::HashMap<TokenName, TokenKind> {
+        [
+            ("attacker".into(), TokenKind::Entity),
+            ("target".into(), TokenKind::Entity),
+            ("weapon".into(), TokenKind::Entity),
+        ]
+        .into()
+    }
+}
+
+impl WeaponMessages {
+    /// Creates weapon messages using all the variants of the provided templates, in every locale.
+    /// If `self_hit` is `None`, entities won't be able to hit themselves with the weapon.
+    pub fn from_templates(
+        miss: &MessageTemplateId<WeaponMissMessageTokens>,
+        minor_hit: &MessageTemplateId<WeaponHitMessageTokens>,
+        regular_hit: &MessageTemplateId<WeaponHitMessageTokens>,
+        major_hit: &MessageTemplateId<WeaponHitMessageTokens>,
+        self_hit: Option<&MessageTemplateId<WeaponHitMessageTokens>>,
+        world: &World,
+    ) -> WeaponMessages {
+        let in_locale = |locale: Locale| WeaponMessages {
+            miss: miss.get_variants_in(locale, world),
+            minor_hit: minor_hit.get_variants_in(locale, world),
+            regular_hit: regular_hit.get_variants_in(locale, world),
+            major_hit: major_hit.get_variants_in(locale, world),
+            self_hit: self_hit
+                .map(|template| template.get_variants_in(locale, world))
+                .unwrap_or_default(),
+            localized: HashMap::new(),
+        };
+
+        WeaponMessages {
+            localized: Locale::iter()
+                .filter(|locale| *locale != Locale::default())
+                .map(|locale| (locale, in_locale(locale)))
+                .collect(),
+            ..in_locale(Locale::default())
+        }
+    }
+}
+
 impl Weapon {
     /// Gets the primary weapon the provided entity has equipped, including the Entity of the weapon itself.
     /// * If the entity has no weapons equipped, its innate weapon will be returned.
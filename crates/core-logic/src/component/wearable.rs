@@ -2,23 +2,27 @@ use std::collections::HashSet;
 
 use bevy_ecs::prelude::*;
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     body_part::BodyPartType, format_list, resource::BodyPartTypeNameCatalog, AttributeDescription,
 };
 
 use super::{
-    AttributeDescriber, AttributeDetailLevel, AttributeSection, AttributeSectionName,
-    DescribeAttributes, SectionAttributeDescription,
+    AttributeDescriber, AttributeDescriberKind, AttributeDetailLevel, AttributeSection,
+    AttributeSectionName, DescribeAttributes, SectionAttributeDescription,
 };
 
 /// An entity that can be worn.
-#[derive(Component)]
+#[derive(Component, Clone, Serialize, Deserialize)]
 pub struct Wearable {
     /// The thickness of the entity.
     pub thickness: u32,
     /// The body parts the entity covers when worn.
     pub body_parts: HashSet<BodyPartType>,
+    /// How much the entity keeps its wearer warm.
+    #[serde(default)]
+    pub insulation: u32,
 }
 
 /// Describes the wearability of an entity.
@@ -59,12 +63,20 @@ impl AttributeDescriber for WearableAttributeDescriber {
                         name: "Thickness".to_string(),
                         description: wearable.thickness.to_string(),
                     },
+                    SectionAttributeDescription {
+                        name: "Insulation".to_string(),
+                        description: wearable.insulation.to_string(),
+                    },
                 ],
             })];
         }
 
         Vec::new()
     }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::Wearable
+    }
 }
 
 impl DescribeAttributes for Wearable {
@@ -6,16 +6,17 @@ use std::{
 
 use bevy_ecs::prelude::*;
 use float_cmp::approx_eq;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     AttributeDescription, AttributeSection, AttributeSectionName, Container, Density,
     FluidContainer, SectionAttributeDescription, Volume,
 };
 
-use super::{AttributeDescriber, AttributeDetailLevel, DescribeAttributes};
+use super::{AttributeDescriber, AttributeDescriberKind, AttributeDetailLevel, DescribeAttributes};
 
 /// The weight of an entity, in kilograms.
-#[derive(Debug, Clone, Copy, Component, PartialOrd)]
+#[derive(Debug, Clone, Copy, Component, PartialOrd, Serialize, Deserialize)]
 pub struct Weight(pub f32);
 
 impl Add for Weight {
@@ -148,6 +149,10 @@ impl AttributeDescriber for WeightAttributeDescriber {
             }],
         })]
     }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::Weight
+    }
 }
 
 impl DescribeAttributes for Weight {
@@ -0,0 +1,72 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    description::DescribeAttributes, AttributeDescriber, AttributeDescriberKind,
+    AttributeDescription, AttributeDetailLevel,
+};
+
+/// The wetness at or above which an entity is soaked.
+const SOAKED_THRESHOLD: f32 = 0.75;
+/// The wetness at or above which an entity is wet, rather than just damp.
+const WET_THRESHOLD: f32 = 0.3;
+
+/// How wet an entity is, from 0 (dry) to 1 (soaked through). Entities without this are dry.
+#[derive(Debug, Clone, Copy, Component, Serialize, Deserialize)]
+pub struct Wetness(pub f32);
+
+impl Wetness {
+    /// Determines how wet the provided entity is.
+    pub fn get(entity: Entity, world: &World) -> f32 {
+        world
+            .get::<Wetness>(entity)
+            .map_or(0.0, |wetness| wetness.0)
+    }
+
+    /// Makes the provided entity wetter by the provided amount, or drier if it's negative.
+    pub fn change(entity: Entity, amount: f32, world: &mut World) {
+        let new_wetness = (Wetness::get(entity, world) + amount).clamp(0.0, 1.0);
+        if new_wetness > 0.0 {
+            world.entity_mut(entity).insert(Wetness(new_wetness));
+        } else {
+            world.entity_mut(entity).remove::<Wetness>();
+        }
+    }
+}
+
+/// Describes how wet an entity is.
+#[derive(Debug)]
+struct WetnessAttributeDescriber;
+
+impl AttributeDescriber for WetnessAttributeDescriber {
+    fn describe(
+        &self,
+        _: Entity,
+        entity: Entity,
+        _: AttributeDetailLevel,
+        world: &World,
+    ) -> Vec<AttributeDescription> {
+        let wetness = Wetness::get(entity, world);
+        let description = if wetness >= SOAKED_THRESHOLD {
+            "soaked"
+        } else if wetness >= WET_THRESHOLD {
+            "wet"
+        } else if wetness > 0.0 {
+            "damp"
+        } else {
+            return Vec::new();
+        };
+
+        vec![AttributeDescription::is(description.to_string())]
+    }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::Wetness
+    }
+}
+
+impl DescribeAttributes for Wetness {
+    fn get_attribute_describer() -> Box<dyn AttributeDescriber> {
+        Box::new(WetnessAttributeDescriber)
+    }
+}
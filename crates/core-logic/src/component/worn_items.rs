@@ -1,7 +1,11 @@
 use std::collections::{HashMap, HashSet};
 
-use bevy_ecs::prelude::*;
+use bevy_ecs::{
+    entity::{EntityMapper, MapEntities},
+    prelude::*,
+};
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     action::{ActionNotificationSender, PutAction, RemoveAction},
@@ -13,12 +17,12 @@ use crate::{
 };
 
 use super::{
-    ActionQueue, AttributeDescriber, AttributeDetailLevel, BeforeActionNotification,
-    DescribeAttributes, Location, VerifyActionNotification, Wearable,
+    ActionQueue, AttributeDescriber, AttributeDescriberKind, AttributeDetailLevel,
+    BeforeActionNotification, DescribeAttributes, Location, VerifyActionNotification, Wearable,
 };
 
 /// The things an entity is wearing.
-#[derive(Component)]
+#[derive(Component, Serialize, Deserialize)]
 pub struct WornItems {
     /// The maximum total thickness of items allowed on a single body part.
     /// (This is only relevant when trying to wear something on top of something else; a single wearable item can always be worn regardless of its thickness.)
@@ -27,6 +31,24 @@ pub struct WornItems {
     body_part_to_items: HashMap<Entity, Vec<Entity>>,
 }
 
+impl MapEntities for WornItems {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.body_part_to_items = self
+            .body_part_to_items
+            .drain()
+            .map(|(body_part, items)| {
+                (
+                    entity_mapper.map_entity(body_part),
+                    items
+                        .into_iter()
+                        .map(|item| entity_mapper.map_entity(item))
+                        .collect(),
+                )
+            })
+            .collect();
+    }
+}
+
 /// An error when trying to wear something.
 #[derive(Debug)]
 pub enum WearError {
@@ -241,6 +263,10 @@ impl AttributeDescriber for WornItemsAttributeDescriber {
 
         descriptions
     }
+
+    fn get_kind(&self) -> AttributeDescriberKind {
+        AttributeDescriberKind::WornItems
+    }
 }
 
 impl DescribeAttributes for WornItems {
@@ -1,9 +1,10 @@
+use serde::{Deserialize, Serialize};
 use std::ops::{Add, Mul, Range, Sub};
 
 use map_range::MapRange;
 
 /// A value that cannot go over a maximum or under a minimum.
-#[derive(Debug, Clone, PartialOrd, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialOrd, Copy, PartialEq, Serialize, Deserialize)]
 pub struct ConstrainedValue<
     T: PartialOrd<T> + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy,
 > {
@@ -1,7 +1,8 @@
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 /// Directions in which different rooms can be connected.
-#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, PartialOrd, Ord)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Direction {
     North,
     NorthEast,
@@ -33,6 +34,22 @@ impl Direction {
         }
     }
 
+    /// Gets the abbreviated name of this direction, like "n" for North.
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            Direction::North => "n",
+            Direction::NorthEast => "ne",
+            Direction::East => "e",
+            Direction::SouthEast => "se",
+            Direction::South => "s",
+            Direction::SouthWest => "sw",
+            Direction::West => "w",
+            Direction::NorthWest => "nw",
+            Direction::Up => "u",
+            Direction::Down => "d",
+        }
+    }
+
     /// Finds the opposite of this direction.
     ///
     /// East turns into West, North into South, etc.
@@ -3,14 +3,17 @@ use bevy_ecs::prelude::*;
 use std::collections::{HashMap, HashSet};
 
 use crate::{
-    can_receive_messages, send_message, Container, GameMessage, InterpolationError, Invisible,
-    Location, MessageCategory, MessageDecoration, MessageDelay, MessageFormat, MessageTokens,
+    can_receive_messages, resource::MessageTemplateId, send_message, Container, FixedMessageTokens,
+    GameMessage, InterpolationError, Invisible, Locale, Location, MessageCategory,
+    MessageDecoration, MessageDelay, MessageFormat, MessageTokens,
 };
 
 /// A message with receivers determined dynamically.
 pub struct DynamicMessage<T: MessageTokens> {
     /// The format of the message.
     message_format: MessageFormat<T>,
+    /// Translations of the format of the message, for receivers using other locales.
+    localized_formats: HashMap<Locale, MessageFormat<T>>,
     /// The tokens to use to interpolate the message.
     message_tokens: T,
     /// The category of the message.
@@ -39,6 +42,7 @@ impl<T: MessageTokens> DynamicMessage<T> {
     ) -> DynamicMessage<T> {
         DynamicMessage {
             message_format,
+            localized_formats: HashMap::new(),
             message_tokens,
             category,
             delay,
@@ -58,6 +62,7 @@ impl<T: MessageTokens> DynamicMessage<T> {
     ) -> DynamicMessage<T> {
         DynamicMessage {
             message_format,
+            localized_formats: HashMap::new(),
             message_tokens,
             category,
             delay,
@@ -114,6 +119,19 @@ impl<T: MessageTokens> DynamicMessage<T> {
         self
     }
 
+    /// Sets the formats to use for receivers using locales other than the default one.
+    /// Receivers using locales without a format provided will get the message in the default locale.
+    ///
+    /// Calling this multiple times will override any previous calls.
+    pub fn with_localized_formats(
+        mut self,
+        localized_formats: HashMap<Locale, MessageFormat<T>>,
+    ) -> DynamicMessage<T> {
+        self.localized_formats = localized_formats;
+
+        self
+    }
+
     /// Adds a decoration to the mesage.
     ///
     /// Calling this multiple times will add more decorations to the message.
@@ -198,10 +216,13 @@ impl<T: MessageTokens> DynamicMessage<T> {
             self.category
         };
 
+        let message_format = self
+            .localized_formats
+            .get(&Locale::of(Some(pov_entity), world))
+            .unwrap_or(&self.message_format);
+
         Ok(GameMessage::Message {
-            content: self
-                .message_format
-                .interpolate(pov_entity, &self.message_tokens, world)?,
+            content: message_format.interpolate(pov_entity, &self.message_tokens, world)?,
             category,
             delay: self.delay,
             decorations: self.decorations.clone(),
@@ -209,6 +230,32 @@ impl<T: MessageTokens> DynamicMessage<T> {
     }
 }
 
+impl<T: FixedMessageTokens> DynamicMessage<T> {
+    /// Creates a message from a variant of the provided template, with no specific receivers set, which will be sent to the source entity.
+    pub fn new_from_template(
+        category: MessageCategory,
+        delay: MessageDelay,
+        template: &MessageTemplateId<T>,
+        message_tokens: T,
+        world: &World,
+    ) -> DynamicMessage<T> {
+        DynamicMessage::new(category, delay, template.choose(world), message_tokens)
+            .with_localized_formats(template.choose_localized(world))
+    }
+
+    /// Creates a message from a variant of the provided template, with no specific receivers set, which won't be sent to the source entity.
+    pub fn new_third_person_from_template(
+        category: MessageCategory,
+        delay: MessageDelay,
+        template: &MessageTemplateId<T>,
+        message_tokens: T,
+        world: &World,
+    ) -> DynamicMessage<T> {
+        DynamicMessage::new_third_person(category, delay, template.choose(world), message_tokens)
+            .with_localized_formats(template.choose_localized(world))
+    }
+}
+
 /// The location to send a dynamic message in.
 pub enum DynamicMessageLocation {
     /// The location of the entity that caused the message to be sent.
@@ -1,6 +1,7 @@
 use std::{array, collections::HashMap};
 
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::color::Color;
 
@@ -24,7 +25,7 @@ impl GameMap {
 }
 
 /// The coordinates of a location entity.
-#[derive(Component, PartialEq, Eq, Hash, Clone)]
+#[derive(Component, Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub struct Coordinates {
     /// Location on the x-axis (east-west).
     /// Higher values are farther east.
@@ -41,7 +42,7 @@ pub struct Coordinates {
 }
 
 /// An icon for a tile on the map.
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
 pub struct MapIcon {
     /// The characters that make up the icon.
     pub chars: [MapChar; CHARS_PER_TILE],
@@ -61,7 +62,7 @@ impl MapIcon {
 }
 
 /// A single character in a map icon.
-#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct MapChar {
     /// The background color of the character.
     pub bg_color: Color,
@@ -1,5 +1,7 @@
+use serde::Serialize;
+
 /// The description of a command used to perform an action.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ActionDescription {
     pub format: String,
 }
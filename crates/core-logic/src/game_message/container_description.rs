@@ -1,12 +1,14 @@
 use bevy_ecs::prelude::*;
 
+use serde::Serialize;
+
 use crate::{
     component::{Container, Description, Volume, Weight},
     find_wearing_entity, find_wielding_entity, Edible, FluidContainer, Weapon, Wearable,
 };
 
 /// The description of a container.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ContainerDescription {
     /// Descriptions of the items in the container.
     pub items: Vec<ContainerEntityDescription>,
@@ -47,7 +49,7 @@ impl ContainerDescription {
 }
 
 /// The description of an item in a container.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ContainerEntityDescription {
     /// The category of the item.
     pub category: ContainerEntityCategory,
@@ -86,7 +88,7 @@ impl ContainerEntityDescription {
 }
 
 /// The category of an entity in a container
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum ContainerEntityCategory {
     /// Something that's mainly a weapon
     Weapon,
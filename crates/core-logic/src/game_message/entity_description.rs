@@ -2,15 +2,18 @@ use std::iter::once;
 
 use bevy_ecs::prelude::*;
 use itertools::Itertools;
+use serde::Serialize;
 
 use crate::{
-    component::{AttributeDescription, AttributeDetailLevel, Description, Pronouns},
+    component::{
+        AttributeDescription, AttributeDetailLevel, Description, LocalizedDescriptions, Pronouns,
+    },
     input_parser::find_parsers_relevant_for,
-    ActionDescription,
+    ActionDescription, Locale,
 };
 
 /// The description of an entity.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct EntityDescription {
     /// The name of the entity.
     pub name: String,
@@ -51,11 +54,9 @@ impl EntityDescription {
         detail_level: AttributeDetailLevel,
         world: &World,
     ) -> EntityDescription {
-        let pronouns = if entity == pov_entity {
-            Pronouns::you()
-        } else {
-            desc.pronouns.clone()
-        };
+        let pronouns = Pronouns::get(entity, Some(pov_entity), world);
+        let locale = Locale::of(Some(pov_entity), world);
+        let localized = LocalizedDescriptions::get(entity, locale, world);
 
         let raw_attributes = desc
             .attribute_describers
@@ -85,11 +86,17 @@ impl EntityDescription {
             .for_each(|s| attributes.push(AttributeDescription::Section(s)));
 
         EntityDescription {
-            name: desc.name.clone(),
+            name: localized.map_or_else(|| desc.name.clone(), |l| l.name.clone()),
             aliases: build_aliases(desc),
-            article: desc.article.clone(),
+            article: match localized {
+                Some(l) if l.proper_noun => None,
+                Some(l) => Some(locale.indefinite_article(l.gender).to_string()),
+                None => desc.article.clone(),
+            },
             pronouns,
-            description: desc.description.clone(),
+            description: localized
+                .and_then(|l| l.description.clone())
+                .unwrap_or_else(|| desc.description.clone()),
             attributes,
         }
     }
@@ -103,7 +110,7 @@ fn build_aliases(desc: &Description) -> Vec<String> {
 }
 
 /// The detailed description of an entity.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DetailedEntityDescription {
     pub basic_desc: EntityDescription,
     /// Descriptions of the actions that can be performed on the entity.
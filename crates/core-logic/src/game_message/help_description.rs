@@ -1,9 +1,10 @@
 use bevy_ecs::prelude::*;
 use itertools::Itertools;
+use serde::Serialize;
 
 use crate::{input_parser::find_parsers_relevant_for, ActionDescription};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct HelpDescription {
     /// Descriptions of the actions that can be performed.
     pub actions: Vec<ActionDescription>,
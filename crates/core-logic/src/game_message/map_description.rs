@@ -1,10 +1,13 @@
 use std::{array, sync::LazyLock};
 
 use bevy_ecs::prelude::*;
+use itertools::Itertools;
+use serde::{Serialize, Serializer};
 
 use crate::{
     component::{Location, Room},
     game_map::{Coordinates, GameMap},
+    resource::Weather,
     Color, MapIcon,
 };
 
@@ -15,14 +18,38 @@ static PLAYER_MAP_ICON: LazyLock<MapIcon> =
 
 /// A collection of tiles around an entity.
 /// `S` is the length and width of the map, in tiles.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MapDescription<const S: usize> {
     /// The tiles in the map. Formatted as an array of rows.
+    #[serde(serialize_with = "serialize_tiles")]
     pub tiles: [[MapIcon; S]; S],
+    /// The rooms in the map, other than the one in the center. Sorted by distance from the center.
+    pub nearby_rooms: Vec<NearbyRoomDescription>,
+}
+
+/// A room near the center of a map.
+#[derive(Debug, Clone, Serialize)]
+pub struct NearbyRoomDescription {
+    /// The name of the room.
+    pub name: String,
+    /// How many tiles east of the center of the map the room is. Negative values are to the west.
+    pub x_offset: i64,
+    /// How many tiles north of the center of the map the room is. Negative values are to the south.
+    pub y_offset: i64,
+}
+
+/// Serializes map tiles as a sequence of rows, since serde can't serialize arrays of arbitrary length.
+fn serialize_tiles<const S: usize, Ser: Serializer>(
+    tiles: &[[MapIcon; S]; S],
+    serializer: Ser,
+) -> Result<Ser::Ok, Ser::Error> {
+    serializer.collect_seq(tiles.iter().map(|row| row.as_slice()))
 }
 
 impl<const S: usize> MapDescription<S> {
     /// Creates a map centered on the location of the provided entity.
+    ///
+    /// If the weather limits how far can be seen outdoors, anything too far from the center is left blank.
     pub fn for_entity(
         pov_entity: Entity,
         center_coords: &Coordinates,
@@ -30,11 +57,17 @@ impl<const S: usize> MapDescription<S> {
     ) -> MapDescription<S> {
         let pov_coords = find_coordinates_of_entity(pov_entity, world);
         let center_index = S / 2;
+        let max_offset = center_index as i64;
+        let visible_offset = Weather::at(center_coords, world)
+            .and_then(|weather| weather.get_map_visibility())
+            .map_or(max_offset, |visibility| visibility.min(max_offset));
 
         let tiles = array::from_fn(|row_index| {
             array::from_fn(|col_index| {
-                let x = center_coords.x + (col_index as i64 - center_index as i64);
-                let y = center_coords.y - (row_index as i64 - center_index as i64);
+                let x_offset = col_index as i64 - max_offset;
+                let y_offset = max_offset - row_index as i64;
+                let x = center_coords.x + x_offset;
+                let y = center_coords.y + y_offset;
                 let z = center_coords.z;
                 let parent = center_coords.parent.clone();
 
@@ -42,13 +75,48 @@ impl<const S: usize> MapDescription<S> {
 
                 if current_coords == *pov_coords {
                     PLAYER_MAP_ICON.clone()
+                } else if x_offset.abs().max(y_offset.abs()) > visible_offset {
+                    BLANK_ICON.clone()
                 } else {
                     icon_for_coords(&current_coords, world)
                 }
             })
         });
 
-        MapDescription { tiles }
+        let nearby_rooms = (-visible_offset..=visible_offset)
+            .cartesian_product(-visible_offset..=visible_offset)
+            .filter(|offsets| *offsets != (0, 0))
+            .filter_map(|(x_offset, y_offset)| {
+                let coords = Coordinates {
+                    x: center_coords.x + x_offset,
+                    y: center_coords.y + y_offset,
+                    z: center_coords.z,
+                    parent: center_coords.parent.clone(),
+                };
+
+                let room_entity = world.resource::<GameMap>().locations.get(&coords)?;
+                let room = world
+                    .get::<Room>(*room_entity)
+                    .expect("coordinates should map to a room");
+
+                Some(NearbyRoomDescription {
+                    name: room.name.clone(),
+                    x_offset,
+                    y_offset,
+                })
+            })
+            .sorted_by_key(|room| {
+                (
+                    room.x_offset.abs().max(room.y_offset.abs()),
+                    room.name.clone(),
+                )
+            })
+            .collect();
+
+        MapDescription {
+            tiles,
+            nearby_rooms,
+        }
     }
 }
 
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
 mod entity_description;
@@ -55,17 +56,25 @@ pub use room_description::RoomObjectDescription;
 
 mod map_description;
 pub use map_description::MapDescription;
+pub use map_description::NearbyRoomDescription;
 
 mod help_description;
 pub use help_description::HelpDescription;
 
-use crate::AdvancementPointType;
+use crate::{AdvancementPointType, PlayerPreferences};
 
 /// Resolution of the visualization for short vital change messages.
 const SHORT_VITAL_CHANGE_RESOLUTION: u8 = 10;
 
+/// The version of the JSON representation of game messages produced by `GameMessage::to_json`.
+/// This should be incremented whenever that representation changes in a way that could break clients, like removing or renaming a field or changing what a field contains.
+///
+/// Adding new fields or new types of messages doesn't increment it, so clients should ignore any fields and message types they don't recognize.
+pub const GAME_MESSAGE_JSON_VERSION: u32 = 1;
+
 /// A message from the game, such as the description of a location, a message describing the results of an action, etc.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", content = "data")]
 pub enum GameMessage {
     Room(RoomDescription),
     Entity(EntityDescription),
@@ -78,6 +87,7 @@ pub enum GameMessage {
     Help(HelpDescription),
     Players(PlayersDescription),
     Ranges(RangesDescription),
+    Preferences(PlayerPreferences),
     AdvancementPointsGained(u32, AdvancementPointType),
     Message {
         content: String,
@@ -88,8 +98,18 @@ pub enum GameMessage {
     Error(String),
 }
 
+impl GameMessage {
+    /// Builds the JSON representation of this message, along with the version of that representation.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "version": GAME_MESSAGE_JSON_VERSION,
+            "message": serde_json::to_value(self).expect("game message should be serializable"),
+        })
+    }
+}
+
 /// The category of a game message.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum MessageCategory {
     /// A message from an entity's surroundings.
     Surroundings(SurroundingsMessageCategory),
@@ -100,7 +120,7 @@ pub enum MessageCategory {
 }
 
 /// A message from an entity's surroundings.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
 pub enum SurroundingsMessageCategory {
     /// Someone saying something.
     Speech,
@@ -115,7 +135,7 @@ pub enum SurroundingsMessageCategory {
 }
 
 /// A message from the entity itself.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Serialize)]
 pub enum InternalMessageCategory {
     /// The entity saying something.
     Speech,
@@ -145,7 +165,7 @@ impl MessageCategory {
 }
 
 /// The amount of time to wait before any additional messages are displayed.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub enum MessageDelay {
     /// No time should be waited.
     None,
@@ -156,10 +176,88 @@ pub enum MessageDelay {
 }
 
 /// Additional bits of information that can be included with messages.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum MessageDecoration {
     /// A description of a change to an entity's vitals.
     VitalChange(VitalChangeDescription),
     /// A short description of a change to an entity's vitals.
     ShortVitalChange(VitalChangeShortDescription<SHORT_VITAL_CHANGE_RESOLUTION>),
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{component::AttributeDescription, ConstrainedValue, Pronouns, VitalType};
+
+    use super::*;
+
+    #[test]
+    fn message_to_json() {
+        let message = GameMessage::Message {
+            content: "Hello.".to_string(),
+            category: MessageCategory::Surroundings(SurroundingsMessageCategory::Speech),
+            delay: MessageDelay::Short,
+            decorations: vec![MessageDecoration::VitalChange(VitalChangeDescription {
+                vital_type: VitalType::Health,
+                old_value: ConstrainedValue::new(100.0, 0.0, 100.0),
+                new_value: ConstrainedValue::new(90.0, 0.0, 100.0),
+            })],
+        };
+
+        assert_eq!(
+            json!({
+                "version": GAME_MESSAGE_JSON_VERSION,
+                "message": {
+                    "type": "Message",
+                    "data": {
+                        "content": "Hello.",
+                        "category": { "Surroundings": "Speech" },
+                        "delay": "Short",
+                        "decorations": [{
+                            "VitalChange": {
+                                "vital_type": "Health",
+                                "old_value": { "current": 100.0, "min": 0.0, "max": 100.0 },
+                                "new_value": { "current": 90.0, "min": 0.0, "max": 100.0 },
+                            }
+                        }],
+                    },
+                },
+            }),
+            message.to_json()
+        );
+    }
+
+    #[test]
+    fn nested_message_to_json() {
+        let message = GameMessage::Entity(EntityDescription {
+            name: "rock".to_string(),
+            aliases: Vec::new(),
+            article: Some("a".to_string()),
+            pronouns: Pronouns::it(),
+            description: "It's a rock.".to_string(),
+            attributes: vec![AttributeDescription::Message(Box::new(GameMessage::Error(
+                "oops".to_string(),
+            )))],
+        });
+
+        let json = message.to_json();
+
+        assert_eq!("Entity", json["message"]["type"]);
+        assert_eq!("rock", json["message"]["data"]["name"]);
+        assert_eq!(
+            json!({ "Message": { "type": "Error", "data": "oops" } }),
+            json["message"]["data"]["attributes"][0]
+        );
+    }
+
+    #[test]
+    fn advancement_points_gained_to_json() {
+        let message = GameMessage::AdvancementPointsGained(2, AdvancementPointType::Skill);
+
+        assert_eq!(
+            json!({ "type": "AdvancementPointsGained", "data": [2, "Skill"] }),
+            message.to_json()["message"]
+        );
+    }
+}
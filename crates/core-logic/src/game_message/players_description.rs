@@ -1,19 +1,21 @@
 use bevy_ecs::prelude::*;
 
+use serde::Serialize;
+
 use crate::{
     component::{ActionQueue, Description, Player},
     GameOptions,
 };
 
 /// A description of all the players on a server.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PlayersDescription {
     /// Descriptions of the players on the server.
     pub players: Vec<PlayerDescription>,
 }
 
 /// A description of a player.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PlayerDescription {
     /// The name of the player.
     pub name: String,
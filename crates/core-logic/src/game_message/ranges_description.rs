@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use bevy_ecs::prelude::*;
+use serde::Serialize;
 
 use crate::{
     component::{CombatRange, WeaponRanges},
@@ -8,14 +9,14 @@ use crate::{
 };
 
 /// A description of the ranges to combatants.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RangesDescription {
     /// Descriptions of the ranges to combatants.
     pub ranges: Vec<RangeDescription>,
 }
 
 /// A description of the range to a combatant.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RangeDescription {
     /// The name of the combatant.
     pub name: String,
@@ -26,7 +27,7 @@ pub struct RangeDescription {
 }
 
 /// What a range means in the context of a weapon.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub enum WeaponRangeJudgement {
     /// The weapon is outside its usable range.
     NotUsable(WeaponRangeJudgementReason),
@@ -37,7 +38,7 @@ pub enum WeaponRangeJudgement {
 }
 
 /// The reason why a range judgement was chosen.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub enum WeaponRangeJudgementReason {
     /// The range is longer than the ideal range.
     TooLong,
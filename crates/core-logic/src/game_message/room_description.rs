@@ -1,23 +1,35 @@
 use bevy_ecs::prelude::*;
 use itertools::Itertools;
+use serde::Serialize;
 
 use crate::{
-    component::{Connection, Container, Description, Room},
-    game_map::Coordinates,
-    is_living_entity, Direction, MapDescription, OpenState,
+    component::{Connection, Container, Description, LightLevel, Room},
+    game_map::{Coordinates, GameMap},
+    is_living_entity,
+    resource::{Weather, WeatherKind},
+    Direction, MapDescription, OpenState,
 };
 
-#[derive(Debug, Clone)]
+/// The description to show instead of a room's description when it's too dark to see it.
+const DARK_ROOM_DESCRIPTION: &str = "It's too dark to see much of anything.";
+
+#[derive(Debug, Clone, Serialize)]
 pub struct RoomDescription {
     pub name: String,
     pub description: String,
     pub entities: Vec<RoomEntityDescription>,
     pub exits: Vec<ExitDescription>,
-    pub map: Box<MapDescription<5>>,
+    // big enough for the largest `MapSize`, so each player can be shown as much of it as they want
+    pub map: Box<MapDescription<7>>,
+    pub coordinates: Coordinates,
+    /// How well lit the room is.
+    pub light_level: LightLevel,
+    /// A description of the weather, if the room is outdoors and the weather is worth mentioning.
+    pub weather: Option<String>,
 }
 
 /// The description of an entity as part of a room description.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum RoomEntityDescription {
     Object(RoomObjectDescription),
     Living(RoomLivingEntityDescription),
@@ -25,7 +37,7 @@ pub enum RoomEntityDescription {
 }
 
 /// A description of an object as part of a room description.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub struct RoomObjectDescription {
     /// The name of the entity.
     pub name: String,
@@ -36,7 +48,7 @@ pub struct RoomObjectDescription {
 }
 
 /// A description of a living thing as part of a room description.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub struct RoomLivingEntityDescription {
     /// The name of the entity.
     pub name: String,
@@ -47,7 +59,7 @@ pub struct RoomLivingEntityDescription {
 }
 
 /// A description of a connection to another room as part of a room description.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RoomConnectionEntityDescription {
     /// The name of the entity.
     pub name: String,
@@ -57,7 +69,7 @@ pub struct RoomConnectionEntityDescription {
     pub direction: Direction,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ExitDescription {
     pub direction: Direction,
     pub description: String,
@@ -67,6 +79,9 @@ impl RoomDescription {
     /// Creates a `RoomDescription` for the provided room from the perspective of the provided entity.
     ///
     /// The provided Room, Container, and Coordinates should be on the same entity.
+    ///
+    /// If the room is dark, its description is left out, along with anything in it that can't be made out in the dark.
+    /// The weather is still described, since it can be felt even in the dark.
     pub fn from_room(
         room: &Room,
         container: &Container,
@@ -81,12 +96,29 @@ impl RoomDescription {
             .filter_map(|entity| RoomEntityDescription::from_entity(*entity, world))
             .collect();
 
+        let light_level = world
+            .resource::<GameMap>()
+            .locations
+            .get(coordinates)
+            .map(|room_entity| LightLevel::of_room(*room_entity, world))
+            .unwrap_or(LightLevel::Bright);
+        let description = if light_level == LightLevel::Dark {
+            DARK_ROOM_DESCRIPTION.to_string()
+        } else {
+            room.description.clone()
+        };
+
         RoomDescription {
             name: room.name.clone(),
-            description: room.description.clone(),
+            description,
             entities: entity_descriptions,
             exits: ExitDescription::from_container(container, pov_entity, world),
             map: Box::new(MapDescription::for_entity(pov_entity, coordinates, world)),
+            coordinates: coordinates.clone(),
+            light_level,
+            weather: Weather::at(coordinates, world)
+                .and_then(WeatherKind::get_description)
+                .map(str::to_string),
         }
     }
 }
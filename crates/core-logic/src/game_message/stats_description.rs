@@ -1,5 +1,6 @@
 use bevy_ecs::prelude::*;
 use itertools::Itertools;
+use serde::Serialize;
 
 use crate::{
     component::Stats,
@@ -8,7 +9,7 @@ use crate::{
 };
 
 /// The description of an entity's stats.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StatsDescription {
     /// The attributes of the entity.
     pub attributes: Vec<StatAttributeDescription>,
@@ -28,7 +29,7 @@ impl StatsDescription {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StatAttributeDescription {
     /// The name of the attribute
     pub name: String,
@@ -60,7 +61,7 @@ impl StatAttributeDescription {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SkillDescription {
     /// The name of the skill
     pub name: String,
@@ -106,7 +107,7 @@ impl SkillDescription {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AdvancementDescription {
     /// The total amount of XP the entity has earned
     pub total_xp: Xp,
@@ -116,7 +117,7 @@ pub struct AdvancementDescription {
     pub attribute_points: AdvancementPointsDescription,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AdvancementPointsDescription {
     /// The number of points available to spend
     pub available: u32,
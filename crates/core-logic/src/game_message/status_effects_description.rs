@@ -1,6 +1,7 @@
 use std::{collections::HashMap, fmt::Display};
 
 use bevy_ecs::prelude::*;
+use serde::Serialize;
 
 use crate::{
     component::{StatusEffectDetails, StatusEffects},
@@ -8,11 +9,11 @@ use crate::{
 };
 
 /// The description of an entity's status effects.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StatusEffectsDescription(pub Vec<StatusEffectDescription>);
 
 /// The description of a single status effect.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StatusEffectDescription {
     /// The name of the status effect
     pub name: String,
@@ -41,7 +42,7 @@ impl StatusEffectDescription {
 }
 
 /// The name of a stat.
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
 pub struct StatName(pub String);
 
 impl Display for StatName {
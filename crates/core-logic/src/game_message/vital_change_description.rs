@@ -1,7 +1,9 @@
+use serde::Serialize;
+
 use crate::{vital_change::VitalType, ConstrainedValue};
 
 /// A description of a change of a vital value.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VitalChangeDescription {
     /// The type of vital that changed.
     pub vital_type: VitalType,
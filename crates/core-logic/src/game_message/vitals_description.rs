@@ -1,7 +1,9 @@
+use serde::Serialize;
+
 use crate::{component::Vitals, ConstrainedValue};
 
 /// The description of an entity's vitals.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VitalsDescription {
     /// The health of the entity.
     pub health: ConstrainedValue<f32>,
@@ -11,6 +13,8 @@ pub struct VitalsDescription {
     pub hydration: ConstrainedValue<f32>,
     /// The non-tiredness of the entity.
     pub energy: ConstrainedValue<f32>,
+    /// The warmth of the entity.
+    pub body_temperature: ConstrainedValue<f32>,
 }
 
 impl VitalsDescription {
@@ -21,6 +25,7 @@ impl VitalsDescription {
             satiety: vitals.satiety,
             hydration: vitals.hydration,
             energy: vitals.energy,
+            body_temperature: vitals.body_temperature,
         }
     }
 }
@@ -1,5 +1,6 @@
 use bevy_ecs::prelude::*;
 use itertools::Itertools;
+use serde::Serialize;
 
 use crate::{
     body_part::{BodyPartType, BodyParts},
@@ -8,7 +9,7 @@ use crate::{
 };
 
 /// The description of the items an entity is wearing.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct WornItemsDescription {
     /// The items being worn.
     pub items: Vec<WornItemDescription>,
@@ -58,7 +59,7 @@ impl WornItemsDescription {
 /// The description of a single body part.
 ///
 /// Note: declared field order is important for the `PartialOrd` and `Ord` derives
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub struct BodyPartDescription {
     /// The type of the body part.
     pub body_part_type: BodyPartType,
@@ -84,7 +85,7 @@ impl BodyPartDescription {
 }
 
 /// The description of an item being worn.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct WornItemDescription {
     /// The name of the item.
     pub name: String,
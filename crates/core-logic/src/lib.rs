@@ -2,14 +2,18 @@ use bevy_ecs::prelude::*;
 use body_part::{BodyPartDamageMultiplier, BodyPartType, BodyParts};
 use flume::{Receiver, Sender};
 use input_parser::InputParser;
-use log::debug;
-use resource::{insert_resources, register_resource_handlers, BodyPartTypeNameCatalog};
+use log::{debug, info, warn};
+use resource::*;
 use std::{
     collections::{HashMap, HashSet},
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
     sync::{Arc, RwLock},
     thread,
     time::{Duration, SystemTime},
 };
+use strum::IntoEnumIterator;
 
 #[cfg(test)]
 mod test_utils;
@@ -22,6 +26,7 @@ pub use component::AdvancementPointType;
 pub use component::AttributeDescription;
 pub use component::AttributeSection;
 pub use component::AttributeSectionName;
+pub use component::LightLevel;
 pub use component::NonSectionAttributeType;
 pub use component::Pronouns;
 pub use component::StatAdjustment;
@@ -39,6 +44,17 @@ use input_parser::*;
 mod world_setup;
 use world_setup::*;
 
+mod worldgen;
+use worldgen::*;
+
+mod world_definition;
+pub use world_definition::spawn_prototype;
+pub use world_definition::ContentReloadSummary;
+pub use world_definition::SpawnPrototypeError;
+pub use world_definition::WorldDefinitionError;
+pub use world_definition::WorldDefinitionErrorKind;
+use world_definition::*;
+
 mod game_message;
 pub use game_message::*;
 
@@ -49,6 +65,7 @@ mod direction;
 pub use direction::Direction;
 
 mod game_map;
+pub use game_map::Coordinates;
 pub use game_map::MapChar;
 pub use game_map::MapIcon;
 pub use game_map::CHARS_PER_TILE;
@@ -97,6 +114,33 @@ use name_with_article::*;
 
 mod found_entities;
 
+mod map_as_pairs;
+
+mod character_creation;
+pub use character_creation::CharacterCreationOptions;
+pub use character_creation::NewCharacter;
+pub use character_creation::StatAllocation;
+pub use character_creation::StatAllocationError;
+
+mod locale;
+pub use locale::GrammaticalGender;
+pub use locale::Locale;
+
+mod player_preferences;
+pub use player_preferences::MapSize;
+pub use player_preferences::PlayerPreferences;
+pub use player_preferences::PreferenceError;
+
+mod scripting;
+pub use scripting::ScriptCatalog;
+pub use scripting::ScriptCompileError;
+pub use scripting::ScriptWorld;
+
+mod save;
+pub use save::LoadError;
+pub use save::SaveError;
+use save::*;
+
 const CHEATS_ENABLED: bool = true;
 
 pub const AFTERLIFE_ROOM_COORDINATES: Coordinates = Coordinates {
@@ -114,14 +158,47 @@ pub struct SpawnRoom(Coordinates);
 #[derive(Resource)]
 pub struct AfterlifeRoom(Coordinates);
 
-/// Mapping of player IDs to entities.
+/// Mapping of players to the entities they control.
 #[derive(Resource)]
-pub struct PlayerIdMapping(HashMap<PlayerId, Entity>);
+pub struct PlayerIdMapping {
+    /// The entities of connected players, keyed by player ID.
+    connected: HashMap<PlayerId, Entity>,
+    /// The entities of players who have disconnected, keyed by player identity.
+    linkdead: HashMap<String, Entity>,
+}
+
+impl PlayerIdMapping {
+    /// Creates an empty mapping.
+    fn new() -> PlayerIdMapping {
+        PlayerIdMapping {
+            connected: HashMap::new(),
+            linkdead: HashMap::new(),
+        }
+    }
+
+    /// Records the provided player as controlling the provided entity.
+    fn insert(&mut self, player: &Player, entity: Entity) {
+        if player.is_linkdead() {
+            self.linkdead.insert(player.identity.clone(), entity);
+        } else {
+            self.connected.insert(player.id, entity);
+        }
+    }
+
+    /// Removes any mappings to the provided entity.
+    fn remove_entity(&mut self, entity: Entity) {
+        self.connected.retain(|_, e| *e != entity);
+        self.linkdead.retain(|_, e| *e != entity);
+    }
+}
 
 /// Entities that should have in-progress actions interrupted.
 #[derive(Resource)]
 pub struct InterruptedEntities(pub HashSet<Entity>);
 
+/// A player's entity, the sender to send commands from them, and the receiver to receive messages to them.
+pub type PlayerConnection = (Entity, Sender<String>, Receiver<(GameMessage, Time)>);
+
 pub struct Game {
     /// The game world.
     world: Arc<RwLock<World>>,
@@ -170,6 +247,9 @@ impl StandardInputParsers {
             Box::new(PlayersParser),
             Box::new(SpendSkillPointParser),
             Box::new(SpendAttributePointParser),
+            Box::new(PreferencesParser),
+            Box::new(SetPreferenceParser),
+            Box::new(ScreenReaderParser),
             Box::new(HelpParser),
         ];
 
@@ -182,10 +262,86 @@ impl StandardInputParsers {
 }
 
 impl Game {
-    /// Creates a game with a new, empty world
-    pub fn new(game_options: GameOptions) -> Game {
+    /// Creates a game with a new world, defined by the world definition files in `GameOptions::world_dir` (or the built-in ones if that isn't set).
+    pub fn new(game_options: GameOptions) -> Result<Game, WorldDefinitionError> {
         let skip_worldgen = game_options.skip_worldgen;
+        let worldgen_seed = game_options.worldgen_seed;
+        let world_definition = load_world_definition(&game_options)?;
+
+        let mut world = Game::new_base_world(game_options);
+        world_definition.apply_catalog_definitions(&mut world);
+        world.insert_resource(world_definition.build_message_template_catalog()?);
+        world.insert_resource(world_definition.build_prototype_catalog()?);
+        world.insert_resource(world_definition.build_script_catalog()?);
+
+        if !skip_worldgen {
+            let spawn_room_coords = world_definition.spawn(&mut world)?;
+            if let Some(seed) = worldgen_seed {
+                match world_definition.find_city_entrance()? {
+                    Some(entrance) => generate_city(seed, &entrance, &mut world),
+                    None => {
+                        warn!("A worldgen seed was provided, but the world has no city entrance")
+                    }
+                }
+            }
+            spawn_afterlife_room(&mut world);
+            world.insert_resource(SpawnRoom(spawn_room_coords));
+        }
+
+        Ok(Game::from_world(world))
+    }
+
+    /// Creates a game with the world saved in the file at the provided path.
+    ///
+    /// Any entities that were controlled by players when the world was saved are left waiting for those players to reconnect with `Game::reattach_player`.
+    pub fn load(path: impl AsRef<Path>, game_options: GameOptions) -> Result<Game, LoadError> {
+        let world_definition = load_world_definition(&game_options)?;
+        let mut world = Game::new_base_world(game_options);
+        world_definition.apply_catalog_definitions(&mut world);
+        world.insert_resource(world_definition.build_message_template_catalog()?);
+        world.insert_resource(world_definition.build_prototype_catalog()?);
+        world.insert_resource(world_definition.build_script_catalog()?);
+        let reader = BufReader::new(File::open(path)?);
+        let player_entities = load_world(reader, &mut world)?;
+        // the saved messages might be out of date with the current message templates
+        refresh_human_innate_weapon_messages(&mut world);
+
+        let mut game = Game::from_world(world);
+        let players = player_entities
+            .into_iter()
+            .map(|(entity, identity)| {
+                (entity, Player::new_linkdead(game.get_player_id(), identity))
+            })
+            .collect();
+        finish_loading(players, &mut game.world.write().unwrap());
 
+        Ok(game)
+    }
+
+    /// Saves the game world to a file at the provided path, so it can be loaded later with `Game::load`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SaveError> {
+        let writer = BufWriter::new(File::create(path)?);
+        save_world(&self.world.read().unwrap(), writer)
+    }
+
+    /// Re-reads the world definition files the game was created with, and updates the running world's content to match them.
+    ///
+    /// Prototypes, scripts, commands, message templates, and catalog definitions are replaced, and existing rooms and entities spawned from prototypes get their new names and descriptions.
+    /// If the files have any errors, the error is returned and the world is left unchanged.
+    pub fn reload_content(&self) -> Result<ContentReloadSummary, WorldDefinitionError> {
+        let world_definition =
+            load_world_definition(self.world.read().unwrap().resource::<GameOptions>())?;
+        let summary = world_definition.reload(&mut self.world.write().unwrap())?;
+
+        for warning in &summary.warnings {
+            warn!("Content reload: {warning}");
+        }
+
+        Ok(summary)
+    }
+
+    /// Creates a world with only the standard resources in it.
+    fn new_base_world(game_options: GameOptions) -> World {
         let mut world = World::new();
         world.insert_resource(game_options);
         world.insert_resource(Time::new());
@@ -193,14 +349,14 @@ impl Game {
         world.insert_resource(StandardInputParsers::new());
         world.insert_resource(InterruptedEntities(HashSet::new()));
         world.insert_resource(AfterlifeRoom(AFTERLIFE_ROOM_COORDINATES));
-        world.insert_resource(PlayerIdMapping(HashMap::new()));
+        world.insert_resource(PlayerIdMapping::new());
         insert_resources(&mut world);
 
-        if !skip_worldgen {
-            let spawn_room_coords = set_up_world(&mut world);
-            world.insert_resource(SpawnRoom(spawn_room_coords));
-        }
+        world
+    }
 
+    /// Creates a game with the provided world, and registers all the notification handlers.
+    fn from_world(mut world: World) -> Game {
         register_action_handlers(&mut world);
         register_resource_handlers(&mut world);
         register_component_handlers(&mut world);
@@ -216,11 +372,13 @@ impl Game {
         game
     }
 
-    /// Adds a player to the game in the default spawn location.
-    pub fn add_player(
-        &mut self,
-        name: String,
-    ) -> (Entity, Sender<String>, Receiver<(GameMessage, Time)>) {
+    /// Gets the things players can choose from when creating a character.
+    pub fn get_character_creation_options(&self) -> CharacterCreationOptions {
+        CharacterCreationOptions::from_world(&self.world.read().unwrap())
+    }
+
+    /// Adds a player controlling a newly-created character to the game in the default spawn location.
+    pub fn add_player(&mut self, character: NewCharacter) -> PlayerConnection {
         // create channels for communication between the player and the world
         let (commands_sender, commands_receiver) = flume::unbounded::<String>();
         let (messages_sender, messages_receiver) = flume::unbounded::<(GameMessage, Time)>();
@@ -230,90 +388,20 @@ impl Game {
         // add the player to the world
         let mut world = self.world.write().unwrap();
         let spawn_room_id = find_spawn_room(&world);
-        let player = Player::new(player_id, messages_sender);
-        let player_entity = spawn_player(name, player, spawn_room_id, &mut world);
+        let player = Player::new(player_id, character.name.clone(), messages_sender);
+        let player_entity = spawn_player(character, player, spawn_room_id, &mut world);
         self.spawn_command_thread(player_id, commands_receiver);
 
         // add stuff to the player's inventory
-        let medium_thing_id = world
-            .spawn((
-                Description {
-                    name: "medium thing".to_string(),
-                    room_name: "medium thing".to_string(),
-                    plural_name: "medium things".to_string(),
-                    article: Some("a".to_string()),
-                    pronouns: Pronouns::it(),
-                    aliases: vec!["thing".to_string()],
-                    description: "Some kind of medium-sized thing.".to_string(),
-                    attribute_describers: vec![
-                        Item::get_attribute_describer(),
-                        Volume::get_attribute_describer(),
-                        Weight::get_attribute_describer(),
-                    ],
-                },
-                Item::new_one_handed(),
-                Volume(0.25),
-                Weight(0.5),
-            ))
-            .id();
-        move_entity(medium_thing_id, player_entity, &mut world);
-
-        let heavy_thing_id = world
-            .spawn((
-                Description {
-                    name: "heavy thing".to_string(),
-                    room_name: "heavy thing".to_string(),
-                    plural_name: "heavy things".to_string(),
-                    article: Some("a".to_string()),
-                    pronouns: Pronouns::it(),
-                    aliases: vec!["thing".to_string()],
-                    description: "Some kind of heavy thing.".to_string(),
-                    attribute_describers: vec![
-                        Item::get_attribute_describer(),
-                        Volume::get_attribute_describer(),
-                        Weight::get_attribute_describer(),
-                    ],
-                },
-                Item::new_one_handed(),
-                Volume(0.5),
-                Weight(15.0),
-            ))
-            .id();
-        move_entity(heavy_thing_id, player_entity, &mut world);
-
-        let water_bottle_id = world
-            .spawn((
-                Description {
-                    name: "water bottle".to_string(),
-                    room_name: "water bottle".to_string(),
-                    plural_name: "water bottles".to_string(),
-                    article: Some("a".to_string()),
-                    pronouns: Pronouns::it(),
-                    aliases: vec!["bottle".to_string()],
-                    description: "A disposable plastic water bottle.".to_string(),
-                    attribute_describers: vec![
-                        Item::get_attribute_describer(),
-                        Volume::get_attribute_describer(),
-                        Weight::get_attribute_describer(),
-                        FluidContainer::get_attribute_describer(),
-                    ],
-                },
-                Item::new_one_handed(),
-                Volume(0.5),
-                Weight(0.1),
-                FluidContainer {
-                    contents: Fluid {
-                        contents: [
-                            (FluidType::Water, Volume(0.25)),
-                            (FluidType::Alcohol, Volume(0.2)),
-                        ]
-                        .into(),
-                    },
-                    volume: Some(Volume(0.5)),
-                },
-            ))
-            .id();
-        move_entity(water_bottle_id, player_entity, &mut world);
+        let starting_items = world
+            .resource::<PrototypeCatalog>()
+            .get_starting_items()
+            .to_vec();
+        for prototype in starting_items {
+            if let Err(e) = spawn_prototype(&prototype, player_entity, &mut world) {
+                warn!("Unable to give starting item to new player: {e}");
+            }
+        }
 
         // send the player an initial message with their location
         send_current_location_message(player_entity, &world);
@@ -321,6 +409,76 @@ impl Game {
         (player_entity, commands_sender, messages_receiver)
     }
 
+    /// Reattaches a new connection to the entity of the player with the provided identity, if there is one.
+    ///
+    /// If that player is still connected, their existing connection is detached first, so the new connection takes it over.
+    pub fn reattach_player(&mut self, identity: &str) -> Option<PlayerConnection> {
+        let player_id = self.get_player_id();
+
+        let mut world = self.world.write().unwrap();
+        if let Some((connected_player_id, connected_entity)) =
+            find_connected_player(identity, &world)
+        {
+            send_message(
+                &world,
+                connected_entity,
+                GameMessage::Message {
+                    content: "Someone else has connected as you, so you've been disconnected."
+                        .to_string(),
+                    category: MessageCategory::System,
+                    delay: MessageDelay::None,
+                    decorations: Vec::new(),
+                },
+            );
+            detach_player(connected_player_id, &mut world);
+        }
+
+        let player_entity = world
+            .resource_mut::<PlayerIdMapping>()
+            .linkdead
+            .remove(identity)?;
+
+        // create channels for communication between the player and the world
+        let (commands_sender, commands_receiver) = flume::unbounded::<String>();
+        let (messages_sender, messages_receiver) = flume::unbounded::<(GameMessage, Time)>();
+
+        world
+            .get_mut::<Player>(player_entity)?
+            .reattach(player_id, messages_sender);
+        world
+            .resource_mut::<PlayerIdMapping>()
+            .connected
+            .insert(player_id, player_entity);
+        self.spawn_command_thread(player_id, commands_receiver);
+
+        DynamicMessage::new_third_person_from_template(
+            MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
+            MessageDelay::Short,
+            &PLAYER_REATTACHED,
+            PlayerMessageTokens {
+                player: player_entity,
+            },
+            &world,
+        )
+        .send(
+            Some(player_entity),
+            DynamicMessageLocation::SourceEntity,
+            &world,
+        );
+
+        send_current_location_message(player_entity, &world);
+
+        Some((player_entity, commands_sender, messages_receiver))
+    }
+
+    /// Replaces the preferences of the player controlling the provided entity, like when they're loaded for a player who just connected.
+    pub fn set_player_preferences(&self, player_entity: Entity, preferences: PlayerPreferences) {
+        let mut world = self.world.write().unwrap();
+        if let Some(mut player) = world.get_mut::<Player>(player_entity) {
+            player.preferences = preferences;
+        }
+    }
+
     /// Gets the next player ID to use.
     fn get_player_id(&mut self) -> PlayerId {
         let id = self.next_player_id;
@@ -340,7 +498,7 @@ impl Game {
                     Ok(c) => c,
                     Err(_) => {
                         debug!("Command sender for player {player_id:?} has been dropped");
-                        despawn_player(player_id, &mut player_thread_world.write().unwrap());
+                        detach_player(player_id, &mut player_thread_world.write().unwrap());
                         break;
                     }
                 };
@@ -422,30 +580,49 @@ fn send_current_location_message(entity: Entity, world: &World) {
 fn find_entity_for_player(player_id: PlayerId, world: &World) -> Option<Entity> {
     world
         .resource::<PlayerIdMapping>()
-        .0
+        .connected
         .get(&player_id)
         .copied()
 }
 
+/// Finds the ID and entity of the connected player with the provided identity, if there is one.
+fn find_connected_player(identity: &str, world: &World) -> Option<(PlayerId, Entity)> {
+    world
+        .resource::<PlayerIdMapping>()
+        .connected
+        .iter()
+        .find(|(_, entity)| {
+            world
+                .get::<Player>(**entity)
+                .is_some_and(|player| player.identity == identity)
+        })
+        .map(|(player_id, entity)| (*player_id, *entity))
+}
+
 /// Spawns a new player.
-fn spawn_player(name: String, player: Player, spawn_room: Entity, world: &mut World) -> Entity {
-    let player_id = player.id;
+fn spawn_player(
+    character: NewCharacter,
+    player: Player,
+    spawn_room: Entity,
+    world: &mut World,
+) -> Entity {
     let desc = Description {
-        name: name.clone(),
-        room_name: name,
+        name: character.name.clone(),
+        room_name: character.name,
         plural_name: "people".to_string(),
         article: None,
-        pronouns: Pronouns::they(),
+        pronouns: character.pronouns,
         aliases: Vec::new(),
-        description: "A human-shaped person-type thing.".to_string(),
+        description: character.description,
         attribute_describers: vec![
             SleepState::get_attribute_describer(),
             WornItems::get_attribute_describer(),
             EquippedItems::get_attribute_describer(),
+            Wetness::get_attribute_describer(),
         ],
     };
     let vitals = Vitals::new();
-    let stats = build_starting_stats();
+    let stats = character.stat_allocation.build_stats(world);
     let worn_items = WornItems::new(5);
     let equipped_items = EquippedItems::new(2);
     let action_queue = ActionQueue::new();
@@ -468,16 +645,21 @@ fn spawn_player(name: String, player: Player, spawn_room: Entity, world: &mut Wo
     add_human_innate_weapon(player_entity, world);
     add_human_body_parts(player_entity, world);
 
-    world
-        .resource_mut::<PlayerIdMapping>()
-        .0
-        .insert(player_id, player_entity);
+    world.resource_scope(|world, mut mapping: Mut<PlayerIdMapping>| {
+        let player = world
+            .get::<Player>(player_entity)
+            .expect("player entity should be a player");
+        mapping.insert(player, player_entity);
+    });
 
-    DynamicMessage::new_third_person(
+    DynamicMessage::new_third_person_from_template(
         MessageCategory::Surroundings(SurroundingsMessageCategory::Movement),
         MessageDelay::Short,
-        MessageFormat::new("${player.Name} appears.").expect("message format should be valid"),
-        BasicTokens::new().with_entity("player".into(), player_entity),
+        &PLAYER_SPAWNED,
+        PlayerMessageTokens {
+            player: player_entity,
+        },
+        world,
     )
     .send(
         Some(player_entity),
@@ -488,18 +670,6 @@ fn spawn_player(name: String, player: Player, spawn_room: Entity, world: &mut Wo
     player_entity
 }
 
-fn build_starting_stats() -> Stats {
-    let mut stats = Stats::new(10, 5);
-
-    stats.set_attribute(&Attribute::Strength, 12);
-    stats.set_attribute(&Attribute::Intelligence, 9);
-
-    stats.set_skill(&Skill::Construction, 7);
-    stats.set_skill(&Skill::Cook, 8);
-
-    stats
-}
-
 /// Adds the innate weapon for a human (a fist) to an entity.
 fn add_human_innate_weapon(entity: Entity, world: &mut World) {
     let weapon = world
@@ -521,13 +691,7 @@ fn add_human_innate_weapon(entity: Entity, world: &mut World) {
                     to_hit_bonus_stat_range: 10.0..=20.0,
                     to_hit_bonus_per_stat_point: 0.2,
                 },
-                default_attack_messages: WeaponMessages {
-                    miss: vec![MessageFormat::new("${attacker.Name} ${attacker.you:lurch/lurches} forward as ${weapon.name} sails harmlessly past ${target.name}.").expect("message format should be valid")],
-                    minor_hit: vec![MessageFormat::new("${attacker.Name's} ${weapon.plain_name} ${weapon.glance/glances} off of ${target.name's} ${body_part.plain_name}.").expect("message format should be valid")],
-                    regular_hit: vec![MessageFormat::new("${attacker.Name} ${attacker.you:punch/punches} ${target.name} in the ${body_part.plain_name}.").expect("message format should be valid")],
-                    major_hit: vec![MessageFormat::new("${attacker.Name's} ${weapon.plain_name} ${weapon.wallop/wallops} ${target.name's} ${body_part.plain_name} with a crunch.").expect("message format should be valid")],
-                    self_hit: vec![MessageFormat::new("${attacker.Name} ${attacker.you:punch/punches} ${attacker.themself} in the ${body_part.plain_name}.").expect("message format should be valid")]
-                },
+                default_attack_messages: build_fist_messages(world),
             },
             InnateWeapon,
             Description {
@@ -540,29 +704,73 @@ fn add_human_innate_weapon(entity: Entity, world: &mut World) {
                 description: "a fleshy bundle of fingers".to_string(),
                 attribute_describers: vec![],
             },
+            LocalizedDescriptions(
+                [(
+                    Locale::Spanish,
+                    LocalizedDescription {
+                        name: "puño".to_string(),
+                        plural_name: Some("puños".to_string()),
+                        gender: GrammaticalGender::Masculine,
+                        proper_noun: false,
+                        description: Some("un manojo carnoso de dedos".to_string()),
+                    },
+                )]
+                .into(),
+            ),
             Invisible::to_all(),
-            FistActions {
-                uppercut_messages: WeaponMessages {
-                    miss: vec![MessageFormat::new("${attacker.Name} ${attacker.you:jut/juts} ${weapon.name} upward near where ${target.name} ${target.you:were/was} moments ago.").expect("message format should be valid")],
-                    minor_hit: vec![MessageFormat::new("${attacker.Name} barely ${attacker.you:catch/catches} ${target.name's} ${body_part.plain_name} with an uppercut.").expect("message format should be valid")],
-                    regular_hit: vec![MessageFormat::new("${attacker.Name} ${attacker.you:strike/strikes} ${target.name} in the ${body_part.plain_name} with a solid uppercut.").expect("message format should be valid")],
-                    major_hit: vec![MessageFormat::new("${attacker.Name} ${attacker.you:send/sends} ${weapon.name} flying upward into ${target.name's} ${body_part.plain_name} with a crunch.").expect("message format should be valid")],
-                    self_hit: Vec::new(), // self hits aren't allowed for this attack type
-                },
-                haymaker_messages: WeaponMessages {
-                    miss: vec![MessageFormat::new("${attacker.Name} ${attacker.you:stumble/stumbles} as ${target.name} dodges out of the way of what looks like would have been a painful hit from ${weapon.name}.").expect("message format should be valid")],
-                    minor_hit: vec![MessageFormat::new("${attacker.Name's} haymaker barely catches ${target.name's} ${body_part.plain_name}.").expect("message format should be valid")],
-                    regular_hit: vec![MessageFormat::new("${attacker.Name} ${attacker.you:land/lands} a powerful punch to ${target.name's} ${body_part.plain_name}.").expect("message format should be valid")],
-                    major_hit: vec![MessageFormat::new("${attacker.Name} ${attacker.you:lunge/lunge} forward and ${attacker.you:smash/smashes} ${weapon.name} into ${target.name's} ${body_part.plain_name} with a sickening crunch.").expect("message format should be valid")],
-                    self_hit: Vec::new(), // self hits aren't allowed for this attack type
-                },
-            },
+            build_fist_actions(world),
         ))
         .id();
     FistActions::register_custom_input_parser(entity, world);
     move_entity(weapon, entity, world);
 }
 
+/// Builds the messages for attacks with fists.
+fn build_fist_messages(world: &World) -> WeaponMessages {
+    WeaponMessages::from_templates(
+        &FIST_MISS,
+        &FIST_MINOR_HIT,
+        &FIST_REGULAR_HIT,
+        &FIST_MAJOR_HIT,
+        Some(&FIST_SELF_HIT),
+        world,
+    )
+}
+
+/// Builds the special actions for fists, with their messages.
+fn build_fist_actions(world: &World) -> FistActions {
+    FistActions {
+        // self hits aren't allowed for these attack types
+        uppercut_messages: WeaponMessages::from_templates(
+            &UPPERCUT_MISS,
+            &UPPERCUT_MINOR_HIT,
+            &UPPERCUT_REGULAR_HIT,
+            &UPPERCUT_MAJOR_HIT,
+            None,
+            world,
+        ),
+        haymaker_messages: WeaponMessages::from_templates(
+            &HAYMAKER_MISS,
+            &HAYMAKER_MINOR_HIT,
+            &HAYMAKER_REGULAR_HIT,
+            &HAYMAKER_MAJOR_HIT,
+            None,
+            world,
+        ),
+    }
+}
+
+/// Replaces the messages of every human innate weapon (fist) with ones built from the current message templates.
+pub(crate) fn refresh_human_innate_weapon_messages(world: &mut World) {
+    let fist_messages = build_fist_messages(world);
+    let fist_actions = build_fist_actions(world);
+    let mut fists = world.query_filtered::<(&mut Weapon, &mut FistActions), With<InnateWeapon>>();
+    for (mut weapon, mut actions) in fists.iter_mut(world) {
+        weapon.default_attack_messages = fist_messages.clone();
+        *actions = fist_actions.clone();
+    }
+}
+
 /// Multiplier applied to damage done to the head.
 const HEAD_DAMAGE_MULT: BodyPartDamageMultiplier = BodyPartDamageMultiplier(1.2);
 
@@ -682,6 +890,23 @@ fn spawn_body_part_entity<T: Into<String>>(
     world: &mut World,
 ) -> Entity {
     let name_with_article = BodyPartTypeNameCatalog::get_name(&part_type, world);
+    let localized_descriptions = Locale::iter()
+        .filter(|locale| *locale != Locale::default())
+        .filter_map(|locale| {
+            BodyPartTypeNameCatalog::get_localized_name(&part_type, locale, world).map(|name| {
+                (
+                    locale,
+                    LocalizedDescription {
+                        gender: name.article.get_gender(),
+                        name: name.name,
+                        plural_name: None,
+                        proper_noun: false,
+                        description: None,
+                    },
+                )
+            })
+        })
+        .collect();
     world
         .spawn((
             BodyPart {
@@ -698,30 +923,75 @@ fn spawn_body_part_entity<T: Into<String>>(
                 description: description.into(),
                 attribute_describers: Vec::new(),
             },
+            LocalizedDescriptions(localized_descriptions),
             damage_multiplier,
         ))
         .id()
 }
 
-/// Despawns the player with the provided ID.
-fn despawn_player(player_id: PlayerId, world: &mut World) {
+/// Detaches the player with the provided ID from their entity, leaving the entity in the world so they can be reattached to it if they reconnect.
+fn detach_player(player_id: PlayerId, world: &mut World) {
     if let Some(entity) = find_entity_for_player(player_id, world) {
-        DynamicMessage::new_third_person(
-            MessageCategory::Surroundings(SurroundingsMessageCategory::Movement),
+        world
+            .resource_mut::<PlayerIdMapping>()
+            .remove_entity(entity);
+
+        if let Some(mut player) = world.get_mut::<Player>(entity) {
+            player.detach();
+            let identity = player.identity.clone();
+            world
+                .resource_mut::<PlayerIdMapping>()
+                .linkdead
+                .insert(identity, entity);
+        }
+
+        ActionQueue::clear(world, entity);
+
+        DynamicMessage::new_third_person_from_template(
+            MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
             MessageDelay::Short,
-            MessageFormat::new("${player.Name} disappears.")
-                .expect("message format should be valid"),
-            BasicTokens::new().with_entity("player".into(), entity),
+            &PLAYER_DETACHED,
+            PlayerMessageTokens { player: entity },
+            world,
         )
         .send(Some(entity), DynamicMessageLocation::SourceEntity, world);
 
-        despawn_entity(entity, world);
-
         // that player might have been the only one without a queued action, so try performing actions
         try_perform_queued_actions(world);
     }
 }
 
+/// Parses the world definition files in `GameOptions::world_dir` (or the built-in ones if that isn't set), and the ones from any mods in `GameOptions::mods_dir`.
+///
+/// Logs which mods were loaded, and anything they override.
+fn load_world_definition(
+    game_options: &GameOptions,
+) -> Result<WorldDefinition, WorldDefinitionError> {
+    let mut world_definition = match &game_options.world_dir {
+        Some(dir) => WorldDefinition::from_dir(dir)?,
+        None => WorldDefinition::built_in()?,
+    };
+
+    if let Some(mods_dir) = &game_options.mods_dir {
+        world_definition =
+            world_definition.with_mods(mods_dir, game_options.active_mods.as_deref())?;
+
+        for manifest in world_definition.get_mods() {
+            info!(
+                "Loaded mod {} version {} ({})",
+                manifest.name.as_ref().unwrap_or(&manifest.id),
+                manifest.version,
+                manifest.id
+            );
+        }
+        for conflict in world_definition.find_conflicts() {
+            warn!("Mod conflict: {conflict}");
+        }
+    }
+
+    Ok(world_definition)
+}
+
 /// Finds the ID of the spawn room in the provided world.
 fn find_spawn_room(world: &World) -> Entity {
     let spawn_room_coords = &world.resource::<SpawnRoom>().0;
@@ -920,12 +1190,12 @@ fn kill_entity(entity: Entity, world: &mut World) {
         },
     );
 
-    DynamicMessage::new_third_person(
+    DynamicMessage::new_third_person_from_template(
         MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
         MessageDelay::Short,
-        MessageFormat::new("${player.Name} falls to the ground, dead.")
-            .expect("message format should be valid"),
-        BasicTokens::new().with_entity("player".into(), entity),
+        &PLAYER_DIED,
+        PlayerMessageTokens { player: entity },
+        world,
     )
     .send(Some(entity), DynamicMessageLocation::SourceEntity, world);
 
@@ -933,10 +1203,16 @@ fn kill_entity(entity: Entity, world: &mut World) {
 
     Notification::send_no_contents(DeathNotification { entity }, world);
 
-    let name = world
+    // keep track of who the entity was, in case they need to be respawned
+    let character = world
         .get::<Description>(entity)
-        .map(|d| d.name.clone())
-        .unwrap_or_default();
+        .map(|d| NewCharacter {
+            name: d.name.clone(),
+            pronouns: d.pronouns.clone(),
+            description: d.description.clone(),
+            stat_allocation: StatAllocation::default(),
+        })
+        .unwrap_or_else(|| NewCharacter::new(String::new()));
 
     let mut entity_ref = world.entity_mut(entity);
     entity_ref.remove::<Vitals>();
@@ -966,7 +1242,7 @@ fn kill_entity(entity: Entity, world: &mut World) {
     }
 
     if let Some(player) = world.entity_mut(entity).take::<Player>() {
-        let new_entity = spawn_player(name, player, find_afterlife_room(world), world);
+        let new_entity = spawn_player(character, player, find_afterlife_room(world), world);
 
         // copy stats to new entity
         if let Some(stats) = world.entity_mut(entity).take::<Stats>() {
@@ -1016,6 +1292,11 @@ fn despawn_entity(entity: Entity, world: &mut World) {
         }
     }
 
+    // forget about any player that was controlling the entity
+    if let Some(mut mapping) = world.get_resource_mut::<PlayerIdMapping>() {
+        mapping.remove_entity(entity);
+    }
+
     // send despawn notification
     Notification::send_no_contents(DespawnNotification { entity }, world);
 
@@ -0,0 +1,244 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
+
+use crate::{component::Player, Pronouns};
+
+/// A language the game can be presented to players in.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Default,
+    Serialize,
+    Deserialize,
+    EnumIter,
+)]
+pub enum Locale {
+    /// English, which everything is written in first.
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    /// Spanish.
+    #[serde(rename = "es")]
+    Spanish,
+}
+
+/// The grammatical gender of a noun, for languages where articles and pronouns depend on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GrammaticalGender {
+    #[default]
+    Masculine,
+    Feminine,
+}
+
+impl Locale {
+    /// The code players and content files use to refer to this locale, like "en".
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::Spanish => "es",
+        }
+    }
+
+    /// Finds the locale with the provided code.
+    pub fn from_code(code: &str) -> Option<Locale> {
+        Locale::iter().find(|locale| locale.code() == code)
+    }
+
+    /// Gets the locale messages to the provided entity should be in.
+    ///
+    /// Entities that aren't players, or no entity at all, get the default locale.
+    pub fn of(entity: Option<Entity>, world: &World) -> Locale {
+        entity
+            .and_then(|entity| world.get::<Player>(entity))
+            .map(|player| player.preferences.locale)
+            .unwrap_or_default()
+    }
+
+    /// Gets the word for "you" in this locale, or "your" if `possessive` is true.
+    pub fn you(self, possessive: bool) -> &'static str {
+        match (self, possessive) {
+            (Locale::English, false) => "you",
+            (Locale::English, true) => "your",
+            (Locale::Spanish, false) => "tú",
+            (Locale::Spanish, true) => "tu",
+        }
+    }
+
+    /// Builds the possessive form of the provided name, like "the man's".
+    ///
+    /// In Spanish this is "de" followed by the name, so it should come after the thing that's possessed.
+    pub fn possessive(self, name: &str) -> String {
+        match self {
+            Locale::English => {
+                if name == "it" {
+                    "its".to_string()
+                } else {
+                    format!("{name}'s")
+                }
+            }
+            Locale::Spanish => match name.strip_prefix("el ") {
+                Some(rest) => format!("del {rest}"),
+                None => format!("de {name}"),
+            },
+        }
+    }
+
+    /// Applies the contractions this locale requires to the provided text, like "a el" to "al" in Spanish.
+    ///
+    /// Messages are built up from names and pronouns that are interpolated separately, so these can't be written into them directly.
+    pub fn contract(self, text: &str) -> String {
+        match self {
+            Locale::English => text.to_string(),
+            Locale::Spanish => {
+                let mut words = Vec::new();
+                let mut remaining = text.split(' ').peekable();
+                while let Some(word) = remaining.next() {
+                    let preposition = word.to_lowercase();
+                    if preposition != "a" && preposition != "de" && preposition != "con" {
+                        words.push(word.to_string());
+                        continue;
+                    }
+                    let Some(next_word) = remaining.peek() else {
+                        words.push(word.to_string());
+                        continue;
+                    };
+
+                    if preposition != "con" && *next_word == "el" {
+                        // "a el" to "al", "de el" to "del"
+                        words.push(format!("{word}l"));
+                        remaining.next();
+                    } else if let Some(rest) = next_word.strip_prefix("tú") {
+                        if preposition == "con" {
+                            words.push(format!("{word}tigo{rest}"));
+                        } else {
+                            words.push(word.to_string());
+                            words.push(format!("ti{rest}"));
+                        }
+                        remaining.next();
+                    } else {
+                        words.push(word.to_string());
+                    }
+                }
+
+                words.join(" ")
+            }
+        }
+    }
+
+    /// Gets the indefinite article for a noun with the provided gender, like "a".
+    ///
+    /// English articles depend on how the noun sounds rather than its gender, so things in English should have their own article set instead.
+    pub fn indefinite_article(self, gender: GrammaticalGender) -> &'static str {
+        match (self, gender) {
+            (Locale::English, _) => "a",
+            (Locale::Spanish, GrammaticalGender::Masculine) => "un",
+            (Locale::Spanish, GrammaticalGender::Feminine) => "una",
+        }
+    }
+
+    /// Gets the definite article for a noun with the provided gender, like "the".
+    pub fn definite_article(self, gender: GrammaticalGender, plural: bool) -> &'static str {
+        match (self, gender, plural) {
+            (Locale::English, _, _) => "the",
+            (Locale::Spanish, GrammaticalGender::Masculine, false) => "el",
+            (Locale::Spanish, GrammaticalGender::Feminine, false) => "la",
+            (Locale::Spanish, GrammaticalGender::Masculine, true) => "los",
+            (Locale::Spanish, GrammaticalGender::Feminine, true) => "las",
+        }
+    }
+
+    /// Gets the pronouns to use for the entity messages are being written to.
+    pub fn second_person_pronouns(self) -> Pronouns {
+        match self {
+            Locale::English => Pronouns::you(),
+            Locale::Spanish => Pronouns::new("tú", "te", "tuyo", "tu", "ti mismo", true),
+        }
+    }
+
+    /// Gets the pronouns to use for something with the provided gender and number, for locales where pronouns are determined by them.
+    ///
+    /// Returns `None` for English, where pronouns are chosen for each thing individually.
+    pub fn gendered_pronouns(self, gender: GrammaticalGender, plural: bool) -> Option<Pronouns> {
+        match (self, gender, plural) {
+            (Locale::English, _, _) => None,
+            (Locale::Spanish, GrammaticalGender::Masculine, false) => {
+                Some(Pronouns::new("él", "lo", "suyo", "su", "sí mismo", false))
+            }
+            (Locale::Spanish, GrammaticalGender::Feminine, false) => {
+                Some(Pronouns::new("ella", "la", "suya", "su", "sí misma", false))
+            }
+            (Locale::Spanish, GrammaticalGender::Masculine, true) => Some(Pronouns::new(
+                "ellos",
+                "los",
+                "suyos",
+                "sus",
+                "sí mismos",
+                true,
+            )),
+            (Locale::Spanish, GrammaticalGender::Feminine, true) => Some(Pronouns::new(
+                "ellas",
+                "las",
+                "suyas",
+                "sus",
+                "sí mismas",
+                true,
+            )),
+        }
+    }
+
+    /// Gets the pronouns to use for something without a description.
+    pub fn default_pronouns(self, is_living: bool) -> Pronouns {
+        match (self, is_living) {
+            (Locale::English, true) => Pronouns::they(),
+            (Locale::English, false) => Pronouns::it(),
+            (Locale::Spanish, true) => Pronouns::new("él", "lo", "suyo", "su", "sí mismo", false),
+            (Locale::Spanish, false) => Pronouns::new("eso", "lo", "suyo", "su", "sí mismo", false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn possessive() {
+        assert_eq!("the man's", Locale::English.possessive("the man"));
+        assert_eq!("its", Locale::English.possessive("it"));
+        assert_eq!("de la mujer", Locale::Spanish.possessive("la mujer"));
+        assert_eq!("del hombre", Locale::Spanish.possessive("el hombre"));
+    }
+
+    #[test]
+    fn contract() {
+        assert_eq!(
+            "Golpeas al hombre con el puño.",
+            Locale::Spanish.contract("Golpeas a el hombre con el puño.")
+        );
+        assert_eq!(
+            "Pasa junto a ti, lejos del muro.",
+            Locale::Spanish.contract("Pasa junto a tú, lejos de el muro.")
+        );
+        assert_eq!("Habla contigo.", Locale::Spanish.contract("Habla con tú."));
+        assert_eq!(
+            "Tú ves a el hombre.",
+            Locale::English.contract("Tú ves a el hombre.")
+        );
+    }
+
+    #[test]
+    fn codes_round_trip() {
+        for locale in Locale::iter() {
+            assert_eq!(Some(locale), Locale::from_code(locale.code()));
+        }
+        assert_eq!(None, Locale::from_code("xx"));
+    }
+}
@@ -0,0 +1,25 @@
+//! (De)serializes maps as lists of key-value pairs, for maps with keys that can't be represented as strings (like enums with data).
+//!
+//! Use with `#[serde(with = "crate::map_as_pairs")]`.
+
+use std::{collections::HashMap, hash::Hash};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize<K: Serialize, V: Serialize, S: Serializer>(
+    map: &HashMap<K, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(map.iter())
+}
+
+pub fn deserialize<
+    'de,
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+>(
+    deserializer: D,
+) -> Result<HashMap<K, V>, D::Error> {
+    Vec::<(K, V)>::deserialize(deserializer).map(|pairs| pairs.into_iter().collect())
+}
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, marker::PhantomData};
+use std::{collections::HashMap, fmt::Display, marker::PhantomData};
 
 use bevy_ecs::prelude::*;
 use nom::{
@@ -8,13 +8,20 @@ use nom::{
     sequence::{delimited, separated_pair, tuple},
     IResult,
 };
+use serde::{Deserialize, Serialize};
 use voca_rs::Voca;
 
-use crate::{Description, Pronouns};
+use crate::{Description, Locale, Pronouns};
 
 /// A message with places for interpolated values, such as entity names.
 #[derive(Clone, Debug)]
-pub struct MessageFormat<T: MessageTokens>(Vec<MessageFormatChunk>, PhantomData<fn(T)>);
+pub struct MessageFormat<T: MessageTokens> {
+    /// The parsed chunks of the format string.
+    chunks: Vec<MessageFormatChunk>,
+    /// The format string the chunks were parsed from.
+    format_string: String,
+    _t: PhantomData<fn(T)>,
+}
 
 /// The name of a token
 #[derive(Debug, Eq, Hash, PartialEq, Clone)]
@@ -32,6 +39,12 @@ impl From<String> for TokenName {
     }
 }
 
+impl Display for TokenName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
 /// The value to use when interpolating a token
 #[derive(Debug, Eq, Hash, PartialEq, Clone)]
 pub enum TokenValue {
@@ -39,12 +52,25 @@ pub enum TokenValue {
     Entity(Entity),
 }
 
+/// The kind of value a token has.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum TokenKind {
+    String,
+    Entity,
+}
+
 /// Trait for providing tokens for message interpolation.
 pub trait MessageTokens {
     /// Returns a map of token names to what to use to fill in the interpolated values.
     fn get_token_map(&self) -> HashMap<TokenName, TokenValue>;
 }
 
+/// Trait for tokens that always provide the same set of token names, so message formats can be checked against them before they're used.
+pub trait FixedMessageTokens: MessageTokens {
+    /// Returns a map of the names of the provided tokens to the kind of value each one has.
+    fn get_token_kinds() -> HashMap<TokenName, TokenKind>;
+}
+
 /// A generic set of tokens, for use with one-time message formats that don't need fancy token type safety.
 #[derive(Debug)]
 pub struct BasicTokens(HashMap<TokenName, TokenValue>);
@@ -79,7 +105,7 @@ impl BasicTokens {
 }
 
 /// No tokens at all.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct NoTokens;
 
 impl MessageTokens for NoTokens {
@@ -97,6 +123,42 @@ pub enum InterpolationError {
     InvalidTokenValue(TokenName, TokenValue),
 }
 
+/// A problem with the tokens in a message format, found before interpolating it.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TokenCheckError {
+    /// A token in the format string isn't one of the available tokens.
+    UnknownToken(TokenName),
+    /// A token in the format string is used as a different kind of value than the one it has.
+    WrongKind {
+        /// The name of the token.
+        name: TokenName,
+        /// The kind of value the token has.
+        kind: TokenKind,
+    },
+}
+
+impl Display for TokenCheckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenCheckError::UnknownToken(name) => write!(f, "unknown token \"{name}\""),
+            TokenCheckError::WrongKind {
+                name,
+                kind: TokenKind::String,
+            } => write!(
+                f,
+                "token \"{name}\" is text, so it has to be used like ${{{name}}}"
+            ),
+            TokenCheckError::WrongKind {
+                name,
+                kind: TokenKind::Entity,
+            } => write!(
+                f,
+                "token \"{name}\" is an entity, so it has to be used like ${{{name}.name}}"
+            ),
+        }
+    }
+}
+
 impl<T: MessageTokens> MessageFormat<T> {
     /// Creates a `MessageFormat` with the provided format string.
     ///
@@ -106,6 +168,7 @@ impl<T: MessageTokens> MessageFormat<T> {
     ///   * `name`: the entity's name, including an article like "your" or "the"
     ///   * `plain_name`: just the entity's name, with no article
     ///   * `name's`: the possessive version of the entity's name, which will be either "your", or the entity's name followed by "'s", depending on if the entity is the POV entity, including an article like "your" or "the"
+    ///     (in Spanish this is "de" followed by the name, which goes after the thing that's possessed)
     ///   * `plain_name's`: the possessive version of the entity's name, as above, but with no article
     ///   * `they`: the entity's personal subject pronoun
     ///   * `them`: the entity's personal object pronoun
@@ -126,13 +189,57 @@ impl<T: MessageTokens> MessageFormat<T> {
     /// An example format string: `${attacker.name} throws ${object.name}, but ${target.name} ${target.you:move/moves} out of the way just before ${object.they} ${object.hit/hits} ${target.them}.`
     /// This format string might produce the following result from `interpolate`: "Bob throws the rock, but Fred moves out of the way just before it hits him."
     pub fn new(format_string: &str) -> Result<MessageFormat<T>, ParseError<'_>> {
-        Ok(MessageFormat(
-            MessageFormatChunk::parse(format_string)?,
-            PhantomData,
-        ))
+        Ok(MessageFormat {
+            chunks: MessageFormatChunk::parse(format_string)?,
+            format_string: format_string.to_string(),
+            _t: PhantomData,
+        })
+    }
+
+    /// Gets the format string this `MessageFormat` was created from.
+    pub fn as_format_string(&self) -> &str {
+        &self.format_string
+    }
+
+    /// Checks that every token in this format is one of the provided tokens, and is used as the right kind of value.
+    pub fn check_tokens(
+        &self,
+        token_kinds: &HashMap<TokenName, TokenKind>,
+    ) -> Result<(), TokenCheckError> {
+        for chunk in &self.chunks {
+            let (name, expected_kind) = match chunk {
+                MessageFormatChunk::String(_) => continue,
+                MessageFormatChunk::PlainToken { name, .. } => (name, TokenKind::String),
+                MessageFormatChunk::Token { name, .. } => (name, TokenKind::Entity),
+            };
+
+            match token_kinds.get(name) {
+                Some(kind) if *kind == expected_kind => (),
+                Some(kind) => {
+                    return Err(TokenCheckError::WrongKind {
+                        name: name.clone(),
+                        kind: *kind,
+                    })
+                }
+                None => return Err(TokenCheckError::UnknownToken(name.clone())),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Converts this format into one for a different type of tokens, without checking that the tokens it uses are provided by them.
+    pub(crate) fn with_token_type<U: MessageTokens>(self) -> MessageFormat<U> {
+        MessageFormat {
+            chunks: self.chunks,
+            format_string: self.format_string,
+            _t: PhantomData,
+        }
     }
 
     /// Produces an interpolated string to display to `pov_entity` using the provided tokens, or `Err` if the interpolation failed.
+    ///
+    /// Any contractions needed in `pov_entity`'s locale (like "a el" to "al" in Spanish) are applied to the result.
     pub fn interpolate(
         &self,
         pov_entity: Entity,
@@ -140,10 +247,25 @@ impl<T: MessageTokens> MessageFormat<T> {
         world: &World,
     ) -> Result<String, InterpolationError> {
         let mut interpolated_chunks = Vec::new();
-        for chunk in &self.0 {
+        for chunk in &self.chunks {
             interpolated_chunks.push(chunk.interpolate(pov_entity, tokens, world)?);
         }
-        Ok(interpolated_chunks.join(""))
+        Ok(Locale::of(Some(pov_entity), world).contract(&interpolated_chunks.join("")))
+    }
+}
+
+impl<T: MessageTokens> Serialize for MessageFormat<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.format_string)
+    }
+}
+
+impl<'de, T: MessageTokens> Deserialize<'de> for MessageFormat<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let format_string = String::deserialize(deserializer)?;
+        MessageFormat::new(&format_string).map_err(|e| {
+            serde::de::Error::custom(format!("invalid message format {format_string:?}: {e:?}"))
+        })
     }
 }
 
@@ -410,26 +532,19 @@ impl TokenType {
                 with_article,
                 possessive,
             } => {
+                let locale = Locale::of(Some(pov_entity), world);
                 if entity == pov_entity {
-                    if *possessive {
-                        "your".to_string()
-                    } else {
-                        "you".to_string()
-                    }
+                    locale.you(*possessive).to_string()
                 } else {
                     let name = if *with_article {
                         Description::get_reference_name(entity, Some(pov_entity), world)
                     } else {
-                        Description::get_name(entity, world).unwrap_or_else(|| "???".to_string())
+                        Description::get_localized_name(entity, Some(pov_entity), world)
+                            .unwrap_or_else(|| "???".to_string())
                     };
 
                     if *possessive {
-                        // english, amirite
-                        if name == "it" {
-                            "its".to_string()
-                        } else {
-                            format!("{name}'s")
-                        }
+                        locale.possessive(&name)
                     } else {
                         name
                     }
@@ -472,6 +587,11 @@ impl TokenType {
 
 #[cfg(test)]
 mod tests {
+    use crate::{
+        component::{LocalizedDescription, LocalizedDescriptions, Player, PlayerId},
+        GrammaticalGender,
+    };
+
     use super::*;
 
     #[allow(unused)]
@@ -1454,4 +1574,102 @@ mod tests {
             Err(ParseError::InternalParserError(_))
         ));
     }
+
+    #[test]
+    fn check_tokens() {
+        let token_kinds = HashMap::from([
+            ("entity".into(), TokenKind::Entity),
+            ("a_string".into(), TokenKind::String),
+        ]);
+
+        assert_eq!(
+            Ok(()),
+            MessageFormat::<BasicTokens>::new("${entity.Name} ${entity.are/is} ${a_string}.")
+                .unwrap()
+                .check_tokens(&token_kinds)
+        );
+        assert_eq!(
+            Err(TokenCheckError::UnknownToken("other".into())),
+            MessageFormat::<BasicTokens>::new("${entity.Name} and ${other.name}")
+                .unwrap()
+                .check_tokens(&token_kinds)
+        );
+        assert_eq!(
+            Err(TokenCheckError::WrongKind {
+                name: "a_string".into(),
+                kind: TokenKind::String
+            }),
+            MessageFormat::<BasicTokens>::new("${a_string.name}")
+                .unwrap()
+                .check_tokens(&token_kinds)
+        );
+        assert_eq!(
+            Err(TokenCheckError::WrongKind {
+                name: "entity".into(),
+                kind: TokenKind::Entity
+            }),
+            MessageFormat::<BasicTokens>::new("${entity}")
+                .unwrap()
+                .check_tokens(&token_kinds)
+        );
+    }
+
+    #[test]
+    fn interpolate_in_other_locale() {
+        let mut world = World::new();
+        let mut player = Player::new(PlayerId(0), "player".to_string(), flume::unbounded().0);
+        player.preferences.locale = Locale::Spanish;
+        let pov_entity = world.spawn(player).id();
+        let man = world
+            .spawn((
+                Description {
+                    name: "man".to_string(),
+                    room_name: "man".to_string(),
+                    plural_name: "men".to_string(),
+                    article: Some("a".to_string()),
+                    pronouns: Pronouns::he(),
+                    aliases: vec![],
+                    description: "It's a man.".to_string(),
+                    attribute_describers: vec![],
+                },
+                LocalizedDescriptions(
+                    [(
+                        Locale::Spanish,
+                        LocalizedDescription {
+                            name: "hombre".to_string(),
+                            plural_name: None,
+                            gender: GrammaticalGender::Masculine,
+                            proper_noun: false,
+                            description: None,
+                        },
+                    )]
+                    .into(),
+                ),
+            ))
+            .id();
+        let format = MessageFormat::new(
+            "${attacker.Name} ${target.you:te /}${attacker.you:golpeas/golpea} a ${target.name}, y ${attacker.they} ${attacker.you:sonríes/sonríe}.",
+        )
+        .unwrap();
+
+        let player_attacks = BasicTokens::new()
+            .with_entity("attacker".into(), pov_entity)
+            .with_entity("target".into(), man);
+        assert_eq!(
+            "Tú golpeas al hombre, y tú sonríes.",
+            format
+                .interpolate(pov_entity, &player_attacks, &world)
+                .unwrap()
+        );
+
+        let man_attacks = BasicTokens::new()
+            .with_entity("attacker".into(), man)
+            .with_entity("target".into(), pov_entity);
+        assert_eq!(
+            "El hombre te golpea a ti, y él sonríe.",
+            format
+                .interpolate(pov_entity, &man_attacks, &world)
+                .unwrap()
+        );
+    }
 }
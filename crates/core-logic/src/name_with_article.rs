@@ -1,7 +1,12 @@
 use std::fmt::Display;
 
+use serde::Deserialize;
+
+use crate::GrammaticalGender;
+
 /// The name of something including the article to use with it.
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct NameWithArticle {
     /// The indefinite article
     pub article: IndefiniteArticle,
@@ -25,6 +30,22 @@ impl NameWithArticle {
             name: name.into(),
         }
     }
+
+    /// Creates a `NameWithArticle` with the Spanish article "un", for masculine names
+    pub fn un<T: Into<String>>(name: T) -> NameWithArticle {
+        NameWithArticle {
+            article: IndefiniteArticle::Un,
+            name: name.into(),
+        }
+    }
+
+    /// Creates a `NameWithArticle` with the Spanish article "una", for feminine names
+    pub fn una<T: Into<String>>(name: T) -> NameWithArticle {
+        NameWithArticle {
+            article: IndefiniteArticle::Una,
+            name: name.into(),
+        }
+    }
 }
 
 impl Display for NameWithArticle {
@@ -33,10 +54,27 @@ impl Display for NameWithArticle {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum IndefiniteArticle {
     A,
     An,
+    /// Spanish, for masculine names
+    Un,
+    /// Spanish, for feminine names
+    Una,
+}
+
+impl IndefiniteArticle {
+    /// Gets the grammatical gender of names that use this article. English articles don't have one, so they count as masculine.
+    pub fn get_gender(self) -> GrammaticalGender {
+        match self {
+            IndefiniteArticle::Una => GrammaticalGender::Feminine,
+            IndefiniteArticle::A | IndefiniteArticle::An | IndefiniteArticle::Un => {
+                GrammaticalGender::Masculine
+            }
+        }
+    }
 }
 
 impl Display for IndefiniteArticle {
@@ -44,6 +82,8 @@ impl Display for IndefiniteArticle {
         let string = match self {
             IndefiniteArticle::A => "a",
             IndefiniteArticle::An => "an",
+            IndefiniteArticle::Un => "un",
+            IndefiniteArticle::Una => "una",
         };
 
         string.fmt(f)
@@ -0,0 +1,352 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
+
+use crate::{GameMessage, Locale, MessageCategory, MessageDelay, SurroundingsMessageCategory};
+
+/// The smallest line width players can choose.
+pub const MIN_LINE_WIDTH: usize = 40;
+
+/// The largest line width players can choose.
+pub const MAX_LINE_WIDTH: usize = 250;
+
+/// The settings a player has chosen for how the game is presented to them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerPreferences {
+    /// Whether output should include colors and other styling.
+    pub color: bool,
+    /// Whether output should be laid out to be read by a screen reader.
+    pub screen_reader: bool,
+    /// The width to wrap output to, in characters. `None` if the width reported by the player's client should be used.
+    pub line_width: Option<usize>,
+    /// How much of the map to show.
+    pub map_size: MapSize,
+    /// Whether to pause after messages that are meant to be followed by a delay.
+    pub message_delays: bool,
+    /// The categories of messages from the player's surroundings that shouldn't be sent to them.
+    pub hidden_message_categories: Vec<SurroundingsMessageCategory>,
+    /// The language messages should be in, where they've been translated.
+    pub locale: Locale,
+}
+
+impl Default for PlayerPreferences {
+    fn default() -> Self {
+        PlayerPreferences {
+            color: true,
+            screen_reader: false,
+            line_width: None,
+            map_size: MapSize::default(),
+            message_delays: true,
+            hidden_message_categories: Vec::new(),
+            locale: Locale::default(),
+        }
+    }
+}
+
+/// How much of the map a player wants to see.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, EnumIter)]
+pub enum MapSize {
+    /// Only the rooms right next to the player.
+    Small,
+    /// Rooms up to 2 tiles away from the player.
+    #[default]
+    Medium,
+    /// Rooms up to 3 tiles away from the player.
+    Large,
+}
+
+impl MapSize {
+    /// The number of tiles across a map of this size is.
+    pub fn tiles(self) -> usize {
+        match self {
+            MapSize::Small => 3,
+            MapSize::Medium => 5,
+            MapSize::Large => 7,
+        }
+    }
+
+    /// The name players use to refer to this size.
+    fn name(self) -> &'static str {
+        match self {
+            MapSize::Small => "small",
+            MapSize::Medium => "medium",
+            MapSize::Large => "large",
+        }
+    }
+}
+
+/// An error encountered while changing a preference.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PreferenceError {
+    /// There is no preference with the provided name.
+    UnknownSetting(String),
+    /// The provided value isn't valid for the setting. Contains a description of the valid values.
+    InvalidValue { setting: String, expected: String },
+}
+
+impl Display for PreferenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PreferenceError::UnknownSetting(setting) => {
+                write!(f, "There's no setting called {setting}.")
+            }
+            PreferenceError::InvalidValue { setting, expected } => {
+                write!(f, "The {setting} setting must be {expected}.")
+            }
+        }
+    }
+}
+
+impl PlayerPreferences {
+    /// Changes the setting with the provided name to the provided value.
+    pub fn set(&mut self, setting: &str, value: &str) -> Result<(), PreferenceError> {
+        let value = value.trim().to_lowercase();
+        match setting.trim().to_lowercase().as_str() {
+            "color" | "colour" => self.color = parse_on_off("color", &value)?,
+            "screenreader" => self.screen_reader = parse_on_off("screenreader", &value)?,
+            "width" => self.line_width = parse_line_width(&value)?,
+            "map" => {
+                self.map_size = MapSize::iter()
+                    .find(|size| size.name() == value)
+                    .ok_or_else(|| PreferenceError::InvalidValue {
+                        setting: "map".to_string(),
+                        expected: "small, medium, or large".to_string(),
+                    })?
+            }
+            "delays" => self.message_delays = parse_on_off("delays", &value)?,
+            "messages" => self.set_message_category_shown(&value)?,
+            "language" => {
+                self.locale =
+                    Locale::from_code(&value).ok_or_else(|| PreferenceError::InvalidValue {
+                        setting: "language".to_string(),
+                        expected: format!(
+                            "one of {}",
+                            Locale::iter()
+                                .map(Locale::code)
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    })?
+            }
+            x => return Err(PreferenceError::UnknownSetting(x.to_string())),
+        }
+
+        Ok(())
+    }
+
+    /// Handles a value like `speech off` for the `messages` setting.
+    fn set_message_category_shown(&mut self, value: &str) -> Result<(), PreferenceError> {
+        let invalid_value = || PreferenceError::InvalidValue {
+            setting: "messages".to_string(),
+            expected: format!(
+                "one of {} followed by on or off",
+                SurroundingsMessageCategory::iter()
+                    .map(category_name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+
+        let (category_name_to_find, shown) = value.rsplit_once(' ').ok_or_else(invalid_value)?;
+        let category = SurroundingsMessageCategory::iter()
+            .find(|category| category_name(*category) == category_name_to_find.trim())
+            .ok_or_else(invalid_value)?;
+        let shown = parse_on_off("messages", shown).map_err(|_| invalid_value())?;
+
+        self.hidden_message_categories.retain(|c| *c != category);
+        if !shown {
+            self.hidden_message_categories.push(category);
+        }
+
+        Ok(())
+    }
+
+    /// Describes each setting, as pairs of the input used to change it and its current value.
+    pub fn describe(&self) -> Vec<(String, String)> {
+        let mut settings = vec![
+            ("color".to_string(), on_off(self.color)),
+            ("screenreader".to_string(), on_off(self.screen_reader)),
+            (
+                "width".to_string(),
+                self.line_width
+                    .map(|width| width.to_string())
+                    .unwrap_or_else(|| "auto".to_string()),
+            ),
+            ("map".to_string(), self.map_size.name().to_string()),
+            ("delays".to_string(), on_off(self.message_delays)),
+            ("language".to_string(), self.locale.code().to_string()),
+        ];
+
+        for category in SurroundingsMessageCategory::iter() {
+            settings.push((
+                format!("messages {}", category_name(category)),
+                on_off(!self.hidden_message_categories.contains(&category)),
+            ));
+        }
+
+        settings
+    }
+
+    /// Returns `false` if the player doesn't want to be sent the provided message, `true` otherwise.
+    pub fn accept(&self, message: &GameMessage) -> bool {
+        match message {
+            GameMessage::Message {
+                category: MessageCategory::Surroundings(category),
+                ..
+            } => !self.hidden_message_categories.contains(category),
+            _ => true,
+        }
+    }
+
+    /// Adjusts the provided message to match these preferences.
+    pub fn apply(&self, message: GameMessage) -> GameMessage {
+        match message {
+            GameMessage::Message {
+                content,
+                category,
+                delay: _,
+                decorations,
+            } if !self.message_delays => GameMessage::Message {
+                content,
+                category,
+                delay: MessageDelay::None,
+                decorations,
+            },
+            message => message,
+        }
+    }
+}
+
+/// The name players use to refer to the provided message category.
+fn category_name(category: SurroundingsMessageCategory) -> &'static str {
+    match category {
+        SurroundingsMessageCategory::Speech => "speech",
+        SurroundingsMessageCategory::Sound => "sound",
+        SurroundingsMessageCategory::Flavor => "flavor",
+        SurroundingsMessageCategory::Movement => "movement",
+        SurroundingsMessageCategory::Action => "action",
+    }
+}
+
+/// Parses an on or off value for the setting with the provided name.
+fn parse_on_off(setting: &str, value: &str) -> Result<bool, PreferenceError> {
+    match value.trim() {
+        "on" | "yes" | "true" => Ok(true),
+        "off" | "no" | "false" => Ok(false),
+        _ => Err(PreferenceError::InvalidValue {
+            setting: setting.to_string(),
+            expected: "on or off".to_string(),
+        }),
+    }
+}
+
+/// Parses a value for the width setting.
+fn parse_line_width(value: &str) -> Result<Option<usize>, PreferenceError> {
+    if value == "auto" {
+        return Ok(None);
+    }
+
+    match value.parse() {
+        Ok(width) if (MIN_LINE_WIDTH..=MAX_LINE_WIDTH).contains(&width) => Ok(Some(width)),
+        _ => Err(PreferenceError::InvalidValue {
+            setting: "width".to_string(),
+            expected: format!("auto or a number from {MIN_LINE_WIDTH} to {MAX_LINE_WIDTH}"),
+        }),
+    }
+}
+
+/// Describes the provided boolean setting value.
+fn on_off(value: bool) -> String {
+    if value { "on" } else { "off" }.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_valid_values() {
+        let mut preferences = PlayerPreferences::default();
+
+        preferences.set("color", "off").unwrap();
+        preferences.set("width", "120").unwrap();
+        preferences.set("map", "Large").unwrap();
+        preferences.set("messages", "flavor off").unwrap();
+        preferences.set("language", "ES").unwrap();
+
+        assert_eq!(
+            PlayerPreferences {
+                color: false,
+                line_width: Some(120),
+                map_size: MapSize::Large,
+                hidden_message_categories: vec![SurroundingsMessageCategory::Flavor],
+                locale: Locale::Spanish,
+                ..PlayerPreferences::default()
+            },
+            preferences
+        );
+    }
+
+    #[test]
+    fn set_invalid_values() {
+        let mut preferences = PlayerPreferences::default();
+
+        assert_eq!(
+            Err(PreferenceError::UnknownSetting("volume".to_string())),
+            preferences.set("volume", "11")
+        );
+        assert!(preferences.set("width", "10").is_err());
+        assert!(preferences.set("delays", "sometimes").is_err());
+        assert!(preferences.set("messages", "gossip off").is_err());
+        assert!(preferences.set("language", "klingon").is_err());
+        assert_eq!(PlayerPreferences::default(), preferences);
+    }
+
+    #[test]
+    fn show_hidden_message_category_again() {
+        let mut preferences = PlayerPreferences::default();
+
+        preferences.set("messages", "speech off").unwrap();
+        preferences.set("messages", "speech off").unwrap();
+        preferences.set("messages", "speech on").unwrap();
+
+        assert!(preferences.hidden_message_categories.is_empty());
+    }
+
+    #[test]
+    fn hidden_messages_are_not_accepted() {
+        let mut preferences = PlayerPreferences::default();
+        preferences.set("messages", "sound off").unwrap();
+
+        let message = |category| GameMessage::Message {
+            content: "bang".to_string(),
+            category,
+            delay: MessageDelay::Short,
+            decorations: Vec::new(),
+        };
+
+        assert!(!preferences.accept(&message(MessageCategory::Surroundings(
+            SurroundingsMessageCategory::Sound
+        ))));
+        assert!(preferences.accept(&message(MessageCategory::Surroundings(
+            SurroundingsMessageCategory::Speech
+        ))));
+        assert!(preferences.accept(&message(MessageCategory::System)));
+    }
+
+    #[test]
+    fn missing_fields_are_defaulted() {
+        let preferences: PlayerPreferences =
+            serde_json::from_str(r#"{ "screen_reader": true }"#).unwrap();
+
+        assert_eq!(
+            PlayerPreferences {
+                screen_reader: true,
+                ..PlayerPreferences::default()
+            },
+            preferences
+        );
+    }
+}
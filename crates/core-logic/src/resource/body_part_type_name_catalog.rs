@@ -3,13 +3,15 @@ use std::collections::HashMap;
 use bevy_ecs::prelude::*;
 use strum::IntoEnumIterator;
 
-use crate::{body_part::BodyPartType, NameWithArticle};
+use crate::{body_part::BodyPartType, Locale, NameWithArticle};
 
 /// Map of body part types to their display names.
 #[derive(Resource)]
 pub struct BodyPartTypeNameCatalog {
     standard: HashMap<BodyPartType, NameWithArticle>,
     custom: HashMap<String, NameWithArticle>,
+    /// Names in locales other than the default one. Body part types without one use the name in the default locale.
+    localized: HashMap<Locale, HashMap<BodyPartType, NameWithArticle>>,
 }
 
 impl BodyPartTypeNameCatalog {
@@ -19,6 +21,7 @@ impl BodyPartTypeNameCatalog {
         BodyPartTypeNameCatalog {
             standard: standard_names,
             custom: HashMap::new(),
+            localized: build_standard_localized_names(),
         }
     }
 
@@ -30,7 +33,6 @@ impl BodyPartTypeNameCatalog {
     }
 
     /// Sets the name of the provided body part type.
-    #[expect(unused)]
     pub fn set(&mut self, body_part_type: &BodyPartType, name: NameWithArticle) {
         match body_part_type {
             BodyPartType::Custom(id) => self.custom.insert(id.clone(), name),
@@ -38,6 +40,51 @@ impl BodyPartTypeNameCatalog {
         };
     }
 
+    /// Gets the name of the provided body part type in the provided locale, if it has one.
+    pub fn get_localized_name(
+        body_part_type: &BodyPartType,
+        locale: Locale,
+        world: &World,
+    ) -> Option<NameWithArticle> {
+        world
+            .resource::<BodyPartTypeNameCatalog>()
+            .get_localized(body_part_type, locale)
+    }
+
+    /// Sets the name of the provided body part type in the provided locale.
+    pub fn set_localized(
+        &mut self,
+        body_part_type: &BodyPartType,
+        locale: Locale,
+        name: NameWithArticle,
+    ) {
+        if locale == Locale::default() {
+            self.set(body_part_type, name);
+            return;
+        }
+
+        self.localized
+            .entry(locale)
+            .or_default()
+            .insert(body_part_type.clone(), name);
+    }
+
+    /// Determines the name for the provided body part type in the provided locale, if it has one.
+    pub fn get_localized(
+        &self,
+        body_part_type: &BodyPartType,
+        locale: Locale,
+    ) -> Option<NameWithArticle> {
+        if locale == Locale::default() {
+            return Some(self.get(body_part_type));
+        }
+
+        self.localized
+            .get(&locale)
+            .and_then(|names| names.get(body_part_type))
+            .cloned()
+    }
+
     /// Determines the name for the provided body part type.
     pub fn get(&self, body_part_type: &BodyPartType) -> NameWithArticle {
         match body_part_type {
@@ -58,6 +105,39 @@ fn build_standard_names() -> HashMap<BodyPartType, NameWithArticle> {
         .collect()
 }
 
+/// Builds the default display names of standard body part types in locales other than the default one.
+fn build_standard_localized_names() -> HashMap<Locale, HashMap<BodyPartType, NameWithArticle>> {
+    let spanish = [
+        (BodyPartType::Head, NameWithArticle::una("cabeza")),
+        (BodyPartType::Torso, NameWithArticle::un("torso")),
+        (
+            BodyPartType::LeftArm,
+            NameWithArticle::un("brazo izquierdo"),
+        ),
+        (BodyPartType::RightArm, NameWithArticle::un("brazo derecho")),
+        (
+            BodyPartType::LeftHand,
+            NameWithArticle::una("mano izquierda"),
+        ),
+        (
+            BodyPartType::RightHand,
+            NameWithArticle::una("mano derecha"),
+        ),
+        (
+            BodyPartType::LeftLeg,
+            NameWithArticle::una("pierna izquierda"),
+        ),
+        (
+            BodyPartType::RightLeg,
+            NameWithArticle::una("pierna derecha"),
+        ),
+        (BodyPartType::LeftFoot, NameWithArticle::un("pie izquierdo")),
+        (BodyPartType::RightFoot, NameWithArticle::un("pie derecho")),
+    ];
+
+    [(Locale::Spanish, spanish.into_iter().collect())].into()
+}
+
 /// Gets the default display name of a body part type.
 fn get_default_name(body_part_type: &BodyPartType) -> Option<NameWithArticle> {
     match body_part_type {
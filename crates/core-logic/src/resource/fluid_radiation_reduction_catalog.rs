@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+
+use crate::{
+    action::DrinkAction,
+    component::{AfterActionPerformNotification, FluidType, RadiationDose},
+    notification::Notification,
+};
+
+/// Map of fluids to how many rads of absorbed radiation drinking a liter of that fluid gets rid of.
+/// None of the standard fluid types reduce absorbed radiation unless a world definition says they do.
+#[derive(Resource)]
+pub struct FluidRadiationReductionCatalog(HashMap<FluidType, f32>);
+
+impl FluidRadiationReductionCatalog {
+    /// Creates the default catalog of radiation reductions.
+    pub fn new() -> FluidRadiationReductionCatalog {
+        FluidRadiationReductionCatalog(HashMap::new())
+    }
+
+    /// Sets the radiation reduction per liter of the provided fluid type.
+    pub fn set(&mut self, fluid_type: &FluidType, reduction: f32) {
+        self.0.insert(fluid_type.clone(), reduction);
+    }
+
+    /// Determines the radiation reduction per liter of the provided fluid type.
+    pub fn get(&self, fluid_type: &FluidType) -> f32 {
+        *self.0.get(fluid_type).unwrap_or(&0.0)
+    }
+}
+
+/// Reduces absorbed radiation when an entity drinks fluids that treat it.
+pub fn reduce_radiation_dose_on_drink(
+    notification: &Notification<AfterActionPerformNotification, DrinkAction>,
+    world: &mut World,
+) {
+    if notification.notification_type.action_complete
+        && notification.notification_type.action_successful
+    {
+        let reduction = notification
+            .contents
+            .fluids_to_volume_drank
+            .iter()
+            .map(|(fluid_type, volume)| {
+                volume.0
+                    * world
+                        .resource::<FluidRadiationReductionCatalog>()
+                        .get(fluid_type)
+            })
+            .sum::<f32>();
+
+        RadiationDose::treat(
+            notification.notification_type.performing_entity,
+            reduction,
+            world,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        action::{Action, ActionNotificationSender},
+        component::{Vitals, Volume},
+        notification::NotificationHandlers,
+    };
+
+    use super::*;
+
+    #[test]
+    fn drinking_treating_fluid_reduces_dose() {
+        let mut world = World::new();
+        let mut catalog = FluidRadiationReductionCatalog::new();
+        catalog.set(&FluidType::Custom("iodine_tea".to_string()), 100.0);
+        world.insert_resource(catalog);
+        NotificationHandlers::add_handler(reduce_radiation_dose_on_drink, &mut world);
+        let person = world.spawn((Vitals::new(), RadiationDose(200.0))).id();
+        let cup = world.spawn_empty().id();
+
+        let action = DrinkAction {
+            target: cup,
+            amount: Volume(0.75),
+            fluids_to_volume_drank: [
+                (FluidType::Custom("iodine_tea".to_string()), Volume(0.5)),
+                (FluidType::Water, Volume(0.25)),
+            ]
+            .into(),
+            notification_sender: ActionNotificationSender::new(),
+        };
+        action.send_after_perform_notification(
+            AfterActionPerformNotification {
+                performing_entity: person,
+                action_complete: true,
+                action_successful: true,
+            },
+            &mut world,
+        );
+
+        assert_eq!(150.0, RadiationDose::get(person, &world));
+    }
+}
@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
 use bevy_ecs::prelude::*;
 
@@ -12,4 +12,20 @@ pub struct GameOptions {
     ///
     /// If true, no rooms or items or anything will be generated.
     pub skip_worldgen: bool,
+    /// The seed to procedurally generate a city around the defined world from. The same seed always generates the same city.
+    ///
+    /// If not set, no city will be generated.
+    pub worldgen_seed: Option<u64>,
+    /// The directory to load world definition files from.
+    ///
+    /// If not set, the world definition files built into the game will be used.
+    pub world_dir: Option<PathBuf>,
+    /// The directory to load mods from. Each mod is a directory inside it with a `mod.toml` manifest and any number of world definition files.
+    ///
+    /// If not set, no mods will be loaded.
+    pub mods_dir: Option<PathBuf>,
+    /// The IDs of the mods in `mods_dir` to load.
+    ///
+    /// If not set, all of them will be loaded.
+    pub active_mods: Option<Vec<String>>,
 }
@@ -0,0 +1,420 @@
+use std::{collections::HashMap, fmt::Display, marker::PhantomData};
+
+use bevy_ecs::prelude::*;
+use rand::seq::SliceRandom;
+use strum::IntoEnumIterator;
+
+use crate::{
+    action::ThrowMessageTokens, component::PlayerMessageTokens, FixedMessageTokens, Locale,
+    MessageFormat, NoTokens, TokenCheckError, TokenKind, TokenName, WeaponHitMessageTokens,
+    WeaponMissMessageTokens,
+};
+
+/// The message templates built into the game.
+const BUILT_IN_MESSAGE_TEMPLATES: &str = include_str!("../../data/messages.toml");
+
+/// Translations of the built-in message templates into locales other than the default one.
+const BUILT_IN_LOCALIZED_MESSAGE_TEMPLATES: [(Locale, &str); 1] =
+    [(Locale::Spanish, include_str!("../../data/messages.es.toml"))];
+
+pub const PLAYER_SPAWNED: MessageTemplateId<PlayerMessageTokens> =
+    MessageTemplateId::new("player_spawned");
+pub const PLAYER_REATTACHED: MessageTemplateId<PlayerMessageTokens> =
+    MessageTemplateId::new("player_reattached");
+pub const PLAYER_DETACHED: MessageTemplateId<PlayerMessageTokens> =
+    MessageTemplateId::new("player_detached");
+pub const PLAYER_DIED: MessageTemplateId<PlayerMessageTokens> =
+    MessageTemplateId::new("player_died");
+
+pub const THROW_EXTREME_FAIL: MessageTemplateId<ThrowMessageTokens> =
+    MessageTemplateId::new("throw_extreme_fail");
+pub const THROW_FAIL: MessageTemplateId<ThrowMessageTokens> = MessageTemplateId::new("throw_fail");
+pub const THROW_SUCCESS: MessageTemplateId<ThrowMessageTokens> =
+    MessageTemplateId::new("throw_success");
+pub const THROW_EXTREME_SUCCESS: MessageTemplateId<ThrowMessageTokens> =
+    MessageTemplateId::new("throw_extreme_success");
+pub const DODGE_EXTREME_FAIL: MessageTemplateId<ThrowMessageTokens> =
+    MessageTemplateId::new("dodge_extreme_fail");
+pub const DODGE_FAIL: MessageTemplateId<ThrowMessageTokens> = MessageTemplateId::new("dodge_fail");
+pub const DODGE_SUCCESS: MessageTemplateId<ThrowMessageTokens> =
+    MessageTemplateId::new("dodge_success");
+pub const DODGE_EXTREME_SUCCESS: MessageTemplateId<ThrowMessageTokens> =
+    MessageTemplateId::new("dodge_extreme_success");
+
+pub const WEAPON_HIT: MessageTemplateId<WeaponHitMessageTokens> =
+    MessageTemplateId::new("weapon_hit");
+pub const WEAPON_MISS: MessageTemplateId<WeaponMissMessageTokens> =
+    MessageTemplateId::new("weapon_miss");
+
+pub const FIST_MISS: MessageTemplateId<WeaponMissMessageTokens> =
+    MessageTemplateId::new("fist_miss");
+pub const FIST_MINOR_HIT: MessageTemplateId<WeaponHitMessageTokens> =
+    MessageTemplateId::new("fist_minor_hit");
+pub const FIST_REGULAR_HIT: MessageTemplateId<WeaponHitMessageTokens> =
+    MessageTemplateId::new("fist_regular_hit");
+pub const FIST_MAJOR_HIT: MessageTemplateId<WeaponHitMessageTokens> =
+    MessageTemplateId::new("fist_major_hit");
+pub const FIST_SELF_HIT: MessageTemplateId<WeaponHitMessageTokens> =
+    MessageTemplateId::new("fist_self_hit");
+
+pub const UPPERCUT_MISS: MessageTemplateId<WeaponMissMessageTokens> =
+    MessageTemplateId::new("uppercut_miss");
+pub const UPPERCUT_MINOR_HIT: MessageTemplateId<WeaponHitMessageTokens> =
+    MessageTemplateId::new("uppercut_minor_hit");
+pub const UPPERCUT_REGULAR_HIT: MessageTemplateId<WeaponHitMessageTokens> =
+    MessageTemplateId::new("uppercut_regular_hit");
+pub const UPPERCUT_MAJOR_HIT: MessageTemplateId<WeaponHitMessageTokens> =
+    MessageTemplateId::new("uppercut_major_hit");
+
+pub const HAYMAKER_MISS: MessageTemplateId<WeaponMissMessageTokens> =
+    MessageTemplateId::new("haymaker_miss");
+pub const HAYMAKER_MINOR_HIT: MessageTemplateId<WeaponHitMessageTokens> =
+    MessageTemplateId::new("haymaker_minor_hit");
+pub const HAYMAKER_REGULAR_HIT: MessageTemplateId<WeaponHitMessageTokens> =
+    MessageTemplateId::new("haymaker_regular_hit");
+pub const HAYMAKER_MAJOR_HIT: MessageTemplateId<WeaponHitMessageTokens> =
+    MessageTemplateId::new("haymaker_major_hit");
+
+/// Describes every message template the game uses, so they can be checked against the tokens they'll be interpolated with.
+fn get_known_templates() -> Vec<KnownMessageTemplate> {
+    vec![
+        PLAYER_SPAWNED.describe(),
+        PLAYER_REATTACHED.describe(),
+        PLAYER_DETACHED.describe(),
+        PLAYER_DIED.describe(),
+        THROW_EXTREME_FAIL.describe(),
+        THROW_FAIL.describe(),
+        THROW_SUCCESS.describe(),
+        THROW_EXTREME_SUCCESS.describe(),
+        DODGE_EXTREME_FAIL.describe(),
+        DODGE_FAIL.describe(),
+        DODGE_SUCCESS.describe(),
+        DODGE_EXTREME_SUCCESS.describe(),
+        WEAPON_HIT.describe(),
+        WEAPON_MISS.describe(),
+        FIST_MISS.describe(),
+        FIST_MINOR_HIT.describe(),
+        FIST_REGULAR_HIT.describe(),
+        FIST_MAJOR_HIT.describe(),
+        FIST_SELF_HIT.describe(),
+        UPPERCUT_MISS.describe(),
+        UPPERCUT_MINOR_HIT.describe(),
+        UPPERCUT_REGULAR_HIT.describe(),
+        UPPERCUT_MAJOR_HIT.describe(),
+        HAYMAKER_MISS.describe(),
+        HAYMAKER_MINOR_HIT.describe(),
+        HAYMAKER_REGULAR_HIT.describe(),
+        HAYMAKER_MAJOR_HIT.describe(),
+    ]
+}
+
+/// The ID of a message template, along with the type of tokens its messages are interpolated with.
+pub struct MessageTemplateId<T: FixedMessageTokens> {
+    id: &'static str,
+    _t: PhantomData<fn(T)>,
+}
+
+impl<T: FixedMessageTokens> MessageTemplateId<T> {
+    /// Creates a template ID.
+    const fn new(id: &'static str) -> MessageTemplateId<T> {
+        MessageTemplateId {
+            id,
+            _t: PhantomData,
+        }
+    }
+
+    /// Gets the ID of the template.
+    pub fn get_id(&self) -> &'static str {
+        self.id
+    }
+
+    /// Chooses one of the variants of this template in the default locale at random.
+    pub fn choose(&self, world: &World) -> MessageFormat<T> {
+        self.choose_in(Locale::default(), world)
+    }
+
+    /// Chooses one of the variants of this template in the provided locale at random.
+    pub fn choose_in(&self, locale: Locale, world: &World) -> MessageFormat<T> {
+        world
+            .resource::<MessageTemplateCatalog>()
+            .get_variants(locale, self.id)
+            .choose(&mut rand::thread_rng())
+            .cloned()
+            .expect("message templates should have at least one variant")
+            .with_token_type()
+    }
+
+    /// Chooses one of the variants of this template at random for each locale other than the default one.
+    pub fn choose_localized(&self, world: &World) -> HashMap<Locale, MessageFormat<T>> {
+        Locale::iter()
+            .filter(|locale| *locale != Locale::default())
+            .map(|locale| (locale, self.choose_in(locale, world)))
+            .collect()
+    }
+
+    /// Gets all the variants of this template in the default locale.
+    pub fn get_variants(&self, world: &World) -> Vec<MessageFormat<T>> {
+        self.get_variants_in(Locale::default(), world)
+    }
+
+    /// Gets all the variants of this template in the provided locale.
+    pub fn get_variants_in(&self, locale: Locale, world: &World) -> Vec<MessageFormat<T>> {
+        world
+            .resource::<MessageTemplateCatalog>()
+            .get_variants(locale, self.id)
+            .iter()
+            .cloned()
+            .map(MessageFormat::with_token_type)
+            .collect()
+    }
+
+    /// Describes this template, without the type of its tokens.
+    fn describe(&self) -> KnownMessageTemplate {
+        KnownMessageTemplate {
+            id: self.id,
+            get_token_kinds: T::get_token_kinds,
+        }
+    }
+}
+
+/// A message template the game uses.
+struct KnownMessageTemplate {
+    /// The ID of the template.
+    id: &'static str,
+    /// Gets the tokens messages from the template are interpolated with.
+    get_token_kinds: fn() -> HashMap<TokenName, TokenKind>,
+}
+
+/// Map of message template IDs to the variants of each template, for each locale.
+///
+/// Every template has variants in the default locale. Templates that haven't been translated into other locales use those.
+#[derive(Resource)]
+pub struct MessageTemplateCatalog {
+    templates: HashMap<Locale, HashMap<&'static str, Vec<MessageFormat<NoTokens>>>>,
+    token_kinds: HashMap<&'static str, HashMap<TokenName, TokenKind>>,
+}
+
+/// An error setting the variants of a message template.
+#[derive(Debug)]
+pub enum MessageTemplateError {
+    /// There's no template with the provided ID.
+    UnknownTemplate,
+    /// No variants were provided.
+    NoVariants,
+    /// A variant isn't a valid message format.
+    InvalidFormat {
+        /// The index of the variant.
+        index: usize,
+        /// Why the variant is invalid.
+        message: String,
+    },
+    /// A variant uses tokens that won't be provided when the message is sent.
+    InvalidTokens {
+        /// The index of the variant.
+        index: usize,
+        /// The problem with the tokens.
+        error: TokenCheckError,
+    },
+}
+
+impl Display for MessageTemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MessageTemplateError::UnknownTemplate => write!(f, "no such message template exists"),
+            MessageTemplateError::NoVariants => write!(f, "at least one variant is required"),
+            MessageTemplateError::InvalidFormat { index, message } => {
+                write!(f, "variant {} is invalid: {message}", index + 1)
+            }
+            MessageTemplateError::InvalidTokens { index, error } => {
+                write!(f, "variant {} has an {error}", index + 1)
+            }
+        }
+    }
+}
+
+impl MessageTemplateCatalog {
+    /// Creates the default catalog of message templates, from the ones built into the game.
+    pub fn new() -> MessageTemplateCatalog {
+        let mut catalog = MessageTemplateCatalog {
+            templates: HashMap::new(),
+            token_kinds: get_known_templates()
+                .into_iter()
+                .map(|template| (template.id, (template.get_token_kinds)()))
+                .collect(),
+        };
+
+        let built_in_files = [(Locale::default(), BUILT_IN_MESSAGE_TEMPLATES)]
+            .into_iter()
+            .chain(BUILT_IN_LOCALIZED_MESSAGE_TEMPLATES);
+        for (locale, contents) in built_in_files {
+            let built_in: HashMap<String, Vec<String>> =
+                toml::from_str(contents).expect("built-in message templates should be valid TOML");
+            for (id, variants) in built_in {
+                if let Err(e) = catalog.set(locale, &id, &variants) {
+                    panic!(
+                        "built-in message template {id:?} in locale {} is invalid: {e}",
+                        locale.code()
+                    );
+                }
+            }
+        }
+
+        for id in catalog.token_kinds.keys() {
+            assert!(
+                catalog.templates[&Locale::default()].contains_key(id),
+                "message template {id:?} should be built in"
+            );
+        }
+
+        catalog
+    }
+
+    /// Replaces the variants of the message template with the provided ID in the provided locale, after checking that they're valid.
+    pub fn set(
+        &mut self,
+        locale: Locale,
+        id: &str,
+        variants: &[String],
+    ) -> Result<(), MessageTemplateError> {
+        let Some((id, token_kinds)) = self.token_kinds.get_key_value(id) else {
+            return Err(MessageTemplateError::UnknownTemplate);
+        };
+
+        if variants.is_empty() {
+            return Err(MessageTemplateError::NoVariants);
+        }
+
+        let mut formats = Vec::new();
+        for (index, variant) in variants.iter().enumerate() {
+            let format =
+                MessageFormat::new(variant).map_err(|e| MessageTemplateError::InvalidFormat {
+                    index,
+                    message: format!("{e:?}"),
+                })?;
+            format
+                .check_tokens(token_kinds)
+                .map_err(|error| MessageTemplateError::InvalidTokens { index, error })?;
+            formats.push(format);
+        }
+
+        self.templates
+            .entry(locale)
+            .or_default()
+            .insert(id, formats);
+
+        Ok(())
+    }
+
+    /// Gets the variants of the message template with the provided ID in the provided locale,
+    /// or in the default locale if it hasn't been translated into the provided one.
+    fn get_variants(&self, locale: Locale, id: &str) -> &[MessageFormat<NoTokens>] {
+        self.templates
+            .get(&locale)
+            .and_then(|templates| templates.get(id))
+            .or_else(|| {
+                self.templates
+                    .get(&Locale::default())
+                    .and_then(|templates| templates.get(id))
+            })
+            .map(|variants| variants.as_slice())
+            .unwrap_or_else(|| panic!("message template {id:?} should exist"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_in_templates_are_valid() {
+        let catalog = MessageTemplateCatalog::new();
+
+        for locale in Locale::iter() {
+            assert_eq!(
+                get_known_templates().len(),
+                catalog.templates[&locale].len(),
+                "locale {} should have every template",
+                locale.code()
+            );
+        }
+    }
+
+    #[test]
+    fn set_replaces_variants() {
+        let mut catalog = MessageTemplateCatalog::new();
+
+        catalog
+            .set(
+                Locale::default(),
+                FIST_MISS.get_id(),
+                &[
+                    "${attacker.Name} ${attacker.you:whiff/whiffs}.".to_string(),
+                    "${target.Name} ${target.you:duck/ducks}.".to_string(),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(
+            2,
+            catalog
+                .get_variants(Locale::default(), FIST_MISS.get_id())
+                .len()
+        );
+        assert_eq!(
+            1,
+            catalog
+                .get_variants(Locale::Spanish, FIST_MISS.get_id())
+                .len()
+        );
+    }
+
+    #[test]
+    fn untranslated_templates_fall_back_to_default_locale() {
+        let mut catalog = MessageTemplateCatalog::new();
+        catalog.templates.remove(&Locale::Spanish);
+
+        assert_eq!(
+            catalog.get_variants(Locale::default(), FIST_MISS.get_id())[0].as_format_string(),
+            catalog.get_variants(Locale::Spanish, FIST_MISS.get_id())[0].as_format_string()
+        );
+    }
+
+    #[test]
+    fn set_rejects_invalid_variants() {
+        let mut catalog = MessageTemplateCatalog::new();
+        let set = |catalog: &mut MessageTemplateCatalog, id: &str, variant: &str| {
+            catalog.set(Locale::default(), id, &[variant.to_string()])
+        };
+
+        assert!(matches!(
+            set(&mut catalog, "not_a_template", "Hello."),
+            Err(MessageTemplateError::UnknownTemplate)
+        ));
+        assert!(matches!(
+            catalog.set(Locale::default(), FIST_MISS.get_id(), &[]),
+            Err(MessageTemplateError::NoVariants)
+        ));
+        assert!(matches!(
+            set(&mut catalog, FIST_MISS.get_id(), "${attacker.Name"),
+            Err(MessageTemplateError::InvalidFormat { index: 0, .. })
+        ));
+        // misses don't hit a body part
+        assert!(matches!(
+            set(
+                &mut catalog,
+                FIST_MISS.get_id(),
+                "${body_part.Name} is fine."
+            ),
+            Err(MessageTemplateError::InvalidTokens {
+                index: 0,
+                error: TokenCheckError::UnknownToken(_)
+            })
+        ));
+        assert_eq!(
+            1,
+            catalog
+                .get_variants(Locale::default(), FIST_MISS.get_id())
+                .len()
+        );
+    }
+}
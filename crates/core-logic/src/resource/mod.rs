@@ -9,6 +9,9 @@ pub use game_options::GameOptions;
 mod fluid_hydration_factor_catalog;
 pub use fluid_hydration_factor_catalog::FluidHydrationFactorCatalog;
 
+mod fluid_radiation_reduction_catalog;
+pub use fluid_radiation_reduction_catalog::FluidRadiationReductionCatalog;
+
 mod fluid_name_catalog;
 pub use fluid_name_catalog::get_fluid_name;
 pub use fluid_name_catalog::FluidNameCatalog;
@@ -32,7 +35,6 @@ pub use skill_base_attribute_catalog::SkillBaseAttributeCatalog;
 
 mod weapon_type_stat_catalog;
 pub use weapon_type_stat_catalog::WeaponTypeStatCatalog;
-#[expect(unused)]
 pub use weapon_type_stat_catalog::WeaponTypeStats;
 
 mod weapon_type_name_catalog;
@@ -41,10 +43,18 @@ pub use weapon_type_name_catalog::WeaponTypeNameCatalog;
 mod body_part_type_name_catalog;
 pub use body_part_type_name_catalog::BodyPartTypeNameCatalog;
 
+mod weather;
+pub use weather::Weather;
+pub use weather::WeatherKind;
+
+mod message_template_catalog;
+pub use message_template_catalog::*;
+
 /// Inserts all the resources into the world.
 pub fn insert_resources(world: &mut World) {
     world.insert_resource(FluidNameCatalog::new());
     world.insert_resource(FluidHydrationFactorCatalog::new());
+    world.insert_resource(FluidRadiationReductionCatalog::new());
     world.insert_resource(FluidDensityCatalog::new());
     world.insert_resource(AttributeNameCatalog::new());
     world.insert_resource(SkillNameCatalog::new());
@@ -52,6 +62,8 @@ pub fn insert_resources(world: &mut World) {
     world.insert_resource(WeaponTypeStatCatalog::new());
     world.insert_resource(WeaponTypeNameCatalog::new());
     world.insert_resource(BodyPartTypeNameCatalog::new());
+    world.insert_resource(MessageTemplateCatalog::new());
+    world.insert_resource(Weather::new());
 }
 
 /// Registers notification handlers related to resources.
@@ -60,6 +72,11 @@ pub fn register_resource_handlers(world: &mut World) {
         fluid_hydration_factor_catalog::increase_hydration_on_drink,
         world,
     );
+    NotificationHandlers::add_handler(
+        fluid_radiation_reduction_catalog::reduce_radiation_dose_on_drink,
+        world,
+    );
+    NotificationHandlers::add_handler(weather::update_weather_on_tick, world);
 }
 
 /// Gets the name of a stat. If it's an attribute, the full name will be returned.
@@ -0,0 +1,350 @@
+use std::ops::RangeInclusive;
+
+use bevy_ecs::prelude::*;
+use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    component::{find_containing_room, Player, Vitals, Wetness},
+    game_map::Coordinates,
+    notification::Notification,
+    send_message,
+    vital_change::{
+        ValueChangeOperation, VitalChange, VitalChangeMessageParams, VitalChangeVisualizationType,
+        VitalType,
+    },
+    GameMessage, InternalMessageCategory, MessageCategory, MessageDelay, NoTokens,
+    SurroundingsMessageCategory, TickNotification,
+};
+
+/// How many ticks a spell of weather lasts for.
+const WEATHER_DURATION_TICKS: RangeInclusive<u32> = 120..=480; // 30 minutes to 2 hours
+
+/// The kinds of weather that can follow clear skies, along with how likely each one is to.
+const WEATHER_WEIGHTS: [(WeatherKind, u8); 5] = [
+    (WeatherKind::Clear, 4),
+    (WeatherKind::Rain, 3),
+    (WeatherKind::DustStorm, 2),
+    (WeatherKind::AcidRain, 1),
+    (WeatherKind::Snow, 1),
+];
+
+/// How much wetness is lost per tick while not being rained or snowed on.
+const DRYING_PER_TICK: f32 = 0.02;
+/// How much health is lost per tick while being rained on by acid rain.
+const ACID_RAIN_DAMAGE_PER_TICK: f32 = 1.0;
+/// How many ticks apart entities out in acid rain are reminded that it's burning them.
+const ACID_RAIN_MESSAGE_INTERVAL_TICKS: u32 = 20; // 5 minutes
+
+/// The kinds of weather there can be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WeatherKind {
+    Clear,
+    Rain,
+    DustStorm,
+    AcidRain,
+    Snow,
+}
+
+impl WeatherKind {
+    /// The description of this weather to include in the descriptions of outdoor rooms, if it's worth mentioning.
+    pub fn get_description(self) -> Option<&'static str> {
+        match self {
+            WeatherKind::Clear => None,
+            WeatherKind::Rain => Some("Rain falls steadily from a gray sky."),
+            WeatherKind::DustStorm => {
+                Some("Thick clouds of dust howl through the air, stinging your eyes.")
+            }
+            WeatherKind::AcidRain => {
+                Some("A yellowish rain hisses down, leaving pockmarks wherever it lands.")
+            }
+            WeatherKind::Snow => Some("Snow drifts down from the sky."),
+        }
+    }
+
+    /// The message to send to entities outside when this weather starts.
+    fn get_start_message(self) -> &'static str {
+        match self {
+            WeatherKind::Clear => "The sky clears up.",
+            WeatherKind::Rain => "It starts to rain.",
+            WeatherKind::DustStorm => "The wind picks up, and a wall of dust sweeps in.",
+            WeatherKind::AcidRain => "A sickly yellow rain starts to fall, hissing as it lands.",
+            WeatherKind::Snow => "It starts to snow.",
+        }
+    }
+
+    /// The message to send to entities outside when this weather ends.
+    fn get_end_message(self) -> &'static str {
+        match self {
+            WeatherKind::Clear => "Clouds roll in overhead.",
+            WeatherKind::Rain => "The rain lets up.",
+            WeatherKind::DustStorm => "The wind dies down, and the dust starts to settle.",
+            WeatherKind::AcidRain => "The acid rain tapers off.",
+            WeatherKind::Snow => "The snow stops falling.",
+        }
+    }
+
+    /// How much wetter this weather makes entities outside in it each tick.
+    fn get_wetness_per_tick(self) -> f32 {
+        match self {
+            WeatherKind::Clear | WeatherKind::DustStorm => 0.0,
+            WeatherKind::Rain | WeatherKind::AcidRain => 0.05,
+            WeatherKind::Snow => 0.02,
+        }
+    }
+
+    /// How many degrees Celsius warmer this weather makes it outside. Negative values make it colder.
+    pub fn get_temperature_change(self) -> f32 {
+        match self {
+            WeatherKind::Clear => 0.0,
+            WeatherKind::Rain | WeatherKind::AcidRain => -5.0,
+            WeatherKind::DustStorm => 6.0,
+            WeatherKind::Snow => -18.0,
+        }
+    }
+
+    /// How many tiles away from the center of the map can be seen in this weather, if it limits how far can be seen.
+    pub fn get_map_visibility(self) -> Option<i64> {
+        match self {
+            WeatherKind::Clear => None,
+            WeatherKind::Rain | WeatherKind::AcidRain => Some(2),
+            WeatherKind::DustStorm | WeatherKind::Snow => Some(1),
+        }
+    }
+
+    /// How many extra ticks it takes to move from one outdoor room to another in this weather, along with the message to show when it happens.
+    pub fn get_movement_penalty(self) -> Option<(u8, &'static str)> {
+        match self {
+            WeatherKind::Clear | WeatherKind::Rain | WeatherKind::AcidRain => None,
+            WeatherKind::DustStorm => Some((1, "You push your way through the blowing dust...")),
+            WeatherKind::Snow => Some((1, "You trudge through the snow...")),
+        }
+    }
+}
+
+/// The current weather, which affects everything that's outdoors.
+#[derive(Debug, Clone, PartialEq, Eq, Resource, Serialize, Deserialize)]
+pub struct Weather {
+    /// What the weather is like.
+    pub kind: WeatherKind,
+    /// How many more ticks until the weather changes.
+    pub ticks_until_change: u32,
+}
+
+impl Default for Weather {
+    fn default() -> Self {
+        Weather::new()
+    }
+}
+
+impl Weather {
+    /// Creates clear weather.
+    pub fn new() -> Weather {
+        Weather {
+            kind: WeatherKind::Clear,
+            ticks_until_change: *WEATHER_DURATION_TICKS.start(),
+        }
+    }
+
+    /// Determines what the weather is like in the room with the provided coordinates, if the room is outdoors.
+    pub fn at(coordinates: &Coordinates, world: &World) -> Option<WeatherKind> {
+        if coordinates.parent.is_some() {
+            return None;
+        }
+
+        world.get_resource::<Weather>().map(|weather| weather.kind)
+    }
+
+    /// Determines what the weather is like where the provided entity is, if it's outdoors.
+    pub fn around(entity: Entity, world: &World) -> Option<WeatherKind> {
+        let room = find_containing_room(entity, world)?;
+        Weather::at(world.get::<Coordinates>(room)?, world)
+    }
+
+    /// Picks the weather to follow the current weather. Bad weather is always followed by clear skies.
+    fn choose_next_kind(&self, rng: &mut impl Rng) -> WeatherKind {
+        if self.kind != WeatherKind::Clear {
+            return WeatherKind::Clear;
+        }
+
+        WEATHER_WEIGHTS
+            .choose_weighted(rng, |(_, weight)| *weight)
+            .map(|(kind, _)| *kind)
+            .unwrap_or(WeatherKind::Clear)
+    }
+}
+
+/// Counts down to the next change in the weather, and applies the effects of the current weather to everything outside.
+pub fn update_weather_on_tick(_: &Notification<TickNotification, ()>, world: &mut World) {
+    let mut rng = rand::thread_rng();
+    let mut weather = world.resource_mut::<Weather>();
+    let previous_kind = weather.kind;
+    weather.ticks_until_change = weather.ticks_until_change.saturating_sub(1);
+    if weather.ticks_until_change == 0 {
+        weather.kind = weather.choose_next_kind(&mut rng);
+        weather.ticks_until_change = rng.gen_range(WEATHER_DURATION_TICKS);
+    }
+    let current_kind = weather.kind;
+    let ticks_until_change = weather.ticks_until_change;
+
+    if current_kind != previous_kind {
+        let message = if current_kind == WeatherKind::Clear {
+            previous_kind.get_end_message()
+        } else {
+            current_kind.get_start_message()
+        };
+        send_weather_change_messages(message, world);
+    }
+
+    let send_acid_rain_message = current_kind != previous_kind
+        || ticks_until_change.is_multiple_of(ACID_RAIN_MESSAGE_INTERVAL_TICKS);
+    apply_weather_effects(send_acid_rain_message, world);
+}
+
+/// Sends a message about the weather changing to every player that's outside.
+fn send_weather_change_messages(message: &str, world: &mut World) {
+    let mut query = world.query_filtered::<Entity, With<Player>>();
+    for entity in query.iter(world) {
+        if Weather::around(entity, world).is_some() {
+            send_message(
+                world,
+                entity,
+                GameMessage::Message {
+                    content: message.to_string(),
+                    category: MessageCategory::Surroundings(SurroundingsMessageCategory::Flavor),
+                    delay: MessageDelay::Short,
+                    decorations: Vec::new(),
+                },
+            );
+        }
+    }
+}
+
+/// Gets living things outside wet (or lets them dry off), and burns them if there's acid rain.
+/// Anyone being burned is only told about it if `send_acid_rain_message` is set, so they aren't told every tick.
+fn apply_weather_effects(send_acid_rain_message: bool, world: &mut World) {
+    let mut query = world.query_filtered::<Entity, With<Vitals>>();
+    let living_entities = query.iter(world).collect::<Vec<_>>();
+    for entity in living_entities {
+        let exposed_to = Weather::around(entity, world);
+        let wetness_change = match exposed_to.map(WeatherKind::get_wetness_per_tick) {
+            Some(wetness_per_tick) if wetness_per_tick > 0.0 => wetness_per_tick,
+            _ => -DRYING_PER_TICK,
+        };
+        Wetness::change(entity, wetness_change, world);
+
+        if exposed_to == Some(WeatherKind::AcidRain) {
+            let message_params = if send_acid_rain_message {
+                vec![(
+                    VitalChangeMessageParams::Direct {
+                        entity,
+                        message: "The acid rain stings your skin!".to_string(),
+                        category: MessageCategory::Internal(InternalMessageCategory::Misc),
+                    },
+                    VitalChangeVisualizationType::Full,
+                )]
+            } else {
+                Vec::new()
+            };
+            VitalChange::<NoTokens> {
+                entity,
+                vital_type: VitalType::Health,
+                operation: ValueChangeOperation::Subtract,
+                amount: ACID_RAIN_DAMAGE_PER_TICK,
+                message_params,
+            }
+            .apply(world);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game_map::GameMap, move_entity, notification::NotificationHandlers,
+        test_utils::spawn_test_room,
+    };
+
+    use super::*;
+
+    fn set_up_world(kind: WeatherKind) -> World {
+        let mut world = World::new();
+        world.insert_resource(GameMap::new());
+        world.insert_resource(Weather {
+            kind,
+            ticks_until_change: 100,
+        });
+        NotificationHandlers::add_handler(update_weather_on_tick, &mut world);
+        world
+    }
+
+    #[test]
+    fn only_outdoor_rooms_have_weather() {
+        let mut world = set_up_world(WeatherKind::Snow);
+        let outside = spawn_test_room(None, &mut world);
+        let inside = spawn_test_room(
+            Some(Coordinates {
+                x: 1,
+                y: 1,
+                z: 0,
+                parent: None,
+            }),
+            &mut world,
+        );
+        let outside_thing = world.spawn_empty().id();
+        move_entity(outside_thing, outside, &mut world);
+        let inside_thing = world.spawn_empty().id();
+        move_entity(inside_thing, inside, &mut world);
+
+        assert_eq!(
+            Some(WeatherKind::Snow),
+            Weather::around(outside_thing, &world)
+        );
+        assert_eq!(None, Weather::around(inside_thing, &world));
+    }
+
+    #[test]
+    fn rain_gets_entities_outside_wet() {
+        let mut world = set_up_world(WeatherKind::Rain);
+        let outside = spawn_test_room(None, &mut world);
+        let person = world.spawn(Vitals::new()).id();
+        move_entity(person, outside, &mut world);
+
+        Notification::send_no_contents(TickNotification, &mut world);
+        assert_eq!(0.05, Wetness::get(person, &world));
+
+        world.resource_mut::<Weather>().kind = WeatherKind::Clear;
+        Notification::send_no_contents(TickNotification, &mut world);
+        assert!(Wetness::get(person, &world) < 0.05);
+    }
+
+    #[test]
+    fn acid_rain_burns_entities_outside() {
+        let mut world = set_up_world(WeatherKind::AcidRain);
+        let outside = spawn_test_room(None, &mut world);
+        let person = world.spawn(Vitals::new()).id();
+        move_entity(person, outside, &mut world);
+        let starting_health = world.get::<Vitals>(person).unwrap().health.get();
+
+        for _ in 0..3 {
+            Notification::send_no_contents(TickNotification, &mut world);
+        }
+
+        assert_eq!(
+            starting_health - 3.0 * ACID_RAIN_DAMAGE_PER_TICK,
+            world.get::<Vitals>(person).unwrap().health.get()
+        );
+    }
+
+    #[test]
+    fn bad_weather_clears_up() {
+        let mut world = set_up_world(WeatherKind::DustStorm);
+        world.resource_mut::<Weather>().ticks_until_change = 1;
+
+        Notification::send_no_contents(TickNotification, &mut world);
+
+        let weather = world.resource::<Weather>();
+        assert_eq!(WeatherKind::Clear, weather.kind);
+        assert!(WEATHER_DURATION_TICKS.contains(&weather.ticks_until_change));
+    }
+}
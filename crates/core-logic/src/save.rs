@@ -0,0 +1,656 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io::{Read, Write},
+};
+
+use bevy_ecs::{
+    entity::{EntityMapper, MapEntities},
+    prelude::*,
+    world::EntityRef,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    body_part::{BodyPart, BodyPartDamageMultiplier, BodyParts},
+    component::*,
+    game_map::{Coordinates, GameMap},
+    notification::Notification,
+    resource::Weather,
+    vital_change::VitalChangedNotification,
+    PlayerIdMapping, SpawnRoom, Time, VitalType, WorldDefinitionError,
+};
+
+/// The version of the save file format.
+/// This should be incremented whenever a change is made that would make older save files unreadable.
+const SAVE_FORMAT_VERSION: u32 = 1;
+
+/// A snapshot of a game world.
+#[derive(Serialize, Deserialize)]
+struct SavedWorld {
+    /// The version of the save file format used.
+    version: u32,
+    /// The in-game time.
+    time: Time,
+    /// The current weather.
+    #[serde(default)]
+    weather: Weather,
+    /// The coordinates of the spawn room, if there is one.
+    spawn_room: Option<Coordinates>,
+    /// All the entities in the world.
+    entities: Vec<SavedEntity>,
+}
+
+/// A snapshot of a single entity.
+#[derive(Serialize, Deserialize)]
+struct SavedEntity {
+    /// The ID the entity had when it was saved.
+    id: Entity,
+    /// The identity of the player controlling the entity when it was saved, if there was one.
+    #[serde(default)]
+    player_identity: Option<String>,
+    /// The saved components of the entity, keyed by component name.
+    components: Map<String, Value>,
+}
+
+/// An error saving a game world.
+#[derive(Debug)]
+pub enum SaveError {
+    /// The save file couldn't be written.
+    Io(std::io::Error),
+    /// Something in the world couldn't be serialized.
+    Serialization(serde_json::Error),
+}
+
+impl Display for SaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::Io(e) => write!(f, "unable to write save file: {e}"),
+            SaveError::Serialization(e) => write!(f, "unable to serialize world: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<std::io::Error> for SaveError {
+    fn from(value: std::io::Error) -> Self {
+        SaveError::Io(value)
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(value: serde_json::Error) -> Self {
+        SaveError::Serialization(value)
+    }
+}
+
+/// An error loading a game world.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The save file couldn't be read.
+    Io(std::io::Error),
+    /// The save file is malformed.
+    Deserialization(serde_json::Error),
+    /// The save file was written with an unsupported version of the save file format.
+    UnsupportedVersion(u32),
+    /// The save file contains a component that isn't recognized.
+    UnknownComponent(String),
+    /// The world definition files the prototypes are loaded from have a problem.
+    WorldDefinition(WorldDefinitionError),
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Io(e) => write!(f, "unable to read save file: {e}"),
+            LoadError::Deserialization(e) => write!(f, "unable to parse save file: {e}"),
+            LoadError::UnsupportedVersion(version) => write!(
+                f,
+                "save file has version {version}, but only version {SAVE_FORMAT_VERSION} is supported"
+            ),
+            LoadError::UnknownComponent(name) => {
+                write!(f, "save file contains unknown component {name:?}")
+            }
+            LoadError::WorldDefinition(e) => write!(f, "unable to load world definition: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<std::io::Error> for LoadError {
+    fn from(value: std::io::Error) -> Self {
+        LoadError::Io(value)
+    }
+}
+
+impl From<WorldDefinitionError> for LoadError {
+    fn from(value: WorldDefinitionError) -> Self {
+        LoadError::WorldDefinition(value)
+    }
+}
+
+impl From<serde_json::Error> for LoadError {
+    fn from(value: serde_json::Error) -> Self {
+        LoadError::Deserialization(value)
+    }
+}
+
+/// Maps entity IDs from a save file to the IDs of the entities spawned for them.
+struct SavedEntityMapper(HashMap<Entity, Entity>);
+
+impl EntityMapper for SavedEntityMapper {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        // references to entities that didn't exist when the world was saved can't point to anything
+        self.0.get(&entity).copied().unwrap_or(Entity::PLACEHOLDER)
+    }
+}
+
+/// Describes how to save and load a single type of component.
+struct SavedComponentType {
+    /// The name of the component in save files.
+    name: &'static str,
+    /// Serializes the component from the provided entity, if it has one.
+    save: fn(EntityRef) -> Option<Result<Value, serde_json::Error>>,
+    /// Deserializes the component and adds it to the provided entity.
+    load: fn(Value, Entity, &mut SavedEntityMapper, &mut World) -> Result<(), serde_json::Error>,
+}
+
+impl SavedComponentType {
+    /// Describes a component that doesn't reference any other entities.
+    fn new<C: Component + Serialize + DeserializeOwned>(name: &'static str) -> SavedComponentType {
+        SavedComponentType {
+            name,
+            save: save_component::<C>,
+            load: load_component::<C>,
+        }
+    }
+
+    /// Describes a component that references other entities, which need to be remapped on load.
+    fn new_with_entities<C: Component + Serialize + DeserializeOwned + MapEntities>(
+        name: &'static str,
+    ) -> SavedComponentType {
+        SavedComponentType {
+            name,
+            save: save_component::<C>,
+            load: load_component_with_entities::<C>,
+        }
+    }
+}
+
+/// Serializes a component from an entity, if it has one.
+fn save_component<C: Component + Serialize>(
+    entity: EntityRef,
+) -> Option<Result<Value, serde_json::Error>> {
+    entity.get::<C>().map(serde_json::to_value)
+}
+
+/// Deserializes a component and adds it to an entity.
+fn load_component<C: Component + DeserializeOwned>(
+    value: Value,
+    entity: Entity,
+    _: &mut SavedEntityMapper,
+    world: &mut World,
+) -> Result<(), serde_json::Error> {
+    let component = serde_json::from_value::<C>(value)?;
+    world.entity_mut(entity).insert(component);
+
+    Ok(())
+}
+
+/// Deserializes a component, remaps the entities it references, and adds it to an entity.
+fn load_component_with_entities<C: Component + DeserializeOwned + MapEntities>(
+    value: Value,
+    entity: Entity,
+    entity_mapper: &mut SavedEntityMapper,
+    world: &mut World,
+) -> Result<(), serde_json::Error> {
+    let mut component = serde_json::from_value::<C>(value)?;
+    component.map_entities(entity_mapper);
+    world.entity_mut(entity).insert(component);
+
+    Ok(())
+}
+
+/// Builds the list of all the types of components that are saved.
+///
+/// Components not in this list (like `Player` and `ActionQueue`) aren't saved, since they only make sense while the game is running.
+/// Status effects aren't saved either, since they're re-applied based on the state of the entities they're on.
+fn saved_component_types() -> Vec<SavedComponentType> {
+    vec![
+        SavedComponentType::new::<Description>("description"),
+        SavedComponentType::new::<Room>("room"),
+        SavedComponentType::new::<Coordinates>("coordinates"),
+        SavedComponentType::new_with_entities::<Location>("location"),
+        SavedComponentType::new_with_entities::<Container>("container"),
+        SavedComponentType::new_with_entities::<Connection>("connection"),
+        SavedComponentType::new::<OpenState>("open_state"),
+        SavedComponentType::new::<KeyedLock>("keyed_lock"),
+        SavedComponentType::new::<KeyId>("key_id"),
+        SavedComponentType::new::<CustomInputParser>("custom_input_parser"),
+        SavedComponentType::new::<Item>("item"),
+        SavedComponentType::new::<Volume>("volume"),
+        SavedComponentType::new::<Weight>("weight"),
+        SavedComponentType::new::<Density>("density"),
+        SavedComponentType::new::<Fluid>("fluid"),
+        SavedComponentType::new::<FluidContainer>("fluid_container"),
+        SavedComponentType::new::<Edible>("edible"),
+        SavedComponentType::new::<Calories>("calories"),
+        SavedComponentType::new::<Wearable>("wearable"),
+        SavedComponentType::new::<Weapon>("weapon"),
+        SavedComponentType::new::<InnateWeapon>("innate_weapon"),
+        SavedComponentType::new::<FistActions>("fist_actions"),
+        SavedComponentType::new::<Respawner>("respawner"),
+        SavedComponentType::new::<Vitals>("vitals"),
+        SavedComponentType::new::<Stats>("stats"),
+        SavedComponentType::new::<StartingStats>("starting_stats"),
+        SavedComponentType::new::<CheckHistory>("check_history"),
+        SavedComponentType::new::<SleepState>("sleep_state"),
+        SavedComponentType::new_with_entities::<WornItems>("worn_items"),
+        SavedComponentType::new_with_entities::<EquippedItems>("equipped_items"),
+        SavedComponentType::new_with_entities::<CombatState>("combat_state"),
+        SavedComponentType::new_with_entities::<Invisible>("invisible"),
+        SavedComponentType::new::<LightSource>("light_source"),
+        SavedComponentType::new::<PortableLight>("portable_light"),
+        SavedComponentType::new::<Wetness>("wetness"),
+        SavedComponentType::new::<AmbientTemperature>("ambient_temperature"),
+        SavedComponentType::new::<RadiationSource>("radiation_source"),
+        SavedComponentType::new::<RadiationDose>("radiation_dose"),
+        SavedComponentType::new::<GeigerCounter>("geiger_counter"),
+        SavedComponentType::new::<AntiRadiation>("anti_radiation"),
+        SavedComponentType::new_with_entities::<BodyParts>("body_parts"),
+        SavedComponentType::new_with_entities::<BodyPart>("body_part"),
+        SavedComponentType::new::<BodyPartDamageMultiplier>("body_part_damage_multiplier"),
+        SavedComponentType::new::<WanderBehavior>("wander_behavior"),
+        SavedComponentType::new::<GreetBehavior>("greet_behavior"),
+        SavedComponentType::new::<SelfDefenseBehavior>("self_defense_behavior"),
+        SavedComponentType::new::<Script>("script"),
+        SavedComponentType::new::<ScriptCommands>("script_commands"),
+        SavedComponentType::new::<FromPrototype>("from_prototype"),
+        SavedComponentType::new::<LocalizedDescriptions>("localized_descriptions"),
+    ]
+}
+
+/// Writes a snapshot of the provided world.
+pub fn save_world(world: &World, writer: impl Write) -> Result<(), SaveError> {
+    let component_types = saved_component_types();
+
+    let mut entities = Vec::new();
+    for entity in world.iter_entities() {
+        let mut components = Map::new();
+        for component_type in &component_types {
+            if let Some(value) = (component_type.save)(entity) {
+                components.insert(component_type.name.to_string(), value?);
+            }
+        }
+
+        entities.push(SavedEntity {
+            id: entity.id(),
+            player_identity: entity.get::<Player>().map(|player| player.identity.clone()),
+            components,
+        });
+    }
+
+    let saved_world = SavedWorld {
+        version: SAVE_FORMAT_VERSION,
+        time: world.resource::<Time>().clone(),
+        weather: world.resource::<Weather>().clone(),
+        spawn_room: world
+            .get_resource::<SpawnRoom>()
+            .map(|spawn_room| spawn_room.0.clone()),
+        entities,
+    };
+
+    serde_json::to_writer(writer, &saved_world)?;
+
+    Ok(())
+}
+
+/// Reads a snapshot of a world and spawns everything in it into the provided world.
+/// The provided world should already have all the standard resources inserted.
+///
+/// Returns the entities that were controlled by players when the world was saved, along with the identities of those players.
+/// Since those players aren't connected anymore, the caller should call `finish_loading` with them once all the notification handlers are registered.
+pub fn load_world(
+    reader: impl Read,
+    world: &mut World,
+) -> Result<Vec<(Entity, String)>, LoadError> {
+    let saved_world = serde_json::from_reader::<_, SavedWorld>(reader)?;
+    if saved_world.version != SAVE_FORMAT_VERSION {
+        return Err(LoadError::UnsupportedVersion(saved_world.version));
+    }
+
+    // spawn all the entities first so references between them can be remapped
+    let mut entity_mapper = SavedEntityMapper(
+        saved_world
+            .entities
+            .iter()
+            .map(|saved_entity| (saved_entity.id, world.spawn_empty().id()))
+            .collect(),
+    );
+
+    let component_types = saved_component_types()
+        .into_iter()
+        .map(|component_type| (component_type.name, component_type))
+        .collect::<HashMap<&str, SavedComponentType>>();
+
+    let mut player_entities = Vec::new();
+    for saved_entity in saved_world.entities {
+        let entity = entity_mapper.map_entity(saved_entity.id);
+        for (name, value) in saved_entity.components {
+            let Some(component_type) = component_types.get(name.as_str()) else {
+                return Err(LoadError::UnknownComponent(name));
+            };
+            (component_type.load)(value, entity, &mut entity_mapper, world)?;
+        }
+
+        if let Some(identity) = saved_entity.player_identity {
+            player_entities.push((entity, identity));
+        }
+    }
+
+    let mut game_map = GameMap::new();
+    for (entity, coords) in world.query::<(Entity, &Coordinates)>().iter(world) {
+        game_map.locations.insert(coords.clone(), entity);
+    }
+    world.insert_resource(game_map);
+
+    world.insert_resource(saved_world.time);
+    world.insert_resource(saved_world.weather);
+    if let Some(spawn_room_coords) = saved_world.spawn_room {
+        world.insert_resource(SpawnRoom(spawn_room_coords));
+    }
+
+    Ok(player_entities)
+}
+
+/// Finishes setting up a loaded world, once all the notification handlers are registered.
+///
+/// The provided players are added to the world as disconnected, so they can be reattached to their entities when they reconnect.
+pub fn finish_loading(players: Vec<(Entity, Player)>, world: &mut World) {
+    for (entity, player) in players {
+        world
+            .resource_mut::<PlayerIdMapping>()
+            .insert(&player, entity);
+        world
+            .entity_mut(entity)
+            .insert((player, ActionQueue::new()));
+    }
+
+    // parsers for script commands aren't saved
+    ScriptCommands::register_all_parsers(world);
+
+    // re-apply status effects based on vitals
+    let vitals = world
+        .query::<(Entity, &Vitals)>()
+        .iter(world)
+        .map(|(entity, vitals)| (entity, vitals.clone()))
+        .collect::<Vec<(Entity, Vitals)>>();
+    for (entity, vitals) in vitals {
+        for (vital_type, value) in [
+            (VitalType::Health, vitals.health),
+            (VitalType::Satiety, vitals.satiety),
+            (VitalType::Hydration, vitals.hydration),
+            (VitalType::Energy, vitals.energy),
+            (VitalType::BodyTemperature, vitals.body_temperature),
+        ] {
+            Notification::send_no_contents(
+                VitalChangedNotification {
+                    entity,
+                    vital_type,
+                    old_value: value,
+                    new_value: value,
+                },
+                world,
+            );
+        }
+    }
+
+    // re-apply status effects based on how full inventories are
+    let containing_entities = world
+        .query_filtered::<Entity, With<Container>>()
+        .iter(world)
+        .collect::<Vec<Entity>>();
+    for entity in containing_entities {
+        add_or_remove_overencumbered_for_entity(entity, world);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        component::Room,
+        resource::WeatherKind,
+        test_utils::get_entity_by_name,
+        world_setup::{connect_open, spawn_room},
+        Color, Direction, GameMessage, GameOptions, MapIcon, MessageCategory,
+    };
+
+    use super::*;
+
+    fn build_room(name: &str) -> Room {
+        Room {
+            name: name.to_string(),
+            description: format!("{name} description"),
+            map_icon: MapIcon::new_uniform(Color::Black, Color::White, ['[', ']']),
+        }
+    }
+
+    fn set_up_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(GameMap::new());
+        world.insert_resource(Time::new());
+        crate::resource::insert_resources(&mut world);
+
+        world
+    }
+
+    #[test]
+    fn save_and_load_remaps_entities() {
+        let mut world = set_up_world();
+        let room_1_coords = Coordinates {
+            x: 0,
+            y: 0,
+            z: 0,
+            parent: None,
+        };
+        let room_1 = spawn_room(build_room("room 1"), room_1_coords.clone(), &mut world);
+        let room_2 = spawn_room(
+            build_room("room 2"),
+            Coordinates {
+                x: 0,
+                y: 1,
+                z: 0,
+                parent: None,
+            },
+            &mut world,
+        );
+        connect_open(room_1, Direction::North, room_2, &mut world);
+        let item = crate::test_utils::spawn_entity_in_location("1", room_1, &mut world);
+        world.resource_mut::<Time>().tick();
+        world.resource_mut::<Weather>().kind = WeatherKind::Snow;
+        world.insert_resource(SpawnRoom(room_1_coords.clone()));
+
+        let mut saved = Vec::new();
+        save_world(&world, &mut saved).unwrap();
+
+        // offset entity IDs in the new world so any non-remapped references would point to the wrong entities
+        let mut loaded_world = set_up_world();
+        loaded_world.spawn_batch((0..10).map(|_| Volume(1.0)));
+        let player_entities = load_world(saved.as_slice(), &mut loaded_world).unwrap();
+        assert!(player_entities.is_empty());
+
+        assert_eq!(world.resource::<Time>(), loaded_world.resource::<Time>());
+        assert_eq!(
+            world.resource::<Weather>(),
+            loaded_world.resource::<Weather>()
+        );
+        assert!(room_1_coords == loaded_world.resource::<SpawnRoom>().0);
+
+        let loaded_room_1 = *loaded_world
+            .resource::<GameMap>()
+            .locations
+            .get(&room_1_coords)
+            .unwrap();
+        assert_ne!(room_1, loaded_room_1);
+        assert_eq!(
+            "room 1",
+            loaded_world.get::<Room>(loaded_room_1).unwrap().name
+        );
+
+        let loaded_item = get_entity_by_name("entity 1 name", &loaded_world).id();
+        assert_ne!(item, loaded_item);
+        crate::test_utils::assert_entity_in_container(loaded_item, loaded_room_1, &loaded_world);
+
+        let connection = loaded_world
+            .get::<Container>(loaded_room_1)
+            .unwrap()
+            .get_entities_including_invisible()
+            .iter()
+            .find_map(|entity| loaded_world.get::<Connection>(*entity))
+            .unwrap();
+        assert_eq!(Direction::North, connection.direction);
+        assert_eq!(
+            "room 2",
+            loaded_world
+                .get::<Room>(connection.destination)
+                .unwrap()
+                .name
+        );
+    }
+
+    #[test]
+    fn load_unsupported_version() {
+        let mut world = set_up_world();
+        let saved = r#"{"version":0,"time":{"day":1,"hour":7,"minute":0,"second":0},"spawn_room":null,"entities":[]}"#;
+
+        let result = load_world(saved.as_bytes(), &mut world);
+
+        assert!(matches!(result, Err(LoadError::UnsupportedVersion(0))));
+    }
+
+    #[test]
+    fn load_unknown_component() {
+        let mut world = set_up_world();
+        let saved = r#"{"version":1,"time":{"day":1,"hour":7,"minute":0,"second":0},"spawn_room":null,"entities":[{"id":4294967301,"components":{"blorp":{}}}]}"#;
+
+        let result = load_world(saved.as_bytes(), &mut world);
+
+        assert!(matches!(result, Err(LoadError::UnknownComponent(name)) if name == "blorp"));
+    }
+
+    #[test]
+    fn save_and_load_full_world() {
+        let game = crate::Game::new(GameOptions::default()).unwrap();
+        let mut saved = Vec::new();
+        save_world(&game.world.read().unwrap(), &mut saved).unwrap();
+
+        let mut loaded_world = set_up_world();
+        load_world(saved.as_slice(), &mut loaded_world).unwrap();
+
+        let world = game.world.read().unwrap();
+        assert_eq!(
+            world.iter_entities().count(),
+            loaded_world.iter_entities().count()
+        );
+        assert_eq!(
+            world.resource::<GameMap>().locations.len(),
+            loaded_world.resource::<GameMap>().locations.len()
+        );
+    }
+
+    #[test]
+    fn players_can_reattach_after_disconnecting() {
+        let mut game = crate::Game::new(GameOptions::default()).unwrap();
+        let (entity, _commands_sender, _messages_receiver) =
+            game.add_player(crate::NewCharacter::new("bob".to_string()));
+        let mut world = game.world.write().unwrap();
+        let player_id = world.get::<Player>(entity).unwrap().id;
+        crate::detach_player(player_id, &mut world);
+        assert!(world.get::<Player>(entity).unwrap().is_linkdead());
+        drop(world);
+
+        assert!(game.reattach_player("alice").is_none());
+        let (reattached_entity, _commands_sender, messages_receiver) =
+            game.reattach_player("bob").unwrap();
+        assert_eq!(entity, reattached_entity);
+        assert!(!game
+            .world
+            .read()
+            .unwrap()
+            .get::<Player>(entity)
+            .unwrap()
+            .is_linkdead());
+
+        // connecting again while still connected should take over the existing connection
+        let (taken_over_entity, _commands_sender, _messages_receiver) =
+            game.reattach_player("bob").unwrap();
+        assert_eq!(entity, taken_over_entity);
+        assert!(messages_receiver.try_iter().any(|(message, _)| matches!(
+            message,
+            GameMessage::Message {
+                category: MessageCategory::System,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn players_can_reattach_after_loading() {
+        let mut game = crate::Game::new(GameOptions::default()).unwrap();
+        game.add_player(crate::NewCharacter::new("bob".to_string()));
+        let path = std::env::temp_dir().join(format!(
+            "textpocalypse-save-test-{}.json",
+            std::process::id()
+        ));
+        game.save(&path).unwrap();
+
+        let mut loaded_game = crate::Game::load(&path, GameOptions::default()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let (entity, _commands_sender, _messages_receiver) =
+            loaded_game.reattach_player("bob").unwrap();
+        let world = loaded_game.world.read().unwrap();
+        assert_eq!("bob", world.get::<Description>(entity).unwrap().name);
+        assert!(!world.get::<Player>(entity).unwrap().is_linkdead());
+    }
+
+    #[test]
+    fn overencumbered_entities_stay_overencumbered_after_loading() {
+        let game = crate::Game::new(GameOptions::default()).unwrap();
+        let mut world = game.world.write().unwrap();
+        let entity = world
+            .spawn((
+                Vitals::new(),
+                Container::new(Some(Volume(10.0)), Some(Weight(25.0))),
+            ))
+            .id();
+        let boulder = world.spawn(Weight(1000.0)).id();
+        crate::move_entity(boulder, entity, &mut world);
+        assert_eq!(1, count_overencumbered(&world));
+        drop(world);
+
+        let path = std::env::temp_dir().join(format!(
+            "textpocalypse-save-test-overencumbered-{}.json",
+            std::process::id()
+        ));
+        game.save(&path).unwrap();
+        let loaded_game = crate::Game::load(&path, GameOptions::default()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(1, count_overencumbered(&loaded_game.world.read().unwrap()));
+    }
+
+    fn count_overencumbered(world: &World) -> usize {
+        world
+            .iter_entities()
+            .filter(|entity| {
+                StatusEffects::get_all(entity.id(), world)
+                    .iter()
+                    .any(|status_effect| status_effect.name == "Overencumbered")
+            })
+            .count()
+    }
+}
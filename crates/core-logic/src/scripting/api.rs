@@ -0,0 +1,368 @@
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use bevy_ecs::prelude::*;
+use log::error;
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Map};
+
+use crate::{
+    action::{ActionNotificationSender, MoveAction, SayAction},
+    component::{ActionQueue, Container, Description, Location, Player, Room, Vitals},
+    parse_input, send_message, BasicTokens, Direction, DynamicMessage, DynamicMessageLocation,
+    Invisible, MessageCategory, MessageDelay, MessageFormat, SurroundingsMessageCategory,
+};
+
+/// The result of a script API function that can fail.
+type ScriptResult<T> = Result<T, Box<EvalAltResult>>;
+
+/// A handle scripts use to look at and affect the game world.
+///
+/// Scripts get one of these as the first argument to every function the game calls. It's only valid until that function returns.
+#[derive(Clone)]
+pub struct ScriptWorld(Arc<RwLock<World>>);
+
+impl ScriptWorld {
+    /// Wraps the provided world in a handle.
+    pub(super) fn new(world: World) -> ScriptWorld {
+        ScriptWorld(Arc::new(RwLock::new(world)))
+    }
+
+    /// Gets the world back out of this handle.
+    ///
+    /// If a script somehow kept another copy of this handle around, the world is taken out from under it, leaving that copy with an empty world.
+    pub(super) fn into_inner(self) -> World {
+        match Arc::try_unwrap(self.0) {
+            Ok(lock) => lock.into_inner().unwrap_or_else(PoisonError::into_inner),
+            Err(shared) => {
+                error!("A script kept a handle to the world after returning");
+                std::mem::take(&mut *shared.write().unwrap_or_else(PoisonError::into_inner))
+            }
+        }
+    }
+
+    /// Determines whether the provided value is, or contains, a handle to the world.
+    pub(super) fn is_in(value: &Dynamic) -> bool {
+        if value.is::<ScriptWorld>() {
+            return true;
+        }
+
+        if let Some(array) = value.read_lock::<Array>() {
+            return array.iter().any(ScriptWorld::is_in);
+        }
+
+        if let Some(map) = value.read_lock::<Map>() {
+            return map.values().any(ScriptWorld::is_in);
+        }
+
+        false
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, World> {
+        self.0.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, World> {
+        self.0.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Registers the types and functions scripts can use.
+pub(super) fn register_api(engine: &mut Engine) {
+    engine
+        .register_type_with_name::<Entity>("Entity")
+        .register_fn("==", |a: Entity, b: Entity| a == b)
+        .register_fn("!=", |a: Entity, b: Entity| a != b)
+        .register_fn("to_string", |entity: &mut Entity| entity.to_string())
+        .register_fn("to_debug", |entity: &mut Entity| format!("{entity:?}"));
+
+    engine
+        .register_type_with_name::<ScriptWorld>("World")
+        .register_fn("name", name)
+        .register_fn("location", location)
+        .register_fn("contents", contents)
+        .register_fn("exits", exits)
+        .register_fn("can_see", can_see)
+        .register_fn("is_player", is_player)
+        .register_fn("is_alive", is_alive)
+        .register_fn("health", health)
+        .register_fn("has_queued_actions", has_queued_actions)
+        .register_fn("say", say)
+        .register_fn("move", move_in_direction)
+        .register_fn("queue_command", queue_command)
+        .register_fn("message", message)
+        .register_fn(
+            "message",
+            |world: &mut ScriptWorld, source: Entity, format: &str| {
+                message(world, source, format, Map::new())
+            },
+        )
+        .register_fn("message_in", message_in)
+        .register_fn(
+            "message_in",
+            |world: &mut ScriptWorld, location: Entity, format: &str| {
+                message_in(world, location, format, Map::new())
+            },
+        )
+        .register_fn("message_to", message_to)
+        .register_fn(
+            "message_to",
+            |world: &mut ScriptWorld, receiver: Entity, format: &str| {
+                message_to(world, receiver, format, Map::new())
+            },
+        );
+
+    engine.register_fn("random", rand::random::<f64>);
+}
+
+/// Makes sure the provided entity exists, so scripts can't cause panics by passing in entities that have been despawned.
+fn verify_exists(entity: Entity, world: &World) -> ScriptResult<()> {
+    if world.get_entity(entity).is_none() {
+        return Err(format!("entity {entity} doesn't exist").into());
+    }
+
+    Ok(())
+}
+
+/// Gets the name of an entity or room, or an empty string if it doesn't have one.
+fn name(world: &mut ScriptWorld, entity: Entity) -> String {
+    let world = world.read();
+    if let Some(description) = world.get::<Description>(entity) {
+        return description.name.clone();
+    }
+    if let Some(room) = world.get::<Room>(entity) {
+        return room.name.clone();
+    }
+
+    String::new()
+}
+
+/// Gets the entity an entity is in, or `()` if it isn't in anything.
+fn location(world: &mut ScriptWorld, entity: Entity) -> Dynamic {
+    world
+        .read()
+        .get::<Location>(entity)
+        .map_or(Dynamic::UNIT, |location| Dynamic::from(location.id))
+}
+
+/// Gets all the entities in an entity, including invisible ones.
+fn contents(world: &mut ScriptWorld, entity: Entity) -> Array {
+    let world = world.read();
+    let mut entities = world
+        .get::<Container>(entity)
+        .map(|container| {
+            container
+                .get_entities_including_invisible()
+                .iter()
+                .copied()
+                .collect::<Vec<Entity>>()
+        })
+        .unwrap_or_default();
+    entities.sort();
+
+    entities.into_iter().map(Dynamic::from).collect()
+}
+
+/// Gets the directions an entity can move in from the room it's in.
+fn exits(world: &mut ScriptWorld, entity: Entity) -> Array {
+    let world = world.read();
+    let Some(container) = world
+        .get::<Location>(entity)
+        .and_then(|location| world.get::<Container>(location.id))
+    else {
+        return Array::new();
+    };
+
+    let mut directions = container
+        .get_connections(entity, &world)
+        .into_iter()
+        .map(|(_, connection)| connection.direction)
+        .collect::<Vec<Direction>>();
+    directions.sort();
+
+    directions
+        .into_iter()
+        .map(|direction| Dynamic::from(direction.to_string()))
+        .collect()
+}
+
+/// Determines whether `looking_entity` can see `entity`.
+fn can_see(world: &mut ScriptWorld, looking_entity: Entity, entity: Entity) -> bool {
+    Invisible::is_visible_to(entity, looking_entity, &world.read())
+}
+
+/// Determines whether an entity is controlled by a player.
+fn is_player(world: &mut ScriptWorld, entity: Entity) -> bool {
+    world.read().get::<Player>(entity).is_some()
+}
+
+/// Determines whether an entity is alive.
+fn is_alive(world: &mut ScriptWorld, entity: Entity) -> bool {
+    world.read().get::<Vitals>(entity).is_some()
+}
+
+/// Gets the current health of an entity, or `()` if it isn't alive.
+fn health(world: &mut ScriptWorld, entity: Entity) -> Dynamic {
+    world
+        .read()
+        .get::<Vitals>(entity)
+        .map_or(Dynamic::UNIT, |vitals| {
+            Dynamic::from_float(f64::from(vitals.health.get()))
+        })
+}
+
+/// Determines whether an entity has any actions waiting to be performed.
+fn has_queued_actions(world: &mut ScriptWorld, entity: Entity) -> bool {
+    ActionQueue::has_any_queued_actions(&world.read(), entity)
+}
+
+/// Queues an action for an entity to say something.
+fn say(world: &mut ScriptWorld, entity: Entity, text: &str) -> ScriptResult<()> {
+    let mut world = world.write();
+    verify_exists(entity, &world)?;
+    ActionQueue::queue(
+        &mut world,
+        entity,
+        Box::new(SayAction {
+            text: text.to_string(),
+            notification_sender: ActionNotificationSender::new(),
+        }),
+    );
+
+    Ok(())
+}
+
+/// Queues an action for an entity to move in a direction.
+fn move_in_direction(world: &mut ScriptWorld, entity: Entity, direction: &str) -> ScriptResult<()> {
+    let direction = Direction::parse(&direction.to_lowercase())
+        .ok_or_else(|| format!("{direction:?} is not a direction"))?;
+
+    let mut world = world.write();
+    verify_exists(entity, &world)?;
+    ActionQueue::queue(
+        &mut world,
+        entity,
+        Box::new(MoveAction {
+            direction,
+            slowed_ticks: 0,
+            notification_sender: ActionNotificationSender::new(),
+        }),
+    );
+
+    Ok(())
+}
+
+/// Queues an action for an entity as if it had entered the provided command.
+fn queue_command(world: &mut ScriptWorld, entity: Entity, command: &str) -> ScriptResult<()> {
+    let mut world = world.write();
+    verify_exists(entity, &world)?;
+    let action = parse_input(command, entity, &world)
+        .map_err(|e| format!("unable to parse command {command:?}: {e:?}"))?;
+    ActionQueue::queue(&mut world, entity, action);
+
+    Ok(())
+}
+
+/// Sends a message about something `source` did to everyone else in its room.
+fn message(world: &mut ScriptWorld, source: Entity, format: &str, tokens: Map) -> ScriptResult<()> {
+    let world = world.read();
+    verify_exists(source, &world)?;
+    let message = DynamicMessage::new_third_person(
+        MessageCategory::Surroundings(SurroundingsMessageCategory::Action),
+        MessageDelay::Short,
+        parse_format(format)?,
+        build_tokens(tokens),
+    );
+
+    send_dynamic_message(
+        message,
+        Some(source),
+        DynamicMessageLocation::SourceEntity,
+        &world,
+    )
+}
+
+/// Sends a message to everyone in a room.
+fn message_in(
+    world: &mut ScriptWorld,
+    location: Entity,
+    format: &str,
+    tokens: Map,
+) -> ScriptResult<()> {
+    let world = world.read();
+    verify_exists(location, &world)?;
+    let message = DynamicMessage::new_third_person(
+        MessageCategory::Surroundings(SurroundingsMessageCategory::Flavor),
+        MessageDelay::Short,
+        parse_format(format)?,
+        build_tokens(tokens),
+    );
+
+    send_dynamic_message(
+        message,
+        None,
+        DynamicMessageLocation::Location(location),
+        &world,
+    )
+}
+
+/// Sends a message to a single entity.
+fn message_to(
+    world: &mut ScriptWorld,
+    receiver: Entity,
+    format: &str,
+    tokens: Map,
+) -> ScriptResult<()> {
+    let world = world.read();
+    verify_exists(receiver, &world)?;
+    let Some(location) = world.get::<Location>(receiver) else {
+        return Ok(());
+    };
+    let message = DynamicMessage::new_third_person(
+        MessageCategory::Surroundings(SurroundingsMessageCategory::Flavor),
+        MessageDelay::Short,
+        parse_format(format)?,
+        build_tokens(tokens),
+    )
+    .only_send_to(receiver);
+
+    send_dynamic_message(
+        message,
+        None,
+        DynamicMessageLocation::Location(location.id),
+        &world,
+    )
+}
+
+/// Parses a message format provided by a script.
+fn parse_format(format: &str) -> ScriptResult<MessageFormat<BasicTokens>> {
+    MessageFormat::new(format)
+        .map_err(|e| format!("invalid message format {format:?}: {e:?}").into())
+}
+
+/// Builds message tokens from a map provided by a script. Entities become entity tokens, and anything else becomes a string token.
+fn build_tokens(map: Map) -> BasicTokens {
+    map.into_iter()
+        .fold(BasicTokens::new(), |tokens, (name, value)| {
+            if value.is::<Entity>() {
+                tokens.with_entity(name.as_str().into(), value.cast::<Entity>())
+            } else {
+                tokens.with_string(name.as_str().into(), value.to_string())
+            }
+        })
+}
+
+/// Sends a message built by a script.
+fn send_dynamic_message(
+    message: DynamicMessage<BasicTokens>,
+    source: Option<Entity>,
+    location: DynamicMessageLocation,
+    world: &World,
+) -> ScriptResult<()> {
+    let messages = message
+        .into_game_messages(source, location, world)
+        .map_err(|e| format!("unable to build message: {e:?}"))?;
+    for (entity, message) in messages {
+        send_message(world, entity, message);
+    }
+
+    Ok(())
+}
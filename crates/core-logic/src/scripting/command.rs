@@ -0,0 +1,404 @@
+use std::{collections::HashSet, fmt::Display};
+
+use bevy_ecs::prelude::*;
+use regex::Regex;
+use rhai::{Dynamic, Map};
+use serde::Deserialize;
+
+use crate::{
+    command_format::{
+        any_text_part, direction_part, entity_part, literal_part, CommandFormat,
+        CommandFormatDescription, CommandFormatPart, CommandPartId, DirectionMatchMode,
+    },
+    component::{Description, Location},
+    input_parser::InputParseError,
+    Direction,
+};
+
+use super::{CompiledScript, ScriptCompileError};
+
+/// The name of the function a script command's check is wrapped in.
+const CHECK_FUNCTION: &str = "check";
+
+/// The name of the function a script command's body is wrapped in.
+const BODY_FUNCTION: &str = "run";
+
+/// The parameters script commands' checks and bodies are called with.
+const FUNCTION_PARAMETERS: &str = "world, me, performer, args";
+
+/// The kinds of values the placeholders in a script command's format can be filled in with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptCommandPartKind {
+    /// The entity the command is attached to.
+    Target,
+    /// Any entity the performer can interact with.
+    Entity,
+    /// Any text.
+    Text,
+    /// A direction.
+    Direction,
+}
+
+/// A rule that a part of a script command has to follow for the command to be valid.
+#[derive(Debug)]
+pub enum ScriptCommandValidatorRule {
+    /// The text entered for a text part has to match the provided pattern.
+    Matches(String),
+    /// The entity entered for an entity part has to be in the performer's inventory.
+    Held,
+}
+
+/// Checks a part of a script command.
+#[derive(Debug)]
+pub struct ScriptCommandValidator {
+    /// The name of the part to check.
+    pub part: String,
+    /// The rule the part has to follow.
+    pub rule: ScriptCommandValidatorRule,
+    /// The error to show the performer if the part doesn't follow the rule.
+    pub error: String,
+}
+
+/// Everything needed to build a script command.
+pub struct ScriptCommandDefinition<'a> {
+    /// The format of the command, like `"tune <radio> to <station>"`.
+    pub format: &'a str,
+    /// The kinds of the placeholders in the format, by name.
+    pub parts: Vec<(String, ScriptCommandPartKind)>,
+    /// Rules the parts have to follow.
+    pub validators: Vec<ScriptCommandValidator>,
+    /// The number of ticks it takes to perform the command.
+    pub tick_cost: u32,
+    /// The source of a script to run before performing the command, which can return a string to prevent the command from being performed.
+    pub check: Option<&'a str>,
+    /// The source of the script to run to perform the command.
+    pub body: &'a str,
+}
+
+/// An error building a script command.
+#[derive(Debug)]
+pub enum ScriptCommandError {
+    /// The format of the command is invalid.
+    Format(String),
+    /// The validator at the provided index is invalid.
+    Validator {
+        /// The index of the validator.
+        index: usize,
+        /// What's wrong with it.
+        message: String,
+    },
+    /// The check doesn't compile.
+    Check(ScriptCompileError),
+    /// The body doesn't compile.
+    Body(ScriptCompileError),
+}
+
+impl Display for ScriptCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScriptCommandError::Format(message) => write!(f, "invalid format: {message}"),
+            ScriptCommandError::Validator { message, .. } => {
+                write!(f, "invalid validator: {message}")
+            }
+            ScriptCommandError::Check(e) => write!(f, "error in check: {e}"),
+            ScriptCommandError::Body(e) => write!(f, "error in body: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ScriptCommandError {}
+
+/// A command content authors have defined, which can be attached to items and rooms.
+#[derive(Debug)]
+pub struct ScriptCommand {
+    /// The ID of the command.
+    pub id: String,
+    /// The format the command is entered in.
+    format: CommandFormat,
+    /// The names and kinds of the command's parts, in the order they appear in the format.
+    parts: Vec<(String, ScriptCommandPartKind)>,
+    /// Rules the parts have to follow, with compiled patterns for `Matches` rules.
+    validators: Vec<(ScriptCommandValidator, Option<Regex>)>,
+    /// The number of ticks it takes to perform the command.
+    pub tick_cost: u32,
+    /// The script to run before performing the command.
+    check: Option<CompiledScript>,
+    /// The script to run to perform the command.
+    body: CompiledScript,
+}
+
+/// The values parsed from a script command entered by an entity.
+#[derive(Debug)]
+pub struct ParsedScriptCommand {
+    /// The entity the command is attached to.
+    pub me: Entity,
+    /// The parsed parts of the command, by name.
+    pub args: Map,
+}
+
+impl ScriptCommand {
+    /// Builds a script command, compiling its scripts with the provided function.
+    pub(super) fn build(
+        id: String,
+        definition: ScriptCommandDefinition,
+        compile: impl Fn(&str) -> Result<CompiledScript, ScriptCompileError>,
+    ) -> Result<ScriptCommand, ScriptCommandError> {
+        let format = build_format(definition.format, &definition.parts)
+            .map_err(ScriptCommandError::Format)?;
+
+        let validators = definition
+            .validators
+            .into_iter()
+            .enumerate()
+            .map(|(index, validator)| {
+                let pattern = check_validator(&validator, &definition.parts)
+                    .map_err(|message| ScriptCommandError::Validator { index, message })?;
+                Ok((validator, pattern))
+            })
+            .collect::<Result<Vec<_>, ScriptCommandError>>()?;
+
+        let check = definition
+            .check
+            .map(|check| compile(&wrap_in_function(CHECK_FUNCTION, check)))
+            .transpose()
+            .map_err(ScriptCommandError::Check)?;
+        let body = compile(&wrap_in_function(BODY_FUNCTION, definition.body))
+            .map_err(ScriptCommandError::Body)?;
+
+        Ok(ScriptCommand {
+            id,
+            format,
+            parts: definition.parts,
+            validators,
+            tick_cost: definition.tick_cost,
+            check,
+            body,
+        })
+    }
+
+    /// Gets the format description for this command.
+    pub fn get_format_description(&self) -> CommandFormatDescription {
+        self.format.get_format_description()
+    }
+
+    /// Gets the format description for this command, with the target filled in with the provided entity if the command has a target.
+    pub fn get_format_description_for(
+        &self,
+        target: Entity,
+        world: &World,
+    ) -> CommandFormatDescription {
+        let description = self.get_format_description();
+        match self.get_target_part() {
+            Some(target_part) => description.with_targeted_entity(target_part, target, world),
+            None => description,
+        }
+    }
+
+    /// Parses input entered by `source_entity` into the values to run this command with, if `entity` is the one this command is attached to.
+    pub fn parse(
+        &self,
+        input: &str,
+        entity: Entity,
+        source_entity: Entity,
+        world: &World,
+    ) -> Result<ParsedScriptCommand, InputParseError> {
+        let parsed = self.format.parse(input, source_entity, world)?;
+
+        if let Some(target_part) = self.get_target_part() {
+            let target = parsed.get(target_part);
+            if target != entity {
+                let verb = self
+                    .get_format_description()
+                    .to_string()
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or_default()
+                    .to_string();
+                let target_name = if target == source_entity {
+                    "yourself".to_string()
+                } else {
+                    Description::get_reference_name(target, Some(source_entity), world)
+                };
+                return Err(InputParseError::PostFormatParse(format!(
+                    "You can't {verb} {target_name}."
+                )));
+            }
+        }
+
+        let mut args = Map::new();
+        for (name, kind) in &self.parts {
+            let value = match kind {
+                ScriptCommandPartKind::Target | ScriptCommandPartKind::Entity => {
+                    Dynamic::from(parsed.get(CommandPartId::<Entity>::interned(name)))
+                }
+                ScriptCommandPartKind::Text => {
+                    Dynamic::from(parsed.get(CommandPartId::<String>::interned(name)))
+                }
+                ScriptCommandPartKind::Direction => Dynamic::from(
+                    parsed
+                        .get(CommandPartId::<Direction>::interned(name))
+                        .to_string(),
+                ),
+            };
+            args.insert(name.as_str().into(), value);
+        }
+
+        for (validator, pattern) in &self.validators {
+            let Some(value) = args.get(validator.part.as_str()) else {
+                continue;
+            };
+            let is_valid = match (&validator.rule, pattern) {
+                (ScriptCommandValidatorRule::Matches(_), Some(pattern)) => {
+                    pattern.is_match(&value.to_string())
+                }
+                (ScriptCommandValidatorRule::Held, _) => value
+                    .clone()
+                    .try_cast::<Entity>()
+                    .and_then(|value| world.get::<Location>(value))
+                    .is_some_and(|location| location.id == source_entity),
+                _ => true,
+            };
+            if !is_valid {
+                return Err(InputParseError::PostFormatParse(validator.error.clone()));
+            }
+        }
+
+        Ok(ParsedScriptCommand { me: entity, args })
+    }
+
+    /// Gets the check script of this command, if it has one, and the name of the function to call in it.
+    pub(super) fn get_check(&self) -> Option<(&CompiledScript, &'static str)> {
+        self.check.as_ref().map(|check| (check, CHECK_FUNCTION))
+    }
+
+    /// Gets the body script of this command, and the name of the function to call in it.
+    pub(super) fn get_body(&self) -> (&CompiledScript, &'static str) {
+        (&self.body, BODY_FUNCTION)
+    }
+
+    /// Gets the ID of this command's target part, if it has one.
+    fn get_target_part(&self) -> Option<CommandPartId<Entity>> {
+        self.parts
+            .iter()
+            .find(|(_, kind)| *kind == ScriptCommandPartKind::Target)
+            .map(|(name, _)| CommandPartId::interned(name))
+    }
+}
+
+/// Wraps the provided script source in a function with the provided name.
+///
+/// The start of the function is on the same line as the start of the source, so line numbers in compile errors still match up.
+fn wrap_in_function(name: &str, source: &str) -> String {
+    format!("fn {name}({FUNCTION_PARAMETERS}) {{ {source}\n}}")
+}
+
+/// Builds a command format from a format string like `"tune <radio> to <station>"`.
+fn build_format(
+    format_string: &str,
+    parts: &[(String, ScriptCommandPartKind)],
+) -> Result<CommandFormat, String> {
+    let mut format: Option<CommandFormat> = None;
+    let mut used_part_names = HashSet::new();
+    let mut previous_was_placeholder = false;
+    let mut remaining = format_string;
+    while !remaining.is_empty() {
+        let part = if let Some(after_open) = remaining.strip_prefix('<') {
+            let Some((name, after_close)) = after_open.split_once('>') else {
+                return Err("a placeholder is missing its closing '>'".to_string());
+            };
+            if previous_was_placeholder {
+                return Err(format!(
+                    "placeholder <{name}> needs to be separated from the one before it by some text"
+                ));
+            }
+            let Some((_, kind)) = parts.iter().find(|(part_name, _)| part_name == name) else {
+                return Err(format!("placeholder <{name}> isn't in the command's parts"));
+            };
+            if !used_part_names.insert(name) {
+                return Err(format!("placeholder <{name}> is used more than once"));
+            }
+
+            remaining = after_close;
+            previous_was_placeholder = true;
+            build_placeholder_part(name, *kind)
+        } else {
+            let literal_end = remaining.find('<').unwrap_or(remaining.len());
+            let literal = &remaining[..literal_end];
+            remaining = &remaining[literal_end..];
+            previous_was_placeholder = false;
+            literal_part(literal)
+        };
+
+        format = Some(match format {
+            Some(format) => format.then(part),
+            None => {
+                if !matches!(part, CommandFormatPart::Literal(..)) {
+                    return Err("the format needs to start with some text".to_string());
+                }
+                CommandFormat::new(part)
+            }
+        });
+    }
+
+    if let Some((name, _)) = parts
+        .iter()
+        .find(|(name, _)| !used_part_names.contains(name.as_str()))
+    {
+        return Err(format!("part {name:?} isn't in the format"));
+    }
+    if parts
+        .iter()
+        .filter(|(_, kind)| *kind == ScriptCommandPartKind::Target)
+        .count()
+        > 1
+    {
+        return Err("only one part can be the target".to_string());
+    }
+
+    format.ok_or_else(|| "the format is empty".to_string())
+}
+
+/// Builds the part to parse a placeholder with the provided name and kind.
+fn build_placeholder_part(name: &str, kind: ScriptCommandPartKind) -> CommandFormatPart {
+    let part = match kind {
+        ScriptCommandPartKind::Target | ScriptCommandPartKind::Entity => {
+            entity_part(CommandPartId::interned(name)).with_if_unparsed("what")
+        }
+        ScriptCommandPartKind::Text => any_text_part(CommandPartId::interned(name)),
+        ScriptCommandPartKind::Direction => direction_part(
+            CommandPartId::interned(name),
+            DirectionMatchMode::OnlyValidDirections,
+        ),
+    };
+
+    part.with_placeholder_for_format_string(name)
+}
+
+/// Makes sure a validator can be applied to the part it refers to, and compiles its pattern if it has one.
+fn check_validator(
+    validator: &ScriptCommandValidator,
+    parts: &[(String, ScriptCommandPartKind)],
+) -> Result<Option<Regex>, String> {
+    let Some((_, kind)) = parts.iter().find(|(name, _)| *name == validator.part) else {
+        return Err(format!("there's no part named {:?}", validator.part));
+    };
+
+    match &validator.rule {
+        ScriptCommandValidatorRule::Matches(pattern) => {
+            if *kind != ScriptCommandPartKind::Text {
+                return Err("only text parts can be matched against a pattern".to_string());
+            }
+            Regex::new(pattern).map(Some).map_err(|e| e.to_string())
+        }
+        ScriptCommandValidatorRule::Held => {
+            if !matches!(
+                kind,
+                ScriptCommandPartKind::Target | ScriptCommandPartKind::Entity
+            ) {
+                return Err("only entity parts can be required to be held".to_string());
+            }
+            Ok(None)
+        }
+    }
+}
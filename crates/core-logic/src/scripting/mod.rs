@@ -0,0 +1,376 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    sync::Arc,
+};
+
+use bevy_ecs::prelude::*;
+use log::{debug, warn};
+use rhai::{module_resolvers::DummyModuleResolver, CallFnOptions, Dynamic, Engine, Scope, AST};
+
+mod api;
+pub use api::ScriptWorld;
+
+mod command;
+pub use command::ParsedScriptCommand;
+pub use command::ScriptCommand;
+pub use command::ScriptCommandDefinition;
+pub use command::ScriptCommandError;
+pub use command::ScriptCommandPartKind;
+pub use command::ScriptCommandValidator;
+pub use command::ScriptCommandValidatorRule;
+
+/// The maximum number of operations a single call into a script can perform, so a script stuck in a loop can't hang the game.
+const MAX_OPERATIONS: u64 = 100_000;
+
+/// The maximum depth of function calls in a script.
+const MAX_CALL_LEVELS: usize = 32;
+
+/// The maximum length of strings created by scripts.
+const MAX_STRING_SIZE: usize = 10_000;
+
+/// The maximum number of elements in arrays and object maps created by scripts.
+const MAX_COLLECTION_SIZE: usize = 1_000;
+
+/// The scripts content authors have defined, compiled and ready to be run.
+#[derive(Resource)]
+pub struct ScriptCatalog {
+    /// The engine to run the scripts with.
+    engine: Arc<Engine>,
+    /// The compiled scripts, by ID.
+    scripts: HashMap<String, CompiledScript>,
+    /// The commands that can be attached to items and rooms, by ID.
+    commands: HashMap<String, Arc<ScriptCommand>>,
+}
+
+/// A compiled script.
+#[derive(Debug, Clone)]
+struct CompiledScript {
+    /// The compiled script.
+    ast: Arc<AST>,
+    /// The names and number of parameters of the functions the script defines.
+    functions: HashSet<(String, usize)>,
+}
+
+/// An error compiling a script.
+#[derive(Debug)]
+pub struct ScriptCompileError {
+    /// The line in the script the error is on, if it's on a specific line.
+    pub line: Option<usize>,
+    /// What went wrong.
+    pub message: String,
+}
+
+impl Display for ScriptCompileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.message.fmt(f)
+    }
+}
+
+impl std::error::Error for ScriptCompileError {}
+
+impl Default for ScriptCatalog {
+    fn default() -> Self {
+        ScriptCatalog::new()
+    }
+}
+
+impl ScriptCatalog {
+    /// Creates a catalog with no scripts in it.
+    pub fn new() -> ScriptCatalog {
+        ScriptCatalog {
+            engine: Arc::new(build_engine()),
+            scripts: HashMap::new(),
+            commands: HashMap::new(),
+        }
+    }
+
+    /// Compiles the provided script source and adds it to the catalog, replacing any script that already has the provided ID.
+    pub fn add(&mut self, id: String, source: &str) -> Result<(), ScriptCompileError> {
+        let script = self.compile(source)?;
+        self.scripts.insert(id, script);
+
+        Ok(())
+    }
+
+    /// Determines whether there's a script with the provided ID.
+    pub fn contains(&self, id: &str) -> bool {
+        self.scripts.contains_key(id)
+    }
+
+    /// Builds a command from the provided definition and adds it to the catalog, replacing any command that already has the provided ID.
+    pub fn add_command(
+        &mut self,
+        id: String,
+        definition: ScriptCommandDefinition,
+    ) -> Result<(), ScriptCommandError> {
+        let command = ScriptCommand::build(id.clone(), definition, |source| self.compile(source))?;
+        self.commands.insert(id, Arc::new(command));
+
+        Ok(())
+    }
+
+    /// Gets the command with the provided ID, if there is one.
+    pub fn get_command(&self, id: &str) -> Option<Arc<ScriptCommand>> {
+        self.commands.get(id).cloned()
+    }
+
+    /// Compiles the provided script source.
+    fn compile(&self, source: &str) -> Result<CompiledScript, ScriptCompileError> {
+        let ast = self
+            .engine
+            .compile(source)
+            .map_err(|e| ScriptCompileError {
+                line: e.position().line(),
+                message: e.err_type().to_string(),
+            })?;
+
+        let functions = ast
+            .iter_functions()
+            .map(|function| (function.name.to_string(), function.params.len()))
+            .collect();
+
+        Ok(CompiledScript {
+            ast: Arc::new(ast),
+            functions,
+        })
+    }
+}
+
+/// Builds the engine to run scripts with.
+///
+/// Scripts can only affect the game through the API registered here. They can't load other files, and are limited in how much work they can do in a single call.
+fn build_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine
+        .set_module_resolver(DummyModuleResolver::new())
+        .disable_symbol("eval")
+        .set_max_operations(MAX_OPERATIONS)
+        .set_max_call_levels(MAX_CALL_LEVELS)
+        .set_max_string_size(MAX_STRING_SIZE)
+        .set_max_array_size(MAX_COLLECTION_SIZE)
+        .set_max_map_size(MAX_COLLECTION_SIZE)
+        .on_print(|text| debug!("Script printed: {text}"))
+        .on_debug(|text, source, position| debug!("Script debug ({source:?}, {position}): {text}"));
+
+    api::register_api(&mut engine);
+
+    engine
+}
+
+/// Calls the function with the provided name in the script with the provided ID, if the script defines it.
+///
+/// The function is passed a `ScriptWorld` followed by the provided arguments.
+/// Errors in the script are logged rather than returned, so a broken script can't bring the game down with it.
+pub fn call_script_function(
+    script_id: &str,
+    function: &str,
+    args: Vec<Dynamic>,
+    world: &mut World,
+) {
+    let Some(catalog) = world.get_resource::<ScriptCatalog>() else {
+        return;
+    };
+    let Some(script) = catalog.scripts.get(script_id) else {
+        warn!("No script with ID {script_id:?} exists");
+        return;
+    };
+    let script = script.clone();
+
+    call_compiled_function(
+        &format!("script {script_id:?}"),
+        &script,
+        function,
+        args,
+        world,
+    );
+}
+
+/// Runs the check of the provided command with the provided arguments, if it has one.
+///
+/// Returns the reason the command can't be performed, if the check returns one.
+pub fn run_script_command_check(
+    command: &ScriptCommand,
+    args: Vec<Dynamic>,
+    world: &mut World,
+) -> Option<String> {
+    let (script, function) = command.get_check()?;
+
+    call_compiled_function(
+        &format!("check of command {:?}", command.id),
+        script,
+        function,
+        args,
+        world,
+    )
+    .and_then(|result| result.into_string().ok())
+}
+
+/// Runs the body of the provided command with the provided arguments.
+///
+/// Returns the message to show the entity performing the command, if the body returns one.
+pub fn run_script_command_body(
+    command: &ScriptCommand,
+    args: Vec<Dynamic>,
+    world: &mut World,
+) -> Option<String> {
+    let (script, function) = command.get_body();
+
+    call_compiled_function(
+        &format!("body of command {:?}", command.id),
+        script,
+        function,
+        args,
+        world,
+    )
+    .and_then(|result| result.into_string().ok())
+}
+
+/// Calls the function with the provided name in the provided script, if the script defines it.
+///
+/// Returns what the function returned, or `None` if it isn't defined or there was an error running it.
+fn call_compiled_function(
+    script_description: &str,
+    script: &CompiledScript,
+    function: &str,
+    args: Vec<Dynamic>,
+    world: &mut World,
+) -> Option<Dynamic> {
+    if !script
+        .functions
+        .contains(&(function.to_string(), args.len() + 1))
+    {
+        // scripts only need to define the functions they care about
+        return None;
+    }
+    let engine = world.get_resource::<ScriptCatalog>()?.engine.clone();
+
+    // the world is moved into the handle for the duration of the call, so the script's API functions can get at it
+    let script_world = ScriptWorld::new(std::mem::take(world));
+    let mut all_args = vec![Dynamic::from(script_world.clone())];
+    all_args.extend(args);
+
+    let result = engine
+        .call_fn_with_options::<Dynamic>(
+            CallFnOptions::new().eval_ast(false),
+            &mut Scope::new(),
+            &script.ast,
+            function,
+            all_args,
+        )
+        .map_err(|e| e.to_string())
+        .and_then(|result| {
+            if ScriptWorld::is_in(&result) {
+                Err("scripts can't return the world".to_string())
+            } else {
+                Ok(result)
+            }
+        });
+
+    *world = script_world.into_inner();
+
+    match result {
+        Ok(result) => Some(result),
+        Err(error) => {
+            warn!("Error in {script_description} calling {function}: {error}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compile_error_has_line() {
+        let mut catalog = ScriptCatalog::new();
+        let error = catalog
+            .add(
+                "broken".to_string(),
+                "fn on_tick(world, me) {\n    let x = ;\n}",
+            )
+            .unwrap_err();
+
+        assert_eq!(Some(2), error.line);
+        assert!(!catalog.contains("broken"));
+    }
+
+    #[test]
+    fn cannot_import_modules() {
+        let mut catalog = ScriptCatalog::new();
+        catalog
+            .add(
+                "importer".to_string(),
+                "fn on_tick(world, me) { import \"secrets\" as secrets; }",
+            )
+            .unwrap();
+
+        let mut world = World::new();
+        world.insert_resource(catalog);
+        let entity = world.spawn_empty().id();
+
+        // shouldn't panic, and the world should still be there afterwards
+        call_script_function(
+            "importer",
+            "on_tick",
+            vec![Dynamic::from(entity)],
+            &mut world,
+        );
+        assert!(world.get_resource::<ScriptCatalog>().is_some());
+    }
+
+    #[test]
+    fn infinite_loop_is_stopped() {
+        let mut catalog = ScriptCatalog::new();
+        catalog
+            .add("looper".to_string(), "fn on_tick(world, me) { loop { } }")
+            .unwrap();
+
+        let mut world = World::new();
+        world.insert_resource(catalog);
+        let entity = world.spawn_empty().id();
+
+        call_script_function("looper", "on_tick", vec![Dynamic::from(entity)], &mut world);
+        assert!(world.get_resource::<ScriptCatalog>().is_some());
+    }
+
+    #[test]
+    fn returning_world_is_rejected() {
+        let mut catalog = ScriptCatalog::new();
+        catalog
+            .add(
+                "returner".to_string(),
+                "fn on_tick(world, me) { world }\nfn on_look(world, me) { [1, #{ w: world }] }",
+            )
+            .unwrap();
+
+        let mut world = World::new();
+        world.insert_resource(catalog);
+        let entity = world.spawn_empty().id();
+
+        // shouldn't panic, and the world should still be there afterwards
+        for function in ["on_tick", "on_look"] {
+            call_script_function(
+                "returner",
+                function,
+                vec![Dynamic::from(entity)],
+                &mut world,
+            );
+            assert!(world.get_resource::<ScriptCatalog>().is_some());
+            assert!(world.get_entity(entity).is_some());
+        }
+    }
+
+    #[test]
+    fn leaked_world_handle_is_recovered() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        let script_world = ScriptWorld::new(world);
+        let leaked = script_world.clone();
+
+        let world = script_world.into_inner();
+        assert!(world.get_entity(entity).is_some());
+        drop(leaked);
+    }
+}
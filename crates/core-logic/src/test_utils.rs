@@ -1,5 +1,8 @@
 use crate::{
-    component::{Container, Description, Location},
+    color::Color,
+    component::{Container, Description, Location, Room},
+    game_map::{Coordinates, MapIcon},
+    world_setup::spawn_room,
     GameMessage, Time,
 };
 use bevy_ecs::prelude::*;
@@ -23,6 +26,24 @@ pub fn spawn_entity_in_location(id: &str, location: Entity, world: &mut World) -
     entity
 }
 
+/// Spawns an empty room at the origin of the room with the provided coordinates, or of the world if there isn't one.
+pub fn spawn_test_room(parent: Option<Coordinates>, world: &mut World) -> Entity {
+    spawn_room(
+        Room {
+            name: "room".to_string(),
+            description: "a room".to_string(),
+            map_icon: MapIcon::new_uniform(Color::Black, Color::White, ['[', ']']),
+        },
+        Coordinates {
+            x: 0,
+            y: 0,
+            z: 0,
+            parent: parent.map(Box::new),
+        },
+        world,
+    )
+}
+
 pub fn build_entity_description(id: &str) -> Description {
     use crate::Pronouns;
 
@@ -1,7 +1,10 @@
-use std::time::Duration;
+use std::{ops::RangeInclusive, time::Duration};
 
 use bevy_ecs::system::Resource;
 use log::debug;
+use serde::{Deserialize, Serialize};
+
+use crate::component::LightLevel;
 
 pub const SECONDS_PER_MINUTE: u8 = 60;
 pub const MINUTES_PER_HOUR: u8 = 60;
@@ -15,7 +18,12 @@ const START_HOUR: u8 = 7;
 const START_MINUTE: u8 = 0;
 const START_SECOND: u8 = 0;
 
-#[derive(Clone, Debug, PartialEq, Eq, Resource)]
+/// The hours during which the sun lights up outdoor rooms, from the first hour of dawn to the last hour of dusk.
+const DAYLIGHT_HOURS: RangeInclusive<u8> = 5..=20;
+/// The hours during which the sun is fully up, rather than dawn or dusk.
+const BRIGHT_DAYLIGHT_HOURS: RangeInclusive<u8> = 7..=18;
+
+#[derive(Clone, Debug, PartialEq, Eq, Resource, Serialize, Deserialize)]
 pub struct Time {
     pub day: u64,
     pub hour: u8,
@@ -39,6 +47,17 @@ impl Time {
         }
     }
 
+    /// Determines how much the sun lights up outdoor rooms at this time of day.
+    pub fn get_daylight(&self) -> LightLevel {
+        if BRIGHT_DAYLIGHT_HOURS.contains(&self.hour) {
+            LightLevel::Bright
+        } else if DAYLIGHT_HOURS.contains(&self.hour) {
+            LightLevel::Dim
+        } else {
+            LightLevel::Dark
+        }
+    }
+
     pub fn tick(&mut self) {
         self.advance(TICK_DURATION);
     }
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use bevy_ecs::prelude::*;
+use serde::Serialize;
 
 use crate::{
     component::Vitals,
@@ -56,12 +57,13 @@ pub enum ValueChangeOperation {
     Set,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum VitalType {
     Health,
     Satiety,
     Hydration,
     Energy,
+    BodyTemperature,
 }
 
 impl Display for VitalType {
@@ -71,6 +73,7 @@ impl Display for VitalType {
             VitalType::Satiety => "satiety",
             VitalType::Hydration => "hydration",
             VitalType::Energy => "energy",
+            VitalType::BodyTemperature => "body temperature",
         };
 
         string.fmt(f)
@@ -102,6 +105,7 @@ impl<T: MessageTokens> VitalChange<T> {
                 VitalType::Satiety => &mut vitals.satiety,
                 VitalType::Hydration => &mut vitals.hydration,
                 VitalType::Energy => &mut vitals.energy,
+                VitalType::BodyTemperature => &mut vitals.body_temperature,
             };
             let old_value = *value;
 
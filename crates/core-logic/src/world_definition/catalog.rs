@@ -0,0 +1,356 @@
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    body_part::BodyPartType,
+    component::{Attribute, Density, FluidType, Skill, Stat, WeaponType},
+    resource::{
+        AttributeNameCatalog, BodyPartTypeNameCatalog, FluidDensityCatalog,
+        FluidHydrationFactorCatalog, FluidNameCatalog, FluidRadiationReductionCatalog,
+        SkillBaseAttributeCatalog, SkillNameCatalog, WeaponTypeNameCatalog, WeaponTypeStatCatalog,
+        WeaponTypeStats,
+    },
+    IndefiniteArticle, Locale, NameWithArticle,
+};
+
+use super::{ParsedFile, WorldDefinitionFile};
+
+/// Changes to what the game knows about a fluid type.
+///
+/// Any properties that aren't set are left as they were, so a standard fluid type can have just some of its properties changed.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct FluidDefinition {
+    /// The fluid type being defined, e.g. `"Water"` or `{ Custom = "oil" }`.
+    fluid: FluidType,
+    /// The name of the fluid.
+    name: Option<String>,
+    /// The density of the fluid, in kilograms per liter.
+    density: Option<Density>,
+    /// The amount of hydration drinking the fluid provides, compared to pure water.
+    hydration_factor: Option<f32>,
+    /// How many rads of absorbed radiation drinking a liter of the fluid gets rid of.
+    radiation_reduction: Option<f32>,
+}
+
+/// Changes to what the game knows about an attribute.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct AttributeDefinition {
+    /// The attribute being defined, e.g. `"Strength"` or `{ Custom = "Charisma" }`.
+    attribute: Attribute,
+    /// The full name of the attribute, e.g. "Strength".
+    name: Option<String>,
+    /// The short name of the attribute, e.g. "Str".
+    short_name: Option<String>,
+}
+
+/// Changes to what the game knows about a skill.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct SkillDefinition {
+    /// The skill being defined, e.g. `"Blades"` or `{ Custom = "Fishing" }`.
+    skill: Skill,
+    /// The name of the skill.
+    name: Option<String>,
+    /// The attribute the skill is based on.
+    base_attribute: Option<Attribute>,
+}
+
+/// Changes to what the game knows about a weapon type.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct WeaponTypeDefinition {
+    /// The weapon type being defined, e.g. `"Blade"` or `{ Custom = "Spear" }`.
+    weapon_type: WeaponType,
+    /// The name of the weapon type.
+    name: Option<String>,
+    /// The stats involved in attacks with the weapon type.
+    stats: Option<WeaponTypeStatsDefinition>,
+}
+
+/// Defines the stats involved in attacks with a weapon type.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WeaponTypeStatsDefinition {
+    /// The stat used for initial to-hit rolls.
+    primary: Stat,
+    /// The stat that provides a damage bonus, if any.
+    damage_bonus: Option<Stat>,
+    /// The stat that provides a to-hit bonus, if any.
+    to_hit_bonus: Option<Stat>,
+}
+
+/// Changes to what the game knows about a body part type.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct BodyPartTypeDefinition {
+    /// The body part type being defined, e.g. `"Head"` or `{ Custom = "Tail" }`.
+    body_part_type: BodyPartType,
+    /// The name of the body part type.
+    name: Option<String>,
+    /// The indefinite article to use with the name, either "a" or "an".
+    article: Option<IndefiniteArticle>,
+    /// The name of the body part type in other languages, keyed by locale code.
+    #[serde(default)]
+    localized: HashMap<Locale, NameWithArticle>,
+}
+
+impl WorldDefinitionFile {
+    /// Gets the kind and key of every catalog entry this file defines, for finding conflicts between mods.
+    pub(super) fn get_catalog_keys(&self) -> impl Iterator<Item = (&'static str, String)> + '_ {
+        self.fluids
+            .iter()
+            .map(|fluid| ("fluid", key_string(&fluid.fluid)))
+            .chain(
+                self.attributes
+                    .iter()
+                    .map(|attribute| ("attribute", key_string(&attribute.attribute))),
+            )
+            .chain(
+                self.skills
+                    .iter()
+                    .map(|skill| ("skill", key_string(&skill.skill))),
+            )
+            .chain(
+                self.weapon_types
+                    .iter()
+                    .map(|weapon_type| ("weapon type", key_string(&weapon_type.weapon_type))),
+            )
+            .chain(self.body_part_types.iter().map(|body_part_type| {
+                ("body part type", key_string(&body_part_type.body_part_type))
+            }))
+    }
+}
+
+/// Builds a string to identify the provided catalog key by.
+fn key_string(key: &impl Serialize) -> String {
+    serde_json::to_string(key).expect("catalog key should be serializable")
+}
+
+/// Updates the catalogs in the provided world with the definitions in the provided files.
+/// Files later in the list override anything defined by earlier ones.
+pub(super) fn apply_catalog_definitions(files: &[ParsedFile], world: &mut World) {
+    for file in files {
+        let definition = &file.definition;
+
+        for fluid in &definition.fluids {
+            if let Some(name) = &fluid.name {
+                world
+                    .resource_mut::<FluidNameCatalog>()
+                    .set(&fluid.fluid, name.clone());
+            }
+            if let Some(density) = fluid.density {
+                world
+                    .resource_mut::<FluidDensityCatalog>()
+                    .set(&fluid.fluid, density);
+            }
+            if let Some(hydration_factor) = fluid.hydration_factor {
+                world
+                    .resource_mut::<FluidHydrationFactorCatalog>()
+                    .set(&fluid.fluid, hydration_factor);
+            }
+            if let Some(radiation_reduction) = fluid.radiation_reduction {
+                world
+                    .resource_mut::<FluidRadiationReductionCatalog>()
+                    .set(&fluid.fluid, radiation_reduction);
+            }
+        }
+
+        for attribute in &definition.attributes {
+            let mut catalog = world.resource_mut::<AttributeNameCatalog>();
+            let mut name = catalog.get(&attribute.attribute);
+            if let Some(full) = &attribute.name {
+                name.full = full.clone();
+            }
+            if let Some(short) = &attribute.short_name {
+                name.short = short.clone();
+            }
+            catalog.set(&attribute.attribute, name);
+        }
+
+        for skill in &definition.skills {
+            if let Some(name) = &skill.name {
+                world
+                    .resource_mut::<SkillNameCatalog>()
+                    .set(&skill.skill, name.clone());
+            }
+            if let Some(base_attribute) = &skill.base_attribute {
+                world
+                    .resource_mut::<SkillBaseAttributeCatalog>()
+                    .set(&skill.skill, base_attribute.clone());
+            }
+        }
+
+        for weapon_type in &definition.weapon_types {
+            if let Some(name) = &weapon_type.name {
+                world
+                    .resource_mut::<WeaponTypeNameCatalog>()
+                    .set(&weapon_type.weapon_type, name.clone());
+            }
+            if let Some(stats) = &weapon_type.stats {
+                world.resource_mut::<WeaponTypeStatCatalog>().set(
+                    &weapon_type.weapon_type,
+                    WeaponTypeStats {
+                        primary: stats.primary.clone(),
+                        damage_bonus: stats.damage_bonus.clone(),
+                        to_hit_bonus: stats.to_hit_bonus.clone(),
+                    },
+                );
+            }
+        }
+
+        for body_part_type in &definition.body_part_types {
+            let mut catalog = world.resource_mut::<BodyPartTypeNameCatalog>();
+            let current_name = catalog.get(&body_part_type.body_part_type);
+            catalog.set(
+                &body_part_type.body_part_type,
+                NameWithArticle {
+                    article: body_part_type.article.unwrap_or(current_name.article),
+                    name: body_part_type.name.clone().unwrap_or(current_name.name),
+                },
+            );
+            for (locale, name) in &body_part_type.localized {
+                catalog.set_localized(&body_part_type.body_part_type, *locale, name.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        resource::{get_attribute_name, get_base_attribute, get_fluid_name, get_skill_name},
+        world_definition::{SourceFile, BASE_WORLD_ORIGIN},
+    };
+
+    use super::*;
+
+    fn apply_from_str(contents: &str) -> World {
+        let file = SourceFile {
+            name: "test.toml".to_string(),
+            contents: contents.to_string(),
+        }
+        .parse(BASE_WORLD_ORIGIN)
+        .unwrap();
+
+        let mut world = World::new();
+        crate::resource::insert_resources(&mut world);
+        apply_catalog_definitions(&[file], &mut world);
+
+        world
+    }
+
+    #[test]
+    fn custom_entries() {
+        let world = apply_from_str(
+            r#"
+[[fluids]]
+fluid = { Custom = "oil" }
+name = "oil"
+density = 0.9
+radiation_reduction = 50.0
+
+[[attributes]]
+attribute = { Custom = "Charisma" }
+name = "Charisma"
+short_name = "Cha"
+
+[[skills]]
+skill = { Custom = "Persuade" }
+name = "Persuade"
+base_attribute = { Custom = "Charisma" }
+
+[[weapon_types]]
+weapon_type = { Custom = "Spear" }
+name = "spear"
+stats = { primary = { Skill = "Blades" }, damage_bonus = { Attribute = "Strength" } }
+"#,
+        );
+
+        let oil = FluidType::Custom("oil".to_string());
+        assert_eq!("oil", get_fluid_name(&oil, &world));
+        assert_eq!(0.9, world.resource::<FluidDensityCatalog>().get(&oil).0);
+        assert_eq!(
+            50.0,
+            world.resource::<FluidRadiationReductionCatalog>().get(&oil)
+        );
+
+        let charisma = Attribute::Custom("Charisma".to_string());
+        assert_eq!("Cha", get_attribute_name(&charisma, &world).short);
+        assert_eq!(
+            Some(charisma.clone()),
+            AttributeNameCatalog::get_attribute("charisma", &world)
+        );
+
+        let persuade = Skill::Custom("Persuade".to_string());
+        assert_eq!("Persuade", get_skill_name(&persuade, &world));
+        assert_eq!(charisma, get_base_attribute(&persuade, &world));
+
+        let spear = WeaponType::Custom("Spear".to_string());
+        assert_eq!("spear", WeaponTypeNameCatalog::get_name(&spear, &world));
+        assert_eq!(
+            Stat::Skill(Skill::Blades),
+            WeaponTypeStatCatalog::get_stats(&spear, &world).primary
+        );
+    }
+
+    #[test]
+    fn partial_override_of_standard_entry() {
+        let world = apply_from_str(
+            r#"
+[[attributes]]
+attribute = "Strength"
+short_name = "STR"
+
+[[body_part_types]]
+body_part_type = "Head"
+name = "noggin"
+"#,
+        );
+
+        let strength_name = get_attribute_name(&Attribute::Strength, &world);
+        assert_eq!("Strength", strength_name.full);
+        assert_eq!("STR", strength_name.short);
+
+        let head_name = BodyPartTypeNameCatalog::get_name(&BodyPartType::Head, &world);
+        assert_eq!("noggin", head_name.name);
+        assert!(head_name.article == IndefiniteArticle::A);
+    }
+
+    #[test]
+    fn localized_body_part_type_names() {
+        let world = apply_from_str(
+            r#"
+[[body_part_types]]
+body_part_type = { Custom = "Tail" }
+name = "tail"
+article = "a"
+localized.es = { name = "cola", article = "una" }
+"#,
+        );
+
+        let tail = BodyPartType::Custom("Tail".to_string());
+        let tail_name =
+            BodyPartTypeNameCatalog::get_localized_name(&tail, Locale::Spanish, &world).unwrap();
+        assert_eq!("una cola", tail_name.to_string());
+        assert_eq!(
+            "tail",
+            BodyPartTypeNameCatalog::get_localized_name(&tail, Locale::English, &world)
+                .unwrap()
+                .name
+        );
+        assert_eq!(
+            "cabeza",
+            BodyPartTypeNameCatalog::get_localized_name(
+                &BodyPartType::Head,
+                Locale::Spanish,
+                &world
+            )
+            .unwrap()
+            .name
+        );
+    }
+}
@@ -0,0 +1,296 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use toml::Spanned;
+
+use crate::scripting::{
+    ScriptCatalog, ScriptCommandDefinition, ScriptCommandError, ScriptCommandPartKind,
+    ScriptCommandValidator, ScriptCommandValidatorRule,
+};
+
+use super::{
+    find_definitions_to_use, line_number, script_line, ParsedFile, WorldDefinitionError,
+    WorldDefinitionErrorKind,
+};
+
+/// Defines a command that can be attached to items and rooms, which anyone who can interact with them can enter.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct CommandDefinition {
+    /// The ID to refer to the command by.
+    pub(super) id: Spanned<String>,
+    /// The format the command is entered in, with placeholders in angle brackets, like `"tune <radio> to <station>"`.
+    format: Spanned<String>,
+    /// What each placeholder in the format can be filled in with, by name.
+    #[serde(default)]
+    parts: BTreeMap<String, ScriptCommandPartKind>,
+    /// Rules the parts have to follow for the command to be valid.
+    #[serde(default)]
+    validators: Vec<ValidatorDefinition>,
+    /// The number of ticks it takes to perform the command. Defaults to 1.
+    tick_cost: Option<u32>,
+    /// A script to run before the command is performed, which can return a string to explain why it can't be performed.
+    check: Option<Spanned<String>>,
+    /// The script to run to perform the command. If it returns a string, the entity performing the command is shown it.
+    body: Spanned<String>,
+}
+
+/// Defines a rule a part of a command has to follow.
+///
+/// Exactly one of `matches` or `held` must be set.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ValidatorDefinition {
+    /// The name of the part to check.
+    part: Spanned<String>,
+    /// A pattern the text entered for a text part has to match.
+    matches: Option<String>,
+    /// Whether the entity entered for an entity part has to be in the inventory of the entity performing the command.
+    #[serde(default)]
+    held: bool,
+    /// The error to show if the part doesn't follow the rule.
+    error: String,
+}
+
+/// Builds the commands defined in the provided files and adds them to the provided catalog.
+pub(super) fn build_commands(
+    files: &[ParsedFile],
+    catalog: &mut ScriptCatalog,
+) -> Result<(), WorldDefinitionError> {
+    let definitions = find_definitions_to_use(
+        files,
+        |definition| &definition.commands,
+        |command| &command.id,
+    )?;
+
+    for (file_index, file) in files.iter().enumerate() {
+        for command in &file.definition.commands {
+            if definitions[command.id.get_ref()] != file_index {
+                // this command has been overridden
+                continue;
+            }
+
+            let command_error = |line, message| WorldDefinitionError {
+                file: file.source.name.clone(),
+                line: Some(line),
+                kind: WorldDefinitionErrorKind::Command {
+                    id: command.id.get_ref().clone(),
+                    message,
+                },
+            };
+
+            let validators = command
+                .validators
+                .iter()
+                .map(|validator| {
+                    let rule = match (&validator.matches, validator.held) {
+                        (Some(pattern), false) => {
+                            ScriptCommandValidatorRule::Matches(pattern.clone())
+                        }
+                        (None, true) => ScriptCommandValidatorRule::Held,
+                        _ => {
+                            return Err(command_error(
+                                line_number(&file.source.contents, validator.part.span().start),
+                                "a validator needs to set exactly one of `matches` or `held`"
+                                    .to_string(),
+                            ))
+                        }
+                    };
+
+                    Ok(ScriptCommandValidator {
+                        part: validator.part.get_ref().clone(),
+                        rule,
+                        error: validator.error.clone(),
+                    })
+                })
+                .collect::<Result<Vec<ScriptCommandValidator>, WorldDefinitionError>>()?;
+
+            let definition = ScriptCommandDefinition {
+                format: command.format.get_ref(),
+                parts: command
+                    .parts
+                    .iter()
+                    .map(|(name, kind)| (name.clone(), *kind))
+                    .collect(),
+                validators,
+                tick_cost: command.tick_cost.unwrap_or(1),
+                check: command.check.as_ref().map(|check| check.get_ref().as_str()),
+                body: command.body.get_ref(),
+            };
+
+            catalog
+                .add_command(command.id.get_ref().clone(), definition)
+                .map_err(|e| {
+                    let contents = &file.source.contents;
+                    let line = match &e {
+                        ScriptCommandError::Format(_) => {
+                            line_number(contents, command.format.span().start)
+                        }
+                        ScriptCommandError::Validator { index, .. } => {
+                            line_number(contents, command.validators[*index].part.span().start)
+                        }
+                        ScriptCommandError::Check(e) => script_line(
+                            contents,
+                            command
+                                .check
+                                .as_ref()
+                                .expect("check should exist if it has an error"),
+                            e.line.unwrap_or(1),
+                        ),
+                        ScriptCommandError::Body(e) => {
+                            script_line(contents, &command.body, e.line.unwrap_or(1))
+                        }
+                    };
+
+                    command_error(line, e.to_string())
+                })?;
+        }
+    }
+
+    for file in files {
+        let command_references = file
+            .definition
+            .rooms
+            .iter()
+            .flat_map(|room| &room.commands)
+            .chain(
+                file.definition
+                    .item_prototypes
+                    .iter()
+                    .flat_map(|prototype| prototype.get_commands()),
+            );
+        for id in command_references {
+            if catalog.get_command(id.get_ref()).is_none() {
+                return Err(file
+                    .source
+                    .error_at_id(id, WorldDefinitionErrorKind::UnknownCommand));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::world_definition::{build_script_catalog, SourceFile, BASE_WORLD_ORIGIN};
+
+    use super::*;
+
+    fn build_from_str(contents: &str) -> Result<ScriptCatalog, WorldDefinitionError> {
+        let file = SourceFile {
+            name: "test.toml".to_string(),
+            contents: contents.to_string(),
+        }
+        .parse(BASE_WORLD_ORIGIN)
+        .unwrap();
+
+        build_script_catalog(&[file])
+    }
+
+    #[test]
+    fn valid_command() {
+        let catalog = build_from_str(
+            r#"
+[[commands]]
+id = "tune_radio"
+format = "tune <radio> to <station>"
+parts = { radio = "target", station = "text" }
+validators = [{ part = "station", matches = "^[0-9.]+$", error = "That's not a station." }]
+body = """
+"You tune to " + args.station + "."
+"""
+"#,
+        )
+        .unwrap();
+
+        let command = catalog.get_command("tune_radio").unwrap();
+        assert_eq!(
+            "tune <radio> to <station>",
+            command.get_format_description().to_string()
+        );
+        assert_eq!(1, command.tick_cost);
+    }
+
+    #[test]
+    fn placeholder_without_part() {
+        let error = build_from_str(
+            r#"
+[[commands]]
+id = "tune_radio"
+format = "tune <radio> to <station>"
+parts = { radio = "target" }
+body = ""
+"#,
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!(Some(4), error.line);
+        assert!(matches!(
+            error.kind,
+            WorldDefinitionErrorKind::Command { id, .. } if id == "tune_radio"
+        ));
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        let error = build_from_str(
+            r#"
+[[commands]]
+id = "tune_radio"
+format = "tune <radio> to <station>"
+parts = { radio = "target", station = "text" }
+body = ""
+
+[[commands.validators]]
+part = "station"
+matches = "[unclosed"
+error = "That's not a station."
+"#,
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!(Some(9), error.line);
+    }
+
+    #[test]
+    fn body_compile_error_has_line() {
+        let error = build_from_str(
+            r#"
+[[commands]]
+id = "tune_radio"
+format = "tune <radio>"
+parts = { radio = "target" }
+body = """
+let x = 1;
+let y = ;
+"""
+"#,
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!(Some(8), error.line);
+    }
+
+    #[test]
+    fn unknown_command() {
+        let error = build_from_str(
+            r#"
+[[item_prototypes]]
+id = "radio"
+commands = ["nonexistent"]
+"#,
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!(Some(4), error.line);
+        assert!(matches!(
+            error.kind,
+            WorldDefinitionErrorKind::UnknownCommand(id) if id == "nonexistent"
+        ));
+    }
+}
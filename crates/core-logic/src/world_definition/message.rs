@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+
+use toml::Spanned;
+
+use crate::{
+    resource::{MessageTemplateCatalog, MessageTemplateError},
+    Locale,
+};
+
+use super::{ParsedFile, WorldDefinitionError, WorldDefinitionErrorKind};
+
+/// Builds a catalog of the built-in message templates, with the variants defined in the provided files replacing them.
+/// If more than one file defines variants for the same template in the same locale, the later one wins.
+pub(super) fn build_message_template_catalog(
+    files: &[ParsedFile],
+) -> Result<MessageTemplateCatalog, WorldDefinitionError> {
+    let mut catalog = MessageTemplateCatalog::new();
+    for file in files {
+        set_templates(
+            &mut catalog,
+            file,
+            Locale::default(),
+            &file.definition.messages,
+        )?;
+        for (locale, messages) in &file.definition.localized_messages {
+            set_templates(&mut catalog, file, *locale, messages)?;
+        }
+    }
+
+    Ok(catalog)
+}
+
+/// Sets the variants of the provided templates in the provided locale.
+fn set_templates(
+    catalog: &mut MessageTemplateCatalog,
+    file: &ParsedFile,
+    locale: Locale,
+    messages: &BTreeMap<Spanned<String>, Vec<Spanned<String>>>,
+) -> Result<(), WorldDefinitionError> {
+    for (id, variants) in messages {
+        let variant_strings = variants
+            .iter()
+            .map(|variant| variant.get_ref().clone())
+            .collect::<Vec<String>>();
+        catalog
+            .set(locale, id.get_ref(), &variant_strings)
+            .map_err(|e| {
+                let span = match &e {
+                    MessageTemplateError::InvalidFormat { index, .. }
+                    | MessageTemplateError::InvalidTokens { index, .. } => variants[*index].span(),
+                    _ => id.span(),
+                };
+                file.source.error_at(
+                    span,
+                    WorldDefinitionErrorKind::MessageTemplate {
+                        id: id.get_ref().clone(),
+                        message: e.to_string(),
+                    },
+                )
+            })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+
+    use crate::{
+        resource::FIST_MISS,
+        world_definition::{SourceFile, BASE_WORLD_ORIGIN},
+    };
+
+    use super::*;
+
+    fn build_from_strs(contents: &[&str]) -> Result<MessageTemplateCatalog, WorldDefinitionError> {
+        let files = contents
+            .iter()
+            .map(|contents| {
+                SourceFile {
+                    name: "test.toml".to_string(),
+                    contents: contents.to_string(),
+                }
+                .parse(BASE_WORLD_ORIGIN)
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        build_message_template_catalog(&files)
+    }
+
+    #[test]
+    fn later_files_override_earlier_ones() {
+        let catalog = build_from_strs(&[
+            r#"
+[messages]
+fist_miss = ["${attacker.Name} ${attacker.you:whiff/whiffs}."]
+"#,
+            r#"
+[messages]
+fist_miss = [
+    "${attacker.Name} ${attacker.you:swing/swings} at the air.",
+    "${target.Name} ${target.you:lean/leans} back just in time.",
+]
+"#,
+        ])
+        .unwrap();
+
+        let mut world = World::new();
+        world.insert_resource(catalog);
+        let variants = FIST_MISS
+            .get_variants(&world)
+            .iter()
+            .map(|format| format.as_format_string().to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(
+            vec![
+                "${attacker.Name} ${attacker.you:swing/swings} at the air.",
+                "${target.Name} ${target.you:lean/leans} back just in time."
+            ],
+            variants
+        );
+    }
+
+    #[test]
+    fn localized_messages() {
+        let catalog = build_from_strs(&[r#"
+[localized_messages.es]
+fist_miss = ["${attacker.Name} ${attacker.you:fallas/falla}."]
+"#])
+        .unwrap();
+
+        let mut world = World::new();
+        world.insert_resource(catalog);
+        let spanish_variants = FIST_MISS.get_variants_in(Locale::Spanish, &world);
+        assert_eq!(
+            "${attacker.Name} ${attacker.you:fallas/falla}.",
+            spanish_variants[0].as_format_string()
+        );
+        assert_ne!(
+            spanish_variants[0].as_format_string(),
+            FIST_MISS.get_variants(&world)[0].as_format_string()
+        );
+    }
+
+    #[test]
+    fn unknown_template() {
+        let error = build_from_strs(&["[messages]\nfist_hug = [\"${attacker.Name} hugs.\"]\n"])
+            .err()
+            .unwrap();
+
+        assert_eq!(Some(2), error.line);
+        assert!(matches!(
+            error.kind,
+            WorldDefinitionErrorKind::MessageTemplate { id, .. } if id == "fist_hug"
+        ));
+    }
+
+    #[test]
+    fn variant_with_unavailable_token() {
+        let error = build_from_strs(&[r#"
+[messages]
+fist_miss = [
+    "${attacker.Name} ${attacker.you:miss/misses}.",
+    "${attacker.Name} ${attacker.you:miss/misses} ${target.name's} ${body_part.plain_name}.",
+]
+"#])
+        .err()
+        .unwrap();
+
+        assert_eq!(Some(5), error.line);
+        assert_eq!(
+            "test.toml:5: error in message template \"fist_miss\": variant 2 has an unknown token \"body_part\"",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,1479 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use bevy_ecs::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize};
+use toml::Spanned;
+
+use crate::{
+    color::Color,
+    component::{
+        AmbientTemperature, Connection, DescribeAttributes, Description, KeyId, KeyedLock,
+        LightLevel, LightSource, OpenState, ParseCustomInput, Pronouns, RadiationSource, Room,
+        Script, ScriptCommands, WornItems,
+    },
+    game_map::{Coordinates, MapIcon, CHARS_PER_TILE},
+    move_entity,
+    resource::MessageTemplateCatalog,
+    scripting::ScriptCatalog,
+    world_setup::{connect_open, spawn_room},
+    worldgen::CityEntrance,
+    Direction, Locale,
+};
+
+mod catalog;
+use catalog::*;
+
+mod mods;
+pub use mods::ModConflict;
+pub use mods::ModManifest;
+use mods::*;
+
+mod script;
+use script::*;
+
+mod command;
+use command::*;
+
+mod message;
+use message::*;
+
+mod reload;
+pub use reload::ContentReloadSummary;
+
+mod prototype;
+pub use prototype::spawn_prototype;
+pub use prototype::PrototypeCatalog;
+pub use prototype::SpawnPrototypeError;
+use prototype::*;
+
+/// What to call the origin of the files that define the base world, as opposed to the ones that come from mods.
+pub const BASE_WORLD_ORIGIN: &str = "the base world";
+
+/// The world definition files the game comes with, used when no other world definition directory is provided.
+const BUILT_IN_WORLD_FILES: [(&str, &str); 3] = [
+    (
+        "prototypes.toml",
+        include_str!("../../data/world/prototypes.toml"),
+    ),
+    (
+        "streets.toml",
+        include_str!("../../data/world/streets.toml"),
+    ),
+    (
+        "start_building.toml",
+        include_str!("../../data/world/start_building.toml"),
+    ),
+];
+
+/// The contents of a single world definition file.
+///
+/// A world can be split across any number of files. Anything can refer to things defined in any of the files by their IDs.
+/// Prototypes have their own IDs, separate from the IDs of rooms, NPCs, and items.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WorldDefinitionFile {
+    /// The ID of the room new players should spawn in. Exactly one file in a world must set this.
+    spawn_room: Option<Spanned<String>>,
+    /// Where the procedurally generated city attaches to the rooms defined here, if one is generated. If more than one file sets this, the last one loaded wins.
+    city_entrance: Option<CityEntranceDefinition>,
+    /// The IDs of the item prototypes new players start with one of each of.
+    #[serde(default)]
+    starting_items: Vec<Spanned<String>>,
+    /// The kinds of items that can be spawned.
+    #[serde(default)]
+    item_prototypes: Vec<ItemPrototype>,
+    /// The kinds of NPCs that can be spawned.
+    #[serde(default)]
+    npc_prototypes: Vec<NpcPrototype>,
+    /// Scripts rooms, NPCs, and items can behave according to.
+    #[serde(default)]
+    scripts: Vec<ScriptDefinition>,
+    /// Commands that can be attached to rooms and items.
+    #[serde(default)]
+    commands: Vec<CommandDefinition>,
+    /// The rooms in the world.
+    #[serde(default)]
+    rooms: Vec<RoomDefinition>,
+    /// Open connections between rooms.
+    #[serde(default)]
+    connections: Vec<ConnectionDefinition>,
+    /// Doors between rooms.
+    #[serde(default)]
+    doors: Vec<DoorDefinition>,
+    /// The NPCs in the world.
+    #[serde(default)]
+    npcs: Vec<NpcPlacement>,
+    /// The items in the world.
+    #[serde(default)]
+    items: Vec<ItemPlacement>,
+    /// New fluid types, and changes to existing ones.
+    #[serde(default)]
+    fluids: Vec<FluidDefinition>,
+    /// New attributes, and changes to existing ones.
+    #[serde(default)]
+    attributes: Vec<AttributeDefinition>,
+    /// New skills, and changes to existing ones.
+    #[serde(default)]
+    skills: Vec<SkillDefinition>,
+    /// New weapon types, and changes to existing ones.
+    #[serde(default)]
+    weapon_types: Vec<WeaponTypeDefinition>,
+    /// New body part types, and changes to existing ones.
+    #[serde(default)]
+    body_part_types: Vec<BodyPartTypeDefinition>,
+    /// Replacement variants for message templates, keyed by template ID.
+    #[serde(default)]
+    messages: BTreeMap<Spanned<String>, Vec<Spanned<String>>>,
+    /// Translations of message templates, keyed by locale code and then template ID.
+    #[serde(default)]
+    localized_messages: BTreeMap<Locale, BTreeMap<Spanned<String>, Vec<Spanned<String>>>>,
+}
+
+/// Defines a room.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RoomDefinition {
+    /// The ID to refer to the room by in other definitions.
+    id: Spanned<String>,
+    /// The name of the room.
+    #[serde(default)]
+    name: String,
+    /// The description of the room.
+    #[serde(default)]
+    description: String,
+    /// The icon to show for the room on the map.
+    icon: MapIconDefinition,
+    /// The x, y, and z coordinates of the room.
+    coordinates: [i64; 3],
+    /// The ID of the room this room is inside of, if it's inside of one.
+    parent: Option<Spanned<String>>,
+    /// How much light the room has of its own, from windows or lights that still work.
+    /// Rooms that aren't inside another room are also lit by the sun during the day.
+    light: Option<LightLevel>,
+    /// The temperature of the room, in degrees Celsius.
+    /// Defaults to a comfortable temperature for rooms inside another room, and depends on the time of day and weather for rooms that aren't.
+    temperature: Option<f32>,
+    /// How much radiation the room gives off, in rads per tick.
+    radiation: Option<f32>,
+    /// The ID of the script the room behaves according to, if any.
+    script: Option<Spanned<String>>,
+    /// The IDs of the commands that can be entered in the room.
+    #[serde(default)]
+    commands: Vec<Spanned<String>>,
+}
+
+/// Defines a map icon.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MapIconDefinition {
+    /// The characters that make up the icon.
+    chars: MapIconChars,
+    /// The color of the characters.
+    foreground: Color,
+    /// The color behind the characters.
+    #[serde(default = "default_icon_background")]
+    background: Color,
+}
+
+fn default_icon_background() -> Color {
+    Color::Black
+}
+
+/// The characters in a map icon.
+#[derive(Deserialize)]
+#[serde(try_from = "String")]
+struct MapIconChars([char; CHARS_PER_TILE]);
+
+impl TryFrom<String> for MapIconChars {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value
+            .chars()
+            .collect::<Vec<char>>()
+            .try_into()
+            .map(MapIconChars)
+            .map_err(|_| format!("map icons must be exactly {CHARS_PER_TILE} characters long"))
+    }
+}
+
+/// Defines an open connection between two rooms, traversable in both directions.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConnectionDefinition {
+    /// The ID of the room the connection starts in.
+    from: Spanned<String>,
+    /// The direction to go from the starting room to get to the destination room.
+    direction: Direction,
+    /// The ID of the destination room.
+    to: Spanned<String>,
+}
+
+/// Defines where the procedurally generated city attaches to the defined world.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CityEntranceDefinition {
+    /// The ID of the room to enter the city from. It can't be inside another room.
+    room: Spanned<String>,
+    /// The direction to go from the room to get into the city. Must be north, south, east, or west.
+    direction: Spanned<Direction>,
+}
+
+/// Defines a door between two rooms.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DoorDefinition {
+    /// The ID of the room the door starts in.
+    from: Spanned<String>,
+    /// The direction to go from the starting room to get to the destination room.
+    direction: Direction,
+    /// The ID of the destination room.
+    to: Spanned<String>,
+    /// The name of the door, without the direction it's in.
+    name: String,
+    /// The name to use when referring to multiple of the door. Defaults to the name with an "s" on the end.
+    plural_name: Option<String>,
+    /// Alternate names of the door. The direction the door is in is always added to these.
+    #[serde(default)]
+    aliases: Vec<String>,
+    /// The description of the door.
+    description: String,
+    /// Whether the door starts out open.
+    #[serde(default)]
+    open: bool,
+    /// The lock on the door, if it has one.
+    lock: Option<LockDefinition>,
+}
+
+/// Defines a lock on a door.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LockDefinition {
+    /// Whether the door starts out locked.
+    locked: bool,
+    /// The ID of the key needed to lock or unlock the door from the starting room, if one is needed.
+    key: Option<u32>,
+    /// The ID of the key needed to lock or unlock the door from the destination room, if one is needed.
+    other_side_key: Option<u32>,
+}
+
+/// Places an NPC spawned from a prototype in the world.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NpcPlacement {
+    /// The ID to refer to the NPC by in other definitions, if it needs one.
+    id: Option<Spanned<String>>,
+    /// The ID of the room the NPC starts in.
+    location: Spanned<String>,
+    /// The ID of the NPC prototype to spawn the NPC from.
+    prototype: Spanned<String>,
+}
+
+/// Places items spawned from a prototype in the world.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ItemPlacement {
+    /// The ID to refer to the item by in other definitions, if it needs one.
+    id: Option<Spanned<String>>,
+    /// The ID of the room, NPC, or item the item starts in.
+    location: Spanned<String>,
+    /// The ID of the item prototype to spawn the item from.
+    prototype: Spanned<String>,
+    /// The number of copies of the item to spawn. Items with IDs can only be spawned once.
+    #[serde(default = "default_count")]
+    count: u32,
+    /// Whether the item starts out being worn by the NPC it's in.
+    #[serde(default)]
+    worn: bool,
+}
+
+fn default_count() -> u32 {
+    1
+}
+
+/// An error loading a world definition.
+#[derive(Debug)]
+pub struct WorldDefinitionError {
+    /// The name of the file the error is in.
+    pub file: String,
+    /// The line in the file the error is on, if it's on a specific line.
+    pub line: Option<usize>,
+    /// What went wrong.
+    pub kind: WorldDefinitionErrorKind,
+}
+
+/// The different kinds of things that can be wrong with a world definition.
+#[derive(Debug)]
+pub enum WorldDefinitionErrorKind {
+    /// A file couldn't be read.
+    Io(std::io::Error),
+    /// A file is malformed or doesn't match the schema.
+    Parse(String),
+    /// Something has the same ID as something else.
+    DuplicateId(String),
+    /// Something refers to an ID that isn't defined.
+    UnknownId(String),
+    /// A room is inside itself, either directly or through its parents.
+    RoomInsideItself(String),
+    /// Two rooms have the same coordinates.
+    DuplicateCoordinates(String),
+    /// A room or NPC is in something other than a room.
+    NotInRoom(String),
+    /// An item with an ID has a count other than 1.
+    CountWithId(String),
+    /// An item is worn, but it can't be worn by the thing it's in.
+    CannotBeWorn(String),
+    /// Something refers to a prototype that isn't defined.
+    UnknownPrototype(String),
+    /// Something that needs an item prototype refers to a different kind of prototype.
+    NotItemPrototype(String),
+    /// Something that needs an NPC prototype refers to a different kind of prototype.
+    NotNpcPrototype(String),
+    /// A prototype extends itself, either directly or through the prototypes it extends.
+    PrototypeExtendsItself(String),
+    /// A script doesn't compile.
+    Script {
+        /// The ID of the script.
+        id: String,
+        /// What's wrong with the script.
+        message: String,
+    },
+    /// Something refers to a script that isn't defined.
+    UnknownScript(String),
+    /// A message template is invalid.
+    MessageTemplate {
+        /// The ID of the template.
+        id: String,
+        /// What's wrong with the template.
+        message: String,
+    },
+    /// A command is invalid.
+    Command {
+        /// The ID of the command.
+        id: String,
+        /// What's wrong with the command.
+        message: String,
+    },
+    /// Something refers to a command that isn't defined.
+    UnknownCommand(String),
+    /// More than one mod has the same ID.
+    DuplicateModId(String),
+    /// A mod that's supposed to be active doesn't exist.
+    UnknownMod(String),
+    /// A mod depends on a mod that isn't active.
+    MissingDependency {
+        /// The ID of the mod with the dependency.
+        mod_id: String,
+        /// The ID of the mod it depends on.
+        dependency: String,
+    },
+    /// A mod can't be loaded because it depends on a cycle of mods that depend on each other, or is part of one.
+    DependencyCycle(String),
+    /// A prototype is missing a property needed to spawn something from it.
+    MissingField {
+        /// The ID of the prototype.
+        prototype: String,
+        /// The name of the missing property.
+        field: &'static str,
+    },
+    /// No file defines the spawn room.
+    MissingSpawnRoom,
+    /// More than one file defines the spawn room.
+    DuplicateSpawnRoom,
+    /// The city entrance is inside another room.
+    CityEntranceInsideRoom(String),
+    /// The city entrance isn't entered by going north, south, east, or west.
+    InvalidCityEntranceDirection(Direction),
+}
+
+impl Display for WorldDefinitionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{line}: {}", self.file, self.kind),
+            None => write!(f, "{}: {}", self.file, self.kind),
+        }
+    }
+}
+
+impl Display for WorldDefinitionErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorldDefinitionErrorKind::Io(e) => write!(f, "unable to read file: {e}"),
+            WorldDefinitionErrorKind::Parse(message) => write!(f, "{message}"),
+            WorldDefinitionErrorKind::DuplicateId(id) => {
+                write!(f, "ID {id:?} is already used by something else")
+            }
+            WorldDefinitionErrorKind::UnknownId(id) => {
+                write!(f, "nothing with ID {id:?} is defined")
+            }
+            WorldDefinitionErrorKind::RoomInsideItself(id) => {
+                write!(f, "room {id:?} is inside itself")
+            }
+            WorldDefinitionErrorKind::DuplicateCoordinates(id) => {
+                write!(f, "room {id:?} has the same coordinates as another room")
+            }
+            WorldDefinitionErrorKind::NotInRoom(id) => write!(f, "{id:?} is not a room"),
+            WorldDefinitionErrorKind::CountWithId(id) => {
+                write!(
+                    f,
+                    "item {id:?} has an ID, so it can't have a count other than 1"
+                )
+            }
+            WorldDefinitionErrorKind::CannotBeWorn(id) => {
+                write!(f, "{id:?} can't be worn by the thing it's in")
+            }
+            WorldDefinitionErrorKind::UnknownPrototype(id) => {
+                write!(f, "no prototype with ID {id:?} is defined")
+            }
+            WorldDefinitionErrorKind::NotItemPrototype(id) => {
+                write!(f, "{id:?} is not an item prototype")
+            }
+            WorldDefinitionErrorKind::NotNpcPrototype(id) => {
+                write!(f, "{id:?} is not an NPC prototype")
+            }
+            WorldDefinitionErrorKind::PrototypeExtendsItself(id) => {
+                write!(f, "prototype {id:?} extends itself")
+            }
+            WorldDefinitionErrorKind::Script { id, message } => {
+                write!(f, "error in script {id:?}: {message}")
+            }
+            WorldDefinitionErrorKind::UnknownScript(id) => {
+                write!(f, "no script with ID {id:?} is defined")
+            }
+            WorldDefinitionErrorKind::MessageTemplate { id, message } => {
+                write!(f, "error in message template {id:?}: {message}")
+            }
+            WorldDefinitionErrorKind::Command { id, message } => {
+                write!(f, "error in command {id:?}: {message}")
+            }
+            WorldDefinitionErrorKind::UnknownCommand(id) => {
+                write!(f, "no command with ID {id:?} is defined")
+            }
+            WorldDefinitionErrorKind::MissingField { prototype, field } => {
+                write!(f, "prototype {prototype:?} doesn't set {field:?}")
+            }
+            WorldDefinitionErrorKind::DuplicateModId(id) => {
+                write!(f, "more than one mod has ID {id:?}")
+            }
+            WorldDefinitionErrorKind::UnknownMod(id) => write!(f, "no mod with ID {id:?} exists"),
+            WorldDefinitionErrorKind::MissingDependency { mod_id, dependency } => write!(
+                f,
+                "mod {mod_id:?} depends on mod {dependency:?}, which isn't active"
+            ),
+            WorldDefinitionErrorKind::DependencyCycle(id) => {
+                write!(
+                    f,
+                    "mod {id:?} can't be loaded because of a dependency cycle"
+                )
+            }
+            WorldDefinitionErrorKind::MissingSpawnRoom => write!(f, "no spawn room is defined"),
+            WorldDefinitionErrorKind::DuplicateSpawnRoom => {
+                write!(f, "the spawn room is already defined in another file")
+            }
+            WorldDefinitionErrorKind::CityEntranceInsideRoom(id) => {
+                write!(f, "city entrance {id:?} is inside another room")
+            }
+            WorldDefinitionErrorKind::InvalidCityEntranceDirection(direction) => write!(
+                f,
+                "the city must be entered going north, south, east, or west, not {direction}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WorldDefinitionError {}
+
+impl From<SpawnPrototypeError> for WorldDefinitionErrorKind {
+    fn from(value: SpawnPrototypeError) -> Self {
+        match value {
+            SpawnPrototypeError::UnknownPrototype(id) => {
+                WorldDefinitionErrorKind::UnknownPrototype(id)
+            }
+            SpawnPrototypeError::MissingField { prototype, field } => {
+                WorldDefinitionErrorKind::MissingField { prototype, field }
+            }
+        }
+    }
+}
+
+/// A world definition file that has been read, but not necessarily parsed yet.
+struct SourceFile {
+    /// The name of the file, for error messages.
+    name: String,
+    /// The contents of the file.
+    contents: String,
+}
+
+impl SourceFile {
+    /// Builds an error located at the provided span in this file.
+    fn error_at(&self, span: Range<usize>, kind: WorldDefinitionErrorKind) -> WorldDefinitionError {
+        WorldDefinitionError {
+            file: self.name.clone(),
+            line: Some(line_number(&self.contents, span.start)),
+            kind,
+        }
+    }
+
+    /// Builds an error located at the provided ID reference in this file.
+    fn error_at_id(
+        &self,
+        id: &Spanned<String>,
+        kind: fn(String) -> WorldDefinitionErrorKind,
+    ) -> WorldDefinitionError {
+        self.error_at(id.span(), kind(id.get_ref().clone()))
+    }
+
+    /// Parses this file as a world definition file.
+    fn parse(self, origin: &str) -> Result<ParsedFile, WorldDefinitionError> {
+        let definition = self.parse_as()?;
+        Ok(ParsedFile {
+            source: self,
+            origin: origin.to_string(),
+            definition,
+        })
+    }
+
+    /// Parses this file as the provided type.
+    fn parse_as<T: DeserializeOwned>(&self) -> Result<T, WorldDefinitionError> {
+        toml::from_str(&self.contents).map_err(|e| WorldDefinitionError {
+            file: self.name.clone(),
+            line: e.span().map(|span| line_number(&self.contents, span.start)),
+            kind: WorldDefinitionErrorKind::Parse(e.message().to_string()),
+        })
+    }
+}
+
+/// Finds the 1-based line number of the provided byte offset in the provided text.
+fn line_number(text: &str, offset: usize) -> usize {
+    text[..offset.min(text.len())].matches('\n').count() + 1
+}
+
+/// Reads all the world definition files in the provided directory, in order of file name.
+fn read_world_files(dir: &Path) -> Result<Vec<SourceFile>, WorldDefinitionError> {
+    let io_error = |path: &Path, e| WorldDefinitionError {
+        file: path.display().to_string(),
+        line: None,
+        kind: WorldDefinitionErrorKind::Io(e),
+    };
+
+    let mut paths = std::fs::read_dir(dir)
+        .map_err(|e| io_error(dir, e))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<PathBuf>, std::io::Error>>()
+        .map_err(|e| io_error(dir, e))?;
+    paths.retain(|path| {
+        path.extension()
+            .is_some_and(|extension| extension == "toml")
+    });
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let contents = std::fs::read_to_string(&path).map_err(|e| io_error(&path, e))?;
+            Ok(SourceFile {
+                name: path.display().to_string(),
+                contents,
+            })
+        })
+        .collect()
+}
+
+/// A world, as defined in a set of parsed world definition files.
+pub struct WorldDefinition {
+    /// The files, in the order they were loaded in.
+    files: Vec<ParsedFile>,
+    /// The mods the files came from, in the order they were loaded in.
+    mods: Vec<ModManifest>,
+}
+
+/// A parsed world definition file, along with the file it came from.
+struct ParsedFile {
+    source: SourceFile,
+    /// The ID of the mod the file came from, or `BASE_WORLD_ORIGIN`.
+    ///
+    /// Things defined by files from different origins don't conflict with each other; whichever file was loaded later wins instead.
+    origin: String,
+    definition: WorldDefinitionFile,
+}
+
+/// Something with an ID that can contain other things.
+#[derive(Clone, Copy)]
+enum DefinedEntity {
+    /// A room, along with the index of its file and its index within that file.
+    Room(usize, usize),
+    /// An NPC.
+    Npc(Entity),
+    /// An item.
+    Item(Entity),
+}
+
+impl WorldDefinition {
+    /// Parses the world definition files the game comes with.
+    pub fn built_in() -> Result<WorldDefinition, WorldDefinitionError> {
+        let files = BUILT_IN_WORLD_FILES
+            .iter()
+            .map(|(name, contents)| SourceFile {
+                name: name.to_string(),
+                contents: contents.to_string(),
+            })
+            .collect();
+
+        WorldDefinition::from_files(files)
+    }
+
+    /// Parses the `.toml` files in the provided directory.
+    pub fn from_dir(dir: &Path) -> Result<WorldDefinition, WorldDefinitionError> {
+        WorldDefinition::from_files(read_world_files(dir)?)
+    }
+
+    /// Parses the provided files, which define the base world.
+    fn from_files(files: Vec<SourceFile>) -> Result<WorldDefinition, WorldDefinitionError> {
+        let files = files
+            .into_iter()
+            .map(|file| file.parse(BASE_WORLD_ORIGIN))
+            .collect::<Result<Vec<ParsedFile>, WorldDefinitionError>>()?;
+
+        Ok(WorldDefinition {
+            files,
+            mods: Vec::new(),
+        })
+    }
+
+    /// Adds the mods in the provided directory on top of this world, in dependency order.
+    ///
+    /// If `active_mods` is set, only the mods with those IDs are added. Otherwise all the mods in the directory are.
+    pub fn with_mods(
+        mut self,
+        mods_dir: &Path,
+        active_mods: Option<&[String]>,
+    ) -> Result<WorldDefinition, WorldDefinitionError> {
+        for (manifest, files) in read_mods(mods_dir, active_mods)? {
+            for file in files {
+                self.files.push(file.parse(&manifest.id)?);
+            }
+            self.mods.push(manifest);
+        }
+
+        Ok(self)
+    }
+
+    /// Gets the manifests of the mods that were added to this world, in the order they were loaded in.
+    pub fn get_mods(&self) -> &[ModManifest] {
+        &self.mods
+    }
+
+    /// Finds everything that's defined by more than one mod (or by the base world and a mod), which the later-loaded definition overrides.
+    pub fn find_conflicts(&self) -> Vec<ModConflict> {
+        find_conflicts(&self.files)
+    }
+
+    /// Adds the fluids, attributes, skills, weapon types, and body part types defined in this world to the catalogs in the provided world.
+    pub fn apply_catalog_definitions(&self, world: &mut World) {
+        apply_catalog_definitions(&self.files, world);
+    }
+
+    /// Builds a catalog of the prototypes defined in this world, with all their inheritance resolved.
+    pub fn build_prototype_catalog(&self) -> Result<PrototypeCatalog, WorldDefinitionError> {
+        PrototypeCatalog::from_files(&self.files)
+    }
+
+    /// Compiles the scripts and commands defined in this world.
+    pub fn build_script_catalog(&self) -> Result<ScriptCatalog, WorldDefinitionError> {
+        build_script_catalog(&self.files)
+    }
+
+    /// Builds a catalog of the built-in message templates, with the variants defined in this world replacing the built-in ones.
+    pub fn build_message_template_catalog(
+        &self,
+    ) -> Result<MessageTemplateCatalog, WorldDefinitionError> {
+        build_message_template_catalog(&self.files)
+    }
+
+    /// Spawns everything defined in this world. The world must already have the catalog from `build_prototype_catalog` in it.
+    ///
+    /// Returns the coordinates of the spawn room.
+    pub fn spawn(&self, world: &mut World) -> Result<Coordinates, WorldDefinitionError> {
+        let parsed_files = &self.files;
+
+        // spawn NPCs and items without any components first, so everything can be referred to by ID regardless of the order it's defined in
+        let mut defined = define_rooms(parsed_files)?;
+        let mut npc_entities = Vec::new();
+        let mut item_entities = Vec::new();
+        for file in parsed_files {
+            let mut file_npc_entities = Vec::new();
+            for npc in &file.definition.npcs {
+                let entity = world.spawn_empty().id();
+                if let Some(id) = &npc.id {
+                    define_id(id, DefinedEntity::Npc(entity), &file.source, &mut defined)?;
+                }
+                file_npc_entities.push(entity);
+            }
+            npc_entities.push(file_npc_entities);
+
+            let mut file_item_entities = Vec::new();
+            for item in &file.definition.items {
+                if let Some(id) = &item.id {
+                    if item.count != 1 {
+                        return Err(file
+                            .source
+                            .error_at_id(id, WorldDefinitionErrorKind::CountWithId));
+                    }
+                }
+                let entities = (0..item.count)
+                    .map(|_| world.spawn_empty().id())
+                    .collect::<Vec<Entity>>();
+                if let (Some(id), Some(entity)) = (&item.id, entities.first()) {
+                    define_id(id, DefinedEntity::Item(*entity), &file.source, &mut defined)?;
+                }
+                file_item_entities.push(entities);
+            }
+            item_entities.push(file_item_entities);
+        }
+
+        // rooms
+        let mut room_coordinates = HashMap::new();
+        let mut room_entities = HashMap::new();
+        for (file_index, file) in parsed_files.iter().enumerate() {
+            for (room_index, room) in file.definition.rooms.iter().enumerate() {
+                if is_overridden(room, file_index, room_index, &defined) {
+                    continue;
+                }
+                let coords = find_room_coordinates(
+                    &room.id,
+                    file_index,
+                    parsed_files,
+                    &defined,
+                    &mut room_coordinates,
+                    &mut Vec::new(),
+                )?;
+                if room_entities
+                    .values()
+                    .any(|(_, other_coords)| *other_coords == coords)
+                {
+                    return Err(file
+                        .source
+                        .error_at_id(&room.id, WorldDefinitionErrorKind::DuplicateCoordinates));
+                }
+                let room_entity = spawn_room(room.build(), coords.clone(), world);
+                if let Some(light_level) = room.light {
+                    world
+                        .entity_mut(room_entity)
+                        .insert(LightSource { light_level });
+                }
+                if let Some(temperature) = room.temperature {
+                    world
+                        .entity_mut(room_entity)
+                        .insert(AmbientTemperature(temperature));
+                }
+                if let Some(rads_per_tick) = room.radiation {
+                    world
+                        .entity_mut(room_entity)
+                        .insert(RadiationSource { rads_per_tick });
+                }
+                if let Some(script) = &room.script {
+                    world.entity_mut(room_entity).insert(Script {
+                        id: script.get_ref().clone(),
+                    });
+                }
+                if !room.commands.is_empty() {
+                    ScriptCommands::add_to(
+                        room_entity,
+                        room.commands
+                            .iter()
+                            .map(|id| id.get_ref().clone())
+                            .collect(),
+                        world,
+                    );
+                }
+                room_entities.insert(room.id.get_ref().clone(), (room_entity, coords));
+            }
+        }
+
+        let find_room = |id: &Spanned<String>, source: &SourceFile| match defined.get(id.get_ref())
+        {
+            Some(DefinedEntity::Room(_, _)) => Ok(room_entities[id.get_ref()].0),
+            Some(_) => Err(source.error_at_id(id, WorldDefinitionErrorKind::NotInRoom)),
+            None => Err(source.error_at_id(id, WorldDefinitionErrorKind::UnknownId)),
+        };
+
+        // connections
+        let mut spawn_room_coords = None;
+        let mut spawn_room_origin = None;
+        for file in parsed_files {
+            if let Some(spawn_room) = &file.definition.spawn_room {
+                // the spawn room can be overridden by later mods
+                if spawn_room_origin == Some(&file.origin) {
+                    return Err(file.source.error_at(
+                        spawn_room.span(),
+                        WorldDefinitionErrorKind::DuplicateSpawnRoom,
+                    ));
+                }
+                find_room(spawn_room, &file.source)?;
+                spawn_room_coords = Some(room_entities[spawn_room.get_ref()].1.clone());
+                spawn_room_origin = Some(&file.origin);
+            }
+
+            for connection in &file.definition.connections {
+                let from = find_room(&connection.from, &file.source)?;
+                let to = find_room(&connection.to, &file.source)?;
+                connect_open(from, connection.direction, to, world);
+            }
+
+            for door in file.definition.doors.iter() {
+                let from = find_room(&door.from, &file.source)?;
+                let to = find_room(&door.to, &file.source)?;
+                spawn_door(door, from, to, world);
+            }
+        }
+
+        // NPCs
+        for (file, entities) in parsed_files.iter().zip(npc_entities) {
+            for (npc, entity) in file.definition.npcs.iter().zip(entities) {
+                let room = find_room(&npc.location, &file.source)?;
+                match world
+                    .resource::<PrototypeCatalog>()
+                    .get(npc.prototype.get_ref())
+                {
+                    Some(Prototype::Npc(_)) => (),
+                    Some(Prototype::Item(_)) => {
+                        return Err(file.source.error_at_id(
+                            &npc.prototype,
+                            WorldDefinitionErrorKind::NotNpcPrototype,
+                        ))
+                    }
+                    None => {
+                        return Err(file.source.error_at_id(
+                            &npc.prototype,
+                            WorldDefinitionErrorKind::UnknownPrototype,
+                        ))
+                    }
+                }
+                build_prototype(npc.prototype.get_ref(), entity, world)
+                    .map_err(|e| file.source.error_at(npc.prototype.span(), e.into()))?;
+                move_entity(entity, room, world);
+            }
+        }
+
+        // items
+        let mut worn_items = Vec::new();
+        for (file, entities) in parsed_files.iter().zip(item_entities) {
+            for (item, entities) in file.definition.items.iter().zip(entities) {
+                let location = match defined.get(item.location.get_ref()) {
+                    Some(DefinedEntity::Room(_, _)) => room_entities[item.location.get_ref()].0,
+                    Some(DefinedEntity::Npc(entity) | DefinedEntity::Item(entity)) => *entity,
+                    None => {
+                        return Err(file
+                            .source
+                            .error_at_id(&item.location, WorldDefinitionErrorKind::UnknownId))
+                    }
+                };
+                match world
+                    .resource::<PrototypeCatalog>()
+                    .get(item.prototype.get_ref())
+                {
+                    Some(Prototype::Item(_)) => (),
+                    Some(Prototype::Npc(_)) => {
+                        return Err(file.source.error_at_id(
+                            &item.prototype,
+                            WorldDefinitionErrorKind::NotItemPrototype,
+                        ))
+                    }
+                    None => {
+                        return Err(file.source.error_at_id(
+                            &item.prototype,
+                            WorldDefinitionErrorKind::UnknownPrototype,
+                        ))
+                    }
+                }
+                for entity in entities {
+                    build_prototype(item.prototype.get_ref(), entity, world)
+                        .map_err(|e| file.source.error_at(item.prototype.span(), e.into()))?;
+                    if item.worn {
+                        worn_items.push((entity, location, &file.source, item));
+                    }
+                    move_entity(entity, location, world);
+                }
+            }
+        }
+
+        // wear things after everything has been moved to where it goes
+        for (entity, wearing_entity, source, item) in worn_items {
+            if WornItems::wear(wearing_entity, entity, world).is_err() {
+                return Err(
+                    source.error_at_id(&item.prototype, WorldDefinitionErrorKind::CannotBeWorn)
+                );
+            }
+        }
+
+        spawn_room_coords.ok_or_else(|| WorldDefinitionError {
+            file: parsed_files
+                .iter()
+                .map(|file| file.source.name.as_str())
+                .collect::<Vec<&str>>()
+                .join(", "),
+            line: None,
+            kind: WorldDefinitionErrorKind::MissingSpawnRoom,
+        })
+    }
+}
+
+impl WorldDefinition {
+    /// Finds where the procedurally generated city attaches to this world, if anywhere.
+    pub fn find_city_entrance(&self) -> Result<Option<CityEntrance>, WorldDefinitionError> {
+        let Some((file_index, file, entrance)) = self
+            .files
+            .iter()
+            .enumerate()
+            .filter_map(|(index, file)| {
+                file.definition
+                    .city_entrance
+                    .as_ref()
+                    .map(|entrance| (index, file, entrance))
+            })
+            .next_back()
+        else {
+            return Ok(None);
+        };
+
+        let direction = *entrance.direction.get_ref();
+        if !matches!(
+            direction,
+            Direction::North | Direction::South | Direction::East | Direction::West
+        ) {
+            return Err(file.source.error_at(
+                entrance.direction.span(),
+                WorldDefinitionErrorKind::InvalidCityEntranceDirection(direction),
+            ));
+        }
+
+        let defined = define_rooms(&self.files)?;
+        let coordinates = find_room_coordinates(
+            &entrance.room,
+            file_index,
+            &self.files,
+            &defined,
+            &mut HashMap::new(),
+            &mut Vec::new(),
+        )?;
+        if coordinates.parent.is_some() {
+            return Err(file.source.error_at_id(
+                &entrance.room,
+                WorldDefinitionErrorKind::CityEntranceInsideRoom,
+            ));
+        }
+
+        Ok(Some(CityEntrance {
+            coordinates,
+            direction,
+        }))
+    }
+}
+
+impl RoomDefinition {
+    /// Builds the room this defines.
+    fn build(&self) -> Room {
+        Room {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            map_icon: MapIcon::new_uniform(
+                self.icon.background,
+                self.icon.foreground,
+                self.icon.chars.0,
+            ),
+        }
+    }
+}
+
+/// Records the IDs of the rooms defined in the provided files.
+fn define_rooms(
+    files: &[ParsedFile],
+) -> Result<HashMap<String, DefinedEntity>, WorldDefinitionError> {
+    let mut defined = HashMap::new();
+    for (file_index, file) in files.iter().enumerate() {
+        for (room_index, room) in file.definition.rooms.iter().enumerate() {
+            // rooms can be overridden by rooms from later mods
+            if let Some(DefinedEntity::Room(other_file_index, _)) = defined.get(room.id.get_ref()) {
+                if files[*other_file_index].origin != file.origin {
+                    defined.remove(room.id.get_ref());
+                }
+            }
+            define_id(
+                &room.id,
+                DefinedEntity::Room(file_index, room_index),
+                &file.source,
+                &mut defined,
+            )?;
+        }
+    }
+
+    Ok(defined)
+}
+
+/// Determines whether the provided room, which is at the provided index in the file with the provided index, has been overridden by a room from a later mod.
+fn is_overridden(
+    room: &RoomDefinition,
+    file_index: usize,
+    room_index: usize,
+    defined: &HashMap<String, DefinedEntity>,
+) -> bool {
+    !matches!(
+        defined[room.id.get_ref()],
+        DefinedEntity::Room(defined_file_index, defined_room_index)
+            if defined_file_index == file_index && defined_room_index == room_index
+    )
+}
+
+/// Records the provided ID as referring to the provided thing, if it's not already in use.
+fn define_id(
+    id: &Spanned<String>,
+    defined_entity: DefinedEntity,
+    source: &SourceFile,
+    defined: &mut HashMap<String, DefinedEntity>,
+) -> Result<(), WorldDefinitionError> {
+    if defined
+        .insert(id.get_ref().clone(), defined_entity)
+        .is_some()
+    {
+        return Err(source.error_at_id(id, WorldDefinitionErrorKind::DuplicateId));
+    }
+
+    Ok(())
+}
+
+/// Determines the coordinates of the room with the provided ID, which is referred to from the file with the provided index.
+fn find_room_coordinates(
+    id: &Spanned<String>,
+    referring_file_index: usize,
+    files: &[ParsedFile],
+    defined: &HashMap<String, DefinedEntity>,
+    room_coordinates: &mut HashMap<String, Coordinates>,
+    visited: &mut Vec<String>,
+) -> Result<Coordinates, WorldDefinitionError> {
+    if let Some(coords) = room_coordinates.get(id.get_ref()) {
+        return Ok(coords.clone());
+    }
+
+    let referring_source = &files[referring_file_index].source;
+    let (file_index, room_index) = match defined.get(id.get_ref()) {
+        Some(DefinedEntity::Room(file_index, room_index)) => (*file_index, *room_index),
+        Some(_) => {
+            return Err(referring_source.error_at_id(id, WorldDefinitionErrorKind::NotInRoom))
+        }
+        None => return Err(referring_source.error_at_id(id, WorldDefinitionErrorKind::UnknownId)),
+    };
+
+    if visited.contains(id.get_ref()) {
+        return Err(referring_source.error_at_id(id, WorldDefinitionErrorKind::RoomInsideItself));
+    }
+    visited.push(id.get_ref().clone());
+
+    let room = &files[file_index].definition.rooms[room_index];
+    let parent = match &room.parent {
+        Some(parent_id) => Some(Box::new(find_room_coordinates(
+            parent_id,
+            file_index,
+            files,
+            defined,
+            room_coordinates,
+            visited,
+        )?)),
+        None => None,
+    };
+    let [x, y, z] = room.coordinates;
+    let coords = Coordinates { x, y, z, parent };
+    room_coordinates.insert(id.get_ref().clone(), coords.clone());
+
+    Ok(coords)
+}
+
+/// Spawns both sides of the provided door.
+fn spawn_door(door: &DoorDefinition, from: Entity, to: Entity, world: &mut World) {
+    let from_side = world.spawn_empty().id();
+    let to_side = world.spawn_empty().id();
+
+    for (side, direction, destination, other_side, key) in [
+        (
+            from_side,
+            door.direction,
+            to,
+            to_side,
+            door.lock.as_ref().and_then(|lock| lock.key),
+        ),
+        (
+            to_side,
+            door.direction.opposite(),
+            from,
+            from_side,
+            door.lock.as_ref().and_then(|lock| lock.other_side_key),
+        ),
+    ] {
+        let mut aliases = door.aliases.clone();
+        aliases.push(direction.to_string());
+        aliases.push(direction.short_name().to_string());
+        let mut attribute_describers = vec![
+            Connection::get_attribute_describer(),
+            OpenState::get_attribute_describer(),
+        ];
+        if door.lock.is_some() {
+            attribute_describers.push(KeyedLock::get_attribute_describer());
+        }
+
+        let mut side_entity = world.entity_mut(side);
+        side_entity.insert((
+            Description {
+                name: format!("{} to the {direction}", door.name),
+                room_name: door.name.clone(),
+                plural_name: door
+                    .plural_name
+                    .clone()
+                    .unwrap_or_else(|| format!("{}s", door.name)),
+                article: Some("a".to_string()),
+                pronouns: Pronouns::it(),
+                aliases,
+                description: door.description.clone(),
+                attribute_describers,
+            },
+            Connection {
+                direction,
+                destination,
+                other_side: Some(other_side),
+            },
+            OpenState { is_open: door.open },
+        ));
+        OpenState::register_custom_input_parser(side, world);
+
+        if let Some(lock) = &door.lock {
+            world.entity_mut(side).insert(KeyedLock {
+                is_locked: lock.locked,
+                key_id: key.map(KeyId),
+            });
+            KeyedLock::register_custom_input_parser(side, world);
+        }
+
+        let room = if side == from_side { from } else { to };
+        move_entity(side, room, world);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{game_map::GameMap, test_utils::get_entity_by_name, Time};
+
+    use super::*;
+
+    fn set_up_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(GameMap::new());
+        world.insert_resource(Time::new());
+        crate::resource::insert_resources(&mut world);
+
+        world
+    }
+
+    fn spawn_from_str(
+        contents: &str,
+        world: &mut World,
+    ) -> Result<Coordinates, WorldDefinitionError> {
+        let world_definition = WorldDefinition::from_files(vec![SourceFile {
+            name: "test.toml".to_string(),
+            contents: contents.to_string(),
+        }])?;
+        world.insert_resource(world_definition.build_prototype_catalog()?);
+
+        world_definition.spawn(world)
+    }
+
+    const ROOMS: &str = r#"
+spawn_room = "room_1"
+
+[[rooms]]
+id = "room_1"
+name = "Room 1"
+icon = { chars = "[]", foreground = "White" }
+coordinates = [0, 0, 0]
+
+[[rooms]]
+id = "room_2"
+name = "Room 2"
+icon = { chars = "[]", foreground = "White" }
+coordinates = [0, 1, 0]
+"#;
+
+    #[test]
+    fn built_in_world() {
+        let mut world = set_up_world();
+
+        let world_definition = WorldDefinition::built_in().unwrap();
+        world.insert_resource(world_definition.build_prototype_catalog().unwrap());
+        let spawn_room_coords = world_definition.spawn(&mut world).unwrap();
+
+        let spawn_room = world.resource::<GameMap>().locations[&spawn_room_coords];
+        assert_eq!(
+            "The middle room",
+            world.get::<Room>(spawn_room).unwrap().name
+        );
+        assert_eq!(11, world.resource::<GameMap>().locations.len());
+
+        let npc = get_entity_by_name("Some Guy", &world).id();
+        let shirt = get_entity_by_name("cool shirt", &world).id();
+        assert!(world
+            .get::<WornItems>(npc)
+            .unwrap()
+            .get_all_items()
+            .contains(&shirt));
+
+        let door = get_entity_by_name("fancy door to the north", &world);
+        assert!(door.get::<KeyedLock>().unwrap().is_locked);
+        assert!(!door.get::<OpenState>().unwrap().is_open);
+        assert_eq!(
+            2,
+            world
+                .query::<&Description>()
+                .iter(&world)
+                .filter(|desc| desc.name == "lead weight")
+                .count()
+        );
+    }
+
+    #[test]
+    fn connections_and_nested_rooms() {
+        let mut world = set_up_world();
+        let contents = format!(
+            r#"{ROOMS}
+[[rooms]]
+id = "inner_room"
+name = "Inner Room"
+icon = {{ chars = "[]", foreground = "White" }}
+coordinates = [0, 0, 0]
+parent = "room_2"
+
+[[connections]]
+from = "room_1"
+direction = "North"
+to = "room_2"
+
+[[item_prototypes]]
+id = "thing"
+name = "thing"
+description = "A thing."
+
+[[items]]
+location = "inner_room"
+prototype = "thing"
+"#
+        );
+
+        spawn_from_str(&contents, &mut world).unwrap();
+
+        let inner_room_coords = Coordinates {
+            x: 0,
+            y: 0,
+            z: 0,
+            parent: Some(Box::new(Coordinates {
+                x: 0,
+                y: 1,
+                z: 0,
+                parent: None,
+            })),
+        };
+        let inner_room = world.resource::<GameMap>().locations[&inner_room_coords];
+        let thing = get_entity_by_name("thing", &world).id();
+        crate::test_utils::assert_entity_in_container(thing, inner_room, &world);
+        assert_eq!(
+            2,
+            world.query::<&Connection>().iter(&world).count(),
+            "an open connection should be spawned in each direction"
+        );
+    }
+
+    #[test]
+    fn parse_error_has_line() {
+        let mut world = set_up_world();
+        let contents = format!("{ROOMS}\n[[items]]\nlocation = \"room_1\"\nprototype = 5\n");
+
+        let error = spawn_from_str(&contents, &mut world).unwrap_err();
+
+        assert_eq!("test.toml", error.file);
+        assert_eq!(Some(18), error.line);
+        assert!(matches!(error.kind, WorldDefinitionErrorKind::Parse(_)));
+    }
+
+    #[test]
+    fn unknown_id_has_line() {
+        let mut world = set_up_world();
+        let contents = format!(
+            "{ROOMS}\n[[connections]]\nfrom = \"room_1\"\ndirection = \"North\"\nto = \"room_3\"\n"
+        );
+
+        let error = spawn_from_str(&contents, &mut world).unwrap_err();
+
+        assert_eq!(Some(19), error.line);
+        assert!(
+            matches!(error.kind, WorldDefinitionErrorKind::UnknownId(ref id) if id == "room_3")
+        );
+        assert_eq!(
+            "test.toml:19: nothing with ID \"room_3\" is defined",
+            format!("{error}")
+        );
+    }
+
+    #[test]
+    fn duplicate_id() {
+        let mut world = set_up_world();
+        let contents = format!(
+            "{ROOMS}\n[[item_prototypes]]\nid = \"thing\"\nname = \"thing\"\ndescription = \"A thing.\"\n\n[[items]]\nid = \"room_2\"\nlocation = \"room_1\"\nprototype = \"thing\"\n"
+        );
+
+        let error = spawn_from_str(&contents, &mut world).unwrap_err();
+
+        assert_eq!(Some(22), error.line);
+        assert!(matches!(error.kind, WorldDefinitionErrorKind::DuplicateId(id) if id == "room_2"));
+    }
+
+    #[test]
+    fn placement_of_wrong_prototype_kind() {
+        let mut world = set_up_world();
+        let contents = format!(
+            "{ROOMS}\n[[item_prototypes]]\nid = \"thing\"\n\n[[npcs]]\nlocation = \"room_1\"\nprototype = \"thing\"\n"
+        );
+
+        let error = spawn_from_str(&contents, &mut world).unwrap_err();
+
+        assert_eq!(Some(21), error.line);
+        assert!(
+            matches!(error.kind, WorldDefinitionErrorKind::NotNpcPrototype(id) if id == "thing")
+        );
+    }
+
+    #[test]
+    fn placement_missing_field() {
+        let mut world = set_up_world();
+        let contents = format!(
+            "{ROOMS}\n[[item_prototypes]]\nid = \"thing\"\nname = \"thing\"\n\n[[items]]\nlocation = \"room_1\"\nprototype = \"thing\"\n"
+        );
+
+        let error = spawn_from_str(&contents, &mut world).unwrap_err();
+
+        assert_eq!(Some(22), error.line);
+        assert!(matches!(
+            error.kind,
+            WorldDefinitionErrorKind::MissingField {
+                field: "description",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn room_inside_itself() {
+        let mut world = set_up_world();
+        let contents = r#"
+spawn_room = "room_1"
+
+[[rooms]]
+id = "room_1"
+icon = { chars = "[]", foreground = "White" }
+coordinates = [0, 0, 0]
+parent = "room_2"
+
+[[rooms]]
+id = "room_2"
+icon = { chars = "[]", foreground = "White" }
+coordinates = [0, 0, 0]
+parent = "room_1"
+"#;
+
+        let error = spawn_from_str(contents, &mut world).unwrap_err();
+
+        assert!(matches!(
+            error.kind,
+            WorldDefinitionErrorKind::RoomInsideItself(_)
+        ));
+    }
+
+    #[test]
+    fn missing_spawn_room() {
+        let mut world = set_up_world();
+        let contents = ROOMS.replace("spawn_room = \"room_1\"", "");
+
+        let error = spawn_from_str(&contents, &mut world).unwrap_err();
+
+        assert!(matches!(
+            error.kind,
+            WorldDefinitionErrorKind::MissingSpawnRoom
+        ));
+    }
+
+    /// Parses a world definition from the provided contents and finds its city entrance.
+    fn find_city_entrance_in_str(
+        contents: &str,
+    ) -> Result<Option<CityEntrance>, WorldDefinitionError> {
+        WorldDefinition::from_files(vec![SourceFile {
+            name: "test.toml".to_string(),
+            contents: contents.to_string(),
+        }])?
+        .find_city_entrance()
+    }
+
+    #[test]
+    fn city_entrance() {
+        let contents =
+            format!("city_entrance = {{ room = \"room_2\", direction = \"North\" }}\n{ROOMS}");
+
+        let entrance = find_city_entrance_in_str(&contents).unwrap().unwrap();
+
+        assert_eq!(
+            CityEntrance {
+                coordinates: Coordinates {
+                    x: 0,
+                    y: 1,
+                    z: 0,
+                    parent: None
+                },
+                direction: Direction::North,
+            },
+            entrance
+        );
+        assert_eq!(None, find_city_entrance_in_str(ROOMS).unwrap());
+    }
+
+    #[test]
+    fn city_entrance_inside_room() {
+        let contents = format!(
+            "city_entrance = {{ room = \"room_3\", direction = \"North\" }}\n{ROOMS}
+[[rooms]]
+id = \"room_3\"
+name = \"Room 3\"
+parent = \"room_1\"
+icon = {{ chars = \"[]\", foreground = \"White\" }}
+coordinates = [0, 0, 0]
+"
+        );
+
+        let error = find_city_entrance_in_str(&contents).unwrap_err();
+
+        assert!(matches!(
+            error.kind,
+            WorldDefinitionErrorKind::CityEntranceInsideRoom(id) if id == "room_3"
+        ));
+    }
+
+    #[test]
+    fn city_entrance_going_up() {
+        let contents =
+            format!("city_entrance = {{ room = \"room_1\", direction = \"Up\" }}\n{ROOMS}");
+
+        let error = find_city_entrance_in_str(&contents).unwrap_err();
+
+        assert!(matches!(
+            error.kind,
+            WorldDefinitionErrorKind::InvalidCityEntranceDirection(Direction::Up)
+        ));
+    }
+}
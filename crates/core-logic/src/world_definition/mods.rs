@@ -0,0 +1,461 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use super::{
+    read_world_files, ParsedFile, SourceFile, WorldDefinitionError, WorldDefinitionErrorKind,
+};
+
+/// The name of the file in each mod's directory that describes the mod.
+const MANIFEST_FILE_NAME: &str = "mod.toml";
+
+/// Describes a mod.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ModManifest {
+    /// The unique ID of the mod.
+    pub id: String,
+    /// The version of the mod.
+    pub version: String,
+    /// The name of the mod to show to people. Defaults to the ID.
+    pub name: Option<String>,
+    /// The IDs of the mods that need to be loaded before this one.
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+}
+
+/// A mod that has been found, but not loaded yet.
+struct FoundMod {
+    /// The manifest of the mod.
+    manifest: ModManifest,
+    /// The path to the manifest, for error messages.
+    manifest_path: String,
+    /// The directory the mod is in.
+    dir: PathBuf,
+}
+
+/// Reads the manifests of all the mods in the provided directory, and determines which ones should be loaded and in what order.
+///
+/// If `active_mods` is set, only the mods with those IDs are loaded. Otherwise all of them are.
+/// Mods are loaded after the mods they depend on, and otherwise in order of ID.
+fn find_mods(
+    mods_dir: &Path,
+    active_mods: Option<&[String]>,
+) -> Result<Vec<FoundMod>, WorldDefinitionError> {
+    let io_error = |path: &Path, e| WorldDefinitionError {
+        file: path.display().to_string(),
+        line: None,
+        kind: WorldDefinitionErrorKind::Io(e),
+    };
+
+    let mut dirs = std::fs::read_dir(mods_dir)
+        .map_err(|e| io_error(mods_dir, e))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<PathBuf>, std::io::Error>>()
+        .map_err(|e| io_error(mods_dir, e))?;
+    dirs.retain(|dir| dir.join(MANIFEST_FILE_NAME).is_file());
+    dirs.sort();
+
+    let mut found_mods = HashMap::new();
+    for dir in dirs {
+        let manifest_path = dir.join(MANIFEST_FILE_NAME);
+        let source = SourceFile {
+            name: manifest_path.display().to_string(),
+            contents: std::fs::read_to_string(&manifest_path)
+                .map_err(|e| io_error(&manifest_path, e))?,
+        };
+        let manifest = source.parse_as::<ModManifest>()?;
+        let found_mod = FoundMod {
+            manifest,
+            manifest_path: source.name,
+            dir,
+        };
+        let id = found_mod.manifest.id.clone();
+        if let Some(other_mod) = found_mods.insert(id.clone(), found_mod) {
+            return Err(WorldDefinitionError {
+                file: other_mod.manifest_path,
+                line: None,
+                kind: WorldDefinitionErrorKind::DuplicateModId(id),
+            });
+        }
+    }
+
+    let active_ids = match active_mods {
+        Some(active_mods) => {
+            for id in active_mods {
+                if !found_mods.contains_key(id) {
+                    return Err(WorldDefinitionError {
+                        file: mods_dir.display().to_string(),
+                        line: None,
+                        kind: WorldDefinitionErrorKind::UnknownMod(id.clone()),
+                    });
+                }
+            }
+            active_mods.iter().cloned().collect::<BTreeSet<String>>()
+        }
+        None => found_mods.keys().cloned().collect(),
+    };
+    found_mods.retain(|id, _| active_ids.contains(id));
+
+    for found_mod in found_mods.values() {
+        for dependency in &found_mod.manifest.dependencies {
+            if !found_mods.contains_key(dependency) {
+                return Err(WorldDefinitionError {
+                    file: found_mod.manifest_path.clone(),
+                    line: None,
+                    kind: WorldDefinitionErrorKind::MissingDependency {
+                        mod_id: found_mod.manifest.id.clone(),
+                        dependency: dependency.clone(),
+                    },
+                });
+            }
+        }
+    }
+
+    // repeatedly load whichever mod comes first by ID out of the ones that have all their dependencies loaded already
+    let mut ordered_mods: Vec<FoundMod> = Vec::new();
+    let mut remaining_ids = active_ids;
+    while !remaining_ids.is_empty() {
+        let next_id = remaining_ids
+            .iter()
+            .find(|id| {
+                found_mods[*id]
+                    .manifest
+                    .dependencies
+                    .iter()
+                    .all(|dependency| !remaining_ids.contains(dependency))
+            })
+            .cloned();
+
+        let Some(next_id) = next_id else {
+            // everything left depends on something else that's left, so there must be a cycle
+            let stuck_mod = &found_mods[remaining_ids.first().expect("there should be a mod left")];
+            return Err(WorldDefinitionError {
+                file: stuck_mod.manifest_path.clone(),
+                line: None,
+                kind: WorldDefinitionErrorKind::DependencyCycle(stuck_mod.manifest.id.clone()),
+            });
+        };
+
+        remaining_ids.remove(&next_id);
+        ordered_mods.push(found_mods.remove(&next_id).expect("mod should exist"));
+    }
+
+    Ok(ordered_mods)
+}
+
+/// Reads the manifests and world definition files of the mods to load from the provided directory, in the order they should be loaded.
+pub(super) fn read_mods(
+    mods_dir: &Path,
+    active_mods: Option<&[String]>,
+) -> Result<Vec<(ModManifest, Vec<SourceFile>)>, WorldDefinitionError> {
+    find_mods(mods_dir, active_mods)?
+        .into_iter()
+        .map(|found_mod| {
+            let mut files = read_world_files(&found_mod.dir)?;
+            files.retain(|file| {
+                Path::new(&file.name)
+                    .file_name()
+                    .is_none_or(|name| name != MANIFEST_FILE_NAME)
+            });
+            Ok((found_mod.manifest, files))
+        })
+        .collect()
+}
+
+/// Something defined by one mod (or the base world) that's overridden by a mod loaded later.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModConflict {
+    /// What kind of thing was overridden, e.g. "room".
+    pub kind: &'static str,
+    /// The ID of the thing that was overridden.
+    pub id: String,
+    /// The ID of the mod that defined the thing first, or `BASE_WORLD_ORIGIN`.
+    pub overridden: String,
+    /// The ID of the mod that overrode it.
+    pub overridden_by: String,
+}
+
+impl Display for ModConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.id.is_empty() {
+            write!(
+                f,
+                "{} from {} is overridden by {}",
+                self.kind, self.overridden, self.overridden_by
+            )
+        } else {
+            write!(
+                f,
+                "{} {} from {} is overridden by {}",
+                self.kind, self.id, self.overridden, self.overridden_by
+            )
+        }
+    }
+}
+
+/// Finds everything in the provided files that's defined in more than one place, and which definition wins.
+pub(super) fn find_conflicts(files: &[ParsedFile]) -> Vec<ModConflict> {
+    let mut conflicts = Vec::new();
+    let mut origins = HashMap::new();
+    for file in files {
+        let definition = &file.definition;
+        let ids = definition
+            .rooms
+            .iter()
+            .map(|room| ("room", format!("{:?}", room.id.get_ref())))
+            .chain(
+                definition
+                    .spawn_room
+                    .iter()
+                    .map(|_| ("spawn room", String::new())),
+            )
+            .chain(
+                definition
+                    .item_prototypes
+                    .iter()
+                    .map(|prototype| ("prototype", format!("{:?}", prototype.get_id()))),
+            )
+            .chain(
+                definition
+                    .npc_prototypes
+                    .iter()
+                    .map(|prototype| ("prototype", format!("{:?}", prototype.get_id()))),
+            )
+            .chain(
+                definition
+                    .scripts
+                    .iter()
+                    .map(|script| ("script", format!("{:?}", script.id.get_ref()))),
+            )
+            .chain(
+                definition
+                    .commands
+                    .iter()
+                    .map(|command| ("command", format!("{:?}", command.id.get_ref()))),
+            )
+            .chain(
+                definition
+                    .messages
+                    .keys()
+                    .map(|id| ("message template", format!("{:?}", id.get_ref()))),
+            )
+            .chain(
+                definition
+                    .localized_messages
+                    .iter()
+                    .flat_map(|(locale, messages)| {
+                        messages.keys().map(move |id| {
+                            (
+                                "message template",
+                                format!("{:?} ({})", id.get_ref(), locale.code()),
+                            )
+                        })
+                    }),
+            )
+            .chain(definition.get_catalog_keys());
+
+        for (kind, id) in ids {
+            match origins.insert((kind, id.clone()), &file.origin) {
+                Some(previous_origin) if *previous_origin != file.origin => {
+                    conflicts.push(ModConflict {
+                        kind,
+                        id,
+                        overridden: previous_origin.clone(),
+                        overridden_by: file.origin.clone(),
+                    })
+                }
+                _ => (),
+            }
+        }
+    }
+
+    conflicts
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+
+    use crate::{component::Description, game_map::GameMap, spawn_prototype, Time};
+
+    use super::*;
+    use crate::world_definition::WorldDefinition;
+
+    /// A mod to write to a test mods directory.
+    struct TestMod {
+        dir: &'static str,
+        manifest: &'static str,
+        files: &'static [(&'static str, &'static str)],
+    }
+
+    /// Writes the provided mods to a new directory, and returns its path.
+    fn write_mods(test_name: &str, mods: &[TestMod]) -> PathBuf {
+        let mods_dir = std::env::temp_dir().join(format!(
+            "textpocalypse-mods-test-{test_name}-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&mods_dir);
+        for test_mod in mods {
+            let dir = mods_dir.join(test_mod.dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join(MANIFEST_FILE_NAME), test_mod.manifest).unwrap();
+            for (name, contents) in test_mod.files {
+                std::fs::write(dir.join(name), contents).unwrap();
+            }
+        }
+
+        mods_dir
+    }
+
+    fn find_mod_ids(
+        mods_dir: &Path,
+        active_mods: Option<&[String]>,
+    ) -> Result<Vec<String>, WorldDefinitionError> {
+        let result = find_mods(mods_dir, active_mods).map(|mods| {
+            mods.into_iter()
+                .map(|found_mod| found_mod.manifest.id)
+                .collect()
+        });
+        std::fs::remove_dir_all(mods_dir).unwrap();
+
+        result
+    }
+
+    const INDEPENDENT_MODS: [TestMod; 3] = [
+        TestMod {
+            dir: "1",
+            manifest: "id = \"a\"\nversion = \"1.0\"\ndependencies = [\"b\"]\n",
+            files: &[],
+        },
+        TestMod {
+            dir: "2",
+            manifest: "id = \"b\"\nversion = \"1.0\"\n",
+            files: &[],
+        },
+        TestMod {
+            dir: "3",
+            manifest: "id = \"c\"\nversion = \"1.0\"\n",
+            files: &[],
+        },
+    ];
+
+    #[test]
+    fn load_order_follows_dependencies() {
+        let mods_dir = write_mods("order", &INDEPENDENT_MODS);
+
+        let ids = find_mod_ids(&mods_dir, None).unwrap();
+
+        assert_eq!(vec!["b", "a", "c"], ids);
+    }
+
+    #[test]
+    fn only_active_mods_are_loaded() {
+        let mods_dir = write_mods("active", &INDEPENDENT_MODS);
+
+        let ids = find_mod_ids(&mods_dir, Some(&["c".to_string()])).unwrap();
+
+        assert_eq!(vec!["c"], ids);
+    }
+
+    #[test]
+    fn inactive_dependency() {
+        let mods_dir = write_mods("inactive_dependency", &INDEPENDENT_MODS);
+
+        let error = find_mod_ids(&mods_dir, Some(&["a".to_string()])).unwrap_err();
+
+        assert!(matches!(
+            error.kind,
+            WorldDefinitionErrorKind::MissingDependency { mod_id, dependency } if mod_id == "a" && dependency == "b"
+        ));
+    }
+
+    #[test]
+    fn dependency_cycle() {
+        let mods_dir = write_mods(
+            "cycle",
+            &[
+                TestMod {
+                    dir: "1",
+                    manifest: "id = \"a\"\nversion = \"1.0\"\ndependencies = [\"b\"]\n",
+                    files: &[],
+                },
+                TestMod {
+                    dir: "2",
+                    manifest: "id = \"b\"\nversion = \"1.0\"\ndependencies = [\"a\"]\n",
+                    files: &[],
+                },
+            ],
+        );
+
+        let error = find_mod_ids(&mods_dir, None).unwrap_err();
+
+        assert!(matches!(
+            error.kind,
+            WorldDefinitionErrorKind::DependencyCycle(_)
+        ));
+    }
+
+    #[test]
+    fn later_mods_override_earlier_content() {
+        let mods_dir = write_mods(
+            "override",
+            &[
+                TestMod {
+                    dir: "base_override",
+                    manifest: "id = \"base_override\"\nversion = \"1.0\"\n",
+                    files: &[(
+                        "prototypes.toml",
+                        "[[item_prototypes]]\nid = \"water_bottle\"\nextends = \"bottle\"\nname = \"canteen\"\n",
+                    )],
+                },
+                TestMod {
+                    dir: "fluids",
+                    manifest: "id = \"fluids\"\nversion = \"1.0\"\ndependencies = [\"base_override\"]\n",
+                    files: &[(
+                        "fluids.toml",
+                        "[[fluids]]\nfluid = \"Water\"\nname = \"H2O\"\n",
+                    )],
+                },
+            ],
+        );
+
+        let world_definition = WorldDefinition::built_in()
+            .unwrap()
+            .with_mods(&mods_dir, None);
+        std::fs::remove_dir_all(&mods_dir).unwrap();
+        let world_definition = world_definition.unwrap();
+
+        assert_eq!(
+            vec![ModConflict {
+                kind: "prototype",
+                id: "\"water_bottle\"".to_string(),
+                overridden: crate::world_definition::BASE_WORLD_ORIGIN.to_string(),
+                overridden_by: "base_override".to_string(),
+            }],
+            world_definition.find_conflicts()
+        );
+
+        let mut world = World::new();
+        world.insert_resource(GameMap::new());
+        world.insert_resource(Time::new());
+        crate::resource::insert_resources(&mut world);
+        world_definition.apply_catalog_definitions(&mut world);
+        world.insert_resource(world_definition.build_prototype_catalog().unwrap());
+        world_definition.spawn(&mut world).unwrap();
+
+        let room = world
+            .spawn(crate::component::Container::new_infinite())
+            .id();
+        let bottle = spawn_prototype("water_bottle", room, &mut world).unwrap();
+        assert_eq!("canteen", world.get::<Description>(bottle).unwrap().name);
+        assert_eq!(
+            "H2O",
+            crate::resource::get_fluid_name(&crate::component::FluidType::Water, &world)
+        );
+    }
+}
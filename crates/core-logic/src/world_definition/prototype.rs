@@ -0,0 +1,939 @@
+use std::{collections::HashMap, fmt::Display, num::NonZeroU8};
+
+use bevy_ecs::prelude::*;
+use serde::Deserialize;
+use toml::Spanned;
+
+use crate::{
+    add_human_body_parts, add_human_innate_weapon,
+    component::{
+        AntiRadiation, AttributeDescriber, Calories, Container, DescribeAttributes, Description,
+        Edible, EquippedItems, Fluid, FluidContainer, FluidType, FromPrototype, GeigerCounter,
+        GreetBehavior, Item, KeyId, LightLevel, LocalizedDescription, LocalizedDescriptions,
+        ParseCustomInput, PortableLight, PowerSource, Pronouns, RadiationSource, Script,
+        ScriptCommands, SelfDefenseBehavior, SleepState, Stats, Vitals, Volume, WanderBehavior,
+        Weapon, Wearable, Weight, Wetness, WornItems,
+    },
+    move_entity, ConstrainedValue, Invisible, Locale, StartingStats,
+};
+
+use super::{ParsedFile, WorldDefinitionError, WorldDefinitionErrorKind};
+
+/// Defines a kind of item that can be spawned any number of times.
+///
+/// Every property is optional so prototypes can leave things to be filled in by the prototype they extend, but an item can't be spawned from a prototype without a name and description.
+#[derive(Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct ItemPrototype {
+    /// The ID to refer to the prototype by.
+    id: Spanned<String>,
+    /// The ID of the item prototype to take any properties this one doesn't set from.
+    extends: Option<Spanned<String>>,
+    /// The name of the item.
+    name: Option<String>,
+    /// The name to use when referring to the item as part of a room description. Defaults to the name.
+    room_name: Option<String>,
+    /// The name to use when referring to multiple of the item. Defaults to the name with an "s" on the end.
+    plural_name: Option<String>,
+    /// The article to use when referring to the item. An empty string means no article. Defaults to "a".
+    article: Option<String>,
+    /// The pronouns to use when referring to the item. Defaults to "it".
+    pronouns: Option<PronounsDefinition>,
+    /// Alternate names of the item.
+    aliases: Option<Vec<String>>,
+    /// The description of the item.
+    description: Option<String>,
+    /// The name and description of the item in other languages, keyed by locale code.
+    localized: Option<HashMap<Locale, LocalizedDescription>>,
+    /// The number of hands needed to equip the item. Defaults to 1.
+    hands: Option<NonZeroU8>,
+    /// The volume of the item.
+    volume: Option<Volume>,
+    /// The weight of the item.
+    weight: Option<Weight>,
+    /// The number of calories in the item. Items with calories can be eaten.
+    calories: Option<u16>,
+    /// How many rads of absorbed radiation eating the item gets rid of. Items with this can be eaten.
+    anti_radiation: Option<f32>,
+    /// The ID of the locks the item is a key for.
+    key_id: Option<u32>,
+    /// Makes the item able to hold other items, if set.
+    container: Option<ContainerDefinition>,
+    /// Makes the item able to hold fluids, if set.
+    fluid_container: Option<FluidContainerDefinition>,
+    /// Makes the item able to be worn, if set.
+    wearable: Option<Wearable>,
+    /// Makes the item usable as a weapon, if set.
+    weapon: Option<Weapon>,
+    /// Makes the item a light that can be turned on and off, if set.
+    light: Option<PortableLightDefinition>,
+    /// How much radiation the item gives off, in rads per tick.
+    radiation: Option<f32>,
+    /// Whether the item is a Geiger counter, which shows how much radiation there is where it is. Defaults to false.
+    geiger_counter: Option<bool>,
+    /// Whether the item is invisible to everyone. Defaults to false.
+    invisible: Option<bool>,
+    /// The ID of the script the item behaves according to, if any.
+    script: Option<Spanned<String>>,
+    /// The IDs of the commands that can be entered to interact with the item.
+    commands: Option<Vec<Spanned<String>>>,
+}
+
+impl ItemPrototype {
+    /// Gets the ID of this prototype.
+    pub(super) fn get_id(&self) -> &str {
+        self.id.get_ref()
+    }
+
+    /// Gets the ID of the script this prototype sets, if it sets one.
+    pub(super) fn get_script(&self) -> Option<&Spanned<String>> {
+        self.script.as_ref()
+    }
+
+    /// Gets the IDs of the commands this prototype sets.
+    pub(super) fn get_commands(&self) -> &[Spanned<String>] {
+        self.commands.as_deref().unwrap_or_default()
+    }
+
+    /// Fills in any properties this prototype doesn't set with the ones from the provided prototype.
+    fn inherit_from(self, parent: &ItemPrototype) -> ItemPrototype {
+        ItemPrototype {
+            id: self.id,
+            extends: self.extends,
+            name: self.name.or_else(|| parent.name.clone()),
+            room_name: self.room_name.or_else(|| parent.room_name.clone()),
+            plural_name: self.plural_name.or_else(|| parent.plural_name.clone()),
+            article: self.article.or_else(|| parent.article.clone()),
+            pronouns: self.pronouns.or_else(|| parent.pronouns.clone()),
+            aliases: self.aliases.or_else(|| parent.aliases.clone()),
+            description: self.description.or_else(|| parent.description.clone()),
+            localized: self.localized.or_else(|| parent.localized.clone()),
+            hands: self.hands.or(parent.hands),
+            volume: self.volume.or(parent.volume),
+            weight: self.weight.or(parent.weight),
+            calories: self.calories.or(parent.calories),
+            anti_radiation: self.anti_radiation.or(parent.anti_radiation),
+            key_id: self.key_id.or(parent.key_id),
+            container: self.container.or_else(|| parent.container.clone()),
+            fluid_container: self
+                .fluid_container
+                .or_else(|| parent.fluid_container.clone()),
+            wearable: self.wearable.or_else(|| parent.wearable.clone()),
+            weapon: self.weapon.or_else(|| parent.weapon.clone()),
+            light: self.light.or(parent.light),
+            radiation: self.radiation.or(parent.radiation),
+            geiger_counter: self.geiger_counter.or(parent.geiger_counter),
+            invisible: self.invisible.or(parent.invisible),
+            script: self.script.or_else(|| parent.script.clone()),
+            commands: self.commands.or_else(|| parent.commands.clone()),
+        }
+    }
+}
+
+/// Defines a kind of NPC that can be spawned any number of times. NPCs always have human bodies.
+///
+/// Every property is optional so prototypes can leave things to be filled in by the prototype they extend, but an NPC can't be spawned from a prototype without a name, pronouns, description, volume, weight, maximum health, stats, and inventory.
+#[derive(Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct NpcPrototype {
+    /// The ID to refer to the prototype by.
+    id: Spanned<String>,
+    /// The ID of the NPC prototype to take any properties this one doesn't set from.
+    extends: Option<Spanned<String>>,
+    /// The name of the NPC.
+    name: Option<String>,
+    /// The name to use when referring to the NPC as part of a room description. Defaults to the name.
+    room_name: Option<String>,
+    /// The name to use when referring to multiple of the NPC. Defaults to the name with an "s" on the end.
+    plural_name: Option<String>,
+    /// The article to use when referring to the NPC. An empty string means no article. Defaults to "a".
+    article: Option<String>,
+    /// The pronouns to use when referring to the NPC.
+    pronouns: Option<PronounsDefinition>,
+    /// Alternate names of the NPC.
+    aliases: Option<Vec<String>>,
+    /// The description of the NPC.
+    description: Option<String>,
+    /// The name and description of the NPC in other languages, keyed by locale code.
+    localized: Option<HashMap<Locale, LocalizedDescription>>,
+    /// The volume of the NPC.
+    volume: Option<Volume>,
+    /// The weight of the NPC.
+    weight: Option<Weight>,
+    /// The maximum health of the NPC.
+    max_health: Option<f32>,
+    /// The starting stats of the NPC.
+    stats: Option<StatsDefinition>,
+    /// How much the NPC can carry.
+    inventory: Option<ContainerDefinition>,
+    /// The maximum total thickness of things the NPC can wear on each body part. Defaults to 5.
+    max_worn_thickness: Option<u32>,
+    /// The number of hands the NPC has to equip things with. Defaults to 2.
+    hands: Option<u8>,
+    /// Makes the NPC wander around, if set.
+    wander: Option<WanderBehavior>,
+    /// Whether the NPC fights back when attacked. Defaults to false.
+    self_defense: Option<bool>,
+    /// What the NPC says to entities that enter its room, if anything.
+    greeting: Option<String>,
+    /// The ID of the script the NPC behaves according to, if any.
+    script: Option<Spanned<String>>,
+}
+
+impl NpcPrototype {
+    /// Gets the ID of this prototype.
+    pub(super) fn get_id(&self) -> &str {
+        self.id.get_ref()
+    }
+
+    /// Gets the ID of the script this prototype sets, if it sets one.
+    pub(super) fn get_script(&self) -> Option<&Spanned<String>> {
+        self.script.as_ref()
+    }
+
+    /// Fills in any properties this prototype doesn't set with the ones from the provided prototype.
+    fn inherit_from(self, parent: &NpcPrototype) -> NpcPrototype {
+        NpcPrototype {
+            id: self.id,
+            extends: self.extends,
+            name: self.name.or_else(|| parent.name.clone()),
+            room_name: self.room_name.or_else(|| parent.room_name.clone()),
+            plural_name: self.plural_name.or_else(|| parent.plural_name.clone()),
+            article: self.article.or_else(|| parent.article.clone()),
+            pronouns: self.pronouns.or_else(|| parent.pronouns.clone()),
+            aliases: self.aliases.or_else(|| parent.aliases.clone()),
+            description: self.description.or_else(|| parent.description.clone()),
+            localized: self.localized.or_else(|| parent.localized.clone()),
+            volume: self.volume.or(parent.volume),
+            weight: self.weight.or(parent.weight),
+            max_health: self.max_health.or(parent.max_health),
+            stats: self.stats.or_else(|| parent.stats.clone()),
+            inventory: self.inventory.or_else(|| parent.inventory.clone()),
+            max_worn_thickness: self.max_worn_thickness.or(parent.max_worn_thickness),
+            hands: self.hands.or(parent.hands),
+            wander: self.wander.or_else(|| parent.wander.clone()),
+            self_defense: self.self_defense.or(parent.self_defense),
+            greeting: self.greeting.or_else(|| parent.greeting.clone()),
+            script: self.script.or_else(|| parent.script.clone()),
+        }
+    }
+}
+
+/// Which pronouns to use for an entity.
+#[derive(Clone, Deserialize)]
+#[serde(untagged)]
+enum PronounsDefinition {
+    /// One of the standard sets of pronouns.
+    Standard(StandardPronouns),
+    /// A custom set of pronouns.
+    Custom(Pronouns),
+}
+
+/// The standard sets of pronouns.
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum StandardPronouns {
+    He,
+    She,
+    They,
+    It,
+}
+
+impl PronounsDefinition {
+    /// Builds the pronouns this defines.
+    fn build(self) -> Pronouns {
+        match self {
+            PronounsDefinition::Standard(StandardPronouns::He) => Pronouns::he(),
+            PronounsDefinition::Standard(StandardPronouns::She) => Pronouns::she(),
+            PronounsDefinition::Standard(StandardPronouns::They) => Pronouns::they(),
+            PronounsDefinition::Standard(StandardPronouns::It) => Pronouns::it(),
+            PronounsDefinition::Custom(pronouns) => pronouns,
+        }
+    }
+}
+
+/// Defines a set of starting stats.
+#[derive(Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StatsDefinition {
+    /// The value of every attribute.
+    attributes: u16,
+    /// The value of every skill.
+    skills: u16,
+}
+
+/// Defines the limits of a container.
+#[derive(Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ContainerDefinition {
+    /// The maximum volume of things the container can hold, if it's limited.
+    volume: Option<Volume>,
+    /// The maximum weight of things the container can hold, if it's limited.
+    weight: Option<Weight>,
+}
+
+/// Defines a container of fluid.
+#[derive(Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FluidContainerDefinition {
+    /// The maximum volume of fluid the container can hold, if it's limited.
+    volume: Option<Volume>,
+    /// The fluid the container starts out with, keyed by type.
+    #[serde(default)]
+    contents: HashMap<FluidType, Volume>,
+}
+
+/// Defines a light that can be carried around.
+#[derive(Clone, Copy, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PortableLightDefinition {
+    /// How much light the light gives off while it's on.
+    light_level: LightLevel,
+    /// What the light runs on.
+    power_source: PowerSource,
+    /// How many ticks the light can stay on for before running out.
+    capacity: u32,
+}
+
+/// A prototype of any kind.
+#[derive(Clone)]
+pub(super) enum Prototype {
+    Item(Box<ItemPrototype>),
+    Npc(Box<NpcPrototype>),
+}
+
+/// The prototypes entities can be spawned from, keyed by ID.
+#[derive(Resource, Default)]
+pub struct PrototypeCatalog {
+    /// The prototypes, with everything they inherit from other prototypes already filled in.
+    prototypes: HashMap<String, Prototype>,
+    /// The IDs of the item prototypes new players start with one of each of.
+    starting_items: Vec<String>,
+}
+
+impl PrototypeCatalog {
+    /// Builds a catalog of the prototypes defined in the provided files.
+    pub(super) fn from_files(
+        files: &[ParsedFile],
+    ) -> Result<PrototypeCatalog, WorldDefinitionError> {
+        let mut definitions = HashMap::new();
+        for (file_index, file) in files.iter().enumerate() {
+            let item_prototypes = file
+                .definition
+                .item_prototypes
+                .iter()
+                .map(|prototype| (&prototype.id, Prototype::Item(Box::new(prototype.clone()))));
+            let npc_prototypes = file
+                .definition
+                .npc_prototypes
+                .iter()
+                .map(|prototype| (&prototype.id, Prototype::Npc(Box::new(prototype.clone()))));
+            for (id, prototype) in item_prototypes.chain(npc_prototypes) {
+                // prototypes can be overridden by prototypes from later mods
+                if let Some((other_file_index, _)) =
+                    definitions.insert(id.get_ref().clone(), (file_index, prototype))
+                {
+                    if files[other_file_index].origin == file.origin {
+                        return Err(file
+                            .source
+                            .error_at_id(id, WorldDefinitionErrorKind::DuplicateId));
+                    }
+                }
+            }
+        }
+
+        let mut catalog = PrototypeCatalog::default();
+        for id in definitions.keys() {
+            resolve_prototype(id, files, &definitions, &mut catalog, &mut Vec::new())?;
+        }
+
+        for file in files {
+            for id in &file.definition.starting_items {
+                match catalog.prototypes.get(id.get_ref()) {
+                    Some(Prototype::Item(_)) => catalog.starting_items.push(id.get_ref().clone()),
+                    Some(Prototype::Npc(_)) => {
+                        return Err(file
+                            .source
+                            .error_at_id(id, WorldDefinitionErrorKind::NotItemPrototype))
+                    }
+                    None => {
+                        return Err(file
+                            .source
+                            .error_at_id(id, WorldDefinitionErrorKind::UnknownPrototype))
+                    }
+                }
+            }
+        }
+
+        Ok(catalog)
+    }
+
+    /// Gets the prototype with the provided ID, if there is one.
+    pub(super) fn get(&self, id: &str) -> Option<&Prototype> {
+        self.prototypes.get(id)
+    }
+
+    /// Gets the IDs of the item prototypes new players start with one of each of.
+    pub fn get_starting_items(&self) -> &[String] {
+        &self.starting_items
+    }
+}
+
+/// Fills in the properties the prototype with the provided ID inherits from other prototypes, and adds it to the catalog.
+fn resolve_prototype(
+    id: &str,
+    files: &[ParsedFile],
+    definitions: &HashMap<String, (usize, Prototype)>,
+    catalog: &mut PrototypeCatalog,
+    visited: &mut Vec<String>,
+) -> Result<Prototype, WorldDefinitionError> {
+    if let Some(prototype) = catalog.prototypes.get(id) {
+        return Ok(prototype.clone());
+    }
+    visited.push(id.to_string());
+
+    let (file_index, prototype) = &definitions[id];
+    let source = &files[*file_index].source;
+    let extends = match prototype {
+        Prototype::Item(item) => &item.extends,
+        Prototype::Npc(npc) => &npc.extends,
+    };
+
+    let resolved =
+        match extends {
+            Some(parent_id) => {
+                if visited.contains(parent_id.get_ref()) {
+                    return Err(source
+                        .error_at_id(parent_id, WorldDefinitionErrorKind::PrototypeExtendsItself));
+                }
+                if !definitions.contains_key(parent_id.get_ref()) {
+                    return Err(
+                        source.error_at_id(parent_id, WorldDefinitionErrorKind::UnknownPrototype)
+                    );
+                }
+                let parent =
+                    resolve_prototype(parent_id.get_ref(), files, definitions, catalog, visited)?;
+                match (prototype.clone(), parent) {
+                    (Prototype::Item(item), Prototype::Item(parent)) => {
+                        Prototype::Item(Box::new(item.inherit_from(&parent)))
+                    }
+                    (Prototype::Npc(npc), Prototype::Npc(parent)) => {
+                        Prototype::Npc(Box::new(npc.inherit_from(&parent)))
+                    }
+                    (Prototype::Item(_), Prototype::Npc(_)) => {
+                        return Err(source
+                            .error_at_id(parent_id, WorldDefinitionErrorKind::NotItemPrototype))
+                    }
+                    (Prototype::Npc(_), Prototype::Item(_)) => {
+                        return Err(source
+                            .error_at_id(parent_id, WorldDefinitionErrorKind::NotNpcPrototype))
+                    }
+                }
+            }
+            None => prototype.clone(),
+        };
+
+    catalog.prototypes.insert(id.to_string(), resolved.clone());
+
+    Ok(resolved)
+}
+
+/// An error spawning an entity from a prototype.
+#[derive(Debug)]
+pub enum SpawnPrototypeError {
+    /// There is no prototype with the provided ID.
+    UnknownPrototype(String),
+    /// The prototype doesn't set a property needed to spawn something from it, and neither does any prototype it extends.
+    MissingField {
+        /// The ID of the prototype.
+        prototype: String,
+        /// The name of the missing property.
+        field: &'static str,
+    },
+}
+
+impl Display for SpawnPrototypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpawnPrototypeError::UnknownPrototype(id) => {
+                write!(f, "no prototype with ID {id:?} is defined")
+            }
+            SpawnPrototypeError::MissingField { prototype, field } => {
+                write!(f, "prototype {prototype:?} doesn't set {field:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SpawnPrototypeError {}
+
+/// Spawns a new entity from the prototype with the provided ID, and puts it in `destination`.
+pub fn spawn_prototype(
+    id: &str,
+    destination: Entity,
+    world: &mut World,
+) -> Result<Entity, SpawnPrototypeError> {
+    let entity = world.spawn_empty().id();
+    if let Err(e) = build_prototype(id, entity, world) {
+        world.despawn(entity);
+        return Err(e);
+    }
+    move_entity(entity, destination, world);
+
+    Ok(entity)
+}
+
+/// Adds the components for the prototype with the provided ID to the provided entity.
+///
+/// If an error is returned, nothing is added to the entity.
+pub(super) fn build_prototype(
+    id: &str,
+    entity: Entity,
+    world: &mut World,
+) -> Result<(), SpawnPrototypeError> {
+    let prototype = world
+        .resource::<PrototypeCatalog>()
+        .get(id)
+        .cloned()
+        .ok_or_else(|| SpawnPrototypeError::UnknownPrototype(id.to_string()))?;
+
+    match prototype {
+        Prototype::Item(item) => build_item(*item, entity, world)?,
+        Prototype::Npc(npc) => build_npc(*npc, entity, world)?,
+    }
+
+    world
+        .entity_mut(entity)
+        .insert(FromPrototype { id: id.to_string() });
+
+    Ok(())
+}
+
+/// Builds the description an entity spawned from the prototype with the provided ID would have.
+pub(super) fn build_prototype_description(
+    id: &str,
+    catalog: &PrototypeCatalog,
+) -> Result<Description, SpawnPrototypeError> {
+    match catalog.get(id) {
+        Some(Prototype::Item(item)) => build_item_description(item),
+        Some(Prototype::Npc(npc)) => build_npc_description(npc),
+        None => Err(SpawnPrototypeError::UnknownPrototype(id.to_string())),
+    }
+}
+
+/// Builds the names and descriptions in other languages an entity spawned from the prototype with the provided ID would have, if it would have any.
+pub(super) fn build_prototype_localized_descriptions(
+    id: &str,
+    catalog: &PrototypeCatalog,
+) -> Option<LocalizedDescriptions> {
+    let localized = match catalog.get(id)? {
+        Prototype::Item(item) => &item.localized,
+        Prototype::Npc(npc) => &npc.localized,
+    };
+
+    localized.clone().map(LocalizedDescriptions)
+}
+
+/// Gets the value of a property that's needed to spawn something from the prototype with the provided ID.
+fn required<T>(
+    value: Option<T>,
+    prototype: &Spanned<String>,
+    field: &'static str,
+) -> Result<T, SpawnPrototypeError> {
+    value.ok_or_else(|| SpawnPrototypeError::MissingField {
+        prototype: prototype.get_ref().clone(),
+        field,
+    })
+}
+
+/// Builds the description of something that isn't a room.
+#[expect(clippy::too_many_arguments)]
+fn build_description(
+    name: String,
+    room_name: Option<String>,
+    plural_name: Option<String>,
+    article: Option<String>,
+    pronouns: Pronouns,
+    aliases: Option<Vec<String>>,
+    description: String,
+    attribute_describers: Vec<Box<dyn AttributeDescriber>>,
+) -> Description {
+    Description {
+        room_name: room_name.unwrap_or_else(|| name.clone()),
+        plural_name: plural_name.unwrap_or_else(|| format!("{name}s")),
+        name,
+        article: match article {
+            Some(article) if article.is_empty() => None,
+            Some(article) => Some(article),
+            None => Some("a".to_string()),
+        },
+        pronouns,
+        aliases: aliases.unwrap_or_default(),
+        description,
+        attribute_describers,
+    }
+}
+
+/// Builds the description of an NPC spawned from the provided prototype.
+fn build_npc_description(npc: &NpcPrototype) -> Result<Description, SpawnPrototypeError> {
+    let name = required(npc.name.clone(), &npc.id, "name")?;
+    let pronouns = required(npc.pronouns.clone(), &npc.id, "pronouns")?;
+    let description = required(npc.description.clone(), &npc.id, "description")?;
+
+    Ok(build_description(
+        name,
+        npc.room_name.clone(),
+        npc.plural_name.clone(),
+        npc.article.clone(),
+        pronouns.build(),
+        npc.aliases.clone(),
+        description,
+        vec![
+            SleepState::get_attribute_describer(),
+            WornItems::get_attribute_describer(),
+            EquippedItems::get_attribute_describer(),
+            Wetness::get_attribute_describer(),
+        ],
+    ))
+}
+
+/// Adds the components for the provided NPC prototype to the provided entity.
+fn build_npc(
+    npc: NpcPrototype,
+    entity: Entity,
+    world: &mut World,
+) -> Result<(), SpawnPrototypeError> {
+    let description = build_npc_description(&npc)?;
+    let volume = required(npc.volume, &npc.id, "volume")?;
+    let weight = required(npc.weight, &npc.id, "weight")?;
+    let max_health = required(npc.max_health, &npc.id, "max_health")?;
+    let stats = required(npc.stats, &npc.id, "stats")?;
+    let inventory = required(npc.inventory, &npc.id, "inventory")?;
+
+    let stats = Stats::new(stats.attributes, stats.skills);
+
+    let mut entity_mut = world.entity_mut(entity);
+    entity_mut.insert((
+        description,
+        volume,
+        weight,
+        Vitals {
+            health: ConstrainedValue::new_max(0.0, max_health),
+            ..Vitals::new()
+        },
+        StartingStats(stats.clone()),
+        stats,
+        Container::new(inventory.volume, inventory.weight),
+        WornItems::new(npc.max_worn_thickness.unwrap_or(5)),
+        EquippedItems::new(npc.hands.unwrap_or(2)),
+    ));
+
+    if let Some(localized) = npc.localized {
+        entity_mut.insert(LocalizedDescriptions(localized));
+    }
+    if let Some(wander) = npc.wander {
+        entity_mut.insert(wander);
+    }
+    if npc.self_defense.unwrap_or(false) {
+        entity_mut.insert(SelfDefenseBehavior);
+    }
+    if let Some(greeting) = npc.greeting {
+        entity_mut.insert(GreetBehavior { greeting });
+    }
+    if let Some(script) = npc.script {
+        entity_mut.insert(Script {
+            id: script.into_inner(),
+        });
+    }
+
+    add_human_innate_weapon(entity, world);
+    add_human_body_parts(entity, world);
+
+    Ok(())
+}
+
+/// Builds the description of an item spawned from the provided prototype.
+fn build_item_description(item: &ItemPrototype) -> Result<Description, SpawnPrototypeError> {
+    let name = required(item.name.clone(), &item.id, "name")?;
+    let description = required(item.description.clone(), &item.id, "description")?;
+
+    let mut attribute_describers = vec![Item::get_attribute_describer()];
+    if item.calories.is_some() || item.anti_radiation.is_some() {
+        attribute_describers.push(Edible::get_attribute_describer());
+    }
+    if item.volume.is_some() {
+        attribute_describers.push(Volume::get_attribute_describer());
+    }
+    if item.weight.is_some() {
+        attribute_describers.push(Weight::get_attribute_describer());
+    }
+    if item.container.is_some() {
+        attribute_describers.push(Container::get_attribute_describer());
+    }
+    if item.fluid_container.is_some() {
+        attribute_describers.push(FluidContainer::get_attribute_describer());
+    }
+    if item.wearable.is_some() {
+        attribute_describers.push(Wearable::get_attribute_describer());
+    }
+    if item.weapon.is_some() {
+        attribute_describers.push(Weapon::get_attribute_describer());
+    }
+    if item.light.is_some() {
+        attribute_describers.push(PortableLight::get_attribute_describer());
+    }
+    if item.geiger_counter.unwrap_or(false) {
+        attribute_describers.push(GeigerCounter::get_attribute_describer());
+    }
+
+    Ok(build_description(
+        name,
+        item.room_name.clone(),
+        item.plural_name.clone(),
+        item.article.clone(),
+        item.pronouns
+            .clone()
+            .map_or_else(Pronouns::it, PronounsDefinition::build),
+        item.aliases.clone(),
+        description,
+        attribute_describers,
+    ))
+}
+
+/// Adds the components for the provided item prototype to the provided entity.
+fn build_item(
+    item: ItemPrototype,
+    entity: Entity,
+    world: &mut World,
+) -> Result<(), SpawnPrototypeError> {
+    let description = build_item_description(&item)?;
+
+    let mut entity_mut = world.entity_mut(entity);
+    entity_mut.insert((
+        description,
+        item.hands.map_or_else(Item::new_one_handed, Item::new),
+    ));
+
+    if let Some(localized) = item.localized {
+        entity_mut.insert(LocalizedDescriptions(localized));
+    }
+    if let Some(volume) = item.volume {
+        entity_mut.insert(volume);
+    }
+    if let Some(weight) = item.weight {
+        entity_mut.insert(weight);
+    }
+    if let Some(calories) = item.calories {
+        entity_mut.insert((Edible, Calories(calories)));
+    }
+    if let Some(anti_radiation) = item.anti_radiation {
+        entity_mut.insert((Edible, AntiRadiation(anti_radiation)));
+    }
+    if let Some(key_id) = item.key_id {
+        entity_mut.insert(KeyId(key_id));
+    }
+    if let Some(container) = item.container {
+        entity_mut.insert(Container::new(container.volume, container.weight));
+    }
+    if let Some(fluid_container) = item.fluid_container {
+        entity_mut.insert(FluidContainer {
+            contents: Fluid {
+                contents: fluid_container.contents,
+            },
+            volume: fluid_container.volume,
+        });
+    }
+    if let Some(wearable) = item.wearable {
+        entity_mut.insert(wearable);
+    }
+    if let Some(weapon) = item.weapon {
+        entity_mut.insert(weapon);
+    }
+    if let Some(light) = item.light {
+        entity_mut.insert(PortableLight::new(
+            light.light_level,
+            light.power_source,
+            light.capacity,
+        ));
+    }
+    if let Some(rads_per_tick) = item.radiation {
+        entity_mut.insert(RadiationSource { rads_per_tick });
+    }
+    if item.geiger_counter.unwrap_or(false) {
+        entity_mut.insert(GeigerCounter);
+    }
+    if item.invisible.unwrap_or(false) {
+        entity_mut.insert(Invisible::to_all());
+    }
+    if let Some(script) = item.script {
+        entity_mut.insert(Script {
+            id: script.into_inner(),
+        });
+    }
+    if item.light.is_some() {
+        PortableLight::register_custom_input_parser(entity, world);
+    }
+    if let Some(commands) = item.commands {
+        ScriptCommands::add_to(
+            entity,
+            commands.into_iter().map(Spanned::into_inner).collect(),
+            world,
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        component::Location,
+        game_map::GameMap,
+        world_definition::{SourceFile, BASE_WORLD_ORIGIN},
+        Time,
+    };
+
+    use super::*;
+
+    fn set_up_world(contents: &str) -> World {
+        let files = vec![SourceFile {
+            name: "test.toml".to_string(),
+            contents: contents.to_string(),
+        }
+        .parse(BASE_WORLD_ORIGIN)
+        .unwrap()];
+
+        let mut world = World::new();
+        world.insert_resource(GameMap::new());
+        world.insert_resource(Time::new());
+        crate::resource::insert_resources(&mut world);
+        world.insert_resource(PrototypeCatalog::from_files(&files).unwrap());
+
+        world
+    }
+
+    fn build_catalog_error(contents: &str) -> WorldDefinitionError {
+        let file = SourceFile {
+            name: "test.toml".to_string(),
+            contents: contents.to_string(),
+        }
+        .parse(BASE_WORLD_ORIGIN)
+        .unwrap();
+
+        PrototypeCatalog::from_files(&[file]).err().unwrap()
+    }
+
+    const PROTOTYPES: &str = r#"
+[[item_prototypes]]
+id = "bottle"
+name = "bottle"
+aliases = ["bottle"]
+description = "A bottle."
+volume = 0.5
+weight = 0.1
+fluid_container = { volume = 0.5 }
+
+[[item_prototypes]]
+id = "water_bottle"
+extends = "bottle"
+name = "water bottle"
+fluid_container = { volume = 0.5, contents = { Water = 0.5 } }
+
+[[item_prototypes]]
+id = "nameless_thing"
+description = "A thing with no name."
+"#;
+
+    #[test]
+    fn spawn_inherits_properties() {
+        let mut world = set_up_world(PROTOTYPES);
+        let room = world.spawn(Container::new_infinite()).id();
+
+        let bottle = spawn_prototype("water_bottle", room, &mut world).unwrap();
+
+        let description = world.get::<Description>(bottle).unwrap();
+        assert_eq!("water bottle", description.name);
+        assert_eq!("A bottle.", description.description);
+        assert_eq!(vec!["bottle".to_string()], description.aliases);
+        assert_eq!(0.5, world.get::<Volume>(bottle).unwrap().0);
+        assert_eq!(
+            Some(0.5),
+            world
+                .get::<FluidContainer>(bottle)
+                .unwrap()
+                .contents
+                .contents
+                .get(&FluidType::Water)
+                .map(|volume| volume.0)
+        );
+        assert_eq!(room, world.get::<Location>(bottle).unwrap().id);
+    }
+
+    #[test]
+    fn spawn_unknown_prototype() {
+        let mut world = set_up_world(PROTOTYPES);
+        let room = world.spawn_empty().id();
+        let entity_count = world.entities().len();
+
+        let result = spawn_prototype("nothing", room, &mut world);
+
+        assert!(matches!(
+            result,
+            Err(SpawnPrototypeError::UnknownPrototype(id)) if id == "nothing"
+        ));
+        assert_eq!(entity_count, world.entities().len());
+    }
+
+    #[test]
+    fn spawn_missing_field() {
+        let mut world = set_up_world(PROTOTYPES);
+        let room = world.spawn_empty().id();
+
+        let result = spawn_prototype("nameless_thing", room, &mut world);
+
+        assert!(matches!(
+            result,
+            Err(SpawnPrototypeError::MissingField { prototype, field: "name" }) if prototype == "nameless_thing"
+        ));
+    }
+
+    #[test]
+    fn extends_itself() {
+        let error = build_catalog_error(
+            r#"
+[[item_prototypes]]
+id = "a"
+extends = "b"
+
+[[item_prototypes]]
+id = "b"
+extends = "a"
+"#,
+        );
+
+        assert!(matches!(
+            error.kind,
+            WorldDefinitionErrorKind::PrototypeExtendsItself(_)
+        ));
+    }
+
+    #[test]
+    fn extends_wrong_kind() {
+        let error = build_catalog_error(
+            r#"
+[[npc_prototypes]]
+id = "guy"
+
+[[item_prototypes]]
+id = "thing"
+extends = "guy"
+"#,
+        );
+
+        assert_eq!(Some(7), error.line);
+        assert!(matches!(
+            error.kind,
+            WorldDefinitionErrorKind::NotItemPrototype(id) if id == "guy"
+        ));
+    }
+}
@@ -0,0 +1,250 @@
+use std::{collections::HashMap, fmt::Display};
+
+use bevy_ecs::prelude::*;
+
+use crate::{
+    component::{Description, FromPrototype, LocalizedDescriptions, Room, ScriptCommands},
+    game_map::{Coordinates, GameMap},
+    refresh_human_innate_weapon_messages,
+};
+
+use super::{
+    build_prototype_description, build_prototype_localized_descriptions, define_rooms,
+    find_room_coordinates, is_overridden, WorldDefinition, WorldDefinitionError,
+};
+
+/// What changed when a world's content was reloaded.
+#[derive(Debug, Default)]
+pub struct ContentReloadSummary {
+    /// The number of rooms whose names, descriptions, or map icons were updated.
+    pub rooms_updated: usize,
+    /// The number of entities whose descriptions were updated from the prototypes they were spawned from.
+    pub entities_updated: usize,
+    /// Things that couldn't be reloaded, like rooms that were added after the world was spawned.
+    pub warnings: Vec<String>,
+}
+
+impl Display for ContentReloadSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "updated {} rooms and {} entities",
+            self.rooms_updated, self.entities_updated
+        )?;
+        if !self.warnings.is_empty() {
+            write!(f, " with {} warnings", self.warnings.len())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl WorldDefinition {
+    /// Updates the content of the provided world, which was spawned from an earlier version of this world, to match this one.
+    ///
+    /// This replaces the message template, prototype, and script catalogs, re-applies the catalog definitions, and updates the text of existing rooms and the descriptions of entities spawned from prototypes.
+    /// Nothing is added or removed from the world, so new rooms and placements only take effect once the world is spawned again.
+    ///
+    /// If an error is returned, the world is left unchanged.
+    pub fn reload(&self, world: &mut World) -> Result<ContentReloadSummary, WorldDefinitionError> {
+        // build everything before changing anything, so errors don't leave the world half-reloaded
+        let message_template_catalog = self.build_message_template_catalog()?;
+        let prototype_catalog = self.build_prototype_catalog()?;
+        let script_catalog = self.build_script_catalog()?;
+        let rooms = self.build_rooms()?;
+
+        let mut summary = ContentReloadSummary::default();
+
+        self.apply_catalog_definitions(world);
+        world.insert_resource(message_template_catalog);
+        refresh_human_innate_weapon_messages(world);
+        world.insert_resource(script_catalog);
+        ScriptCommands::register_all_parsers(world);
+
+        for (id, coords, new_room) in rooms {
+            let Some(room_entity) = world.resource::<GameMap>().locations.get(&coords).copied()
+            else {
+                summary
+                    .warnings
+                    .push(format!("room {id:?} doesn't exist in the world yet"));
+                continue;
+            };
+            let Some(mut room) = world.get_mut::<Room>(room_entity) else {
+                continue;
+            };
+            *room = new_room;
+            summary.rooms_updated += 1;
+        }
+
+        let mut localized_descriptions = Vec::new();
+        let mut prototyped_entities = world.query::<(Entity, &FromPrototype, &mut Description)>();
+        for (entity, from_prototype, mut description) in prototyped_entities.iter_mut(world) {
+            match build_prototype_description(&from_prototype.id, &prototype_catalog) {
+                Ok(new_description) => {
+                    localized_descriptions.push((
+                        entity,
+                        build_prototype_localized_descriptions(
+                            &from_prototype.id,
+                            &prototype_catalog,
+                        ),
+                    ));
+                    // keep the existing attribute describers, since they depend on what components the entity has rather than what the prototype says
+                    let attribute_describers =
+                        std::mem::take(&mut description.attribute_describers);
+                    *description = Description {
+                        attribute_describers,
+                        ..new_description
+                    };
+                    summary.entities_updated += 1;
+                }
+                Err(e) => summary.warnings.push(format!(
+                    "unable to update the description of {}: {e}",
+                    description.name
+                )),
+            }
+        }
+
+        for (entity, localized) in localized_descriptions {
+            match localized {
+                Some(localized) => world.entity_mut(entity).insert(localized),
+                None => world.entity_mut(entity).remove::<LocalizedDescriptions>(),
+            };
+        }
+
+        world.insert_resource(prototype_catalog);
+
+        Ok(summary)
+    }
+
+    /// Builds the rooms defined in this world, along with their IDs and coordinates.
+    fn build_rooms(&self) -> Result<Vec<(String, Coordinates, Room)>, WorldDefinitionError> {
+        let defined = define_rooms(&self.files)?;
+        let mut room_coordinates = HashMap::new();
+        let mut rooms = Vec::new();
+        for (file_index, file) in self.files.iter().enumerate() {
+            for (room_index, room) in file.definition.rooms.iter().enumerate() {
+                if is_overridden(room, file_index, room_index, &defined) {
+                    continue;
+                }
+                let coords = find_room_coordinates(
+                    &room.id,
+                    file_index,
+                    &self.files,
+                    &defined,
+                    &mut room_coordinates,
+                    &mut Vec::new(),
+                )?;
+                rooms.push((room.id.get_ref().clone(), coords, room.build()));
+            }
+        }
+
+        Ok(rooms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::get_entity_by_name, world_definition::SourceFile, Time};
+
+    use super::*;
+
+    const CONTENTS: &str = r#"
+spawn_room = "room_1"
+
+[[rooms]]
+id = "room_1"
+name = "Room 1"
+description = "A room."
+icon = { chars = "[]", foreground = "White" }
+coordinates = [0, 0, 0]
+
+[[item_prototypes]]
+id = "thing"
+name = "thing"
+description = "A thing."
+weight = 1.0
+
+[[items]]
+location = "room_1"
+prototype = "thing"
+"#;
+
+    fn parse(contents: &str) -> Result<WorldDefinition, WorldDefinitionError> {
+        WorldDefinition::from_files(vec![SourceFile {
+            name: "test.toml".to_string(),
+            contents: contents.to_string(),
+        }])
+    }
+
+    fn set_up_world() -> (World, Coordinates) {
+        let mut world = World::new();
+        world.insert_resource(GameMap::new());
+        world.insert_resource(Time::new());
+        crate::resource::insert_resources(&mut world);
+
+        let world_definition = parse(CONTENTS).unwrap();
+        world.insert_resource(world_definition.build_prototype_catalog().unwrap());
+        let spawn_room_coords = world_definition.spawn(&mut world).unwrap();
+
+        (world, spawn_room_coords)
+    }
+
+    fn get_room<'w>(coords: &Coordinates, world: &'w World) -> &'w Room {
+        let room = world.resource::<GameMap>().locations[coords];
+        world.get::<Room>(room).unwrap()
+    }
+
+    #[test]
+    fn reload_updates_rooms_and_prototyped_entities() {
+        let (mut world, room_coords) = set_up_world();
+        let thing = get_entity_by_name("thing", &world).id();
+        let attribute_describer_count = world
+            .get::<Description>(thing)
+            .unwrap()
+            .attribute_describers
+            .len();
+
+        let new_contents = format!(
+            "{}\n[[rooms]]\nid = \"room_2\"\nicon = {{ chars = \"[]\", foreground = \"White\" }}\ncoordinates = [0, 1, 0]\n",
+            CONTENTS
+                .replace("A room.", "A freshly painted room.")
+                .replace("name = \"thing\"", "name = \"shiny thing\"")
+        );
+        let summary = parse(&new_contents).unwrap().reload(&mut world).unwrap();
+
+        assert_eq!(1, summary.rooms_updated);
+        assert_eq!(1, summary.entities_updated);
+        assert_eq!(1, summary.warnings.len(), "room_2 should be reported");
+        assert_eq!(
+            "A freshly painted room.",
+            get_room(&room_coords, &world).description
+        );
+        let description = world.get::<Description>(thing).unwrap();
+        assert_eq!("shiny thing", description.name);
+        assert_eq!(
+            attribute_describer_count,
+            description.attribute_describers.len()
+        );
+    }
+
+    #[test]
+    fn reload_error_leaves_world_unchanged() {
+        let (mut world, room_coords) = set_up_world();
+
+        let new_contents = CONTENTS.replace("A room.", "A freshly painted room.")
+            + "\n[[item_prototypes]]\nid = \"other_thing\"\nextends = \"nothing\"\n";
+        let error = parse(&new_contents)
+            .unwrap()
+            .reload(&mut world)
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind,
+            crate::WorldDefinitionErrorKind::UnknownPrototype(id) if id == "nothing"
+        ));
+        assert_eq!("A room.", get_room(&room_coords, &world).description);
+        assert!(get_entity_by_name("thing", &world)
+            .get::<FromPrototype>()
+            .is_some());
+    }
+}
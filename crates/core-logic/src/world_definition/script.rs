@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use toml::Spanned;
+
+use crate::scripting::ScriptCatalog;
+
+use super::{
+    build_commands, line_number, ParsedFile, WorldDefinitionError, WorldDefinitionErrorKind,
+    WorldDefinitionFile,
+};
+
+/// Defines a script that rooms, NPCs, and items can behave according to.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct ScriptDefinition {
+    /// The ID to refer to the script by.
+    pub(super) id: Spanned<String>,
+    /// The source code of the script.
+    source: Spanned<String>,
+}
+
+/// Compiles the scripts and commands defined in the provided files, and makes sure everything that refers to a script or command refers to one that exists.
+pub(super) fn build_script_catalog(
+    files: &[ParsedFile],
+) -> Result<ScriptCatalog, WorldDefinitionError> {
+    let definitions =
+        find_definitions_to_use(files, |definition| &definition.scripts, |script| &script.id)?;
+
+    let mut catalog = ScriptCatalog::new();
+    for (file_index, file) in files.iter().enumerate() {
+        for script in &file.definition.scripts {
+            if definitions[script.id.get_ref()] != file_index {
+                // this script has been overridden
+                continue;
+            }
+            catalog
+                .add(script.id.get_ref().clone(), script.source.get_ref())
+                .map_err(|e| WorldDefinitionError {
+                    file: file.source.name.clone(),
+                    line: Some(script_line(
+                        &file.source.contents,
+                        &script.source,
+                        e.line.unwrap_or(1),
+                    )),
+                    kind: WorldDefinitionErrorKind::Script {
+                        id: script.id.get_ref().clone(),
+                        message: e.message,
+                    },
+                })?;
+        }
+    }
+
+    for file in files {
+        let script_references = file
+            .definition
+            .rooms
+            .iter()
+            .filter_map(|room| room.script.as_ref())
+            .chain(
+                file.definition
+                    .item_prototypes
+                    .iter()
+                    .filter_map(|prototype| prototype.get_script()),
+            )
+            .chain(
+                file.definition
+                    .npc_prototypes
+                    .iter()
+                    .filter_map(|prototype| prototype.get_script()),
+            );
+        for id in script_references {
+            if !catalog.contains(id.get_ref()) {
+                return Err(file
+                    .source
+                    .error_at_id(id, WorldDefinitionErrorKind::UnknownScript));
+            }
+        }
+    }
+
+    build_commands(files, &mut catalog)?;
+
+    Ok(catalog)
+}
+
+/// Finds the index of the file whose definition should be used for each ID, since definitions can be overridden by ones from later mods.
+pub(super) fn find_definitions_to_use<'a, T: 'a>(
+    files: &'a [ParsedFile],
+    get_definitions: fn(&WorldDefinitionFile) -> &Vec<T>,
+    get_id: fn(&T) -> &Spanned<String>,
+) -> Result<HashMap<&'a String, usize>, WorldDefinitionError> {
+    let mut definitions = HashMap::new();
+    for (file_index, file) in files.iter().enumerate() {
+        for definition in get_definitions(&file.definition) {
+            let id = get_id(definition);
+            if let Some(other_file_index) = definitions.insert(id.get_ref(), file_index) {
+                if files[other_file_index].origin == file.origin {
+                    return Err(file
+                        .source
+                        .error_at_id(id, WorldDefinitionErrorKind::DuplicateId));
+                }
+            }
+        }
+    }
+
+    Ok(definitions)
+}
+
+/// Finds the line in a world definition file that the provided line of a script defined in it is on.
+pub(super) fn script_line(
+    file_contents: &str,
+    source: &Spanned<String>,
+    script_line: usize,
+) -> usize {
+    let source_start = &file_contents[source.span()];
+    // a newline right after the opening delimiter of a multi-line string isn't part of the string
+    let skipped_lines = if source_start.starts_with("\"\"\"\n") || source_start.starts_with("'''\n")
+    {
+        1
+    } else {
+        0
+    };
+
+    line_number(file_contents, source.span().start) + skipped_lines + script_line - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::world_definition::{SourceFile, BASE_WORLD_ORIGIN};
+
+    use super::*;
+
+    fn build_from_str(contents: &str) -> Result<ScriptCatalog, WorldDefinitionError> {
+        let file = SourceFile {
+            name: "test.toml".to_string(),
+            contents: contents.to_string(),
+        }
+        .parse(BASE_WORLD_ORIGIN)
+        .unwrap();
+
+        build_script_catalog(&[file])
+    }
+
+    #[test]
+    fn compile_error_has_line() {
+        let error = build_from_str(
+            r#"
+[[scripts]]
+id = "broken"
+source = """
+fn on_tick(world, me) {
+    let x = ;
+}
+"""
+"#,
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!(Some(6), error.line);
+        assert!(matches!(
+            error.kind,
+            WorldDefinitionErrorKind::Script { id, .. } if id == "broken"
+        ));
+    }
+
+    #[test]
+    fn unknown_script() {
+        let error = build_from_str(
+            r#"
+[[item_prototypes]]
+id = "thing"
+script = "nonexistent"
+"#,
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!(Some(4), error.line);
+        assert!(matches!(
+            error.kind,
+            WorldDefinitionErrorKind::UnknownScript(id) if id == "nonexistent"
+        ));
+    }
+}
@@ -0,0 +1,363 @@
+use std::{collections::HashMap, ops::RangeInclusive};
+
+use bevy_ecs::prelude::*;
+use rand::{seq::SliceRandom, Rng};
+use strum::{EnumIter, IntoEnumIterator};
+
+use crate::{
+    color::Color,
+    component::Room,
+    game_map::{Coordinates, MapIcon},
+    world_setup::{connect_open, spawn_room},
+    Direction,
+};
+
+/// The kinds of buildings a city can have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub enum BuildingKind {
+    House,
+    ApartmentBuilding,
+    Store,
+    Pharmacy,
+    OfficeBuilding,
+    Warehouse,
+}
+
+/// The kinds of outdoor spaces that can take up a spot in a city block instead of a building.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub enum LotKind {
+    VacantLot,
+    ParkingLot,
+    Park,
+    Rubble,
+}
+
+/// A room's name and description.
+pub type RoomText = (&'static str, &'static str);
+
+impl BuildingKind {
+    /// The name to show for the outside of the building.
+    fn get_name(self) -> &'static str {
+        match self {
+            BuildingKind::House => "House",
+            BuildingKind::ApartmentBuilding => "Apartment Building",
+            BuildingKind::Store => "Store",
+            BuildingKind::Pharmacy => "Pharmacy",
+            BuildingKind::OfficeBuilding => "Office Building",
+            BuildingKind::Warehouse => "Warehouse",
+        }
+    }
+
+    /// The color of the building's icon on the map.
+    fn get_color(self) -> Color {
+        match self {
+            BuildingKind::House => Color::White,
+            BuildingKind::ApartmentBuilding => Color::Gray,
+            BuildingKind::Store => Color::DarkCyan,
+            BuildingKind::Pharmacy => Color::Green,
+            BuildingKind::OfficeBuilding => Color::Blue,
+            BuildingKind::Warehouse => Color::DarkRed,
+        }
+    }
+
+    /// How many rooms wide and deep each floor of the building can be.
+    fn get_floor_size(self) -> RangeInclusive<i64> {
+        match self {
+            BuildingKind::House | BuildingKind::ApartmentBuilding | BuildingKind::Pharmacy => 1..=2,
+            BuildingKind::Store => 1..=3,
+            BuildingKind::OfficeBuilding | BuildingKind::Warehouse => 2..=3,
+        }
+    }
+
+    /// How many floors the building can have.
+    fn get_floor_count(self) -> RangeInclusive<i64> {
+        match self {
+            BuildingKind::House => 1..=2,
+            BuildingKind::ApartmentBuilding => 2..=4,
+            BuildingKind::OfficeBuilding => 2..=5,
+            BuildingKind::Store | BuildingKind::Pharmacy | BuildingKind::Warehouse => 1..=1,
+        }
+    }
+
+    /// The room people walk into from the street.
+    fn get_entrance_room(self) -> RoomText {
+        match self {
+            BuildingKind::House => (
+                "Front Hall",
+                "A narrow hallway. A few coats still hang on hooks by the door.",
+            ),
+            BuildingKind::ApartmentBuilding => (
+                "Lobby",
+                "Rows of dented mailboxes line one wall of the small lobby.",
+            ),
+            BuildingKind::Store => (
+                "Storefront",
+                "Empty shelves stand in rows beneath a dead neon sign.",
+            ),
+            BuildingKind::Pharmacy => (
+                "Pharmacy",
+                "Faded signs advertise cold medicine and vitamins to nobody.",
+            ),
+            BuildingKind::OfficeBuilding => (
+                "Lobby",
+                "A marble reception desk sits under a thick layer of dust.",
+            ),
+            BuildingKind::Warehouse => (
+                "Loading Dock",
+                "A wide loading dock. The roll-up door is stuck half open.",
+            ),
+        }
+    }
+
+    /// The other rooms the building can have.
+    fn get_rooms(self) -> &'static [RoomText] {
+        match self {
+            BuildingKind::House => &[
+                (
+                    "Living Room",
+                    "A sagging couch faces a television with a cracked screen.",
+                ),
+                ("Kitchen", "Cabinets hang open, long since picked clean."),
+                ("Bedroom", "A bare mattress lies on a rusted bed frame."),
+                (
+                    "Bathroom",
+                    "The mirror over the sink is cracked down the middle.",
+                ),
+            ],
+            BuildingKind::ApartmentBuilding => &[
+                (
+                    "Hallway",
+                    "A dim hallway lined with doors, most of them kicked in.",
+                ),
+                (
+                    "Apartment",
+                    "A cramped apartment. Whoever lived here left in a hurry.",
+                ),
+                (
+                    "Laundry Room",
+                    "Washing machines stand with their doors hanging open.",
+                ),
+            ],
+            BuildingKind::Store => &[
+                ("Aisle", "A long aisle of bare metal shelves."),
+                (
+                    "Checkout Area",
+                    "Cash registers lie smashed open on the counters.",
+                ),
+                (
+                    "Stockroom",
+                    "Torn cardboard boxes are piled against the walls.",
+                ),
+            ],
+            BuildingKind::Pharmacy => &[
+                (
+                    "Dispensary",
+                    "Drawers of empty pill bottles have been pulled out and dumped on the floor.",
+                ),
+                (
+                    "Aisle",
+                    "Shelves of toothpaste and bandages, mostly picked over.",
+                ),
+            ],
+            BuildingKind::OfficeBuilding => &[
+                (
+                    "Cubicle Farm",
+                    "Rows of cubicles, their partitions sagging.",
+                ),
+                (
+                    "Conference Room",
+                    "A long table surrounded by overturned chairs.",
+                ),
+                (
+                    "Break Room",
+                    "A refrigerator stands open, its contents long since rotted away.",
+                ),
+                (
+                    "Hallway",
+                    "A carpeted hallway with water stains on the ceiling tiles.",
+                ),
+            ],
+            BuildingKind::Warehouse => &[
+                ("Warehouse Floor", "Towering shelves hold rotting pallets."),
+                ("Office", "A small office overlooking the warehouse floor."),
+            ],
+        }
+    }
+}
+
+impl LotKind {
+    /// The room for the lot.
+    fn get_room(self) -> Room {
+        let (name, description, chars, color) = match self {
+            LotKind::VacantLot => (
+                "Vacant Lot",
+                "An empty lot choked with weeds and broken concrete.",
+                ['.', '.'],
+                Color::DarkGreen,
+            ),
+            LotKind::ParkingLot => (
+                "Parking Lot",
+                "A cracked parking lot. A few burned-out cars still sit in their spaces.",
+                [':', ':'],
+                Color::DarkGray,
+            ),
+            LotKind::Park => (
+                "Park",
+                "An overgrown park. A rusted swing set creaks in the wind.",
+                ['*', '*'],
+                Color::Green,
+            ),
+            LotKind::Rubble => (
+                "Rubble",
+                "The remains of a collapsed building, now just a heap of brick and twisted rebar.",
+                ['%', '%'],
+                Color::DarkGray,
+            ),
+        };
+
+        Room {
+            name: name.to_string(),
+            description: description.to_string(),
+            map_icon: MapIcon::new_uniform(Color::Black, color, chars),
+        }
+    }
+}
+
+/// The layout of a building, relative to its entrance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildingPlan {
+    /// The kind of building this is.
+    pub kind: BuildingKind,
+    /// The direction people walk in to enter the building from the street.
+    pub entrance_direction: Direction,
+    /// The rooms inside the building. The first one is the entrance, at the origin of the building's interior coordinates.
+    pub rooms: Vec<InteriorRoomPlan>,
+}
+
+/// A room inside a building.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InteriorRoomPlan {
+    /// The coordinates of the room inside the building.
+    pub coordinates: (i64, i64, i64),
+    /// The name and description of the room.
+    pub text: RoomText,
+}
+
+impl BuildingPlan {
+    /// Plans a building of a random kind, entered by walking in the provided direction.
+    pub fn generate(entrance_direction: Direction, rng: &mut impl Rng) -> BuildingPlan {
+        let kinds = BuildingKind::iter().collect::<Vec<_>>();
+        let kind = *kinds.choose(rng).expect("there should be building kinds");
+
+        let width = rng.gen_range(kind.get_floor_size());
+        let depth = rng.gen_range(kind.get_floor_size());
+        let floors = rng.gen_range(kind.get_floor_count());
+
+        let mut rooms = Vec::new();
+        for z in 0..floors {
+            for along in 0..depth {
+                for across in 0..width {
+                    let text = if (along, across, z) == (0, 0, 0) {
+                        kind.get_entrance_room()
+                    } else {
+                        *kind
+                            .get_rooms()
+                            .choose(rng)
+                            .expect("buildings should have rooms")
+                    };
+                    // the building extends away from the street in the direction it's entered in
+                    let (x, y) = match entrance_direction {
+                        Direction::South => (across, -along),
+                        Direction::East => (along, across),
+                        Direction::West => (-along, across),
+                        _ => (across, along),
+                    };
+                    rooms.push(InteriorRoomPlan {
+                        coordinates: (x, y, z),
+                        text,
+                    });
+                }
+            }
+        }
+
+        BuildingPlan {
+            kind,
+            entrance_direction,
+            rooms,
+        }
+    }
+
+    /// Spawns the outside of the building at the provided coordinates, and the rooms inside it.
+    ///
+    /// Returns the entrance room.
+    pub fn spawn(&self, exterior_coords: Coordinates, world: &mut World) -> Entity {
+        spawn_room(
+            Room {
+                name: self.kind.get_name().to_string(),
+                description: String::new(),
+                map_icon: MapIcon::new_uniform(Color::Black, self.kind.get_color(), ['[', ']']),
+            },
+            exterior_coords.clone(),
+            world,
+        );
+
+        let mut room_entities = HashMap::new();
+        for room in &self.rooms {
+            let (x, y, z) = room.coordinates;
+            let (name, description) = room.text;
+            let entity = spawn_room(
+                Room {
+                    name: name.to_string(),
+                    description: description.to_string(),
+                    map_icon: MapIcon::new_uniform(Color::Black, Color::White, ['[', ']']),
+                },
+                Coordinates {
+                    x,
+                    y,
+                    z,
+                    parent: Some(Box::new(exterior_coords.clone())),
+                },
+                world,
+            );
+            room_entities.insert(room.coordinates, entity);
+        }
+
+        // rooms on the same floor are open to each other, and floors are connected by stairs from the entrance
+        for room in &self.rooms {
+            let (x, y, z) = room.coordinates;
+            let neighbors = [
+                ((x + 1, y, z), Direction::East),
+                ((x, y + 1, z), Direction::North),
+                ((x, y, z + 1), Direction::Up),
+            ];
+            for (neighbor_coords, direction) in neighbors {
+                if direction == Direction::Up && (x, y) != (0, 0) {
+                    continue;
+                }
+                if let Some(neighbor) = room_entities.get(&neighbor_coords) {
+                    connect_open(
+                        room_entities[&room.coordinates],
+                        direction,
+                        *neighbor,
+                        world,
+                    );
+                }
+            }
+        }
+
+        room_entities[&(0, 0, 0)]
+    }
+}
+
+/// Picks a random kind of lot.
+pub fn choose_lot_kind(rng: &mut impl Rng) -> LotKind {
+    *LotKind::iter()
+        .collect::<Vec<_>>()
+        .choose(rng)
+        .expect("there should be lot kinds")
+}
+
+/// Spawns a lot at the provided coordinates.
+pub fn spawn_lot(kind: LotKind, coords: Coordinates, world: &mut World) -> Entity {
+    spawn_room(kind.get_room(), coords, world)
+}
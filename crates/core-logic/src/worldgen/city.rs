@@ -0,0 +1,405 @@
+use std::{collections::HashMap, ops::RangeInclusive};
+
+use bevy_ecs::prelude::*;
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    color::Color,
+    component::Room,
+    game_map::{Coordinates, GameMap, MapIcon},
+    world_setup::{connect_open, spawn_room},
+    Direction,
+};
+
+use super::{choose_lot_kind, spawn_lot, BuildingPlan, LotKind};
+
+/// The number of blocks the city is wide and tall.
+const CITY_BLOCKS: usize = 4;
+
+/// How many tiles wide or tall each block between streets can be.
+const BLOCK_SIZE: RangeInclusive<i64> = 1..=2;
+
+/// The chance of a spot in a block having a building on it, rather than being a lot.
+const BUILDING_CHANCE: f64 = 0.75;
+
+/// The names of the streets running north-south, from west to east.
+const AVENUE_NAMES: [&str; CITY_BLOCKS + 1] = [
+    "1st Avenue",
+    "2nd Avenue",
+    "3rd Avenue",
+    "4th Avenue",
+    "5th Avenue",
+];
+
+/// The names the streets running east-west can have.
+const STREET_NAMES: &[&str] = &[
+    "Main Street",
+    "Elm Street",
+    "Oak Street",
+    "Maple Street",
+    "Pine Street",
+    "Cedar Street",
+    "Washington Street",
+    "Lincoln Street",
+    "Park Street",
+    "Church Street",
+];
+
+/// Extra details to add to the descriptions of streets.
+const STREET_DETAILS: &[&str] = &[
+    "The pavement is cracked and the lines are faded.",
+    "A rusted-out car sits on its rims in the middle of the road.",
+    "Weeds push up through the gaps in the asphalt.",
+    "Shattered glass glitters along the gutter.",
+    "A toppled streetlight lies across the sidewalk.",
+    "Faded posters flap on a boarded-up bus shelter.",
+];
+
+/// The layout of a procedurally generated city: a grid of streets, with buildings and lots in the blocks between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CityLayout {
+    /// The number of tiles the city is wide.
+    pub width: i64,
+    /// The number of tiles the city is tall.
+    pub height: i64,
+    /// The tiles of the city, row by row from south to north.
+    tiles: Vec<CityTile>,
+}
+
+/// A single tile of a city.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CityTile {
+    /// A section of street.
+    Street {
+        /// The name of the street.
+        name: String,
+        /// Whether the street runs north-south rather than east-west.
+        north_south: bool,
+        /// An extra detail about this section of street.
+        detail: &'static str,
+    },
+    /// A place where two streets cross.
+    Intersection {
+        /// The name of the street running east-west.
+        street: String,
+        /// The name of the street running north-south.
+        avenue: String,
+        /// An extra detail about the intersection.
+        detail: &'static str,
+    },
+    /// A building with rooms inside it.
+    Building(BuildingPlan),
+    /// An open space people can walk into from the street.
+    Lot {
+        /// The kind of lot this is.
+        kind: LotKind,
+        /// The direction people walk in to enter the lot from the street.
+        entrance_direction: Direction,
+    },
+}
+
+impl CityTile {
+    /// Whether this tile is part of a street.
+    fn is_street(&self) -> bool {
+        matches!(
+            self,
+            CityTile::Street { .. } | CityTile::Intersection { .. }
+        )
+    }
+}
+
+impl CityLayout {
+    /// Lays out a city using the provided source of randomness.
+    pub fn generate(rng: &mut impl Rng) -> CityLayout {
+        let avenue_positions = generate_street_positions(rng);
+        let street_positions = generate_street_positions(rng);
+        let width = avenue_positions.last().expect("there should be avenues") + 1;
+        let height = street_positions.last().expect("there should be streets") + 1;
+
+        let mut street_names = STREET_NAMES.to_vec();
+        street_names.shuffle(rng);
+
+        let avenue_at = |x: i64| {
+            avenue_positions
+                .iter()
+                .position(|position| *position == x)
+                .map(|index| AVENUE_NAMES[index])
+        };
+        let street_at = |y: i64| {
+            street_positions
+                .iter()
+                .position(|position| *position == y)
+                .map(|index| street_names[index])
+        };
+
+        let mut tiles = Vec::new();
+        for y in 0..height {
+            for x in 0..width {
+                let tile = match (street_at(y), avenue_at(x)) {
+                    (Some(street), Some(avenue)) => CityTile::Intersection {
+                        street: street.to_string(),
+                        avenue: avenue.to_string(),
+                        detail: choose_street_detail(rng),
+                    },
+                    (Some(street), None) => CityTile::Street {
+                        name: street.to_string(),
+                        north_south: false,
+                        detail: choose_street_detail(rng),
+                    },
+                    (None, Some(avenue)) => CityTile::Street {
+                        name: avenue.to_string(),
+                        north_south: true,
+                        detail: choose_street_detail(rng),
+                    },
+                    (None, None) => {
+                        // blocks are at most 2 tiles across, so everything in them is next to a street
+                        let entrance_directions = [
+                            Direction::North,
+                            Direction::East,
+                            Direction::South,
+                            Direction::West,
+                        ]
+                        .into_iter()
+                        .filter(|direction| {
+                            let (dx, dy) = offset(direction.opposite());
+                            street_at(y + dy).is_some() || avenue_at(x + dx).is_some()
+                        })
+                        .collect::<Vec<_>>();
+                        let entrance_direction = *entrance_directions
+                            .choose(rng)
+                            .expect("everything in a block should be next to a street");
+
+                        if rng.gen_bool(BUILDING_CHANCE) {
+                            CityTile::Building(BuildingPlan::generate(entrance_direction, rng))
+                        } else {
+                            CityTile::Lot {
+                                kind: choose_lot_kind(rng),
+                                entrance_direction,
+                            }
+                        }
+                    }
+                };
+                tiles.push(tile);
+            }
+        }
+
+        CityLayout {
+            width,
+            height,
+            tiles,
+        }
+    }
+
+    /// Gets the tile at the provided position in the city, if it's inside the city.
+    pub fn get(&self, x: i64, y: i64) -> Option<&CityTile> {
+        if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            return None;
+        }
+
+        self.tiles.get((y * self.width + x) as usize)
+    }
+
+    /// Spawns the city with its south-west corner at the provided coordinates.
+    /// Tiles that would be where a room already exists are left out.
+    ///
+    /// Returns the spawned outdoor rooms, keyed by their position in the city.
+    pub fn spawn(&self, origin: &Coordinates, world: &mut World) -> HashMap<(i64, i64), Entity> {
+        let coords_at = |x: i64, y: i64| Coordinates {
+            x: origin.x + x,
+            y: origin.y + y,
+            z: origin.z,
+            parent: None,
+        };
+
+        let mut outdoor_rooms = HashMap::new();
+        let mut entrances = Vec::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let coords = coords_at(x, y);
+                if world.resource::<GameMap>().locations.contains_key(&coords) {
+                    continue;
+                }
+
+                match &self.tiles[(y * self.width + x) as usize] {
+                    CityTile::Street {
+                        name,
+                        north_south,
+                        detail,
+                    } => {
+                        let (running, chars) = if *north_south {
+                            ("north-south", ['|', '|'])
+                        } else {
+                            ("east-west", ['=', '='])
+                        };
+                        let room = Room {
+                            name: name.clone(),
+                            description: format!("An old street running {running}. {detail}"),
+                            map_icon: MapIcon::new_uniform(Color::Black, Color::DarkYellow, chars),
+                        };
+                        outdoor_rooms.insert((x, y), spawn_room(room, coords, world));
+                    }
+                    CityTile::Intersection {
+                        street,
+                        avenue,
+                        detail,
+                    } => {
+                        let room = Room {
+                            name: format!("{street} and {avenue}"),
+                            description: format!(
+                                "The intersection of {street} and {avenue}. {detail}"
+                            ),
+                            map_icon: MapIcon::new_uniform(
+                                Color::Black,
+                                Color::DarkYellow,
+                                ['#', '#'],
+                            ),
+                        };
+                        outdoor_rooms.insert((x, y), spawn_room(room, coords, world));
+                    }
+                    CityTile::Building(plan) => {
+                        let entrance_room = plan.spawn(coords, world);
+                        entrances.push(((x, y), plan.entrance_direction, entrance_room));
+                    }
+                    CityTile::Lot {
+                        kind,
+                        entrance_direction,
+                    } => {
+                        let lot = spawn_lot(*kind, coords, world);
+                        outdoor_rooms.insert((x, y), lot);
+                        entrances.push(((x, y), *entrance_direction, lot));
+                    }
+                }
+            }
+        }
+
+        // streets connect to the streets next to them
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if !self.get(x, y).is_some_and(CityTile::is_street) {
+                    continue;
+                }
+                for direction in [Direction::East, Direction::North] {
+                    let (dx, dy) = offset(direction);
+                    if !self.get(x + dx, y + dy).is_some_and(CityTile::is_street) {
+                        continue;
+                    }
+                    if let (Some(room), Some(neighbor)) = (
+                        outdoor_rooms.get(&(x, y)),
+                        outdoor_rooms.get(&(x + dx, y + dy)),
+                    ) {
+                        connect_open(*room, direction, *neighbor, world);
+                    }
+                }
+            }
+        }
+
+        // buildings and lots are entered from the street next to them
+        for ((x, y), direction, entrance_room) in entrances {
+            let (dx, dy) = offset(direction.opposite());
+            if let Some(street) = outdoor_rooms.get(&(x + dx, y + dy)) {
+                connect_open(*street, direction, entrance_room, world);
+            }
+        }
+
+        outdoor_rooms
+    }
+}
+
+/// Chooses where the streets running in one direction go, starting at 0.
+fn generate_street_positions(rng: &mut impl Rng) -> Vec<i64> {
+    let mut positions = vec![0];
+    for _ in 0..CITY_BLOCKS {
+        let last = positions.last().expect("there should be a first street");
+        positions.push(last + rng.gen_range(BLOCK_SIZE) + 1);
+    }
+
+    positions
+}
+
+/// Chooses an extra detail to describe a section of street with.
+fn choose_street_detail(rng: &mut impl Rng) -> &'static str {
+    STREET_DETAILS
+        .choose(rng)
+        .expect("there should be street details")
+}
+
+/// Gets how far the x and y coordinates change when moving one step in the provided direction.
+/// Directions other than north, south, east, and west don't move anywhere.
+pub(super) fn offset(direction: Direction) -> (i64, i64) {
+    match direction {
+        Direction::North => (0, 1),
+        Direction::East => (1, 0),
+        Direction::South => (0, -1),
+        Direction::West => (-1, 0),
+        _ => (0, 0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::worldgen::seeded_rng;
+
+    use super::*;
+
+    #[test]
+    fn same_seed_same_layout() {
+        let layout = CityLayout::generate(&mut seeded_rng(1234));
+
+        assert_eq!(layout, CityLayout::generate(&mut seeded_rng(1234)));
+        assert_ne!(layout, CityLayout::generate(&mut seeded_rng(4321)));
+    }
+
+    #[test]
+    fn streets_are_all_connected() {
+        let layout = CityLayout::generate(&mut seeded_rng(5));
+
+        let street_count = layout.tiles.iter().filter(|tile| tile.is_street()).count();
+        let mut visited = HashSet::from([(0, 0)]);
+        let mut to_visit = vec![(0, 0)];
+        while let Some((x, y)) = to_visit.pop() {
+            for direction in [
+                Direction::North,
+                Direction::East,
+                Direction::South,
+                Direction::West,
+            ] {
+                let (dx, dy) = offset(direction);
+                let neighbor = (x + dx, y + dy);
+                if layout
+                    .get(neighbor.0, neighbor.1)
+                    .is_some_and(CityTile::is_street)
+                    && visited.insert(neighbor)
+                {
+                    to_visit.push(neighbor);
+                }
+            }
+        }
+
+        assert_eq!(street_count, visited.len());
+    }
+
+    #[test]
+    fn buildings_and_lots_are_entered_from_streets() {
+        for seed in 0..10 {
+            let layout = CityLayout::generate(&mut seeded_rng(seed));
+            for y in 0..layout.height {
+                for x in 0..layout.width {
+                    let entrance_direction = match layout.get(x, y).unwrap() {
+                        CityTile::Building(plan) => plan.entrance_direction,
+                        CityTile::Lot {
+                            entrance_direction, ..
+                        } => *entrance_direction,
+                        _ => continue,
+                    };
+                    let (dx, dy) = offset(entrance_direction.opposite());
+                    assert!(
+                        layout.get(x + dx, y + dy).is_some_and(CityTile::is_street),
+                        "tile at {x}, {y} with seed {seed} should be entered from a street"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use bevy_ecs::prelude::*;
+use log::warn;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    game_map::{Coordinates, GameMap},
+    world_setup::connect_open,
+    Direction,
+};
+
+mod building;
+use building::*;
+
+mod city;
+use city::*;
+
+/// Where a generated city attaches to the rest of the world.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CityEntrance {
+    /// The coordinates of the room the city is entered from.
+    pub coordinates: Coordinates,
+    /// The direction to go from that room to get into the city. One of north, south, east, or west.
+    pub direction: Direction,
+}
+
+/// Generates a city of streets, buildings, and lots from the provided seed, and connects it to the provided entrance.
+/// The same seed always generates the same city.
+pub fn generate_city(seed: u64, entrance: &CityEntrance, world: &mut World) {
+    let layout = CityLayout::generate(&mut seeded_rng(seed));
+
+    // the city is entered at the middle of the side facing the entrance
+    let gate = match entrance.direction {
+        Direction::North => (layout.width / 2, 0),
+        Direction::East => (0, layout.height / 2),
+        Direction::South => (layout.width / 2, layout.height - 1),
+        _ => (layout.width - 1, layout.height / 2),
+    };
+    let (dx, dy) = offset(entrance.direction);
+    let origin = Coordinates {
+        x: entrance.coordinates.x + dx - gate.0,
+        y: entrance.coordinates.y + dy - gate.1,
+        z: entrance.coordinates.z,
+        parent: None,
+    };
+
+    let outdoor_rooms = layout.spawn(&origin, world);
+
+    let entrance_room = world
+        .resource::<GameMap>()
+        .locations
+        .get(&entrance.coordinates)
+        .copied();
+    match (entrance_room, outdoor_rooms.get(&gate)) {
+        (Some(entrance_room), Some(gate_room)) => {
+            connect_open(entrance_room, entrance.direction, *gate_room, world)
+        }
+        _ => warn!("Unable to connect generated city to entrance at {entrance:?}"),
+    }
+}
+
+/// Creates the random number generator to generate a city from the provided seed.
+///
+/// This uses a specific algorithm rather than `StdRng`, which can change between versions of `rand`, so that a seed keeps generating the same city.
+fn seeded_rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{
+        component::{Container, Room},
+        game_map::MapIcon,
+        world_setup::spawn_room,
+        Color,
+    };
+
+    use super::*;
+
+    /// Sets up a world with a single room to enter the city from, east of it.
+    fn set_up_world() -> (World, CityEntrance) {
+        let mut world = World::new();
+        world.insert_resource(GameMap::new());
+        let coordinates = Coordinates {
+            x: 0,
+            y: 0,
+            z: 0,
+            parent: None,
+        };
+        spawn_room(
+            Room {
+                name: "Entrance".to_string(),
+                description: "The way into the city.".to_string(),
+                map_icon: MapIcon::new_uniform(Color::Black, Color::White, ['#', '#']),
+            },
+            coordinates.clone(),
+            &mut world,
+        );
+
+        (
+            world,
+            CityEntrance {
+                coordinates,
+                direction: Direction::East,
+            },
+        )
+    }
+
+    /// Gets the coordinates and names of all the rooms in the provided world.
+    fn rooms(world: &mut World) -> BTreeSet<(String, String)> {
+        world
+            .resource::<GameMap>()
+            .locations
+            .iter()
+            .map(|(coords, entity)| {
+                (
+                    format!("{coords:?}"),
+                    world.get::<Room>(*entity).unwrap().name.clone(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn same_seed_same_city() {
+        let (mut world_1, entrance) = set_up_world();
+        generate_city(42, &entrance, &mut world_1);
+        let (mut world_2, entrance) = set_up_world();
+        generate_city(42, &entrance, &mut world_2);
+
+        assert_eq!(rooms(&mut world_1), rooms(&mut world_2));
+    }
+
+    #[test]
+    fn seed_generates_known_city() {
+        let (mut world, entrance) = set_up_world();
+        generate_city(42, &entrance, &mut world);
+
+        let outside = |x, y| Coordinates {
+            x,
+            y,
+            z: 0,
+            parent: None,
+        };
+        let inside = |x, y, parent| Coordinates {
+            x,
+            y,
+            z: 0,
+            parent: Some(Box::new(parent)),
+        };
+        let expected_rooms = [
+            (inside(-1, 0, outside(6, -5)), "Conference Room"),
+            (inside(0, 1, outside(10, -2)), "Conference Room"),
+            (inside(1, 1, outside(8, -5)), "Checkout Area"),
+            (outside(5, -3), "Cedar Street"),
+        ];
+
+        // if this fails, the same seed no longer generates the same city as it used to
+        assert_eq!(349, rooms(&mut world).len());
+        for (coords, name) in expected_rooms {
+            let room = world.resource::<GameMap>().locations[&coords];
+            assert_eq!(
+                name,
+                world.get::<Room>(room).unwrap().name,
+                "room at {coords:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn city_is_connected_to_entrance() {
+        let (mut world, entrance) = set_up_world();
+        generate_city(7, &entrance, &mut world);
+
+        let entrance_room = world.resource::<GameMap>().locations[&entrance.coordinates];
+        let gate_coords = Coordinates {
+            x: 1,
+            y: 0,
+            z: 0,
+            parent: None,
+        };
+        let gate_room = world.resource::<GameMap>().locations[&gate_coords];
+        let connects_to = |from: Entity, direction: Direction, to: Entity| {
+            world
+                .get::<Container>(from)
+                .unwrap()
+                .get_connection_in_direction(&direction, from, &world)
+                .is_some_and(|(_, connection)| connection.destination == to)
+        };
+
+        assert!(connects_to(entrance_room, Direction::East, gate_room));
+        assert!(connects_to(gate_room, Direction::West, entrance_room));
+    }
+
+    #[test]
+    fn buildings_have_interiors() {
+        let (mut world, entrance) = set_up_world();
+        generate_city(3, &entrance, &mut world);
+
+        let locations = &world.resource::<GameMap>().locations;
+        let interior_count = locations
+            .keys()
+            .filter(|coords| coords.parent.is_some())
+            .count();
+        assert!(interior_count > 0);
+        for coords in locations.keys() {
+            if let Some(parent) = &coords.parent {
+                assert!(
+                    locations.contains_key(parent),
+                    "the outside of the building containing {coords:?} should exist"
+                );
+            }
+        }
+    }
+}
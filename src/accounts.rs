@@ -0,0 +1,163 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use argon2::{
+    password_hash::{rand_core::OsRng, SaltString},
+    Argon2, PasswordHash, PasswordHasher, PasswordVerifier,
+};
+use core_logic::{NewCharacter, PlayerPreferences};
+use serde::{Deserialize, Serialize};
+
+/// An account that can log in to the server.
+#[derive(Debug, Serialize, Deserialize)]
+struct Account {
+    /// The hash of the account's password, in PHC string format.
+    password_hash: String,
+    /// The characters belonging to the account.
+    characters: Vec<NewCharacter>,
+    /// The settings the account's player has chosen for how the game is presented to them.
+    #[serde(default)]
+    preferences: PlayerPreferences,
+}
+
+/// Accounts that can log in to the server, saved to a file.
+pub struct AccountStore {
+    /// The path to the file the accounts are saved in.
+    path: PathBuf,
+    /// The accounts, keyed by their normalized names.
+    accounts: HashMap<String, Account>,
+}
+
+impl AccountStore {
+    /// Loads accounts from the file at the provided path. If there is no file there, the store starts out empty.
+    pub fn load(path: impl AsRef<Path>) -> Result<AccountStore> {
+        let path = path.as_ref().to_path_buf();
+        let accounts = if path.exists() {
+            serde_json::from_reader(BufReader::new(File::open(&path)?))?
+        } else {
+            HashMap::new()
+        };
+
+        Ok(AccountStore { path, accounts })
+    }
+
+    /// Determines whether an account with the provided name exists.
+    pub fn account_exists(&self, account_name: &str) -> bool {
+        self.accounts.contains_key(&normalize(account_name))
+    }
+
+    /// Creates an account with the provided name and password hash, and saves it.
+    pub fn create_account(&mut self, account_name: &str, password_hash: String) -> Result<()> {
+        self.accounts.insert(
+            normalize(account_name),
+            Account {
+                password_hash,
+                characters: Vec::new(),
+                preferences: PlayerPreferences::default(),
+            },
+        );
+
+        self.save()
+    }
+
+    /// Gets the hash of the password of the account with the provided name, to check passwords against with `verify_password`.
+    pub fn get_password_hash(&self, account_name: &str) -> Option<String> {
+        self.accounts
+            .get(&normalize(account_name))
+            .map(|account| account.password_hash.clone())
+    }
+
+    /// Gets the characters belonging to the account with the provided name.
+    pub fn get_characters(&self, account_name: &str) -> &[NewCharacter] {
+        self.accounts
+            .get(&normalize(account_name))
+            .map(|account| account.characters.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Finds the character with the provided name, if any account has it.
+    pub fn find_character(&self, character_name: &str) -> Option<&NewCharacter> {
+        self.accounts
+            .values()
+            .flat_map(|account| &account.characters)
+            .find(|character| normalize(&character.name) == normalize(character_name))
+    }
+
+    /// Adds the provided character to the account with the provided name, and saves it.
+    pub fn add_character(&mut self, account_name: &str, character: NewCharacter) -> Result<()> {
+        if let Some(account) = self.accounts.get_mut(&normalize(account_name)) {
+            account.characters.push(character);
+        }
+
+        self.save()
+    }
+
+    /// Gets the preferences of the account with the provided name.
+    pub fn get_preferences(&self, account_name: &str) -> PlayerPreferences {
+        self.accounts
+            .get(&normalize(account_name))
+            .map(|account| account.preferences.clone())
+            .unwrap_or_default()
+    }
+
+    /// Replaces the preferences of the account with the provided name, and saves it if they changed.
+    pub fn set_preferences(
+        &mut self,
+        account_name: &str,
+        preferences: PlayerPreferences,
+    ) -> Result<()> {
+        let Some(account) = self.accounts.get_mut(&normalize(account_name)) else {
+            return Ok(());
+        };
+
+        if account.preferences == preferences {
+            return Ok(());
+        }
+
+        account.preferences = preferences;
+        self.save()
+    }
+
+    /// Writes all the accounts to the file.
+    fn save(&self) -> Result<()> {
+        serde_json::to_writer_pretty(BufWriter::new(File::create(&self.path)?), &self.accounts)?;
+
+        Ok(())
+    }
+}
+
+/// Hashes the provided password, for storing in an account.
+///
+/// This is slow on purpose, so it shouldn't be called while holding the lock on the account store or on an async runtime's worker thread.
+pub fn hash_password(password: &str) -> Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    let password_hash = Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| anyhow::anyhow!("failed to hash password: {e}"))?
+        .to_string();
+
+    Ok(password_hash)
+}
+
+/// Determines whether the provided password matches the provided password hash.
+///
+/// This is slow on purpose, so it shouldn't be called while holding the lock on the account store or on an async runtime's worker thread.
+pub fn verify_password(password: &str, password_hash: &str) -> bool {
+    let Ok(password_hash) = PasswordHash::new(password_hash) else {
+        return false;
+    };
+
+    Argon2::default()
+        .verify_password(password.as_bytes(), &password_hash)
+        .is_ok()
+}
+
+/// Normalizes the provided name so names that only differ by case are treated as the same.
+fn normalize(name: &str) -> String {
+    name.to_lowercase()
+}
@@ -0,0 +1,46 @@
+use std::{
+    io::stdin,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use anyhow::Result;
+use log::{error, warn};
+
+use core_logic::Game;
+
+use crate::{reload_content, save_game};
+
+/// The console command that saves the world.
+const SAVE_COMMAND: &str = "save";
+
+/// The console command that reloads the game's content.
+const RELOAD_COMMAND: &str = "reload";
+
+/// Spawns a thread that reads commands for the server operator from standard input.
+pub fn spawn_console_thread(game: Arc<Mutex<Game>>) -> Result<()> {
+    thread::Builder::new()
+        .name("console".to_string())
+        .spawn(move || {
+            for line in stdin().lines() {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(e) => {
+                        error!("Error reading from console: {e}");
+                        break;
+                    }
+                };
+
+                match line.trim() {
+                    "" => (),
+                    SAVE_COMMAND => save_game(&game.lock().unwrap()),
+                    RELOAD_COMMAND => reload_content(&game),
+                    other => warn!(
+                        "Unknown console command {other:?}. Try \"{SAVE_COMMAND}\" or \"{RELOAD_COMMAND}\"."
+                    ),
+                }
+            }
+        })?;
+
+    Ok(())
+}
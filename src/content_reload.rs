@@ -0,0 +1,101 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::Result;
+use log::{error, info};
+
+use core_logic::Game;
+
+/// Spawns a thread that reloads the game's content whenever any file in the provided directories changes.
+///
+/// Directories are checked for changes every `interval`.
+pub fn spawn_content_watcher_thread(
+    game: Arc<Mutex<Game>>,
+    dirs: Vec<PathBuf>,
+    interval: Duration,
+) -> Result<()> {
+    thread::Builder::new()
+        .name("content watcher".to_string())
+        .spawn(move || {
+            let mut last_snapshot = snapshot_files(&dirs);
+            loop {
+                thread::sleep(interval);
+                let snapshot = snapshot_files(&dirs);
+                if snapshot != last_snapshot {
+                    info!("Content files changed");
+                    reload_content(&game);
+                    last_snapshot = snapshot;
+                }
+            }
+        })?;
+
+    Ok(())
+}
+
+/// Reloads the game's content, logging what happened. Errors in the content are logged and otherwise ignored.
+pub fn reload_content(game: &Mutex<Game>) {
+    info!("Reloading content");
+    match game.lock().unwrap().reload_content() {
+        Ok(summary) => info!("Reloaded content: {summary}"),
+        Err(e) => error!("Unable to reload content, so it was left unchanged: {e}"),
+    }
+}
+
+/// Finds all the files in the provided directories and their subdirectories, along with when they were last modified, sorted by path.
+fn snapshot_files(dirs: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let mut files = Vec::new();
+    for dir in dirs {
+        add_files_in(dir, &mut files);
+    }
+    files.sort();
+
+    files
+}
+
+/// Adds the files in the provided directory and its subdirectories to `files`, along with when they were last modified.
+fn add_files_in(dir: &Path, files: &mut Vec<(PathBuf, Option<SystemTime>)>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            add_files_in(&path, files);
+        } else {
+            files.push((path, metadata.modified().ok()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_includes_nested_files() {
+        let dir = std::env::temp_dir().join(format!(
+            "textpocalypse-content-reload-test-{}",
+            std::process::id()
+        ));
+        let nested_dir = dir.join("some_mod");
+        std::fs::create_dir_all(&nested_dir).unwrap();
+        std::fs::write(dir.join("rooms.toml"), "").unwrap();
+
+        let before = snapshot_files(std::slice::from_ref(&dir));
+        std::fs::write(nested_dir.join("mod.toml"), "").unwrap();
+        let after = snapshot_files(std::slice::from_ref(&dir));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(1, before.len());
+        assert_eq!(2, after.len());
+        assert_ne!(before, after);
+    }
+}
@@ -0,0 +1,843 @@
+use std::sync::Mutex;
+
+use core_logic::{CharacterCreationOptions, NewCharacter, Pronouns, StatAllocation};
+use itertools::Itertools;
+use log::warn;
+
+use crate::accounts::{hash_password, verify_password, AccountStore};
+
+/// The number of wrong passwords someone can enter before they're disconnected.
+const MAX_PASSWORD_ATTEMPTS: u8 = 3;
+
+/// The minimum length of a password.
+const MIN_PASSWORD_LENGTH: usize = 6;
+
+/// The minimum and maximum length of account and character names.
+const NAME_LENGTH_RANGE: std::ops::RangeInclusive<usize> = 2..=20;
+
+/// The maximum length of character descriptions.
+const MAX_DESCRIPTION_LENGTH: usize = 300;
+
+/// A login in progress on a connection that hasn't entered the game yet.
+pub struct Login {
+    /// The step of the login process the connection is on.
+    state: LoginState,
+    /// The things that can be chosen from when creating a character.
+    character_creation_options: CharacterCreationOptions,
+}
+
+/// A step of the login process.
+enum LoginState {
+    /// Waiting for the name of the account to log in to.
+    AccountName,
+    /// Waiting for the password of an existing account.
+    Password {
+        account_name: String,
+        attempts_remaining: u8,
+    },
+    /// Waiting for confirmation that a new account should be created.
+    ConfirmNewAccount { account_name: String },
+    /// Waiting for the password of a new account.
+    NewPassword { account_name: String },
+    /// Waiting for the password of a new account to be entered again.
+    ConfirmNewPassword {
+        account_name: String,
+        password: String,
+    },
+    /// Waiting for the name of the character to play.
+    CharacterName { account_name: String },
+    /// Waiting for confirmation that a new character should be created.
+    ConfirmNewCharacter {
+        account_name: String,
+        character_name: String,
+    },
+    /// Waiting for the pronouns of a new character.
+    Pronouns {
+        account_name: String,
+        character: NewCharacter,
+    },
+    /// Waiting for the forms of a new character's custom pronouns.
+    CustomPronouns {
+        account_name: String,
+        character: NewCharacter,
+    },
+    /// Waiting for whether a new character's custom pronouns are plural.
+    CustomPronounsPlural {
+        account_name: String,
+        character: NewCharacter,
+    },
+    /// Waiting for the description of a new character.
+    Description {
+        account_name: String,
+        character: NewCharacter,
+    },
+    /// Waiting for points to be spent on the stats of a new character.
+    Stats {
+        account_name: String,
+        character: NewCharacter,
+    },
+}
+
+/// The result of handling a line of input during login.
+#[derive(Debug)]
+pub enum LoginStep {
+    /// The login isn't done yet, and the provided message should be sent.
+    Continue(String),
+    /// The login failed, and the connection should be closed after sending the provided message.
+    Disconnect(String),
+    /// The login is done, and the provided character belonging to the account with the provided name should enter the game.
+    Finished {
+        account_name: String,
+        character: Box<NewCharacter>,
+    },
+}
+
+impl Login {
+    /// Starts a new login.
+    pub fn new(character_creation_options: CharacterCreationOptions) -> Login {
+        Login {
+            state: LoginState::AccountName,
+            character_creation_options,
+        }
+    }
+
+    /// The message to send when a connection is first opened.
+    pub fn initial_prompt() -> String {
+        "Welcome to Textpocalypse!\n(If you use a screen reader, enter \"screenreader on\" at any time.)\nEnter your account name:".to_string()
+    }
+
+    /// Determines whether the next line of input will be a password, so it shouldn't be echoed back.
+    pub fn is_expecting_password(&self) -> bool {
+        matches!(
+            self.state,
+            LoginState::Password { .. }
+                | LoginState::NewPassword { .. }
+                | LoginState::ConfirmNewPassword { .. }
+        )
+    }
+
+    /// Handles a line of input, moving on to the next step of the login process if appropriate.
+    ///
+    /// Checking and creating passwords is slow on purpose, so this shouldn't be called on an async runtime's worker thread.
+    pub fn handle_input(&mut self, input: &str, accounts: &Mutex<AccountStore>) -> LoginStep {
+        // passwords are used exactly as they were entered, even if they start or end with whitespace
+        let input = if self.is_expecting_password() {
+            input.trim_end_matches(['\r', '\n'])
+        } else {
+            input.trim()
+        };
+        let state = std::mem::replace(&mut self.state, LoginState::AccountName);
+        let (next_state, step) =
+            next_state(state, input, accounts, &self.character_creation_options);
+        self.state = next_state;
+
+        step
+    }
+}
+
+/// Determines the state to move to after receiving the provided input, and the step to take.
+fn next_state(
+    state: LoginState,
+    input: &str,
+    accounts: &Mutex<AccountStore>,
+    options: &CharacterCreationOptions,
+) -> (LoginState, LoginStep) {
+    match state {
+        LoginState::AccountName => {
+            if let Err(e) = validate_name(input, "Account") {
+                return (LoginState::AccountName, continue_with(&e));
+            }
+
+            let account_name = input.to_string();
+            if accounts.lock().unwrap().account_exists(&account_name) {
+                (
+                    LoginState::Password {
+                        account_name,
+                        attempts_remaining: MAX_PASSWORD_ATTEMPTS,
+                    },
+                    continue_with("Password:"),
+                )
+            } else {
+                let message = format!("There's no account named {account_name}. Create it? (y/n)");
+                (
+                    LoginState::ConfirmNewAccount { account_name },
+                    continue_with(&message),
+                )
+            }
+        }
+        LoginState::Password {
+            account_name,
+            attempts_remaining,
+        } => {
+            // the password is checked without holding the lock, so other logins don't have to wait for it
+            let password_hash = accounts.lock().unwrap().get_password_hash(&account_name);
+            if password_hash.is_some_and(|password_hash| verify_password(input, &password_hash)) {
+                let message = character_prompt(&account_name, &accounts.lock().unwrap());
+                return (
+                    LoginState::CharacterName { account_name },
+                    continue_with(&message),
+                );
+            }
+
+            let attempts_remaining = attempts_remaining.saturating_sub(1);
+            if attempts_remaining == 0 {
+                return (
+                    LoginState::AccountName,
+                    LoginStep::Disconnect("Wrong password. Goodbye.".to_string()),
+                );
+            }
+
+            (
+                LoginState::Password {
+                    account_name,
+                    attempts_remaining,
+                },
+                continue_with("Wrong password. Password:"),
+            )
+        }
+        LoginState::ConfirmNewAccount { account_name } => match parse_yes_no(input) {
+            Some(true) => (
+                LoginState::NewPassword { account_name },
+                continue_with(&format!(
+                    "Choose a password (at least {MIN_PASSWORD_LENGTH} characters):"
+                )),
+            ),
+            Some(false) => (
+                LoginState::AccountName,
+                continue_with("Enter your account name:"),
+            ),
+            None => (
+                LoginState::ConfirmNewAccount { account_name },
+                continue_with("Please enter y or n:"),
+            ),
+        },
+        LoginState::NewPassword { account_name } => {
+            if input.len() < MIN_PASSWORD_LENGTH {
+                return (
+                    LoginState::NewPassword { account_name },
+                    continue_with(&format!(
+                        "Passwords must be at least {MIN_PASSWORD_LENGTH} characters long. Choose a password:"
+                    )),
+                );
+            }
+
+            (
+                LoginState::ConfirmNewPassword {
+                    account_name,
+                    password: input.to_string(),
+                },
+                continue_with("Enter the same password again:"),
+            )
+        }
+        LoginState::ConfirmNewPassword {
+            account_name,
+            password,
+        } => {
+            if input != password {
+                return (
+                    LoginState::NewPassword { account_name },
+                    continue_with("The passwords didn't match. Choose a password:"),
+                );
+            }
+
+            // the password is hashed without holding the lock, so other logins don't have to wait for it
+            let created = hash_password(&password).and_then(|password_hash| {
+                let mut accounts = accounts.lock().unwrap();
+                if accounts.account_exists(&account_name) {
+                    return Ok(false);
+                }
+                accounts.create_account(&account_name, password_hash)?;
+                Ok(true)
+            });
+
+            match created {
+                Ok(true) => (),
+                Ok(false) => {
+                    return (
+                        LoginState::AccountName,
+                        continue_with(
+                            "Someone else just created an account with that name. Enter your account name:",
+                        ),
+                    );
+                }
+                Err(e) => {
+                    warn!("Error creating account {account_name:?}: {e}");
+                    return (
+                        LoginState::AccountName,
+                        LoginStep::Disconnect(
+                            "Something went wrong creating your account.".to_string(),
+                        ),
+                    );
+                }
+            }
+
+            let message = format!(
+                "Account created.\n{}",
+                character_prompt(&account_name, &accounts.lock().unwrap())
+            );
+            (
+                LoginState::CharacterName { account_name },
+                continue_with(&message),
+            )
+        }
+        LoginState::CharacterName { account_name } => {
+            let accounts = accounts.lock().unwrap();
+            if let Some(character) = accounts
+                .get_characters(&account_name)
+                .iter()
+                .find(|character| character.name.eq_ignore_ascii_case(input))
+                .cloned()
+            {
+                return (
+                    LoginState::CharacterName {
+                        account_name: account_name.clone(),
+                    },
+                    LoginStep::Finished {
+                        account_name,
+                        character: Box::new(character),
+                    },
+                );
+            }
+
+            if let Err(e) = validate_name(input, "Character") {
+                return (
+                    LoginState::CharacterName { account_name },
+                    continue_with(&e),
+                );
+            }
+
+            if accounts.find_character(input).is_some() {
+                return (
+                    LoginState::CharacterName { account_name },
+                    continue_with("That name is already taken. Enter a different name:"),
+                );
+            }
+
+            let character_name = input.to_string();
+            let message = format!("Create a new character named {character_name}? (y/n)");
+            (
+                LoginState::ConfirmNewCharacter {
+                    account_name,
+                    character_name,
+                },
+                continue_with(&message),
+            )
+        }
+        LoginState::ConfirmNewCharacter {
+            account_name,
+            character_name,
+        } => match parse_yes_no(input) {
+            Some(true) => {
+                let message = pronouns_prompt(&character_name);
+                (
+                    LoginState::Pronouns {
+                        account_name,
+                        character: NewCharacter::new(character_name),
+                    },
+                    continue_with(&message),
+                )
+            }
+            Some(false) => {
+                let message = character_prompt(&account_name, &accounts.lock().unwrap());
+                (
+                    LoginState::CharacterName { account_name },
+                    continue_with(&message),
+                )
+            }
+            None => (
+                LoginState::ConfirmNewCharacter {
+                    account_name,
+                    character_name,
+                },
+                continue_with("Please enter y or n:"),
+            ),
+        },
+        LoginState::Pronouns {
+            account_name,
+            mut character,
+        } => {
+            let pronouns = match input.to_lowercase().as_str() {
+                "he" => Pronouns::he(),
+                "she" => Pronouns::she(),
+                "they" => Pronouns::they(),
+                "custom" => {
+                    return (
+                        LoginState::CustomPronouns {
+                            account_name,
+                            character,
+                        },
+                        continue_with(
+                            "Enter the forms of your pronouns separated by slashes, like \"xe/xem/xyrs/xyr/xemself\" (subject/object/possessive/possessive adjective/reflexive):",
+                        ),
+                    );
+                }
+                _ => {
+                    let message = pronouns_prompt(&character.name);
+                    return (
+                        LoginState::Pronouns {
+                            account_name,
+                            character,
+                        },
+                        continue_with(&message),
+                    );
+                }
+            };
+
+            character.pronouns = pronouns;
+            let message = description_prompt(&character.name);
+            (
+                LoginState::Description {
+                    account_name,
+                    character,
+                },
+                continue_with(&message),
+            )
+        }
+        LoginState::CustomPronouns {
+            account_name,
+            mut character,
+        } => {
+            let forms = input.split('/').map(str::trim).collect::<Vec<&str>>();
+            let [subject, object, possessive, possessive_adjective, reflexive] = forms[..] else {
+                return (
+                    LoginState::CustomPronouns {
+                        account_name,
+                        character,
+                    },
+                    continue_with("Please enter exactly 5 forms separated by slashes:"),
+                );
+            };
+
+            if forms.iter().any(|form| !is_valid_pronoun_form(form)) {
+                return (
+                    LoginState::CustomPronouns {
+                        account_name,
+                        character,
+                    },
+                    continue_with("Pronouns can only contain letters. Please enter 5 forms separated by slashes:"),
+                );
+            }
+
+            character.pronouns = Pronouns::new(
+                subject.to_lowercase(),
+                object.to_lowercase(),
+                possessive.to_lowercase(),
+                possessive_adjective.to_lowercase(),
+                reflexive.to_lowercase(),
+                false,
+            );
+            (
+                LoginState::CustomPronounsPlural {
+                    account_name,
+                    character,
+                },
+                continue_with(
+                    "Should verbs be used with these pronouns like with \"they\" (\"they are\") rather than \"she\" (\"she is\")? (y/n)",
+                ),
+            )
+        }
+        LoginState::CustomPronounsPlural {
+            account_name,
+            mut character,
+        } => match parse_yes_no(input) {
+            Some(plural) => {
+                character.pronouns.plural = plural;
+                let message = description_prompt(&character.name);
+                (
+                    LoginState::Description {
+                        account_name,
+                        character,
+                    },
+                    continue_with(&message),
+                )
+            }
+            None => (
+                LoginState::CustomPronounsPlural {
+                    account_name,
+                    character,
+                },
+                continue_with("Please enter y or n:"),
+            ),
+        },
+        LoginState::Description {
+            account_name,
+            mut character,
+        } => {
+            if input.chars().count() > MAX_DESCRIPTION_LENGTH {
+                return (
+                    LoginState::Description {
+                        account_name,
+                        character,
+                    },
+                    continue_with(&format!(
+                        "Descriptions can't be longer than {MAX_DESCRIPTION_LENGTH} characters. Enter a shorter description:"
+                    )),
+                );
+            }
+
+            if !input.is_empty() {
+                character.description = input.to_string();
+            }
+
+            let message = stats_prompt(&character.stat_allocation, options);
+            (
+                LoginState::Stats {
+                    account_name,
+                    character,
+                },
+                continue_with(&message),
+            )
+        }
+        LoginState::Stats {
+            account_name,
+            mut character,
+        } => {
+            let lowercase_input = input.to_lowercase();
+            if lowercase_input == "done" {
+                return finish_character_creation(
+                    account_name,
+                    character,
+                    &mut accounts.lock().unwrap(),
+                );
+            }
+
+            let message = if lowercase_input == "reset" {
+                character.stat_allocation.clear();
+                stats_prompt(&character.stat_allocation, options)
+            } else {
+                match parse_stat_allocation(input) {
+                    Some((stat_name, points)) => {
+                        match character
+                            .stat_allocation
+                            .allocate(stat_name, points, options)
+                        {
+                            Ok(()) => stats_prompt(&character.stat_allocation, options),
+                            Err(e) => e.to_string(),
+                        }
+                    }
+                    None => {
+                        "Enter a stat name followed by a number of points, like \"strength 2\":"
+                            .to_string()
+                    }
+                }
+            };
+
+            (
+                LoginState::Stats {
+                    account_name,
+                    character,
+                },
+                continue_with(&message),
+            )
+        }
+    }
+}
+
+/// Saves the provided newly-created character to the account with the provided name.
+fn finish_character_creation(
+    account_name: String,
+    character: NewCharacter,
+    accounts: &mut AccountStore,
+) -> (LoginState, LoginStep) {
+    if accounts.find_character(&character.name).is_some() {
+        return (
+            LoginState::CharacterName { account_name },
+            continue_with("Someone else just took that name. Enter a different name:"),
+        );
+    }
+
+    if let Err(e) = accounts.add_character(&account_name, character.clone()) {
+        warn!(
+            "Error adding character {:?} to account {account_name:?}: {e}",
+            character.name
+        );
+        return (
+            LoginState::CharacterName { account_name },
+            LoginStep::Disconnect("Something went wrong creating your character.".to_string()),
+        );
+    }
+
+    (
+        LoginState::CharacterName {
+            account_name: account_name.clone(),
+        },
+        LoginStep::Finished {
+            account_name,
+            character: Box::new(character),
+        },
+    )
+}
+
+/// Builds a step that continues the login with the provided message.
+fn continue_with(message: &str) -> LoginStep {
+    LoginStep::Continue(message.to_string())
+}
+
+/// Builds the message asking for the name of the character to play.
+fn character_prompt(account_name: &str, accounts: &AccountStore) -> String {
+    let characters = accounts.get_characters(account_name);
+    if characters.is_empty() {
+        "Enter a name for your new character:".to_string()
+    } else {
+        format!(
+            "Your characters: {}\nEnter the name of the character to play, or a new name to create a new character:",
+            characters
+                .iter()
+                .map(|character| character.name.as_str())
+                .join(", ")
+        )
+    }
+}
+
+/// Builds the message asking for the pronouns of a new character.
+fn pronouns_prompt(character_name: &str) -> String {
+    format!("Which pronouns should be used for {character_name}? Enter he, she, they, or custom:")
+}
+
+/// Builds the message asking for the description of a new character.
+fn description_prompt(character_name: &str) -> String {
+    format!(
+        "Describe what {character_name} looks like, or enter nothing to use the default description:"
+    )
+}
+
+/// Builds the message asking how to spend points on the stats of a new character.
+fn stats_prompt(allocation: &StatAllocation, options: &CharacterCreationOptions) -> String {
+    let attributes = options
+        .attribute_names
+        .iter()
+        .map(|name| format!("{name} {}", allocation.get_attribute_value(name, options)))
+        .join(", ");
+    let skills = options
+        .skill_names
+        .iter()
+        .map(|name| format!("{name} {}", allocation.get_skill_value(name, options)))
+        .join(", ");
+
+    format!(
+        "Attributes ({} points left to spend): {attributes}\n\
+        Skills ({} points left to spend): {skills}\n\
+        Each stat can have up to {} points spent on it.\n\
+        Enter a stat name followed by a number of points to spend on it (like \"strength 2\"), \"reset\" to start over, or \"done\" when you're finished:",
+        allocation.get_remaining_attribute_points(options),
+        allocation.get_remaining_skill_points(options),
+        options.max_points_per_stat,
+    )
+}
+
+/// Parses a stat name followed by a number of points, like "strength 2".
+fn parse_stat_allocation(input: &str) -> Option<(&str, u16)> {
+    let (stat_name, points) = input.rsplit_once(' ')?;
+    let points = points.parse().ok()?;
+
+    Some((stat_name.trim(), points))
+}
+
+/// Determines whether the provided form of a pronoun is valid.
+fn is_valid_pronoun_form(form: &str) -> bool {
+    !form.is_empty() && form.chars().all(|c| c.is_alphabetic())
+}
+
+/// Checks that the provided name is valid, returning an explanation if it's not.
+fn validate_name(name: &str, kind: &str) -> Result<(), String> {
+    if !NAME_LENGTH_RANGE.contains(&name.chars().count()) {
+        return Err(format!(
+            "{kind} names must be between {} and {} characters long. Enter a different name:",
+            NAME_LENGTH_RANGE.start(),
+            NAME_LENGTH_RANGE.end()
+        ));
+    }
+
+    if !name.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(format!(
+            "{kind} names can only contain letters. Enter a different name:"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Parses a yes or no answer.
+fn parse_yes_no(input: &str) -> Option<bool> {
+    match input.to_lowercase().as_str() {
+        "y" | "yes" => Some(true),
+        "n" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn temp_accounts_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "textpocalypse-accounts-test-{name}-{}.json",
+            std::process::id()
+        ))
+    }
+
+    fn new_login() -> Login {
+        Login::new(CharacterCreationOptions {
+            attribute_names: vec!["Strength".to_string(), "Agility".to_string()],
+            skill_names: vec!["Cook".to_string()],
+            base_attribute_value: 10,
+            base_skill_value: 5,
+            attribute_points: 4,
+            skill_points: 10,
+            max_points_per_stat: 5,
+        })
+    }
+
+    fn run(login: &mut Login, inputs: &[&str], accounts: &Mutex<AccountStore>) -> LoginStep {
+        let mut step = LoginStep::Continue(String::new());
+        for input in inputs {
+            step = login.handle_input(input, accounts);
+        }
+
+        step
+    }
+
+    fn unwrap_finished(step: LoginStep) -> NewCharacter {
+        match step {
+            LoginStep::Finished { character, .. } => *character,
+            step => panic!("login should be finished, but got {step:?}"),
+        }
+    }
+
+    #[test]
+    fn create_account_and_character_then_log_back_in() {
+        let path = temp_accounts_path("create");
+        let accounts = Mutex::new(AccountStore::load(&path).unwrap());
+
+        let step = run(
+            &mut new_login(),
+            &[
+                "bob",
+                "y",
+                "hunter2",
+                "hunter2",
+                "Bobby",
+                "y",
+                "she",
+                "A tall person.",
+                "strength 3",
+                "cook 2",
+                "done",
+            ],
+            &accounts,
+        );
+        let character = unwrap_finished(step);
+        assert_eq!("Bobby", character.name);
+        assert_eq!("she", character.pronouns.personal_subject);
+        assert_eq!("A tall person.", character.description);
+
+        // the account should have been saved to the file
+        let accounts = Mutex::new(AccountStore::load(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        let step = run(&mut new_login(), &["BOB", "hunter2", "bobby"], &accounts);
+        let character = unwrap_finished(step);
+        assert_eq!("Bobby", character.name);
+        assert_eq!("she", character.pronouns.personal_subject);
+    }
+
+    #[test]
+    fn create_character_with_custom_pronouns() {
+        let path = temp_accounts_path("custom-pronouns");
+        let accounts = Mutex::new(AccountStore::load(&path).unwrap());
+
+        let step = run(
+            &mut new_login(),
+            &[
+                "bob",
+                "y",
+                "hunter2",
+                "hunter2",
+                "Bobby",
+                "y",
+                "custom",
+                "xe/xem/xyrs/xyr/xemself",
+                "n",
+                "",
+                "done",
+            ],
+            &accounts,
+        );
+        std::fs::remove_file(&path).unwrap();
+
+        let character = unwrap_finished(step);
+        assert_eq!("xe", character.pronouns.personal_subject);
+        assert_eq!("xemself", character.pronouns.reflexive);
+        assert!(!character.pronouns.plural);
+    }
+
+    #[test]
+    fn wrong_password_disconnects_eventually() {
+        let path = temp_accounts_path("wrong-password");
+        let accounts = Mutex::new(AccountStore::load(&path).unwrap());
+        run(
+            &mut new_login(),
+            &["bob", "y", "hunter2", "hunter2"],
+            &accounts,
+        );
+        std::fs::remove_file(&path).unwrap();
+
+        let mut login = new_login();
+        let step = run(&mut login, &["bob", "wrong", "wrong"], &accounts);
+        assert!(matches!(step, LoginStep::Continue(_)));
+        let step = login.handle_input("wrong", &accounts);
+        assert!(matches!(step, LoginStep::Disconnect(_)));
+    }
+
+    #[test]
+    fn characters_of_other_accounts_are_taken() {
+        let path = temp_accounts_path("taken");
+        let accounts = Mutex::new(AccountStore::load(&path).unwrap());
+        run(
+            &mut new_login(),
+            &[
+                "bob", "y", "hunter2", "hunter2", "Bobby", "y", "they", "", "done",
+            ],
+            &accounts,
+        );
+
+        let mut login = new_login();
+        let step = run(
+            &mut login,
+            &["alice", "y", "password", "password", "bobby"],
+            &accounts,
+        );
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            step,
+            LoginStep::Continue(message) if message == "That name is already taken. Enter a different name:"
+        ));
+    }
+
+    #[test]
+    fn passwords_keep_surrounding_whitespace() {
+        let path = temp_accounts_path("whitespace-password");
+        let accounts = Mutex::new(AccountStore::load(&path).unwrap());
+        run(
+            &mut new_login(),
+            &["bob", "y", " hunter2 ", " hunter2 "],
+            &accounts,
+        );
+        std::fs::remove_file(&path).unwrap();
+
+        let step = run(&mut new_login(), &["bob", "hunter2"], &accounts);
+        assert!(matches!(
+            step,
+            LoginStep::Continue(message) if message == "Wrong password. Password:"
+        ));
+        let step = run(&mut new_login(), &["bob", " hunter2 \r\n"], &accounts);
+        assert!(matches!(
+            step,
+            LoginStep::Continue(message) if message == "Enter a name for your new character:"
+        ));
+    }
+}
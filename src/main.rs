@@ -2,18 +2,19 @@ use anyhow::Result;
 use crossterm::{
     cursor,
     style::Print,
-    terminal::{Clear, ClearType},
+    terminal::{self, Clear, ClearType},
     QueueableCommand,
 };
-use log::debug;
+use log::{debug, error, info};
 use std::{
     io::{stdin, stdout, Write},
+    path::{Path, PathBuf},
     sync::{
         atomic::{self, AtomicBool},
-        Arc,
+        Arc, Mutex,
     },
     thread,
-    time::Duration,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use core_logic::*;
@@ -27,6 +28,22 @@ use text_bar::*;
 mod tcp_server;
 use tcp_server::*;
 
+mod console;
+use console::*;
+
+mod websocket_server;
+use websocket_server::*;
+
+mod accounts;
+use accounts::AccountStore;
+
+mod login;
+
+mod session;
+
+mod content_reload;
+use content_reload::*;
+
 const PROMPT: &str = "\n> ";
 
 const SHORT_MESSAGE_DELAY: Duration = Duration::from_millis(333);
@@ -35,6 +52,33 @@ const LONG_MESSAGE_DELAY: Duration = Duration::from_millis(666);
 /// Whether the game should be run as a server or just locally.
 const SERVER_MODE: bool = true;
 
+/// The file to store player accounts in.
+const ACCOUNTS_FILE_PATH: &str = "accounts.json";
+
+/// The file to save the world to, and to load it from at startup if it exists.
+const SAVE_FILE_PATH: &str = "save.json";
+
+/// The directory to load world definition files from, if it exists.
+/// If it doesn't exist, the world definition files built into the game are used.
+const WORLD_DIR_PATH: &str = "world";
+
+/// The directory to load mods from, if it exists.
+const MODS_DIR_PATH: &str = "mods";
+
+/// The IDs of the mods to load from the mods directory.
+/// If this is `None`, all the mods in it are loaded.
+const ACTIVE_MODS: Option<&[&str]> = None;
+
+/// Whether to reload the game's content whenever a file in the world or mods directory changes while running as a server.
+const WATCH_CONTENT_FILES: bool = true;
+
+/// How often to check the world and mods directories for changes.
+const CONTENT_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The seed to generate the city from. Set this to reproduce the city from a previous run.
+/// If this is `None`, a new seed is chosen each time the game starts.
+const WORLDGEN_SEED: Option<u64> = None;
+
 /// The amount of time after which a player is considered to be AFK.
 const AFK_TIMEOUT: Duration = Duration::from_secs(90);
 
@@ -44,41 +88,87 @@ async fn main() -> Result<()> {
         env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
     );
 
-    let game = Game::new(GameOptions {
+    let world_dir = Some(PathBuf::from(WORLD_DIR_PATH)).filter(|dir| dir.is_dir());
+    let mods_dir = Some(PathBuf::from(MODS_DIR_PATH)).filter(|dir| dir.is_dir());
+    let content_dirs = world_dir.iter().chain(&mods_dir).cloned().collect();
+    let worldgen_seed = WORLDGEN_SEED.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_nanos() as u64)
+            .unwrap_or_default()
+    });
+    let game_options = GameOptions {
         afk_timeout: Some(AFK_TIMEOUT),
         skip_worldgen: false,
-    });
+        worldgen_seed: Some(worldgen_seed),
+        world_dir,
+        mods_dir,
+        active_mods: ACTIVE_MODS.map(|ids| ids.iter().map(|id| id.to_string()).collect()),
+    };
+    let game = if Path::new(SAVE_FILE_PATH).exists() {
+        info!("Loading saved world from {SAVE_FILE_PATH}");
+        Game::load(SAVE_FILE_PATH, game_options)?
+    } else {
+        info!("Generating world with seed {worldgen_seed}");
+        Game::new(game_options)?
+    };
 
     if SERVER_MODE {
-        start_server(game).await
+        let game = Arc::new(Mutex::new(game));
+        spawn_console_thread(Arc::clone(&game))?;
+        if WATCH_CONTENT_FILES {
+            spawn_content_watcher_thread(Arc::clone(&game), content_dirs, CONTENT_WATCH_INTERVAL)?;
+        }
+        let accounts = Arc::new(Mutex::new(AccountStore::load(ACCOUNTS_FILE_PATH)?));
+        let result = tokio::select! {
+            result = async {
+                tokio::try_join!(
+                    start_server(Arc::clone(&game), Arc::clone(&accounts)),
+                    start_websocket_server(Arc::clone(&game), accounts)
+                )
+            } => result.map(|_| ()),
+            result = tokio::signal::ctrl_c() => {
+                info!("Shutting down");
+                result.map_err(Into::into)
+            }
+        };
+        save_game(&game.lock().unwrap());
+        result
     } else {
         setup_local(game)
     }
 }
 
 fn setup_local(mut game: Game) -> Result<()> {
-    let (_, commands_sender, messages_receiver) = game.add_player("Player".to_string());
+    let (_, commands_sender, messages_receiver) =
+        game.add_player(NewCharacter::new("Player".to_string()));
 
     let quitting = Arc::new(AtomicBool::new(false));
     let quitting_for_thread = Arc::clone(&quitting);
 
     thread::Builder::new()
         .name("message receiver".to_string())
-        .spawn(move || loop {
-            let (message, game_time) = match messages_receiver.recv() {
-                Ok(x) => x,
-                Err(_) => {
-                    debug!("Message sender has been dropped");
-                    if quitting_for_thread.load(atomic::Ordering::Relaxed) {
-                        break;
+        .spawn(move || {
+            let mut preferences = PlayerPreferences::default();
+            loop {
+                let (message, game_time) = match messages_receiver.recv() {
+                    Ok(x) => x,
+                    Err(_) => {
+                        debug!("Message sender has been dropped");
+                        if quitting_for_thread.load(atomic::Ordering::Relaxed) {
+                            break;
+                        }
+                        panic!("Disconnected from game")
                     }
-                    panic!("Disconnected from game")
+                };
+                debug!("Got message: {message:?}");
+                if let GameMessage::Preferences(new_preferences) = &message {
+                    preferences = new_preferences.clone();
                 }
-            };
-            debug!("Got message: {message:?}");
-            let delay = delay_for_message(&message);
-            render_message(message, game_time).unwrap();
-            thread::sleep(delay);
+                let delay = delay_for_message(&message);
+                render_message(message, game_time, &preferences).unwrap();
+                thread::sleep(delay);
+            }
         })?;
 
     let mut input_buf = String::new();
@@ -91,6 +181,7 @@ fn setup_local(mut game: Game) -> Result<()> {
 
         if input == "quit" {
             quitting.store(true, atomic::Ordering::Relaxed);
+            save_game(&game);
             println!("ok bye");
             return Ok(());
         }
@@ -103,6 +194,14 @@ fn setup_local(mut game: Game) -> Result<()> {
     }
 }
 
+/// Saves the game to the save file. Errors are logged and otherwise ignored.
+fn save_game(game: &Game) {
+    match game.save(SAVE_FILE_PATH) {
+        Ok(()) => info!("Saved the world to {SAVE_FILE_PATH}"),
+        Err(e) => error!("Unable to save the world: {e}"),
+    }
+}
+
 /// Determines the amount of time to wait after displaying the provided message.
 fn delay_for_message(message: &GameMessage) -> Duration {
     let delay = match message {
@@ -121,9 +220,12 @@ fn delay_for_message(message: &GameMessage) -> Duration {
     }
 }
 
-/// Renders the provided `GameMessage` to the screen.
-fn render_message(message: GameMessage, time: Time) -> Result<()> {
-    let output = message_to_string(message, Some(time));
+/// Renders the provided `GameMessage` to the screen for a player with the provided preferences.
+fn render_message(message: GameMessage, time: Time, preferences: &PlayerPreferences) -> Result<()> {
+    let width = terminal::size()
+        .map(|(columns, _)| usize::from(columns))
+        .unwrap_or(DEFAULT_WIDTH);
+    let output = message_to_string(message, Some(time), RenderOptions::new(width, preferences));
 
     stdout()
         .queue(Clear(ClearType::CurrentLine))?
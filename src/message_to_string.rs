@@ -12,66 +12,182 @@ const INDENT: &str = "  ";
 const FIRST_PM_HOUR: u8 = 12;
 const ATTRIBUTE_SECTION_NAME_DECORATOR: &str = "-";
 
-const MAX_WIDTH: usize = 80;
+/// The width to render messages at if the width of the display isn't known.
+pub const DEFAULT_WIDTH: usize = 80;
+
+/// The narrowest width messages will be rendered at, even if the display is narrower.
+const MIN_WIDTH: usize = 40;
+
+/// The style to render messages in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenderProfile {
+    /// Messages are laid out visually, with maps, bars, and tables.
+    #[default]
+    Standard,
+    /// Messages are laid out linearly and without decorative characters, so they make sense when read aloud by a screen reader.
+    ScreenReader,
+}
+
+/// Options for how to render messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// The width of the display, in characters.
+    pub width: usize,
+    /// The style to render messages in.
+    pub profile: RenderProfile,
+    /// Whether to include colors and other styling.
+    pub color: bool,
+    /// The number of tiles across to show maps at.
+    pub map_size: usize,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        RenderOptions::new(DEFAULT_WIDTH, &PlayerPreferences::default())
+    }
+}
+
+impl RenderOptions {
+    /// Creates options for rendering messages for a player with the provided preferences, on a display of the provided width.
+    /// The display width is only used if the player hasn't chosen a width of their own.
+    pub fn new(display_width: usize, preferences: &PlayerPreferences) -> RenderOptions {
+        RenderOptions {
+            width: preferences.line_width.unwrap_or(display_width),
+            profile: if preferences.screen_reader {
+                RenderProfile::ScreenReader
+            } else {
+                RenderProfile::Standard
+            },
+            color: preferences.color,
+            map_size: preferences.map_size.tiles(),
+        }
+    }
+}
 
 /// The absolute value of the minimum adjustment to a stat that will be displayed.
 /// Since adjustments are displayed to 1 decimal point, 0.05 is the smallest adjustment that would be displayed as not 0.0.
 const MIN_DISPLAYED_STAT_ADJUSTMENT: f32 = 0.05;
 
-/// Transforms the provided message into a string for display.
-pub fn message_to_string(message: GameMessage, time: Option<Time>) -> String {
-    match message {
+/// Transforms the provided message into a string for display with the provided options.
+pub fn message_to_string(
+    message: GameMessage,
+    time: Option<Time>,
+    options: RenderOptions,
+) -> String {
+    let options = RenderOptions {
+        width: options.width.max(MIN_WIDTH),
+        ..options
+    };
+    let profile = options.profile;
+    let output = match message {
         GameMessage::Error(e) => e._capitalize(false),
         GameMessage::Message {
             content,
             decorations,
             ..
-        } => message_with_decorations_to_string(content, decorations),
+        } => message_with_decorations_to_string(content, decorations, profile),
         GameMessage::Help(h) => help_to_string(h),
-        GameMessage::Room(room) => room_to_string(room, time),
-        GameMessage::Entity(entity) => entity_to_string(entity),
-        GameMessage::DetailedEntity(entity) => detailed_entity_to_string(entity),
-        GameMessage::Container(container) => container_to_string(container),
-        GameMessage::WornItems(worn_items) => worn_items_to_string(worn_items),
-        GameMessage::Vitals(vitals) => vitals_to_string(vitals),
+        GameMessage::Room(room) => match profile {
+            RenderProfile::Standard => room_to_string(room, time, options),
+            RenderProfile::ScreenReader => room_to_accessible_string(room, time, options.map_size),
+        },
+        GameMessage::Entity(entity) => entity_to_string(entity, options),
+        GameMessage::DetailedEntity(entity) => detailed_entity_to_string(entity, options),
+        GameMessage::Container(container) => container_to_string(container, profile),
+        GameMessage::WornItems(worn_items) => match profile {
+            RenderProfile::Standard => worn_items_to_string(worn_items, options.width),
+            RenderProfile::ScreenReader => worn_items_to_accessible_string(worn_items),
+        },
+        GameMessage::Vitals(vitals) => vitals_to_string(vitals, profile),
         GameMessage::StatusEffects(status_effects) => status_effects_to_string(status_effects),
-        GameMessage::Stats(stats) => stats_to_string(stats),
-        GameMessage::Players(players) => players_to_string(players),
-        GameMessage::Ranges(ranges) => ranges_to_string(ranges),
+        GameMessage::Stats(stats) => match profile {
+            RenderProfile::Standard => stats_to_string(stats, options.width),
+            RenderProfile::ScreenReader => stats_to_accessible_string(stats),
+        },
+        GameMessage::Players(players) => match profile {
+            RenderProfile::Standard => players_to_string(players, options.width),
+            RenderProfile::ScreenReader => players_to_accessible_string(players),
+        },
+        GameMessage::Ranges(ranges) => match profile {
+            RenderProfile::Standard => ranges_to_string(ranges, options.width),
+            RenderProfile::ScreenReader => ranges_to_accessible_string(ranges),
+        },
+        GameMessage::Preferences(preferences) => preferences_to_string(preferences),
         GameMessage::AdvancementPointsGained(points, point_type) => {
-            advancement_points_gained_to_string(points, point_type)
+            advancement_points_gained_to_string(points, point_type, profile)
         }
+    };
+
+    if options.color {
+        output
+    } else {
+        strip_styling(&output)
     }
 }
 
+/// Removes any escape sequences that set colors or other styling from the provided string.
+fn strip_styling(s: &str) -> String {
+    let mut output = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // escape sequences end with a character in this range
+            for sequence_char in chars.by_ref() {
+                if ('@'..='~').contains(&sequence_char) && sequence_char != '[' {
+                    break;
+                }
+            }
+        } else {
+            output.push(c);
+        }
+    }
+
+    output
+}
+
 /// Transforms the provided message with decorations into a string for display.
 fn message_with_decorations_to_string(
     content: String,
     decorations: Vec<MessageDecoration>,
+    profile: RenderProfile,
 ) -> String {
     let mut message = content._capitalize(false);
     for decoration in decorations {
-        message = decorate_message(message, decoration);
+        message = decorate_message(message, decoration, profile);
     }
 
     message
 }
 
 /// Adds a decoration to a message.
-fn decorate_message(message: String, decoration: MessageDecoration) -> String {
-    match decoration {
-        MessageDecoration::VitalChange(change) => {
+fn decorate_message(
+    message: String,
+    decoration: MessageDecoration,
+    profile: RenderProfile,
+) -> String {
+    match (decoration, profile) {
+        (MessageDecoration::VitalChange(change), RenderProfile::Standard) => {
             format!("{}\n{}", message, vital_change_to_string(change))
         }
-        MessageDecoration::ShortVitalChange(change) => {
+        (MessageDecoration::VitalChange(change), RenderProfile::ScreenReader) => {
+            format!("{}\n{}", message, vital_change_to_accessible_string(change))
+        }
+        (MessageDecoration::ShortVitalChange(change), RenderProfile::Standard) => {
             format!("{} {}", short_vital_change_to_string(change), message)
         }
+        (MessageDecoration::ShortVitalChange(change), RenderProfile::ScreenReader) => {
+            format!(
+                "{} {}",
+                message,
+                short_vital_change_to_accessible_string(change)
+            )
+        }
     }
 }
 
 /// Transforms the provided room description into a string for display.
-fn room_to_string(room: RoomDescription, time: Option<Time>) -> String {
-    let map = map_to_string(&room.map);
+fn room_to_string(room: RoomDescription, time: Option<Time>, options: RenderOptions) -> String {
+    let map = map_to_string(&room.map, options.map_size);
     let name = style(room.name).bold();
     let time = if let Some(time) = time {
         style(format!("({})", time_to_string(time)))
@@ -80,7 +196,10 @@ fn room_to_string(room: RoomDescription, time: Option<Time>) -> String {
     } else {
         "".to_string()
     };
-    let desc = room.description;
+    let desc = match room.weather {
+        Some(weather) => format!("{} {weather}", room.description),
+        None => room.description,
+    };
     let entities = if room.entities.is_empty() {
         "".to_string()
     } else {
@@ -88,9 +207,11 @@ fn room_to_string(room: RoomDescription, time: Option<Time>) -> String {
     };
     let exits = format!("Exits: {}", exits_to_string(room.exits));
 
-    let mini_map_width = room.map.tiles.len() * CHARS_PER_TILE;
+    let mini_map_width = options.map_size.min(room.map.tiles.len()) * CHARS_PER_TILE;
     let separator = " ";
-    let max_desc_width = MAX_WIDTH - mini_map_width - separator.len();
+    let max_desc_width = options
+        .width
+        .saturating_sub(mini_map_width + separator.len());
     let wrapped_desc = desc._word_wrap(max_desc_width, "\n", "");
 
     let mini_map_and_desc =
@@ -99,19 +220,99 @@ fn room_to_string(room: RoomDescription, time: Option<Time>) -> String {
     format!("{mini_map_and_desc}{entities}\n\n{exits}")
 }
 
-/// Transforms the provided map into a string for display.
-fn map_to_string<const S: usize>(map: &MapDescription<S>) -> String {
-    let mut output = String::new();
-    for (i, row) in map.tiles.iter().enumerate() {
-        for icon in row {
-            output.push_str(&map_icon_to_string(icon));
-        }
-        if i < map.tiles.len() - 1 {
-            output.push('\n');
-        }
+/// Transforms the provided room description into a string to be read by a screen reader.
+/// Only rooms that would be on a map of the provided size are mentioned.
+fn room_to_accessible_string(room: RoomDescription, time: Option<Time>, map_size: usize) -> String {
+    let name = style(room.name).bold();
+    let time = time
+        .map(|time| format!(" ({})", time_to_string(time)))
+        .unwrap_or_default();
+    let desc = match room.weather {
+        Some(weather) => format!("{} {weather}", room.description),
+        None => room.description,
+    };
+    let entities = if room.entities.is_empty() {
+        "".to_string()
+    } else {
+        format!("\n\n{}", room_entities_to_string(&room.entities))
+    };
+    let exits = format!("Exits: {}.", exits_to_accessible_string(&room.exits));
+    let max_offset = i64::try_from(map_size / 2).unwrap_or(i64::MAX);
+    let nearby_rooms = room
+        .map
+        .nearby_rooms
+        .into_iter()
+        .filter(|room| room.x_offset.abs() <= max_offset && room.y_offset.abs() <= max_offset)
+        .collect::<Vec<_>>();
+    let nearby_rooms = nearby_rooms_to_string(&nearby_rooms)
+        .map(|nearby_rooms| format!("\n\n{nearby_rooms}"))
+        .unwrap_or_default();
+
+    format!("{name}{time}\n\n{desc}{entities}\n\n{exits}{nearby_rooms}")
+}
+
+/// Transforms the provided exit descriptions into a string to be read by a screen reader.
+fn exits_to_accessible_string(exits: &[ExitDescription]) -> String {
+    if exits.is_empty() {
+        return "none".to_string();
     }
 
-    output
+    let exits = exits
+        .iter()
+        .map(|exit| format!("{} to {}", exit.direction, exit.description))
+        .collect::<Vec<String>>();
+
+    format_list(&exits)
+}
+
+/// Transforms the provided nearby room descriptions into a string summarizing where they are, to replace the map for screen readers.
+fn nearby_rooms_to_string(nearby_rooms: &[NearbyRoomDescription]) -> Option<String> {
+    if nearby_rooms.is_empty() {
+        return None;
+    }
+
+    let descriptions = nearby_rooms
+        .iter()
+        .map(|room| {
+            let vertical = match room.y_offset.cmp(&0) {
+                Ordering::Greater => Some(format!("{} north", room.y_offset)),
+                Ordering::Less => Some(format!("{} south", -room.y_offset)),
+                Ordering::Equal => None,
+            };
+            let horizontal = match room.x_offset.cmp(&0) {
+                Ordering::Greater => Some(format!("{} east", room.x_offset)),
+                Ordering::Less => Some(format!("{} west", -room.x_offset)),
+                Ordering::Equal => None,
+            };
+
+            format!(
+                "{} ({})",
+                room.name,
+                [vertical, horizontal].into_iter().flatten().join(" and ")
+            )
+        })
+        .collect::<Vec<String>>();
+
+    Some(format!("Nearby: {}.", format_list(&descriptions)))
+}
+
+/// Transforms the provided map into a string for display, only including the provided number of tiles across from the center of it.
+fn map_to_string<const S: usize>(map: &MapDescription<S>, size: usize) -> String {
+    let size = size.min(S);
+    let skipped = (S - size) / 2;
+
+    map.tiles
+        .iter()
+        .skip(skipped)
+        .take(size)
+        .map(|row| {
+            row.iter()
+                .skip(skipped)
+                .take(size)
+                .map(map_icon_to_string)
+                .collect::<String>()
+        })
+        .join("\n")
 }
 
 /// Transforms the provided map icon into a string for display.
@@ -368,7 +569,7 @@ fn connection_entities_to_string(entities: &[&RoomConnectionEntityDescription])
 }
 
 /// Transforms the provided entity description into a string for display.
-fn entity_to_string(entity: EntityDescription) -> String {
+fn entity_to_string(entity: EntityDescription, options: RenderOptions) -> String {
     let name = style(entity.name).bold();
     let aliases = if entity.aliases.is_empty() {
         "".to_string()
@@ -378,7 +579,7 @@ fn entity_to_string(entity: EntityDescription) -> String {
             .to_string()
     };
     let desc = entity.description;
-    let attributes = entity_attributes_to_string(entity.attributes, entity.pronouns)
+    let attributes = entity_attributes_to_string(entity.attributes, entity.pronouns, options)
         .map_or_else(|| "".to_string(), |s| format!("\n\n{s}"));
 
     format!("{name}{aliases}\n{desc}{attributes}")
@@ -388,6 +589,7 @@ fn entity_to_string(entity: EntityDescription) -> String {
 fn entity_attributes_to_string(
     attributes: Vec<AttributeDescription>,
     pronouns: Pronouns,
+    options: RenderOptions,
 ) -> Option<String> {
     if attributes.is_empty() {
         return None;
@@ -487,7 +689,7 @@ fn entity_attributes_to_string(
         Some(
             sections
                 .iter()
-                .map(attribute_section_to_string)
+                .map(|section| attribute_section_to_string(section, options.profile))
                 .join("\n\n"),
         )
     };
@@ -498,7 +700,7 @@ fn entity_attributes_to_string(
         Some(
             messages
                 .into_iter()
-                .map(|message| message_to_string(*message, None))
+                .map(|message| message_to_string(*message, None, options))
                 .collect::<Vec<String>>()
                 .join("\n\n"),
         )
@@ -519,12 +721,20 @@ fn entity_attributes_to_string(
 }
 
 /// Transforms the provided attribute section into a string for display as part of an entity's description.
-fn attribute_section_to_string(section: &AttributeSection) -> String {
+fn attribute_section_to_string(section: &AttributeSection, profile: RenderProfile) -> String {
     let mut attributes = section
         .attributes
         .iter()
         .map(|attribute| format!("{}: {}", attribute.name, attribute.description));
 
+    if profile == RenderProfile::ScreenReader {
+        return format!(
+            "{}:\n{}",
+            section_name_to_string(&section.name),
+            attributes.join("\n")
+        );
+    }
+
     format!(
         "{} {} {}\n{}",
         ATTRIBUTE_SECTION_NAME_DECORATOR.dark_grey(),
@@ -548,8 +758,8 @@ fn section_name_to_string(section_name: &AttributeSectionName) -> String {
 }
 
 /// Transforms the provided detailed entity description into a string for display.
-fn detailed_entity_to_string(entity: DetailedEntityDescription) -> String {
-    let basic_desc = Some(entity_to_string(entity.basic_desc));
+fn detailed_entity_to_string(entity: DetailedEntityDescription, options: RenderOptions) -> String {
+    let basic_desc = Some(entity_to_string(entity.basic_desc, options));
     let actions = action_descriptions_to_string("Actions:", &entity.actions);
 
     [basic_desc, actions].join("\n\n")
@@ -560,6 +770,17 @@ fn help_to_string(help: HelpDescription) -> String {
     action_descriptions_to_string("Available actions:", &help.actions).unwrap_or_default()
 }
 
+/// Transforms the provided preferences into a string for display.
+fn preferences_to_string(preferences: PlayerPreferences) -> String {
+    let settings = preferences
+        .describe()
+        .into_iter()
+        .map(|(setting, value)| format!("{INDENT}{setting}: {value}"))
+        .join("\n");
+
+    format!("Preferences:\n{settings}\n\nEnter \"set <setting> <value>\" to change a preference.")
+}
+
 /// Transforms the provided list of action descriptions into a string for display.
 fn action_descriptions_to_string(
     header: &str,
@@ -651,7 +872,7 @@ fn container_entity_category_label(category: &ContainerEntityCategory) -> String
 }
 
 /// Transforms the provided container description into a string for display.
-fn container_to_string(container: ContainerDescription) -> String {
+fn container_to_string(container: ContainerDescription, profile: RenderProfile) -> String {
     let category_to_items = container
         .items
         .into_iter()
@@ -667,7 +888,7 @@ fn container_to_string(container: ContainerDescription) -> String {
     {
         format!(
             "{}\n",
-            build_container_category_string(ContainerCategory::Equipped, equipped_items)
+            build_container_category_string(ContainerCategory::Equipped, equipped_items, profile)
         )
     } else {
         "".to_string()
@@ -676,7 +897,7 @@ fn container_to_string(container: ContainerDescription) -> String {
     let worn = if let Some(worn_items) = category_to_items.get(&ContainerCategory::Worn) {
         format!(
             "{}\n",
-            build_container_category_string(ContainerCategory::Worn, worn_items)
+            build_container_category_string(ContainerCategory::Worn, worn_items, profile)
         )
     } else {
         "".to_string()
@@ -699,6 +920,7 @@ fn container_to_string(container: ContainerDescription) -> String {
                 ContainerCategory::EntityCategory(c) => Some(build_container_category_string(
                     ContainerCategory::EntityCategory(*c),
                     items,
+                    profile,
                 )),
             }
         })
@@ -749,19 +971,31 @@ fn container_to_string(container: ContainerDescription) -> String {
 fn build_container_category_string(
     category: ContainerCategory,
     items: &[ContainerEntityDescription],
+    profile: RenderProfile,
 ) -> String {
     let label = format!("{INDENT}{category}:\n");
     let items_string = items
         .iter()
-        .map(|item| format!("{INDENT}{INDENT}{}", container_entity_to_string(item)))
+        .map(|item| {
+            format!(
+                "{INDENT}{INDENT}{}",
+                container_entity_to_string(item, profile)
+            )
+        })
         .join("\n");
 
     format!("{label}{items_string}")
 }
 
 /// Transforms the provided container entity description into a string for display.
-fn container_entity_to_string(entity: &ContainerEntityDescription) -> String {
-    let volume_and_weight = format!("[{:.2}L] [{:.2}kg]", entity.volume, entity.weight);
+fn container_entity_to_string(
+    entity: &ContainerEntityDescription,
+    profile: RenderProfile,
+) -> String {
+    let volume_and_weight = match profile {
+        RenderProfile::Standard => format!("[{:.2}L] [{:.2}kg]", entity.volume, entity.weight),
+        RenderProfile::ScreenReader => format!("{:.2}L, {:.2}kg", entity.volume, entity.weight),
+    };
 
     format!(
         "{} {}",
@@ -771,13 +1005,13 @@ fn container_entity_to_string(entity: &ContainerEntityDescription) -> String {
 }
 
 /// Transforms the provided worn items description into a string for display.
-fn worn_items_to_string(worn_items: WornItemsDescription) -> String {
+fn worn_items_to_string(worn_items: WornItemsDescription, width: usize) -> String {
     let mut items_by_body_part_id: HashMap<u64, Vec<&WornItemDescription>> = HashMap::new();
 
     let by_item_string = if worn_items.items.is_empty() {
         "".to_string()
     } else {
-        let mut by_item_table = new_table();
+        let mut by_item_table = new_table(width);
         by_item_table.set_header(vec![
             Cell::new("Item"),
             Cell::new("Thickness"),
@@ -806,7 +1040,7 @@ fn worn_items_to_string(worn_items: WornItemsDescription) -> String {
         format!("By item:\n{by_item_table}\n\n")
     };
 
-    let mut by_body_part_table = new_table();
+    let mut by_body_part_table = new_table(width);
     by_body_part_table.set_header(vec![
         Cell::new("Body part"),
         Cell::new("Thickness"),
@@ -840,8 +1074,74 @@ fn worn_items_to_string(worn_items: WornItemsDescription) -> String {
     format!("{by_item_string}By body part:\n{by_body_part_table}")
 }
 
+/// Transforms the provided worn items description into a string to be read by a screen reader.
+fn worn_items_to_accessible_string(worn_items: WornItemsDescription) -> String {
+    let by_item_string = if worn_items.items.is_empty() {
+        "".to_string()
+    } else {
+        let items = worn_items
+            .items
+            .iter()
+            .map(|item| {
+                let body_part_names = item
+                    .body_parts
+                    .iter()
+                    .map(|body_part| body_part.name.clone())
+                    .join(", ");
+                format!(
+                    "{INDENT}{}: thickness {}, worn on {}",
+                    item.name._capitalize(false),
+                    item.thickness,
+                    body_part_names
+                )
+            })
+            .join("\n");
+
+        format!("By item:\n{items}\n\n")
+    };
+
+    let max_thickness = worn_items.max_thickness;
+    let body_parts = worn_items
+        .all_body_parts
+        .iter()
+        .map(|body_part| {
+            let items = worn_items
+                .items
+                .iter()
+                .filter(|item| item.body_parts.iter().any(|part| part.id == body_part.id))
+                .collect::<Vec<_>>();
+            let total_thickness = items.iter().map(|item| item.thickness).sum::<u32>();
+            let item_names = if items.is_empty() {
+                "nothing".to_string()
+            } else {
+                items.iter().map(|item| item.name.clone()).join(", ")
+            };
+
+            format!(
+                "{INDENT}{}: thickness {total_thickness} of {max_thickness}, {item_names}",
+                body_part.name._capitalize(false)
+            )
+        })
+        .join("\n");
+
+    format!("{by_item_string}By body part:\n{body_parts}")
+}
+
 /// Transforms the provided vitals description into a string for display.
-fn vitals_to_string(vitals: VitalsDescription) -> String {
+fn vitals_to_string(vitals: VitalsDescription, profile: RenderProfile) -> String {
+    if profile == RenderProfile::ScreenReader {
+        return [
+            (VitalType::Health, vitals.health),
+            (VitalType::Satiety, vitals.satiety),
+            (VitalType::Hydration, vitals.hydration),
+            (VitalType::Energy, vitals.energy),
+            (VitalType::BodyTemperature, vitals.body_temperature),
+        ]
+        .iter()
+        .map(|(vital_type, value)| vital_to_accessible_string(vital_type, value))
+        .join("\n");
+    }
+
     let health = format!(
         "Health:    {}",
         TextBar {
@@ -883,7 +1183,18 @@ fn vitals_to_string(vitals: VitalsDescription) -> String {
         }
     );
 
-    [health, satiety, hydration, energy].join("\n")
+    let body_temperature = format!(
+        "Temp:      {}",
+        TextBar {
+            old_value: None,
+            value: vitals.body_temperature,
+            decreased: false,
+            color: vital_type_to_color(&VitalType::BodyTemperature),
+            style: BarStyle::Full
+        }
+    );
+
+    [health, satiety, hydration, energy, body_temperature].join("\n")
 }
 
 /// Transforms the provided status effects description into a string for display.
@@ -921,7 +1232,7 @@ fn status_effect_to_string(status_effect: StatusEffectDescription) -> String {
 }
 
 /// Transforms the provided stats description into a string for display.
-fn stats_to_string(stats: StatsDescription) -> String {
+fn stats_to_string(stats: StatsDescription, width: usize) -> String {
     let styled_attribute_points = if stats.advancement.attribute_points.available > 0 {
         stats
             .advancement
@@ -968,7 +1279,7 @@ fn stats_to_string(stats: StatsDescription) -> String {
         (None, None)
     };
 
-    let mut attributes_table = new_table();
+    let mut attributes_table = new_table(width);
     attributes_table.set_header(
         vec![
             Some(Cell::new("Name").set_alignment(CellAlignment::Center)),
@@ -1035,7 +1346,7 @@ fn stats_to_string(stats: StatsDescription) -> String {
         None
     };
 
-    let mut skills_table = new_table();
+    let mut skills_table = new_table(width);
     skills_table.set_header(
         vec![
             Some(Cell::new("Name").set_alignment(CellAlignment::Center)),
@@ -1087,6 +1398,61 @@ fn stats_to_string(stats: StatsDescription) -> String {
     format!("{xp_info}\n{attribute_points_info}\n{skill_points_info}\n\nAttributes:\n{attributes_table}\n\nSkills:\n{skills_table}")
 }
 
+/// Transforms the provided stats description into a string to be read by a screen reader.
+fn stats_to_accessible_string(stats: StatsDescription) -> String {
+    let advancement = &stats.advancement;
+    let xp_info = format!("XP: {}", advancement.total_xp.0);
+    let attribute_points_info = format!(
+        "Attribute points: {} ({} XP for next)",
+        advancement.attribute_points.available, advancement.attribute_points.xp_for_next.0
+    );
+    let skill_points_info = format!(
+        "Skill points: {} ({} XP for next)",
+        advancement.skill_points.available, advancement.skill_points.xp_for_next.0
+    );
+
+    let attributes = stats
+        .attributes
+        .iter()
+        .map(|attribute| {
+            let adjustments = if should_display_stat_adjustment(attribute.adjustments) {
+                format!(
+                    " (raw {}, adjusted by {:+.1})",
+                    attribute.raw_value, attribute.adjustments
+                )
+            } else {
+                "".to_string()
+            };
+            format!(
+                "{INDENT}{}: {:.1}{adjustments}",
+                attribute.name, attribute.total
+            )
+        })
+        .join("\n");
+
+    let skills = stats
+        .skills
+        .iter()
+        .map(|skill| {
+            let adjustments = if should_display_stat_adjustment(skill.adjustments) {
+                format!(", adjusted by {:+.1}", skill.adjustments)
+            } else {
+                "".to_string()
+            };
+            format!(
+                "{INDENT}{}: {:.1} (raw {}, {} bonus {:+.1}{adjustments})",
+                skill.name,
+                skill.total,
+                skill.raw_value,
+                skill.base_attribute_name,
+                skill.attribute_bonus
+            )
+        })
+        .join("\n");
+
+    format!("{xp_info}\n{attribute_points_info}\n{skill_points_info}\n\nAttributes:\n{attributes}\n\nSkills:\n{skills}")
+}
+
 /// Determines whether the adjustment to a stat is big enough to display.
 fn should_display_stat_adjustment(adjustment: f32) -> bool {
     adjustment.abs() >= MIN_DISPLAYED_STAT_ADJUSTMENT
@@ -1132,6 +1498,44 @@ fn short_vital_change_to_string<const R: u8>(change: VitalChangeShortDescription
     .to_string()
 }
 
+/// Transforms the provided vital into a string to be read by a screen reader.
+fn vital_to_accessible_string(vital_type: &VitalType, value: &ConstrainedValue<f32>) -> String {
+    format!(
+        "{} {:.0} of {:.0}",
+        vital_type_to_bar_title(vital_type),
+        value.get(),
+        value.get_max()
+    )
+}
+
+/// Transforms the provided vital change description into a string to be read by a screen reader.
+fn vital_change_to_accessible_string(change: VitalChangeDescription) -> String {
+    let direction = if change.new_value < change.old_value {
+        "down"
+    } else {
+        "up"
+    };
+
+    format!(
+        "{}, {direction} from {:.0}",
+        vital_to_accessible_string(&change.vital_type, &change.new_value),
+        change.old_value.get()
+    )
+}
+
+/// Transforms the provided short vital change description into a string to be read by a screen reader.
+fn short_vital_change_to_accessible_string<const R: u8>(
+    change: VitalChangeShortDescription<R>,
+) -> String {
+    let direction = if change.decreased {
+        "decreased"
+    } else {
+        "increased"
+    };
+
+    format!("({} {direction})", change.vital_type)
+}
+
 /// Determines the bar title to use for a vital of the provided type.
 fn vital_type_to_bar_title(vital_type: &VitalType) -> String {
     match vital_type {
@@ -1139,6 +1543,7 @@ fn vital_type_to_bar_title(vital_type: &VitalType) -> String {
         VitalType::Satiety => "Satiety",
         VitalType::Hydration => "Hydration",
         VitalType::Energy => "Energy",
+        VitalType::BodyTemperature => "Body temperature",
     }
     .to_string()
 }
@@ -1150,12 +1555,13 @@ fn vital_type_to_color(vital_type: &VitalType) -> crossterm::style::Color {
         VitalType::Satiety => crossterm::style::Color::Yellow,
         VitalType::Hydration => crossterm::style::Color::DarkCyan,
         VitalType::Energy => crossterm::style::Color::Green,
+        VitalType::BodyTemperature => crossterm::style::Color::Magenta,
     }
 }
 
 /// Transforms the provided players description into a string for display.
-fn players_to_string(players: PlayersDescription) -> String {
-    let mut table = new_table();
+fn players_to_string(players: PlayersDescription, width: usize) -> String {
+    let mut table = new_table(width);
     table.set_header(vec![
         Cell::new("Name"),
         Cell::new("Queued action?"),
@@ -1192,33 +1598,45 @@ fn players_to_string(players: PlayersDescription) -> String {
     table.to_string()
 }
 
+/// Transforms the provided players description into a string to be read by a screen reader.
+fn players_to_accessible_string(players: PlayersDescription) -> String {
+    let players = players
+        .players
+        .into_iter()
+        .map(|player| {
+            let name = if player.is_self {
+                format!("{} (you)", player.name)
+            } else {
+                player.name
+            };
+            let queued_action = if player.has_queued_action {
+                "action queued"
+            } else {
+                "no action queued"
+            };
+            let afk = if player.is_afk { ", AFK" } else { "" };
+
+            format!("{INDENT}{name}: {queued_action}{afk}")
+        })
+        .join("\n");
+
+    format!("Players:\n{players}")
+}
+
 /// Transforms the provided ranges description into a string for display.
-fn ranges_to_string(ranges: RangesDescription) -> String {
-    let mut table = new_table();
+fn ranges_to_string(ranges: RangesDescription, width: usize) -> String {
+    let mut table = new_table(width);
     table.set_header(vec![Cell::new("Name"), Cell::new("Range")]);
 
     for range in ranges.ranges {
         let name_cell = Cell::new(range.name);
 
-        let (range_cell_color, range_cell_text_suffix) = match range.weapon_judgement {
-            WeaponRangeJudgement::NotUsable(reason) => {
-                let suffix = match reason {
-                    WeaponRangeJudgementReason::TooLong => " (too far)",
-                    WeaponRangeJudgementReason::TooShort => " (too close)",
-                    WeaponRangeJudgementReason::NoWeapon => " (no weapon)",
-                };
-                (comfy_table::Color::Red, suffix)
-            }
-            WeaponRangeJudgement::Usable(reason) => {
-                let suffix = match reason {
-                    WeaponRangeJudgementReason::TooLong => " (farther than optimal)",
-                    WeaponRangeJudgementReason::TooShort => " (closer than optimal)",
-                    WeaponRangeJudgementReason::NoWeapon => " (no weapon)",
-                };
-                (comfy_table::Color::Grey, suffix)
-            }
-            WeaponRangeJudgement::Optimal => (comfy_table::Color::Green, ""),
+        let range_cell_color = match range.weapon_judgement {
+            WeaponRangeJudgement::NotUsable(_) => comfy_table::Color::Red,
+            WeaponRangeJudgement::Usable(_) => comfy_table::Color::Grey,
+            WeaponRangeJudgement::Optimal => comfy_table::Color::Green,
         };
+        let range_cell_text_suffix = weapon_range_judgement_suffix(range.weapon_judgement);
 
         let range_cell =
             Cell::new(format!("{}{}", range.range, range_cell_text_suffix)).fg(range_cell_color);
@@ -1229,8 +1647,47 @@ fn ranges_to_string(ranges: RangesDescription) -> String {
     table.to_string()
 }
 
+/// Transforms the provided ranges description into a string to be read by a screen reader.
+fn ranges_to_accessible_string(ranges: RangesDescription) -> String {
+    let ranges = ranges
+        .ranges
+        .into_iter()
+        .map(|range| {
+            format!(
+                "{INDENT}{}: {}{}",
+                range.name,
+                range.range,
+                weapon_range_judgement_suffix(range.weapon_judgement)
+            )
+        })
+        .join("\n");
+
+    format!("Ranges:\n{ranges}")
+}
+
+/// Gets the text to display after a range to describe how usable the current weapon is at that range.
+fn weapon_range_judgement_suffix(judgement: WeaponRangeJudgement) -> &'static str {
+    match judgement {
+        WeaponRangeJudgement::NotUsable(reason) => match reason {
+            WeaponRangeJudgementReason::TooLong => " (too far)",
+            WeaponRangeJudgementReason::TooShort => " (too close)",
+            WeaponRangeJudgementReason::NoWeapon => " (no weapon)",
+        },
+        WeaponRangeJudgement::Usable(reason) => match reason {
+            WeaponRangeJudgementReason::TooLong => " (farther than optimal)",
+            WeaponRangeJudgementReason::TooShort => " (closer than optimal)",
+            WeaponRangeJudgementReason::NoWeapon => " (no weapon)",
+        },
+        WeaponRangeJudgement::Optimal => "",
+    }
+}
+
 /// Generates a string announcing that one or more advancement points were gained.
-fn advancement_points_gained_to_string(points: u32, point_type: AdvancementPointType) -> String {
+fn advancement_points_gained_to_string(
+    points: u32,
+    point_type: AdvancementPointType,
+    profile: RenderProfile,
+) -> String {
     let desc = match point_type {
         AdvancementPointType::Attribute => {
             if points == 1 {
@@ -1248,7 +1705,10 @@ fn advancement_points_gained_to_string(points: u32, point_type: AdvancementPoint
         }
     };
 
-    format!("[ You gained {desc}! ]").cyan().to_string()
+    match profile {
+        RenderProfile::Standard => format!("[ You gained {desc}! ]").cyan().to_string(),
+        RenderProfile::ScreenReader => format!("You gained {desc}!").cyan().to_string(),
+    }
 }
 
 trait Join<T> {
@@ -1276,13 +1736,61 @@ fn format_side_by_side(str1: &str, str2: &str, separator: &str) -> String {
         .join("\n")
 }
 
-/// Creates a new empty table.
-fn new_table() -> Table {
+/// Creates a new empty table that fits in the provided width.
+fn new_table(width: usize) -> Table {
     let mut table = Table::new();
     table
         .set_content_arrangement(ContentArrangement::Dynamic)
-        .set_width(MAX_WIDTH.try_into().expect("max size should fit in u16"))
+        .set_width(width.try_into().unwrap_or(u16::MAX))
         .load_preset(comfy_table::presets::ASCII_FULL_CONDENSED);
 
     table
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vital_to_accessible() {
+        let value = ConstrainedValue::new(73.4, 0.0, 100.0);
+
+        assert_eq!(
+            "Health 73 of 100",
+            vital_to_accessible_string(&VitalType::Health, &value)
+        );
+    }
+
+    #[test]
+    fn nearby_rooms_to_accessible() {
+        let nearby_rooms = vec![
+            NearbyRoomDescription {
+                name: "Kitchen".to_string(),
+                x_offset: 0,
+                y_offset: 1,
+            },
+            NearbyRoomDescription {
+                name: "Garage".to_string(),
+                x_offset: -2,
+                y_offset: -1,
+            },
+        ];
+
+        assert_eq!(
+            Some("Nearby: Kitchen (1 north) and Garage (1 south and 2 west).".to_string()),
+            nearby_rooms_to_string(&nearby_rooms)
+        );
+    }
+
+    #[test]
+    fn strip_styling_from_string() {
+        let styled = format!("{} and {}", style("red").red(), style("bold").bold());
+
+        assert_eq!("red and bold", strip_styling(&styled));
+    }
+
+    #[test]
+    fn no_nearby_rooms_to_accessible() {
+        assert_eq!(None, nearby_rooms_to_string(&[]));
+    }
+}
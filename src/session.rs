@@ -0,0 +1,233 @@
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use core_logic::{Game, GameMessage, NewCharacter, PlayerConnection, PlayerPreferences, Time};
+use futures::{Stream, StreamExt};
+use log::{debug, info, trace, warn};
+
+use crate::{
+    accounts::AccountStore,
+    delay_for_message,
+    login::{Login, LoginStep},
+    RenderProfile,
+};
+
+/// Sends output to a connection, in whatever form its protocol needs.
+pub trait SessionOutput: Send + Sync + 'static {
+    /// Sends a message about logging in. `expecting_password` is whether the next line of input will be a password.
+    fn send_login_message(&self, message: String, expecting_password: bool);
+
+    /// Sends a message about something that happened to the connection itself, rather than in the game.
+    fn send_notice(&self, message: String);
+
+    /// Sends a message from the game, rendered for a player with the provided preferences.
+    /// Returns `false` if the connection can't be written to anymore.
+    fn send_game_message(
+        &self,
+        message: GameMessage,
+        time: Time,
+        preferences: &PlayerPreferences,
+    ) -> bool;
+}
+
+/// Logs in the player on a connection, then connects them to the game until they quit or the connection closes.
+///
+/// `lines` is the input from the connection, one line at a time, and `chosen_render_profile` is the render profile the connection asked for before logging in, if any.
+/// `connection_name` is used to refer to the connection in logs.
+pub async fn run_session<E>(
+    lines: impl Stream<Item = Result<String, E>>,
+    output: Arc<impl SessionOutput>,
+    connection_name: &str,
+    mut chosen_render_profile: Option<RenderProfile>,
+    game: Arc<Mutex<Game>>,
+    accounts: Arc<Mutex<AccountStore>>,
+) -> Result<()>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let mut lines = std::pin::pin!(lines);
+    let preferences = Arc::new(Mutex::new(PlayerPreferences {
+        screen_reader: chosen_render_profile == Some(RenderProfile::ScreenReader),
+        ..PlayerPreferences::default()
+    }));
+
+    let character_creation_options = game.lock().unwrap().get_character_creation_options();
+    let mut login = Login::new(character_creation_options);
+    output.send_login_message(Login::initial_prompt(), false);
+    let (account_name, character) = loop {
+        let Some(input) = lines.next().await else {
+            // the client disconnected before finishing logging in
+            return Ok(());
+        };
+
+        let input = input?;
+        // passwords are never commands, even if they happen to look like one
+        if !login.is_expecting_password() {
+            if let Some(profile) = parse_render_profile_command(&input) {
+                preferences.lock().unwrap().screen_reader = profile == RenderProfile::ScreenReader;
+                output.send_notice(render_profile_changed_message(profile));
+                chosen_render_profile = Some(profile);
+                continue;
+            }
+        }
+
+        // checking passwords is slow, so it's done off of the async runtime's worker threads
+        let step_accounts = Arc::clone(&accounts);
+        let (returned_login, step) = tokio::task::spawn_blocking(move || {
+            let step = login.handle_input(&input, &step_accounts);
+            (login, step)
+        })
+        .await?;
+        login = returned_login;
+
+        match step {
+            LoginStep::Continue(message) => {
+                output.send_login_message(message, login.is_expecting_password());
+            }
+            LoginStep::Disconnect(message) => {
+                output.send_login_message(message, false);
+                return Ok(());
+            }
+            LoginStep::Finished {
+                account_name,
+                character,
+            } => break (account_name, *character),
+        }
+    };
+
+    info!("{connection_name} has logged in as {}", character.name);
+
+    let loaded_preferences = load_preferences(&accounts, &account_name, chosen_render_profile);
+    *preferences.lock().unwrap() = loaded_preferences.clone();
+    let (_, command_sender, message_receiver) = join_game(&game, character, loaded_preferences);
+
+    // spawn task for sending messages to player
+    let (closed_sender, mut closed_receiver) = tokio::sync::oneshot::channel::<()>();
+    let message_output = Arc::clone(&output);
+    let message_preferences = Arc::clone(&preferences);
+    let message_accounts = Arc::clone(&accounts);
+    tokio::spawn(async move {
+        loop {
+            let (message, game_time) = match message_receiver.recv_async().await {
+                Ok(x) => x,
+                Err(_) => {
+                    debug!("Message sender has been dropped");
+                    break;
+                }
+            };
+            trace!("Got message: {message:?}");
+            if let GameMessage::Preferences(new_preferences) = &message {
+                *message_preferences.lock().unwrap() = new_preferences.clone();
+                save_preferences(&message_accounts, &account_name, new_preferences.clone());
+            }
+            let delay = delay_for_message(&message);
+            let preferences = message_preferences.lock().unwrap().clone();
+            if !message_output.send_game_message(message, game_time, &preferences) {
+                debug!("Connection writer has been dropped");
+                break;
+            }
+            tokio::time::sleep(delay).await;
+        }
+
+        // the player has been detached from the game, so the connection should be closed
+        let _ = closed_sender.send(());
+    });
+
+    // receive commands from player
+    loop {
+        let input = tokio::select! {
+            input = lines.next() => input,
+            _ = &mut closed_receiver => break,
+        };
+
+        // The stream will return None once the client disconnects.
+        let Some(input) = input else {
+            break;
+        };
+
+        match input {
+            Ok(input) => {
+                debug!("Raw input: {input:?}");
+                let input = input.trim().to_string();
+                if input == "quit" {
+                    break;
+                }
+                if command_sender.send(input).is_err() {
+                    debug!("Command receiver has been dropped");
+                    break;
+                }
+            }
+            Err(err) => {
+                warn!("{connection_name} closed with error: {err:?}");
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds the provided character to the game for a player with the provided preferences. If the character is already in the world, the new connection takes control of it instead.
+fn join_game(
+    game: &Mutex<Game>,
+    character: NewCharacter,
+    preferences: PlayerPreferences,
+) -> PlayerConnection {
+    let mut game = game.lock().unwrap();
+    let connection = match game.reattach_player(&character.name) {
+        Some(connection) => connection,
+        None => game.add_player(character),
+    };
+    game.set_player_preferences(connection.0, preferences);
+
+    connection
+}
+
+/// Loads the preferences of the account with the provided name.
+/// If a render profile was chosen before logging in, it replaces the one in the saved preferences.
+fn load_preferences(
+    accounts: &Mutex<AccountStore>,
+    account_name: &str,
+    chosen_render_profile: Option<RenderProfile>,
+) -> PlayerPreferences {
+    let mut preferences = accounts.lock().unwrap().get_preferences(account_name);
+    if let Some(profile) = chosen_render_profile {
+        preferences.screen_reader = profile == RenderProfile::ScreenReader;
+        save_preferences(accounts, account_name, preferences.clone());
+    }
+
+    preferences
+}
+
+/// Saves the provided preferences to the account with the provided name. Logs and ignores any errors.
+fn save_preferences(
+    accounts: &Mutex<AccountStore>,
+    account_name: &str,
+    preferences: PlayerPreferences,
+) {
+    if let Err(e) = accounts
+        .lock()
+        .unwrap()
+        .set_preferences(account_name, preferences)
+    {
+        warn!("Error saving preferences for account {account_name:?}: {e}");
+    }
+}
+
+/// Parses a command to change the render profile of a connection that hasn't entered the game yet, which is handled by the connection rather than the game.
+/// Returns `None` if the provided input isn't such a command.
+fn parse_render_profile_command(input: &str) -> Option<RenderProfile> {
+    match input.trim().to_lowercase().as_str() {
+        "screenreader on" => Some(RenderProfile::ScreenReader),
+        "screenreader off" => Some(RenderProfile::Standard),
+        _ => None,
+    }
+}
+
+/// Builds the message to send after a connection's render profile is changed to the provided one.
+fn render_profile_changed_message(profile: RenderProfile) -> String {
+    match profile {
+        RenderProfile::Standard => "Screen reader mode is now off.".to_string(),
+        RenderProfile::ScreenReader => "Screen reader mode is now on.".to_string(),
+    }
+}
@@ -0,0 +1,238 @@
+use core_logic::{
+    ConstrainedValue, Coordinates, GameMessage, MapDescription, RoomDescription, StatAdjustment,
+    StatsDescription, StatusEffectsDescription, VitalsDescription,
+};
+use itertools::Itertools;
+use serde_json::{json, Map, Value};
+
+/// A GMCP message, to be sent to the client in a subnegotiation.
+#[derive(Debug, PartialEq)]
+pub struct GmcpMessage {
+    /// The name of the package the message is for, like `Char.Vitals`.
+    pub package: &'static str,
+    /// The data in the message.
+    pub data: Value,
+}
+
+impl GmcpMessage {
+    /// Builds the contents of the subnegotiation to send this message in.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        format!("{} {}", self.package, self.data).into_bytes()
+    }
+}
+
+/// Builds the GMCP messages that carry the same information as the provided message, if there are any.
+pub fn message_to_gmcp(message: &GameMessage) -> Vec<GmcpMessage> {
+    match message {
+        GameMessage::Room(room) => vec![room_info(room), room_map(&room.map)],
+        GameMessage::Vitals(vitals) => vec![char_vitals(vitals)],
+        GameMessage::StatusEffects(status_effects) => vec![char_status_effects(status_effects)],
+        GameMessage::Stats(stats) => vec![char_stats(stats)],
+        _ => Vec::new(),
+    }
+}
+
+/// Builds a `Room.Info` message.
+fn room_info(room: &RoomDescription) -> GmcpMessage {
+    let exits = room
+        .exits
+        .iter()
+        .map(|exit| (exit.direction.to_string(), json!(exit.description)))
+        .collect::<Map<_, _>>();
+
+    GmcpMessage {
+        package: "Room.Info",
+        data: json!({
+            "name": room.name,
+            "description": room.description,
+            "weather": room.weather,
+            "coordinates": coordinates(&room.coordinates),
+            "exits": exits,
+        }),
+    }
+}
+
+/// Builds the JSON representation of the provided coordinates.
+fn coordinates(coordinates: &Coordinates) -> Value {
+    json!({
+        "x": coordinates.x,
+        "y": coordinates.y,
+        "z": coordinates.z,
+        "parent": coordinates.parent.as_deref().map(self::coordinates),
+    })
+}
+
+/// Builds a `Room.Map` message, with each row of the map as a string.
+fn room_map<const S: usize>(map: &MapDescription<S>) -> GmcpMessage {
+    let rows = map
+        .tiles
+        .iter()
+        .map(|row| {
+            row.iter()
+                .flat_map(|icon| icon.chars.iter().map(|c| c.value))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    GmcpMessage {
+        package: "Room.Map",
+        data: json!({ "rows": rows }),
+    }
+}
+
+/// Builds a `Char.Vitals` message.
+fn char_vitals(vitals: &VitalsDescription) -> GmcpMessage {
+    GmcpMessage {
+        package: "Char.Vitals",
+        data: json!({
+            "health": vital(&vitals.health),
+            "satiety": vital(&vitals.satiety),
+            "hydration": vital(&vitals.hydration),
+            "energy": vital(&vitals.energy),
+            "body_temperature": vital(&vitals.body_temperature),
+        }),
+    }
+}
+
+/// Builds the JSON representation of a single vital.
+fn vital(value: &ConstrainedValue<f32>) -> Value {
+    json!({
+        "current": value.get(),
+        "max": value.get_max(),
+    })
+}
+
+/// Builds a `Char.StatusEffects` message.
+fn char_status_effects(status_effects: &StatusEffectsDescription) -> GmcpMessage {
+    let effects = status_effects
+        .0
+        .iter()
+        .map(|effect| {
+            let stat_adjustments = effect
+                .stat_adjustments
+                .iter()
+                .sorted_by(|a, b| a.0 .0.cmp(&b.0 .0))
+                .map(|(stat_name, adjustments)| {
+                    let adjustments = adjustments.iter().map(stat_adjustment).collect::<Vec<_>>();
+                    (stat_name.to_string(), json!(adjustments))
+                })
+                .collect::<Map<_, _>>();
+
+            json!({
+                "name": effect.name,
+                "stat_adjustments": stat_adjustments,
+                "other_effects": effect.other_effects,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    GmcpMessage {
+        package: "Char.StatusEffects",
+        data: json!(effects),
+    }
+}
+
+/// Builds the JSON representation of a single stat adjustment.
+fn stat_adjustment(adjustment: &StatAdjustment) -> Value {
+    match adjustment {
+        StatAdjustment::Add(amount) => json!({ "add": amount }),
+        StatAdjustment::Subtract(amount) => json!({ "subtract": amount }),
+        StatAdjustment::Multiply(amount) => json!({ "multiply": amount }),
+    }
+}
+
+/// Builds a `Char.Stats` message.
+fn char_stats(stats: &StatsDescription) -> GmcpMessage {
+    let attributes = stats
+        .attributes
+        .iter()
+        .map(|attribute| {
+            json!({
+                "name": attribute.name,
+                "raw": attribute.raw_value,
+                "adjustments": attribute.adjustments,
+                "total": attribute.total,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let skills = stats
+        .skills
+        .iter()
+        .map(|skill| {
+            json!({
+                "name": skill.name,
+                "base_attribute": skill.base_attribute_name,
+                "raw": skill.raw_value,
+                "attribute_bonus": skill.attribute_bonus,
+                "adjustments": skill.adjustments,
+                "total": skill.total,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let advancement = &stats.advancement;
+
+    GmcpMessage {
+        package: "Char.Stats",
+        data: json!({
+            "attributes": attributes,
+            "skills": skills,
+            "xp": advancement.total_xp.0,
+            "attribute_points": {
+                "available": advancement.attribute_points.available,
+                "xp_for_next": advancement.attribute_points.xp_for_next.0,
+            },
+            "skill_points": {
+                "available": advancement.skill_points.available,
+                "xp_for_next": advancement.skill_points.xp_for_next.0,
+            },
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vitals_to_gmcp() {
+        let message = GameMessage::Vitals(VitalsDescription {
+            health: ConstrainedValue::new(50.0, 0.0, 100.0),
+            satiety: ConstrainedValue::new(10.0, 0.0, 20.0),
+            hydration: ConstrainedValue::new(20.0, 0.0, 20.0),
+            energy: ConstrainedValue::new(0.0, 0.0, 5.0),
+            body_temperature: ConstrainedValue::new(50.0, 0.0, 100.0),
+        });
+
+        let gmcp = message_to_gmcp(&message);
+
+        assert_eq!(1, gmcp.len());
+        assert_eq!("Char.Vitals", gmcp[0].package);
+        assert_eq!(
+            json!({ "current": 50.0, "max": 100.0 }),
+            gmcp[0].data["health"]
+        );
+        assert_eq!(
+            json!({ "current": 0.0, "max": 5.0 }),
+            gmcp[0].data["energy"]
+        );
+    }
+
+    #[test]
+    fn non_structured_message_to_gmcp() {
+        let message = GameMessage::Error("oh no".to_string());
+
+        assert!(message_to_gmcp(&message).is_empty());
+    }
+
+    #[test]
+    fn gmcp_to_bytes() {
+        let message = GmcpMessage {
+            package: "Char.Test",
+            data: json!({ "a": 1 }),
+        };
+
+        assert_eq!(b"Char.Test {\"a\":1}".to_vec(), message.to_bytes());
+    }
+}
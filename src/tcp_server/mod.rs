@@ -0,0 +1,243 @@
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use anyhow::Result;
+use core_logic::{Game, GameMessage, PlayerPreferences, Time};
+use futures::{stream, SinkExt, Stream, StreamExt};
+use gmcp::message_to_gmcp;
+use log::{debug, info, trace, warn};
+use telnet_codec::{TelnetCodec, TelnetEvent, TelnetOutput, OPTION_ECHO, OPTION_GMCP, OPTION_NAWS};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::mpsc::{self, UnboundedSender},
+};
+use tokio_util::codec::Decoder;
+
+use crate::{
+    accounts::AccountStore,
+    message_to_string,
+    session::{run_session, SessionOutput},
+    RenderOptions, DEFAULT_WIDTH,
+};
+
+mod gmcp;
+mod telnet_codec;
+
+/// The options negotiated with a telnet client.
+struct ClientOptions {
+    /// The width of the client's window, in characters.
+    width: AtomicUsize,
+    /// Whether the client has agreed to receive GMCP messages.
+    gmcp_enabled: AtomicBool,
+    /// Whether the server has told the client it will echo input, so the client doesn't show what's typed.
+    echo_hidden: AtomicBool,
+}
+
+/// Sends output to a telnet client.
+struct TelnetSessionOutput {
+    /// Sends output to the task writing to the socket.
+    output_sender: UnboundedSender<TelnetOutput>,
+    /// The options negotiated with the client.
+    client_options: Arc<ClientOptions>,
+}
+
+impl SessionOutput for TelnetSessionOutput {
+    fn send_login_message(&self, message: String, expecting_password: bool) {
+        if self
+            .client_options
+            .echo_hidden
+            .swap(expecting_password, Ordering::Relaxed)
+            != expecting_password
+        {
+            // the server claiming to echo input makes the client stop echoing it itself
+            let echo_output = if expecting_password {
+                TelnetOutput::Will(OPTION_ECHO)
+            } else {
+                TelnetOutput::Wont(OPTION_ECHO)
+            };
+            let _ = self.output_sender.send(echo_output);
+        }
+
+        let _ = self
+            .output_sender
+            .send(TelnetOutput::Line(format!("{message}\n")));
+    }
+
+    fn send_notice(&self, message: String) {
+        let _ = self
+            .output_sender
+            .send(TelnetOutput::Line(format!("{message}\n")));
+    }
+
+    fn send_game_message(
+        &self,
+        message: GameMessage,
+        time: Time,
+        preferences: &PlayerPreferences,
+    ) -> bool {
+        if self.client_options.gmcp_enabled.load(Ordering::Relaxed) {
+            for gmcp_message in message_to_gmcp(&message) {
+                let _ = self.output_sender.send(TelnetOutput::Subnegotiation(
+                    OPTION_GMCP,
+                    gmcp_message.to_bytes(),
+                ));
+            }
+        }
+
+        let render_options = RenderOptions::new(
+            self.client_options.width.load(Ordering::Relaxed),
+            preferences,
+        );
+        let rendered_message = message_to_string(message, Some(time), render_options);
+        self.output_sender
+            .send(TelnetOutput::Line(format!("{rendered_message}\n")))
+            .is_ok()
+    }
+}
+
+pub async fn start_server(
+    game: Arc<Mutex<Game>>,
+    accounts: Arc<Mutex<AccountStore>>,
+) -> Result<()> {
+    let addr = "0.0.0.0:8080".to_string();
+
+    // Next up we create a TCP listener which will listen for incoming
+    // connections. This TCP listener is bound to the address we determined
+    // above and must be associated with an event loop, so we pass in a handle
+    // to our event loop. After the socket's created we inform that we're ready
+    // to go and start accepting connections.
+    let listener = TcpListener::bind(&addr).await?;
+    info!("Listening on: {}", addr);
+
+    let mut next_connection_id = 0;
+    loop {
+        // Asynchronously wait for an inbound socket.
+        let (socket, _) = listener.accept().await?;
+
+        let connection_id = next_connection_id;
+        next_connection_id += 1;
+
+        info!("Connection {connection_id} has opened");
+
+        let game = Arc::clone(&game);
+        let accounts = Arc::clone(&accounts);
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(socket, connection_id, game, accounts).await {
+                warn!("Error on connection {connection_id}: {e:?}");
+            }
+
+            info!("Connection {connection_id} has closed");
+        });
+    }
+}
+
+/// Logs in the player on the provided socket, then connects them to the game.
+async fn handle_connection(
+    socket: TcpStream,
+    connection_id: u32,
+    game: Arc<Mutex<Game>>,
+    accounts: Arc<Mutex<AccountStore>>,
+) -> Result<()> {
+    let (mut sink, stream) = TelnetCodec::new().framed(socket).split::<TelnetOutput>();
+
+    // spawn task for writing to the socket, so output can be sent from multiple places
+    let (output_sender, mut output_receiver) = mpsc::unbounded_channel::<TelnetOutput>();
+    tokio::spawn(async move {
+        while let Some(output) = output_receiver.recv().await {
+            if let Err(e) = sink.send(output).await {
+                debug!("Unable to write to socket: {e:?}");
+                break;
+            }
+        }
+    });
+
+    let client_options = Arc::new(ClientOptions {
+        width: AtomicUsize::new(DEFAULT_WIDTH),
+        gmcp_enabled: AtomicBool::new(false),
+        echo_hidden: AtomicBool::new(false),
+    });
+    let _ = output_sender.send(TelnetOutput::Do(OPTION_NAWS));
+    let _ = output_sender.send(TelnetOutput::Will(OPTION_GMCP));
+
+    let lines = stream::unfold(
+        (stream, output_sender.clone(), Arc::clone(&client_options)),
+        |(mut stream, output_sender, client_options)| async move {
+            let line = next_line(&mut stream, &output_sender, &client_options).await?;
+            Some((line, (stream, output_sender, client_options)))
+        },
+    );
+    let output = Arc::new(TelnetSessionOutput {
+        output_sender,
+        client_options,
+    });
+
+    run_session(
+        lines,
+        output,
+        &format!("Connection {connection_id}"),
+        None,
+        game,
+        accounts,
+    )
+    .await
+}
+
+/// Reads from the provided stream until a full line of input is received, handling any telnet negotiation along the way.
+/// Returns `None` once the client disconnects.
+async fn next_line(
+    stream: &mut (impl Stream<Item = Result<TelnetEvent, io::Error>> + Unpin),
+    output_sender: &UnboundedSender<TelnetOutput>,
+    client_options: &ClientOptions,
+) -> Option<Result<String, io::Error>> {
+    loop {
+        let event = match stream.next().await? {
+            Ok(event) => event,
+            Err(e) => return Some(Err(e)),
+        };
+
+        trace!("Got telnet event: {event:?}");
+        match event {
+            TelnetEvent::Line(line) => {
+                if client_options.echo_hidden.load(Ordering::Relaxed) {
+                    // the client didn't echo the line ending either, so add one
+                    let _ = output_sender.send(TelnetOutput::Line(String::new()));
+                }
+
+                return Some(Ok(line));
+            }
+            TelnetEvent::WindowSize {
+                width: new_width, ..
+            } => {
+                // some clients report a width of 0 when they don't know it
+                if new_width > 0 {
+                    client_options
+                        .width
+                        .store(new_width.into(), Ordering::Relaxed);
+                }
+            }
+            TelnetEvent::Will(option) => {
+                if option != OPTION_NAWS {
+                    let _ = output_sender.send(TelnetOutput::Dont(option));
+                }
+            }
+            TelnetEvent::Do(OPTION_GMCP) => {
+                client_options.gmcp_enabled.store(true, Ordering::Relaxed)
+            }
+            TelnetEvent::Dont(OPTION_GMCP) => {
+                client_options.gmcp_enabled.store(false, Ordering::Relaxed)
+            }
+            TelnetEvent::Do(option) => {
+                if option != OPTION_ECHO {
+                    let _ = output_sender.send(TelnetOutput::Wont(option));
+                }
+            }
+            // messages clients send over GMCP (like `Core.Hello`) aren't needed for anything yet
+            TelnetEvent::Wont(_) | TelnetEvent::Dont(_) | TelnetEvent::Subnegotiation(_, _) => {}
+        }
+    }
+}
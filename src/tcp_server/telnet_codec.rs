@@ -0,0 +1,428 @@
+use std::io;
+
+use tokio_util::bytes::{Buf, BufMut, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+/// Interpret As Command: marks the start of a telnet command.
+const IAC: u8 = 255;
+/// The sender wants to start performing an option.
+const WILL: u8 = 251;
+/// The sender won't perform an option.
+const WONT: u8 = 252;
+/// The sender wants the receiver to start performing an option.
+const DO: u8 = 253;
+/// The sender wants the receiver to not perform an option.
+const DONT: u8 = 254;
+/// Start of subnegotiation.
+const SB: u8 = 250;
+/// End of subnegotiation.
+const SE: u8 = 240;
+
+/// The telnet echo option.
+pub const OPTION_ECHO: u8 = 1;
+/// The telnet Negotiate About Window Size option.
+pub const OPTION_NAWS: u8 = 31;
+/// The Generic MUD Communication Protocol option.
+pub const OPTION_GMCP: u8 = 201;
+
+/// The maximum number of bytes allowed in a single line or subnegotiation, to avoid buffering unbounded input.
+const MAX_FRAME_LENGTH: usize = 8 * 1024;
+
+/// Something received from a telnet client.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TelnetEvent {
+    /// A line of input, without its line ending.
+    Line(String),
+    /// The client wants to start performing an option, or agreed to perform it.
+    Will(u8),
+    /// The client won't perform an option.
+    Wont(u8),
+    /// The client wants the server to start performing an option, or agreed to let it.
+    Do(u8),
+    /// The client wants the server to not perform an option.
+    Dont(u8),
+    /// The client sent the provided size of its window, in characters.
+    WindowSize { width: u16, height: u16 },
+    /// The client sent a subnegotiation for an option not handled by the codec.
+    Subnegotiation(u8, Vec<u8>),
+}
+
+/// Something to send to a telnet client.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TelnetOutput {
+    /// A line of text. A line ending will be added to the end of it.
+    Line(String),
+    /// Tell the client the server will start performing an option.
+    Will(u8),
+    /// Tell the client the server won't perform an option.
+    Wont(u8),
+    /// Ask the client to start performing an option.
+    Do(u8),
+    /// Ask the client to not perform an option.
+    Dont(u8),
+    /// A subnegotiation for an option.
+    Subnegotiation(u8, Vec<u8>),
+}
+
+/// Codec that splits telnet input into lines, and separates out any telnet commands mixed in with it.
+pub struct TelnetCodec {
+    /// The bytes of the line currently being received.
+    line: Vec<u8>,
+}
+
+impl TelnetCodec {
+    /// Creates a codec with no input received yet.
+    pub fn new() -> TelnetCodec {
+        TelnetCodec { line: Vec::new() }
+    }
+}
+
+impl Decoder for TelnetCodec {
+    type Item = TelnetEvent;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        loop {
+            let Some(&byte) = src.first() else {
+                return Ok(None);
+            };
+
+            if byte == IAC {
+                match decode_command(src)? {
+                    Command::Incomplete => return Ok(None),
+                    Command::EscapedIac => {
+                        src.advance(2);
+                        self.push_line_byte(IAC)?;
+                    }
+                    Command::Event(event, len) => {
+                        src.advance(len);
+                        return Ok(Some(event));
+                    }
+                    Command::Ignored(len) => src.advance(len),
+                }
+                continue;
+            }
+
+            src.advance(1);
+            match byte {
+                b'\n' => {
+                    let line = String::from_utf8_lossy(&self.line).into_owned();
+                    self.line.clear();
+                    return Ok(Some(TelnetEvent::Line(line)));
+                }
+                // carriage returns are followed by a line feed or a null byte, neither of which needs it
+                b'\r' | b'\0' => (),
+                _ => self.push_line_byte(byte)?,
+            }
+        }
+    }
+}
+
+impl TelnetCodec {
+    /// Adds a byte to the line currently being received.
+    fn push_line_byte(&mut self, byte: u8) -> Result<(), io::Error> {
+        if self.line.len() >= MAX_FRAME_LENGTH {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "line is too long",
+            ));
+        }
+
+        self.line.push(byte);
+
+        Ok(())
+    }
+}
+
+/// The result of trying to decode a telnet command.
+enum Command {
+    /// Not enough bytes have been received to decode the whole command yet.
+    Incomplete,
+    /// The command is an escaped 255 byte, which is part of the line rather than a command.
+    EscapedIac,
+    /// The command was decoded into an event, and was the provided number of bytes long.
+    Event(TelnetEvent, usize),
+    /// The command doesn't need to be handled, and was the provided number of bytes long.
+    Ignored(usize),
+}
+
+/// Decodes the telnet command at the start of the provided bytes, which should start with `IAC`.
+fn decode_command(src: &BytesMut) -> Result<Command, io::Error> {
+    let Some(&command) = src.get(1) else {
+        return Ok(Command::Incomplete);
+    };
+
+    match command {
+        IAC => Ok(Command::EscapedIac),
+        WILL | WONT | DO | DONT => {
+            let Some(&option) = src.get(2) else {
+                return Ok(Command::Incomplete);
+            };
+
+            let event = match command {
+                WILL => TelnetEvent::Will(option),
+                WONT => TelnetEvent::Wont(option),
+                DO => TelnetEvent::Do(option),
+                _ => TelnetEvent::Dont(option),
+            };
+
+            Ok(Command::Event(event, 3))
+        }
+        SB => decode_subnegotiation(src),
+        // other commands (like go ahead or no-op) don't need to be handled
+        _ => Ok(Command::Ignored(2)),
+    }
+}
+
+/// Decodes the subnegotiation at the start of the provided bytes, which should start with `IAC SB`.
+fn decode_subnegotiation(src: &BytesMut) -> Result<Command, io::Error> {
+    let Some(&option) = src.get(2) else {
+        return Ok(Command::Incomplete);
+    };
+
+    let mut data = Vec::new();
+    let mut i = 3;
+    loop {
+        if i > MAX_FRAME_LENGTH {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "subnegotiation is too long",
+            ));
+        }
+
+        let (Some(&byte), next) = (src.get(i), src.get(i + 1)) else {
+            return Ok(Command::Incomplete);
+        };
+
+        if byte == IAC {
+            match next {
+                None => return Ok(Command::Incomplete),
+                Some(&SE) => break,
+                Some(&IAC) => {
+                    data.push(IAC);
+                    i += 2;
+                }
+                // IAC followed by anything else isn't valid in a subnegotiation, so just skip it
+                Some(_) => i += 2,
+            }
+        } else {
+            data.push(byte);
+            i += 1;
+        }
+    }
+
+    // add 2 for the IAC SE at the end
+    let len = i + 2;
+
+    if option == OPTION_NAWS {
+        if let [width_high, width_low, height_high, height_low] = data[..] {
+            let width = u16::from_be_bytes([width_high, width_low]);
+            let height = u16::from_be_bytes([height_high, height_low]);
+            return Ok(Command::Event(
+                TelnetEvent::WindowSize { width, height },
+                len,
+            ));
+        }
+
+        return Ok(Command::Ignored(len));
+    }
+
+    Ok(Command::Event(
+        TelnetEvent::Subnegotiation(option, data),
+        len,
+    ))
+}
+
+impl Encoder<TelnetOutput> for TelnetCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: TelnetOutput, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        match item {
+            TelnetOutput::Line(line) => {
+                for byte in line.bytes() {
+                    match byte {
+                        IAC => dst.put_slice(&[IAC, IAC]),
+                        b'\n' => dst.put_slice(b"\r\n"),
+                        _ => dst.put_u8(byte),
+                    }
+                }
+                dst.put_slice(b"\r\n");
+            }
+            TelnetOutput::Will(option) => dst.put_slice(&[IAC, WILL, option]),
+            TelnetOutput::Wont(option) => dst.put_slice(&[IAC, WONT, option]),
+            TelnetOutput::Do(option) => dst.put_slice(&[IAC, DO, option]),
+            TelnetOutput::Dont(option) => dst.put_slice(&[IAC, DONT, option]),
+            TelnetOutput::Subnegotiation(option, data) => {
+                dst.put_slice(&[IAC, SB, option]);
+                for byte in data {
+                    if byte == IAC {
+                        dst.put_slice(&[IAC, IAC]);
+                    } else {
+                        dst.put_u8(byte);
+                    }
+                }
+                dst.put_slice(&[IAC, SE]);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_all(codec: &mut TelnetCodec, bytes: &[u8]) -> Vec<TelnetEvent> {
+        let mut src = BytesMut::from(bytes);
+        let mut events = Vec::new();
+        while let Some(event) = codec.decode(&mut src).unwrap() {
+            events.push(event);
+        }
+
+        events
+    }
+
+    #[test]
+    fn decode_lines() {
+        let mut codec = TelnetCodec::new();
+
+        let events = decode_all(&mut codec, b"look\r\nget thing\n");
+
+        assert_eq!(
+            vec![
+                TelnetEvent::Line("look".to_string()),
+                TelnetEvent::Line("get thing".to_string())
+            ],
+            events
+        );
+    }
+
+    #[test]
+    fn decode_commands_mixed_with_line() {
+        let mut codec = TelnetCodec::new();
+
+        let events = decode_all(
+            &mut codec,
+            &[
+                b'l',
+                IAC,
+                WILL,
+                OPTION_NAWS,
+                b'o',
+                IAC,
+                241,
+                b'o',
+                b'k',
+                b'\r',
+                b'\n',
+            ],
+        );
+
+        assert_eq!(
+            vec![
+                TelnetEvent::Will(OPTION_NAWS),
+                TelnetEvent::Line("look".to_string())
+            ],
+            events
+        );
+    }
+
+    #[test]
+    fn decode_window_size() {
+        let mut codec = TelnetCodec::new();
+
+        let events = decode_all(&mut codec, &[IAC, SB, OPTION_NAWS, 0, 120, 0, 40, IAC, SE]);
+
+        assert_eq!(
+            vec![TelnetEvent::WindowSize {
+                width: 120,
+                height: 40
+            }],
+            events
+        );
+    }
+
+    #[test]
+    fn decode_window_size_with_escaped_iac() {
+        let mut codec = TelnetCodec::new();
+
+        let events = decode_all(
+            &mut codec,
+            &[IAC, SB, OPTION_NAWS, 0, IAC, IAC, 0, 40, IAC, SE],
+        );
+
+        assert_eq!(
+            vec![TelnetEvent::WindowSize {
+                width: 255,
+                height: 40
+            }],
+            events
+        );
+    }
+
+    #[test]
+    fn decode_command_split_across_reads() {
+        let mut codec = TelnetCodec::new();
+        let mut src = BytesMut::from(&[IAC, SB, OPTION_NAWS, 0][..]);
+
+        assert_eq!(None, codec.decode(&mut src).unwrap());
+
+        src.extend_from_slice(&[80, 0, 24, IAC, SE]);
+        assert_eq!(
+            Some(TelnetEvent::WindowSize {
+                width: 80,
+                height: 24
+            }),
+            codec.decode(&mut src).unwrap()
+        );
+    }
+
+    #[test]
+    fn encode_line() {
+        let mut codec = TelnetCodec::new();
+        let mut dst = BytesMut::new();
+
+        codec
+            .encode(TelnetOutput::Line("a\nb\u{ff}".to_string()), &mut dst)
+            .unwrap();
+
+        assert_eq!(&b"a\r\nb\xc3\xbf\r\n"[..], &dst[..]);
+    }
+
+    #[test]
+    fn encode_negotiation() {
+        let mut codec = TelnetCodec::new();
+        let mut dst = BytesMut::new();
+
+        codec
+            .encode(TelnetOutput::Will(OPTION_ECHO), &mut dst)
+            .unwrap();
+        codec
+            .encode(TelnetOutput::Do(OPTION_NAWS), &mut dst)
+            .unwrap();
+
+        assert_eq!(
+            &[IAC, WILL, OPTION_ECHO, IAC, DO, OPTION_NAWS][..],
+            &dst[..]
+        );
+    }
+
+    #[test]
+    fn encode_subnegotiation() {
+        let mut codec = TelnetCodec::new();
+        let mut dst = BytesMut::new();
+
+        codec
+            .encode(
+                TelnetOutput::Subnegotiation(OPTION_GMCP, vec![b'a', IAC, b'b']),
+                &mut dst,
+            )
+            .unwrap();
+
+        assert_eq!(
+            &[IAC, SB, OPTION_GMCP, b'a', IAC, IAC, b'b', IAC, SE][..],
+            &dst[..]
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use core_logic::{Game, GameMessage, PlayerPreferences, Time};
+use futures::{stream, SinkExt, Stream, StreamExt};
+use log::{debug, info, warn};
+use serde_json::json;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::mpsc::{self, UnboundedSender},
+};
+use tokio_tungstenite::{
+    accept_hdr_async,
+    tungstenite::{
+        self,
+        handshake::server::{Request, Response},
+        Message,
+    },
+};
+
+use crate::{
+    accounts::AccountStore,
+    message_to_string,
+    session::{run_session, SessionOutput},
+    RenderOptions, RenderProfile, DEFAULT_WIDTH,
+};
+
+/// The format to send output to a WebSocket client in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    /// Each message is sent as the same text a telnet client would get.
+    Text,
+    /// Each message is sent as a JSON object, containing both the rendered text and the structured version of the message.
+    Json,
+}
+
+/// The options a WebSocket client chose via the query string of the URL it connected to, like `?format=json&width=100`.
+#[derive(Debug, PartialEq, Eq)]
+struct ClientOptions {
+    /// The format to send output in.
+    format: OutputFormat,
+    /// The width of the client's display, in characters.
+    width: usize,
+    /// The render profile the client asked for, if any. Replaces the one in the player's saved preferences.
+    profile: Option<RenderProfile>,
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        ClientOptions {
+            format: OutputFormat::Text,
+            width: DEFAULT_WIDTH,
+            profile: None,
+        }
+    }
+}
+
+impl ClientOptions {
+    /// Parses client options from the provided query string. Unknown or invalid options are ignored.
+    fn from_query(query: Option<&str>) -> ClientOptions {
+        let mut options = ClientOptions::default();
+
+        for (key, value) in query
+            .unwrap_or_default()
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+        {
+            match key {
+                "format" => match value {
+                    "text" => options.format = OutputFormat::Text,
+                    "json" => options.format = OutputFormat::Json,
+                    _ => (),
+                },
+                "width" => {
+                    if let Ok(width) = value.parse() {
+                        options.width = width;
+                    }
+                }
+                "profile" => match value {
+                    "standard" => options.profile = Some(RenderProfile::Standard),
+                    "screen_reader" => options.profile = Some(RenderProfile::ScreenReader),
+                    _ => (),
+                },
+                _ => (),
+            }
+        }
+
+        options
+    }
+}
+
+pub async fn start_websocket_server(
+    game: Arc<Mutex<Game>>,
+    accounts: Arc<Mutex<AccountStore>>,
+) -> Result<()> {
+    let addr = "0.0.0.0:8081".to_string();
+
+    let listener = TcpListener::bind(&addr).await?;
+    info!("Listening for WebSocket connections on: {}", addr);
+
+    let mut next_connection_id = 0;
+    loop {
+        let (socket, _) = listener.accept().await?;
+
+        let connection_id = next_connection_id;
+        next_connection_id += 1;
+
+        info!("WebSocket connection {connection_id} has opened");
+
+        let game = Arc::clone(&game);
+        let accounts = Arc::clone(&accounts);
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(socket, connection_id, game, accounts).await {
+                warn!("Error on WebSocket connection {connection_id}: {e:?}");
+            }
+
+            info!("WebSocket connection {connection_id} has closed");
+        });
+    }
+}
+
+/// Sends output to a WebSocket client.
+struct WebSocketSessionOutput {
+    /// Sends messages to the task writing to the socket.
+    output_sender: UnboundedSender<Message>,
+    /// The format to send output in.
+    format: OutputFormat,
+    /// The width of the client's display, in characters.
+    width: usize,
+}
+
+impl SessionOutput for WebSocketSessionOutput {
+    fn send_login_message(&self, message: String, expecting_password: bool) {
+        let _ = self
+            .output_sender
+            .send(login_output(self.format, message, expecting_password));
+    }
+
+    fn send_notice(&self, message: String) {
+        let _ = self.output_sender.send(notice_output(self.format, message));
+    }
+
+    fn send_game_message(
+        &self,
+        message: GameMessage,
+        time: Time,
+        preferences: &PlayerPreferences,
+    ) -> bool {
+        let render_options = RenderOptions::new(self.width, preferences);
+        self.output_sender
+            .send(game_output(self.format, message, time, render_options))
+            .is_ok()
+    }
+}
+
+/// Logs in the player on the provided socket, then connects them to the game.
+async fn handle_connection(
+    socket: TcpStream,
+    connection_id: u32,
+    game: Arc<Mutex<Game>>,
+    accounts: Arc<Mutex<AccountStore>>,
+) -> Result<()> {
+    let mut options = ClientOptions::default();
+    // the callback's signature is dictated by tungstenite, so its error type can't be made smaller
+    #[allow(clippy::result_large_err)]
+    let websocket = accept_hdr_async(socket, |request: &Request, response: Response| {
+        options = ClientOptions::from_query(request.uri().query());
+        Ok(response)
+    })
+    .await?;
+    debug!("WebSocket connection {connection_id} has options {options:?}");
+    let (mut sink, stream) = websocket.split();
+
+    // spawn task for writing to the socket, so output can be sent from multiple places
+    let (output_sender, mut output_receiver) = mpsc::unbounded_channel::<Message>();
+    tokio::spawn(async move {
+        while let Some(output) = output_receiver.recv().await {
+            if let Err(e) = sink.send(output).await {
+                debug!("Unable to write to WebSocket: {e:?}");
+                return;
+            }
+        }
+
+        let _ = sink.close().await;
+    });
+
+    let lines = stream::unfold(stream, |mut stream| async move {
+        let text = next_text(&mut stream).await?;
+        Some((text, stream))
+    });
+    let output = Arc::new(WebSocketSessionOutput {
+        output_sender,
+        format: options.format,
+        width: options.width,
+    });
+
+    run_session(
+        lines,
+        output,
+        &format!("WebSocket connection {connection_id}"),
+        options.profile,
+        game,
+        accounts,
+    )
+    .await
+}
+
+/// Reads from the provided stream until a text message is received.
+/// Returns `None` once the client disconnects.
+async fn next_text(
+    stream: &mut (impl Stream<Item = Result<Message, tungstenite::Error>> + Unpin),
+) -> Option<Result<String, tungstenite::Error>> {
+    loop {
+        match stream.next().await? {
+            Ok(Message::Text(text)) => return Some(Ok(text)),
+            Ok(Message::Close(_)) => return None,
+            // pings are responded to automatically, and nothing else is needed
+            Ok(_) => (),
+            Err(e) => return Some(Err(e)),
+        }
+    }
+}
+
+/// Builds the message to send a client to tell them about something that happened to their connection.
+fn notice_output(format: OutputFormat, text: String) -> Message {
+    match format {
+        OutputFormat::Text => Message::Text(text),
+        OutputFormat::Json => Message::Text(
+            json!({
+                "type": "notice",
+                "text": text,
+            })
+            .to_string(),
+        ),
+    }
+}
+
+/// Builds the message to send a client during login.
+fn login_output(format: OutputFormat, text: String, expecting_password: bool) -> Message {
+    match format {
+        OutputFormat::Text => Message::Text(text),
+        OutputFormat::Json => Message::Text(
+            json!({
+                "type": "login",
+                "text": text,
+                "expecting_password": expecting_password,
+            })
+            .to_string(),
+        ),
+    }
+}
+
+/// Builds the message to send a client for the provided game message.
+fn game_output(
+    format: OutputFormat,
+    message: GameMessage,
+    time: Time,
+    render_options: RenderOptions,
+) -> Message {
+    match format {
+        OutputFormat::Text => Message::Text(message_to_string(message, Some(time), render_options)),
+        OutputFormat::Json => {
+            let structured = message.to_json();
+            let text = message_to_string(message, Some(time), render_options);
+            Message::Text(
+                json!({
+                    "type": "message",
+                    "text": text,
+                    "message": structured,
+                })
+                .to_string(),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_from_no_query() {
+        assert_eq!(ClientOptions::default(), ClientOptions::from_query(None));
+    }
+
+    #[test]
+    fn options_from_query() {
+        let options =
+            ClientOptions::from_query(Some("width=120&format=json&profile=screen_reader"));
+
+        assert_eq!(
+            ClientOptions {
+                format: OutputFormat::Json,
+                width: 120,
+                profile: Some(RenderProfile::ScreenReader),
+            },
+            options
+        );
+    }
+
+    #[test]
+    fn options_from_invalid_query() {
+        let options = ClientOptions::from_query(Some("format=xml&width=wide&whatever"));
+
+        assert_eq!(ClientOptions::default(), options);
+    }
+
+    #[test]
+    fn json_login_output() {
+        let output = login_output(OutputFormat::Json, "Enter your password:".to_string(), true);
+
+        let Message::Text(text) = output else {
+            panic!("output should be text");
+        };
+        assert_eq!(
+            json!({
+                "type": "login",
+                "text": "Enter your password:",
+                "expecting_password": true,
+            }),
+            serde_json::from_str::<serde_json::Value>(&text).unwrap()
+        );
+    }
+}